@@ -0,0 +1,263 @@
+//! Checkpoint stack for multi-level undo and redo.
+//!
+//! A checkpoint copies every runtime node. Values and schema nodes are
+//! `Arc`-backed, so a copy clones pointers rather than data.
+
+use std::collections::VecDeque;
+
+use super::Context;
+use crate::core::{FxHashMap, Key};
+use crate::runtime::ErasedRuntimeNode;
+
+/// Number of checkpoints kept unless changed with
+/// [`Context::set_history_limit`].
+pub const DEFAULT_HISTORY_LIMIT: usize = 100;
+
+type Snapshot = FxHashMap<Key, ErasedRuntimeNode>;
+
+/// Undo and redo stacks of a [`Context`].
+#[derive(Debug)]
+pub(super) struct History {
+    /// Oldest checkpoint first.
+    undo: VecDeque<Snapshot>,
+    /// Most recently undone state last.
+    redo: Vec<Snapshot>,
+    limit: usize,
+}
+
+impl Default for History {
+    fn default() -> Self {
+        Self {
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            limit: DEFAULT_HISTORY_LIMIT,
+        }
+    }
+}
+
+impl History {
+    /// Returns an empty history with the same limit.
+    pub(super) fn fresh(&self) -> Self {
+        Self {
+            limit: self.limit,
+            ..Self::default()
+        }
+    }
+
+    fn push_undo(&mut self, snapshot: Snapshot) {
+        if self.limit == 0 {
+            return;
+        }
+        while self.undo.len() >= self.limit {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(snapshot);
+    }
+}
+
+impl Context {
+    /// Records the current values and states as an undo point.
+    ///
+    /// Clears the redo stack. When the stack holds
+    /// [`history_limit`](Self::history_limit) checkpoints, the oldest is
+    /// dropped. Does nothing if the limit is `0`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::context::Context;
+    /// use paramdef::core::Value;
+    /// use paramdef::schema::Schema;
+    /// use paramdef::types::leaf::Text;
+    /// use std::sync::Arc;
+    ///
+    /// let schema = Arc::new(Schema::builder()
+    ///     .parameter(Text::builder("title").build())
+    ///     .build());
+    /// let mut ctx = Context::new(schema);
+    ///
+    /// ctx.set("title", Value::text("Draft"));
+    /// ctx.checkpoint();
+    /// ctx.set("title", Value::text("Final"));
+    ///
+    /// assert!(ctx.undo());
+    /// assert_eq!(ctx.get_text("title"), Some("Draft"));
+    /// assert!(ctx.redo());
+    /// assert_eq!(ctx.get_text("title"), Some("Final"));
+    /// ```
+    pub fn checkpoint(&mut self) {
+        let snapshot = self.nodes.clone();
+        self.history.redo.clear();
+        self.history.push_undo(snapshot);
+    }
+
+    /// Restores the most recent checkpoint.
+    ///
+    /// The current state is pushed onto the redo stack first. Returns
+    /// `false` if there is no checkpoint.
+    pub fn undo(&mut self) -> bool {
+        let Some(snapshot) = self.history.undo.pop_back() else {
+            return false;
+        };
+        let current = self.restore(snapshot);
+        self.history.redo.push(current);
+        true
+    }
+
+    /// Reapplies the most recently undone state.
+    ///
+    /// The current state is pushed back onto the undo stack. Returns
+    /// `false` if there is nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        let Some(snapshot) = self.history.redo.pop() else {
+            return false;
+        };
+        let current = self.restore(snapshot);
+        self.history.push_undo(current);
+        true
+    }
+
+    /// Returns `true` if [`undo`](Self::undo) would restore a checkpoint.
+    #[must_use]
+    pub fn can_undo(&self) -> bool {
+        !self.history.undo.is_empty()
+    }
+
+    /// Returns `true` if [`redo`](Self::redo) would reapply a state.
+    #[must_use]
+    pub fn can_redo(&self) -> bool {
+        !self.history.redo.is_empty()
+    }
+
+    /// Returns the maximum number of checkpoints kept.
+    #[must_use]
+    pub fn history_limit(&self) -> usize {
+        self.history.limit
+    }
+
+    /// Sets the maximum number of checkpoints kept.
+    ///
+    /// The default is [`DEFAULT_HISTORY_LIMIT`]. Lowering the limit drops
+    /// the oldest checkpoints and redo states beyond it; `0` disables
+    /// history.
+    pub fn set_history_limit(&mut self, limit: usize) {
+        self.history.limit = limit;
+        let excess = self.history.undo.len().saturating_sub(limit);
+        self.history.undo.drain(..excess);
+        let excess = self.history.redo.len().saturating_sub(limit);
+        self.history.redo.drain(..excess);
+    }
+
+    /// Discards all checkpoints and redo states.
+    pub fn clear_history(&mut self) {
+        self.history.undo.clear();
+        self.history.redo.clear();
+    }
+
+    /// Replaces the runtime nodes with `snapshot`, returning the old ones.
+    ///
+    /// Emits a change event for each parameter whose value differs.
+    fn restore(&mut self, snapshot: Snapshot) -> Snapshot {
+        let previous = std::mem::replace(&mut self.nodes, snapshot);
+        #[cfg(feature = "events")]
+        {
+            let keys: Vec<Key> = self.schema.keys().cloned().collect();
+            for key in keys {
+                let old = previous.get(&key).and_then(|n| n.value()).cloned();
+                self.emit_change(&key, old);
+            }
+        }
+        previous
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crate::core::Value;
+    use crate::schema::Schema;
+    use crate::types::leaf::Number;
+
+    use super::*;
+
+    fn context() -> Context {
+        let schema = Schema::builder()
+            .parameter(Number::builder("x").build())
+            .build();
+        Context::new(Arc::new(schema))
+    }
+
+    #[test]
+    fn test_history_undo_redo() {
+        let mut ctx = context();
+        assert!(!ctx.undo());
+        assert!(!ctx.redo());
+
+        for i in 1..=3 {
+            ctx.checkpoint();
+            ctx.set("x", Value::Int(i));
+        }
+
+        assert!(ctx.undo());
+        assert_eq!(ctx.get_int("x"), Some(2));
+        assert!(ctx.undo());
+        assert!(ctx.undo());
+        assert_eq!(ctx.get("x"), None);
+        assert!(!ctx.can_undo());
+
+        assert!(ctx.redo());
+        assert!(ctx.redo());
+        assert_eq!(ctx.get_int("x"), Some(2));
+
+        // A new checkpoint discards the redo stack.
+        ctx.checkpoint();
+        assert!(!ctx.can_redo());
+    }
+
+    #[test]
+    fn test_history_restores_state() {
+        let mut ctx = context();
+        ctx.set("x", Value::Int(1));
+        ctx.checkpoint();
+        ctx.mark_all_clean();
+
+        assert!(ctx.undo());
+        assert!(ctx.is_dirty());
+    }
+
+    #[test]
+    fn test_history_limit() {
+        let mut ctx = context();
+        ctx.set_history_limit(2);
+
+        for i in 1..=4 {
+            ctx.set("x", Value::Int(i));
+            ctx.checkpoint();
+        }
+
+        assert!(ctx.undo());
+        assert!(ctx.undo());
+        assert_eq!(ctx.get_int("x"), Some(3));
+        assert!(!ctx.undo());
+
+        ctx.set_history_limit(0);
+        assert!(!ctx.can_redo());
+        ctx.checkpoint();
+        assert!(!ctx.can_undo());
+        assert_eq!(ctx.history_limit(), 0);
+    }
+
+    #[test]
+    fn test_history_not_forked() {
+        let mut ctx = context();
+        ctx.checkpoint();
+
+        let fork = ctx.fork();
+        assert!(!fork.can_undo());
+        assert_eq!(fork.history_limit(), DEFAULT_HISTORY_LIMIT);
+
+        ctx.clear_history();
+        assert!(!ctx.can_undo());
+    }
+}
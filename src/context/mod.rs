@@ -6,11 +6,76 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use crate::core::{FxHashMap, Key, Value};
-use crate::runtime::ErasedRuntimeNode;
+use crate::core::{Error, FxHashMap, FxHashSet, IndexMap, Key, Result, Value};
+use crate::runtime::{ErasedRuntimeNode, State, redact_value};
 use crate::schema::Schema;
 use rustc_hash::FxBuildHasher;
 
+#[cfg(feature = "events")]
+use crate::event::{CHANNEL_CAPACITY, ChangeEvent, Receiver, SharedWatchers, WatchHandle};
+#[cfg(feature = "events")]
+use tokio::sync::broadcast;
+
+mod history;
+mod query;
+#[cfg(feature = "serde")]
+mod session;
+#[cfg(feature = "toml")]
+mod toml_support;
+
+pub use history::DEFAULT_HISTORY_LIMIT;
+pub use query::ParamQuery;
+
+/// Outcome of applying a set of external values to a [`Context`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ApplyReport {
+    /// Keys whose values were set, in input order.
+    pub applied: Vec<Key>,
+    /// Input keys that do not name a parameter in the schema.
+    pub unknown: Vec<Key>,
+    /// Input entries whose values could not be converted, with the reason.
+    pub failed: Vec<ApplyFailure>,
+}
+
+/// An input entry listed as failed in an [`ApplyReport`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApplyFailure {
+    /// The input key.
+    pub key: Key,
+    /// The error code, such as `"invalid_input"`.
+    pub code: String,
+    /// The human-readable reason.
+    pub message: String,
+}
+
+impl ApplyFailure {
+    /// Records `err` as the reason the entry for `key` was not applied.
+    pub(crate) fn new(key: Key, err: Error) -> Self {
+        match err.into_validation() {
+            Error::Validation { code, message, .. } => Self { key, code, message },
+            other => Self {
+                key,
+                code: "invalid".to_owned(),
+                message: other.to_string(),
+            },
+        }
+    }
+}
+
+/// Updates made by [`Context::on_change`] after a parameter changed.
+///
+/// Each list is in evaluation order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChangeEffects {
+    /// Keys whose validation was re-run, starting with the changed key.
+    pub revalidated: Vec<Key>,
+    /// Computed dependents whose values are now out of date. Recompute
+    /// them in this order with [`Context::set_computed`].
+    pub recomputed: Vec<Key>,
+    /// Dependents that became visible or hidden.
+    pub visibility_changed: Vec<Key>,
+}
+
 /// Runtime manager for a parameter tree.
 ///
 /// Context instantiates runtime nodes for each parameter in a schema,
@@ -42,6 +107,19 @@ pub struct Context {
     /// Runtime nodes indexed by key.
     /// Uses `FxHashMap` for ~2x faster lookups with small keys.
     nodes: FxHashMap<Key, ErasedRuntimeNode>,
+    /// Change event sender, set by [`Context::with_events`].
+    #[cfg(feature = "events")]
+    events: Option<broadcast::Sender<ChangeEvent>>,
+    /// Keys changed during the current [`Context::batch`], if any.
+    #[cfg(feature = "events")]
+    batch_keys: Option<Vec<Key>>,
+    /// Per-key callbacks registered by [`Context::watch`].
+    #[cfg(feature = "events")]
+    watchers: SharedWatchers,
+    /// Lowercased key index, set by [`Context::with_case_insensitive_keys`].
+    ci_index: Option<FxHashMap<Key, Vec<Key>>>,
+    /// Checkpoints recorded by [`Context::checkpoint`].
+    history: history::History,
 }
 
 impl Context {
@@ -58,7 +136,97 @@ impl Context {
             nodes.insert(key, ErasedRuntimeNode::from_arc(Arc::clone(node)));
         }
 
-        Self { schema, nodes }
+        Self {
+            schema,
+            nodes,
+            #[cfg(feature = "events")]
+            events: None,
+            #[cfg(feature = "events")]
+            batch_keys: None,
+            #[cfg(feature = "events")]
+            watchers: SharedWatchers::default(),
+            ci_index: None,
+            history: history::History::default(),
+        }
+    }
+
+    /// Creates a new context with every parameter set to its default.
+    ///
+    /// Each node's [`initial_value`](crate::types::traits::Node::initial_value)
+    /// is applied: leaves get their default value and `Mode` containers
+    /// their default variant. Seeded parameters are clean, not dirty.
+    /// Parameters without a default start empty, as with
+    /// [`new`](Self::new).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::context::Context;
+    /// use paramdef::core::Value;
+    /// use paramdef::schema::Schema;
+    /// use paramdef::types::leaf::{Number, Text};
+    /// use std::sync::Arc;
+    ///
+    /// let schema = Arc::new(Schema::builder()
+    ///     .parameter(Number::builder("port").default(8080.0).build())
+    ///     .parameter(Text::builder("host").build())
+    ///     .build());
+    ///
+    /// let ctx = Context::with_defaults(schema);
+    /// assert_eq!(ctx.get("port"), Some(&Value::Float(8080.0)));
+    /// assert_eq!(ctx.get("host"), None);
+    /// assert!(!ctx.is_dirty());
+    /// ```
+    #[must_use]
+    pub fn with_defaults(schema: Arc<Schema>) -> Self {
+        let mut ctx = Self::new(schema);
+        for node in ctx.nodes.values_mut() {
+            if let Some(value) = node.node().initial_value() {
+                node.set_value(value);
+                node.state_mut().mark_clean();
+            }
+        }
+        ctx
+    }
+
+    /// Builds an index for case-insensitive lookups.
+    ///
+    /// [`get_ci`](Self::get_ci) and [`set_ci`](Self::set_ci) work without
+    /// it, but scan every key. Exact-match lookups never use the index.
+    #[must_use]
+    pub fn with_case_insensitive_keys(mut self) -> Self {
+        let mut index: FxHashMap<Key, Vec<Key>> = FxHashMap::default();
+        for key in self.schema.keys() {
+            index
+                .entry(Key::from(key.to_lowercase()))
+                .or_default()
+                .push(key.clone());
+        }
+        self.ci_index = Some(index);
+        self
+    }
+
+    /// Enables change events, returning the context and a receiver.
+    ///
+    /// Every [`set`](Self::set) or [`clear`](Self::clear) that changes a
+    /// value emits a [`ChangeEvent`]; writing an equal value emits nothing.
+    /// More receivers can be created with [`subscribe`](Self::subscribe).
+    ///
+    /// The channel holds [`CHANNEL_CAPACITY`] events. Slow receivers lose
+    /// the oldest events rather than blocking the context.
+    #[cfg(feature = "events")]
+    #[must_use]
+    pub fn with_events(mut self) -> (Self, Receiver<ChangeEvent>) {
+        let (tx, rx) = broadcast::channel(CHANNEL_CAPACITY);
+        self.events = Some(tx);
+        (self, rx)
+    }
+
+    /// Returns a new change event receiver, if events are enabled.
+    #[cfg(feature = "events")]
+    #[must_use]
+    pub fn subscribe(&self) -> Option<Receiver<ChangeEvent>> {
+        self.events.as_ref().map(broadcast::Sender::subscribe)
     }
 
     /// Returns a reference to the schema.
@@ -85,15 +253,161 @@ impl Context {
         self.nodes.get(key).and_then(|n| n.value())
     }
 
+    /// Gets a value by key as an integer.
+    ///
+    /// Follows [`Value::as_i64`]: floats are truncated.
+    #[must_use]
+    pub fn get_int(&self, key: &str) -> Option<i64> {
+        self.get(key).and_then(Value::as_i64)
+    }
+
+    /// Gets a value by key as a float.
+    ///
+    /// Follows [`Value::as_f64`]: integers are widened.
+    #[must_use]
+    pub fn get_float(&self, key: &str) -> Option<f64> {
+        self.get(key).and_then(Value::as_f64)
+    }
+
+    /// Gets a value by key as a boolean.
+    #[must_use]
+    pub fn get_bool(&self, key: &str) -> Option<bool> {
+        self.get(key).and_then(Value::as_bool)
+    }
+
+    /// Gets a value by key as text.
+    #[must_use]
+    pub fn get_text(&self, key: &str) -> Option<&str> {
+        self.get(key).and_then(Value::as_text)
+    }
+
+    /// Gets a value by key converted to `T`, or `default`.
+    ///
+    /// Falls back to `default` if the parameter is unknown, has no value,
+    /// or holds a value that does not convert to `T`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::context::Context;
+    /// use paramdef::core::Value;
+    /// use paramdef::schema::Schema;
+    /// use paramdef::types::leaf::Number;
+    /// use std::sync::Arc;
+    ///
+    /// let schema = Arc::new(Schema::builder()
+    ///     .parameter(Number::builder("retries").build())
+    ///     .build());
+    ///
+    /// let mut ctx = Context::new(schema);
+    /// assert_eq!(ctx.get_or("retries", 3_i64), 3);
+    ///
+    /// ctx.set("retries", Value::Int(5));
+    /// assert_eq!(ctx.get_or("retries", 3_i64), 5);
+    /// assert_eq!(ctx.get_or("retries", 0.0), 5.0);
+    /// ```
+    #[must_use]
+    pub fn get_or<T: TryFrom<Value>>(&self, key: &str, default: T) -> T {
+        self.get(key)
+            .and_then(|value| T::try_from(value.clone()).ok())
+            .unwrap_or(default)
+    }
+
     /// Sets a value by key.
     ///
-    /// Returns `true` if the parameter exists and was updated.
+    /// Returns `true` if the parameter exists and was updated. Parameters
+    /// flagged [`COMPUTED`](crate::core::Flags::COMPUTED) are refused; the
+    /// evaluation engine writes them with [`set_computed`](Self::set_computed).
     pub fn set(&mut self, key: &str, value: Value) -> bool {
-        if let Some(node) = self.nodes.get_mut(key) {
-            node.set_value(value);
-            true
-        } else {
-            false
+        if self
+            .nodes
+            .get(key)
+            .is_some_and(|n| n.node().flags().is_computed())
+        {
+            return false;
+        }
+        self.write(key, value)
+    }
+
+    /// Sets the value of a computed parameter.
+    ///
+    /// Used by the evaluation engine to store derived values. Unlike
+    /// [`set`](Self::set), this accepts parameters flagged
+    /// [`COMPUTED`](crate::core::Flags::COMPUTED). Returns `true` if the
+    /// parameter exists and was updated.
+    pub fn set_computed(&mut self, key: &str, value: Value) -> bool {
+        self.write(key, value)
+    }
+
+    /// Stores `value` and emits a change event.
+    fn write(&mut self, key: &str, value: Value) -> bool {
+        #[cfg(feature = "events")]
+        let tracked = self.events.is_some() || crate::event::is_watched(&self.watchers, key);
+        let Some(node) = self.nodes.get_mut(key) else {
+            return false;
+        };
+
+        #[cfg(feature = "events")]
+        let old = tracked.then(|| node.value().cloned());
+        node.set_value(value);
+        #[cfg(feature = "events")]
+        if let Some(old) = old {
+            self.emit_change(key, old);
+        }
+
+        true
+    }
+
+    /// Gets a value by key, ignoring case.
+    ///
+    /// An exact match always wins. Otherwise returns `None` if no key
+    /// matches, or if several keys match that differ only in case.
+    #[must_use]
+    pub fn get_ci(&self, key: &str) -> Option<&Value> {
+        let key = self.resolve_ci(key).ok()??;
+        self.get(key.as_str())
+    }
+
+    /// Sets a value by key, ignoring case.
+    ///
+    /// Matching follows [`get_ci`](Self::get_ci). Returns `Ok(true)` if a
+    /// parameter was updated and `Ok(false)` if no key matches.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `"ambiguous_key"` validation error listing the candidates
+    /// if several keys differ from `key` only in case.
+    pub fn set_ci(&mut self, key: &str, value: Value) -> Result<bool> {
+        match self.resolve_ci(key)? {
+            Some(key) => Ok(self.set(key.as_str(), value)),
+            None => Ok(false),
+        }
+    }
+
+    /// Finds the parameter key matching `key` case-insensitively.
+    fn resolve_ci(&self, key: &str) -> Result<Option<Key>> {
+        if self.nodes.contains_key(key) {
+            return Ok(Some(Key::from(key)));
+        }
+
+        let lower = key.to_lowercase();
+        let candidates: Vec<&Key> = match &self.ci_index {
+            Some(index) => index.get(lower.as_str()).into_iter().flatten().collect(),
+            None => self
+                .schema
+                .keys()
+                .filter(|k| k.to_lowercase() == lower)
+                .collect(),
+        };
+
+        match candidates.as_slice() {
+            [] => Ok(None),
+            [key] => Ok(Some((*key).clone())),
+            _ => Err(Error::validation_with_fields(
+                "ambiguous_key",
+                format!("key '{key}' matches several parameters ignoring case"),
+                candidates.iter().map(ToString::to_string).collect(),
+            )),
         }
     }
 
@@ -101,11 +415,118 @@ impl Context {
     ///
     /// Returns `true` if the parameter exists.
     pub fn clear(&mut self, key: &str) -> bool {
-        if let Some(node) = self.nodes.get_mut(key) {
-            node.clear_value();
-            true
-        } else {
-            false
+        #[cfg(feature = "events")]
+        let tracked = self.events.is_some() || crate::event::is_watched(&self.watchers, key);
+        let Some(node) = self.nodes.get_mut(key) else {
+            return false;
+        };
+
+        #[cfg(feature = "events")]
+        let old = tracked.then(|| node.value().cloned());
+        node.clear_value();
+        #[cfg(feature = "events")]
+        if let Some(old) = old {
+            self.emit_change(key, old);
+        }
+
+        true
+    }
+
+    /// Applies several changes, emitting a single change event.
+    ///
+    /// Events from [`set`](Self::set) and [`clear`](Self::clear) calls inside
+    /// `f` are suppressed; afterwards one [`ChangeEvent::Batch`] lists every
+    /// key that changed. Nothing is emitted if no value changed. Dirty state
+    /// is still tracked per field. Nested batches merge into the outermost.
+    #[cfg(feature = "events")]
+    pub fn batch(&mut self, f: impl FnOnce(&mut Context)) {
+        if self.batch_keys.is_some() {
+            f(self);
+            return;
+        }
+
+        self.batch_keys = Some(Vec::new());
+        let guard = BatchGuard(self);
+        f(guard.0);
+        let keys = guard.0.batch_keys.take().unwrap_or_default();
+        drop(guard);
+
+        if keys.is_empty() {
+            return;
+        }
+        if let Some(tx) = &self.events {
+            // An error only means there are no receivers right now.
+            let _ = tx.send(ChangeEvent::Batch { keys });
+        }
+    }
+
+    /// Calls `f` whenever the value of `key` changes.
+    ///
+    /// The callback receives the new value, or [`Value::Null`] when the
+    /// parameter is cleared. Like change events, it only fires when the
+    /// value actually differs, but it runs immediately, even inside a
+    /// [`batch`](Self::batch). A key may have several watchers; they run
+    /// in registration order. Watching an unknown key is allowed but never
+    /// fires. Forks do not inherit watchers.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::context::Context;
+    /// use paramdef::core::Value;
+    /// use paramdef::schema::Schema;
+    /// use paramdef::types::leaf::Text;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// let schema = Arc::new(Schema::builder()
+    ///     .parameter(Text::builder("name").build())
+    ///     .build());
+    /// let mut ctx = Context::new(schema);
+    ///
+    /// let calls = Arc::new(AtomicUsize::new(0));
+    /// let counter = Arc::clone(&calls);
+    /// let handle = ctx.watch("name", move |_| {
+    ///     counter.fetch_add(1, Ordering::Relaxed);
+    /// });
+    ///
+    /// ctx.set("name", Value::text("alice"));
+    /// handle.unsubscribe();
+    /// ctx.set("name", Value::text("bob"));
+    /// assert_eq!(calls.load(Ordering::Relaxed), 1);
+    /// ```
+    #[cfg(feature = "events")]
+    pub fn watch(&mut self, key: &str, f: impl Fn(&Value) + Send + Sync + 'static) -> WatchHandle {
+        crate::event::add_watcher(&self.watchers, Key::from(key), Arc::new(f))
+    }
+
+    /// Notifies watchers and sends a change event if the value of `key`
+    /// differs from `old`.
+    ///
+    /// Inside a batch the key is recorded instead of sending an event.
+    #[cfg(feature = "events")]
+    fn emit_change(&mut self, key: &str, old: Option<Value>) {
+        let new = self.get(key).cloned();
+        if old == new {
+            return;
+        }
+
+        crate::event::notify(&self.watchers, key, new.as_ref().unwrap_or(&Value::Null));
+        if self.events.is_none() {
+            return;
+        }
+
+        if let Some(keys) = &mut self.batch_keys {
+            if !keys.iter().any(|k| k == key) {
+                keys.push(Key::from(key));
+            }
+        } else if let Some(tx) = &self.events {
+            // An error only means there are no receivers right now.
+            let _ = tx.send(ChangeEvent::Changed {
+                key: Key::from(key),
+                old,
+                new,
+            });
         }
     }
 
@@ -122,128 +543,1225 @@ impl Context {
     }
 
     /// Collects all values into a map.
+    ///
+    /// Only [value-bearing](crate::types::kind::NodeKind::is_value_bearing)
+    /// nodes are included; structural nodes such as decorations are
+    /// skipped even if a value was set on them.
     #[must_use]
     pub fn collect_values(&self) -> HashMap<Key, Value> {
         self.nodes
             .iter()
+            .filter(|(_, n)| n.node().kind().is_value_bearing())
             .filter_map(|(k, n)| n.value().map(|v| (k.clone(), v.clone())))
             .collect()
     }
 
-    /// Collects only dirty values into a map.
-    #[must_use]
-    pub fn collect_dirty_values(&self) -> HashMap<Key, Value> {
-        self.nodes
-            .iter()
-            .filter(|(_, n)| n.state().is_dirty())
-            .filter_map(|(k, n)| n.value().map(|v| (k.clone(), v.clone())))
-            .collect()
-    }
-
-    /// Returns an iterator over dirty values without cloning.
+    /// Collects all values into a map, hiding sensitive ones.
     ///
-    /// This is a zero-allocation alternative to [`collect_dirty_values()`](Self::collect_dirty_values)
-    /// that returns references instead of owned values. Use this when you need to inspect
-    /// dirty values without collecting them into a new map.
+    /// Values of parameters flagged [`SENSITIVE`](crate::core::Flags::SENSITIVE)
+    /// are replaced with [`REDACTED`](crate::core::REDACTED), including
+    /// sensitive fields nested in objects, lists, modes, and expirables.
+    /// Use this for logs and telemetry. The `Debug` output of a context is
+    /// redacted the same way.
     ///
     /// # Example
     ///
     /// ```
     /// use paramdef::context::Context;
+    /// use paramdef::core::{REDACTED, Value};
     /// use paramdef::schema::Schema;
     /// use paramdef::types::leaf::Text;
-    /// use paramdef::core::Value;
     /// use std::sync::Arc;
     ///
     /// let schema = Arc::new(Schema::builder()
-    ///     .parameter(Text::builder("name").build())
-    ///     .parameter(Text::builder("email").build())
+    ///     .parameter(Text::builder("user").build())
+    ///     .parameter(Text::password("password"))
     ///     .build());
     ///
     /// let mut ctx = Context::new(schema);
-    /// ctx.set("name", Value::text("Alice"));
+    /// ctx.set("user", Value::text("alice"));
+    /// ctx.set("password", Value::text("hunter2"));
     ///
-    /// for (key, value) in ctx.dirty_values() {
-    ///     println!("{}: {:?}", key, value);
-    /// }
+    /// let values = ctx.collect_values_redacted();
+    /// assert_eq!(values["user"], Value::text("alice"));
+    /// assert_eq!(values["password"], Value::text(REDACTED));
     /// ```
-    pub fn dirty_values(&self) -> impl Iterator<Item = (&Key, &Value)> + '_ {
+    #[must_use]
+    pub fn collect_values_redacted(&self) -> HashMap<Key, Value> {
         self.nodes
             .iter()
-            .filter(|(_, n)| n.state().is_dirty())
-            .filter_map(|(k, n)| n.value().map(|v| (k, v)))
-    }
-
-    /// Returns `true` if any parameter is dirty.
-    #[must_use]
-    pub fn is_dirty(&self) -> bool {
-        self.nodes.values().any(|n| n.state().is_dirty())
+            .filter(|(_, n)| n.node().kind().is_value_bearing())
+            .filter_map(|(k, n)| Some((k.clone(), redact_value(&**n.node(), n.value()?))))
+            .collect()
     }
 
-    /// Returns `true` if all parameters are valid.
+    /// Returns all values as a single `Value::Object` in schema order.
+    ///
+    /// Contains each value-bearing parameter that has a value; container
+    /// values are nested as the arrays and objects they hold. Parameters
+    /// without a value are omitted, so [`load_value`](Self::load_value)
+    /// restores the context exactly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::context::Context;
+    /// use paramdef::core::Value;
+    /// use paramdef::schema::Schema;
+    /// use paramdef::types::leaf::{Number, Text};
+    /// use std::sync::Arc;
+    ///
+    /// let schema = Arc::new(Schema::builder()
+    ///     .parameter(Text::builder("host").build())
+    ///     .parameter(Number::builder("port").build())
+    ///     .build());
+    ///
+    /// let mut ctx = Context::new(Arc::clone(&schema));
+    /// ctx.set("port", Value::Int(8080));
+    /// ctx.set("host", Value::text("localhost"));
+    ///
+    /// let snapshot = ctx.to_value();
+    /// let keys: Vec<_> = snapshot.as_object().unwrap().keys().collect();
+    /// assert_eq!(keys, ["host", "port"]);
+    ///
+    /// let mut restored = Context::new(schema);
+    /// restored.load_value(&snapshot).unwrap();
+    /// assert_eq!(restored.get("port"), Some(&Value::Int(8080)));
+    /// ```
     #[must_use]
-    pub fn is_valid(&self) -> bool {
-        self.nodes.values().all(|n| n.state().is_valid())
-    }
-
-    /// Marks all parameters as clean.
-    pub fn mark_all_clean(&mut self) {
-        for node in self.nodes.values_mut() {
-            node.state_mut().mark_clean();
+    pub fn to_value(&self) -> Value {
+        let mut map = IndexMap::with_capacity(self.nodes.len());
+        for node in self.schema.iter() {
+            if !node.kind().is_value_bearing() {
+                continue;
+            }
+            if let Some(value) = self.get(node.key()) {
+                map.insert(node.key().clone(), value.clone());
+            }
         }
+        Value::Object(Arc::new(map))
     }
 
-    /// Resets all parameters to initial state.
-    pub fn reset(&mut self) {
-        for node in self.nodes.values_mut() {
-            node.reset();
+    /// Returns the form structure with current values and state.
+    ///
+    /// Produces an array of sections, one per [`Metadata::group`] hint on
+    /// the root parameters in order of first appearance; parameters without
+    /// a hint form a section whose `"key"` is `Null`. Each section's
+    /// `"fields"` lists its parameters sorted by [`Metadata::order`], as
+    /// objects with:
+    ///
+    /// - `"key"`, `"kind"`, `"label"`, `"description"`, and `"required"`
+    /// - `"value"`: the current value, or `Null`; sensitive parts are
+    ///   replaced as in [`collect_values_redacted`](Self::collect_values_redacted)
+    ///   and write-only parameters always give `Null`
+    /// - `"display"`: `"visible"` from the visibility expression (always
+    ///   `true` without the `visibility` feature), `"hidden"`, and
+    ///   `"readonly"`
+    /// - `"validation"`: `"valid"`, `"warning"`, `"stale"` for a value
+    ///   changed since it was validated, and `"errors"` as objects with
+    ///   `"code"` and `"message"`
+    ///
+    /// Validity is the state recorded by the last validation; this method
+    /// does not validate.
+    ///
+    /// Only root parameters hold values in a context, so a [`Group`] or
+    /// [`Panel`] kept as a root node is listed as a single field and its
+    /// children are not. Add groups with
+    /// [`SchemaBuilder::group`](crate::schema::SchemaBuilder::group) and
+    /// give their children a group hint to render them as a section.
+    ///
+    /// [`Group`]: crate::types::group::Group
+    /// [`Panel`]: crate::types::group::Panel
+    /// [`Metadata::group`]: crate::core::Metadata::group
+    /// [`Metadata::order`]: crate::core::Metadata::order
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::context::Context;
+    /// use paramdef::core::Value;
+    /// use paramdef::schema::Schema;
+    /// use paramdef::types::leaf::{Number, Text};
+    /// use std::sync::Arc;
+    ///
+    /// let schema = Arc::new(Schema::builder()
+    ///     .parameter(Text::builder("host").group("connection").build())
+    ///     .parameter(Text::builder("name").build())
+    ///     .parameter(Number::builder("port").group("connection").build())
+    ///     .build());
+    /// let mut ctx = Context::new(schema);
+    /// ctx.set("port", Value::Int(8080));
+    ///
+    /// let form = ctx.to_form_value();
+    /// let sections = form.as_array().unwrap();
+    /// assert_eq!(sections.len(), 2);
+    /// assert_eq!(form.get_pointer("/0/key"), Some(&Value::text("connection")));
+    /// assert_eq!(form.get_pointer("/0/fields/1/value"), Some(&Value::Int(8080)));
+    /// assert_eq!(form.get_pointer("/1/fields/0/key"), Some(&Value::text("name")));
+    /// assert_eq!(
+    ///     form.get_pointer("/1/fields/0/display/visible"),
+    ///     Some(&Value::Bool(true))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_form_value(&self) -> Value {
+        let mut sections: IndexMap<Option<&str>, Vec<Value>> = IndexMap::default();
+        for node in self.schema.children_sorted_by_order() {
+            let field = self.form_field(node.as_ref());
+            sections
+                .entry(node.metadata().group())
+                .or_default()
+                .push(field);
         }
-    }
 
-    /// Returns an iterator over all runtime nodes.
-    pub fn iter(&self) -> impl Iterator<Item = (&Key, &ErasedRuntimeNode)> {
-        self.nodes.iter()
+        Value::array(sections.into_iter().map(|(key, fields)| {
+            Value::object([("key", Value::from(key)), ("fields", Value::array(fields))])
+        }))
     }
 
-    /// Returns an iterator over all keys.
-    pub fn keys(&self) -> impl Iterator<Item = &Key> {
-        self.nodes.keys()
-    }
-}
+    /// Describes one root parameter for [`to_form_value`](Self::to_form_value).
+    fn form_field(&self, node: &dyn crate::types::traits::Node) -> Value {
+        let key = node.key();
+        let flags = node.flags();
+        let state = self.nodes.get(key).map(ErasedRuntimeNode::state);
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::types::leaf::{Number, Text};
+        #[cfg(feature = "visibility")]
+        let visible = self.is_visible(key);
+        #[cfg(not(feature = "visibility"))]
+        let visible = true;
 
-    fn create_test_schema() -> Arc<Schema> {
-        Arc::new(
-            Schema::builder()
-                .parameter(Text::builder("name").build())
-                .parameter(Text::builder("email").build())
-                .parameter(Number::builder("age").build())
-                .build(),
-        )
-    }
+        let value = match self.get(key) {
+            Some(_) if flags.is_write_only() => Value::Null,
+            Some(value) => redact_value(node, value),
+            None => Value::Null,
+        };
 
-    #[test]
-    fn test_context_from_schema() {
-        let schema = create_test_schema();
-        let ctx = Context::new(schema);
+        let errors = state.map_or(&[][..], |s| s.errors()).iter().map(|err| {
+            Value::object([
+                ("code", Value::from(err.code())),
+                ("message", Value::text(err.to_string())),
+            ])
+        });
 
-        assert_eq!(ctx.len(), 3);
-        assert!(!ctx.is_empty());
+        Value::object([
+            ("key", Value::text(key.as_str())),
+            ("kind", Value::text(node.kind().name())),
+            ("label", Value::from(node.metadata().label())),
+            ("description", Value::from(node.metadata().description())),
+            ("required", Value::Bool(flags.is_required())),
+            ("value", value),
+            (
+                "display",
+                Value::object([
+                    ("visible", Value::Bool(visible)),
+                    ("hidden", Value::Bool(flags.is_hidden())),
+                    ("readonly", Value::Bool(flags.is_readonly())),
+                ]),
+            ),
+            (
+                "validation",
+                Value::object([
+                    ("valid", Value::Bool(state.is_none_or(State::is_valid))),
+                    (
+                        "warning",
+                        Value::Bool(state.is_some_and(State::has_warning)),
+                    ),
+                    (
+                        "stale",
+                        Value::Bool(state.is_some_and(State::needs_revalidation)),
+                    ),
+                    ("errors", Value::array(errors)),
+                ]),
+            ),
+        ])
     }
 
-    #[test]
+    /// Sets values from a snapshot produced by [`to_value`](Self::to_value).
+    ///
+    /// Each field is set on the matching value-bearing parameter. Fields
+    /// that name no such parameter are skipped and listed in the report.
+    /// Parameters missing from the snapshot are left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns a type mismatch error if `value` is not an object. No
+    /// values are applied in that case.
+    pub fn load_value(&mut self, value: &Value) -> Result<ApplyReport> {
+        let fields = value
+            .as_object()
+            .ok_or_else(|| Error::type_mismatch("object", value.type_name()))?;
+
+        let mut report = ApplyReport::default();
+        for (key, value) in fields {
+            let value_bearing = self
+                .nodes
+                .get(key)
+                .is_some_and(|n| n.node().kind().is_value_bearing());
+            if value_bearing && self.set(key, value.clone()) {
+                report.applied.push(key.clone());
+            } else {
+                report.unknown.push(key.clone());
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Sets values from string input, such as a submitted web form.
+    ///
+    /// Each string is converted with the matching parameter's
+    /// [`coerce_input`](crate::types::traits::Node::coerce_input): numbers and vectors are
+    /// parsed, checkbox values like `"on"`, `"true"`, and `"1"` become
+    /// booleans, and multiple selects split on commas. Keys that name no
+    /// value-bearing parameter are listed as unknown; inputs that fail to
+    /// convert are listed as failed and leave the parameter unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::context::Context;
+    /// use paramdef::core::Value;
+    /// use paramdef::schema::Schema;
+    /// use paramdef::types::leaf::{Boolean, Number};
+    /// use std::sync::Arc;
+    ///
+    /// let schema = Arc::new(Schema::builder()
+    ///     .parameter(Number::port("port").build())
+    ///     .parameter(Boolean::builder("tls").build())
+    ///     .build());
+    ///
+    /// let mut ctx = Context::new(schema);
+    /// let report = ctx.apply_string_map([
+    ///     ("port".to_string(), "8080".to_string()),
+    ///     ("tls".to_string(), "on".to_string()),
+    /// ]);
+    ///
+    /// assert_eq!(report.applied, ["port", "tls"]);
+    /// assert_eq!(ctx.get("port"), Some(&Value::Int(8080)));
+    /// assert_eq!(ctx.get("tls"), Some(&Value::Bool(true)));
+    /// ```
+    pub fn apply_string_map(
+        &mut self,
+        map: impl IntoIterator<Item = (String, String)>,
+    ) -> ApplyReport {
+        let mut report = ApplyReport::default();
+        for (key, input) in map {
+            let key = Key::from(key);
+            let coerced = match self.nodes.get(&key).map(ErasedRuntimeNode::node) {
+                Some(node) if node.kind().is_value_bearing() => node.coerce_input(&input),
+                _ => {
+                    report.unknown.push(key);
+                    continue;
+                }
+            };
+
+            match coerced {
+                Ok(value) => {
+                    if self.set(&key, value) {
+                        report.applied.push(key);
+                    } else {
+                        report.unknown.push(key);
+                    }
+                }
+                Err(err) => report.failed.push(ApplyFailure::new(key, err)),
+            }
+        }
+
+        report
+    }
+
+    /// Substitutes `${key}` references in text values with sibling values.
+    ///
+    /// Each parameter's value is rewritten with
+    /// [`Value::interpolate`], looking names up among the other
+    /// parameters. Text is inserted as is and booleans and numbers in their
+    /// usual form; references to other values, missing parameters, or
+    /// cycles are kept as written. A referenced value is resolved before it
+    /// is inserted, so references can chain.
+    ///
+    /// Computed parameters are read but not rewritten. Returns the keys
+    /// whose values changed, in schema order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::context::Context;
+    /// use paramdef::core::Value;
+    /// use paramdef::schema::Schema;
+    /// use paramdef::types::leaf::Text;
+    /// use std::sync::Arc;
+    ///
+    /// let schema = Arc::new(Schema::builder()
+    ///     .parameter(Text::builder("base_url").build())
+    ///     .parameter(Text::builder("endpoint").build())
+    ///     .build());
+    /// let mut ctx = Context::new(schema);
+    /// ctx.set("base_url", Value::text("https://example.com"));
+    /// ctx.set("endpoint", Value::text("${base_url}/api"));
+    ///
+    /// assert_eq!(ctx.resolve_interpolations(), ["endpoint"]);
+    /// assert_eq!(ctx.get_text("endpoint"), Some("https://example.com/api"));
+    /// ```
+    pub fn resolve_interpolations(&mut self) -> Vec<Key> {
+        let mut resolved = FxHashMap::default();
+        let mut cyclic = FxHashSet::default();
+        let mut changed = Vec::new();
+        for key in self.schema.keys() {
+            let Some(value) =
+                self.resolve_reference(key, &mut resolved, &mut cyclic, &mut Vec::new())
+            else {
+                continue;
+            };
+            if self.get(key) != Some(&value) && !self.nodes[key].node().flags().is_computed() {
+                changed.push((key.clone(), value));
+            }
+        }
+
+        changed
+            .into_iter()
+            .map(|(key, value)| {
+                self.write(&key, value);
+                key
+            })
+            .collect()
+    }
+
+    /// Returns the value of `key` with its references resolved.
+    ///
+    /// `stack` holds the keys being resolved. A reference back to one of
+    /// them marks every key on the cycle in `cyclic`, and references to
+    /// those keys are left unresolved.
+    fn resolve_reference(
+        &self,
+        key: &str,
+        resolved: &mut FxHashMap<Key, Value>,
+        cyclic: &mut FxHashSet<Key>,
+        stack: &mut Vec<Key>,
+    ) -> Option<Value> {
+        if let Some(value) = resolved.get(key) {
+            return Some(value.clone());
+        }
+        if let Some(start) = stack.iter().position(|k| k == key) {
+            cyclic.extend(stack[start..].iter().cloned());
+            return None;
+        }
+
+        let value = self.get(key)?;
+        stack.push(Key::from(key));
+        let value = value
+            .interpolate_with(
+                &mut |name: &str| {
+                    let value = self.resolve_reference(name, resolved, cyclic, stack)?;
+                    if cyclic.contains(name) {
+                        return None;
+                    }
+                    match value {
+                        Value::Text(text) => Some(text.to_string()),
+                        Value::Bool(b) => Some(b.to_string()),
+                        Value::Int(i) => Some(i.to_string()),
+                        Value::Float(f) => Some(f.to_string()),
+                        _ => None,
+                    }
+                },
+                false,
+            )
+            .unwrap_or_else(|_| value.clone());
+        stack.pop();
+
+        resolved.insert(Key::from(key), value.clone());
+        Some(value)
+    }
+
+    /// Collects only dirty values into a map.
+    #[must_use]
+    pub fn collect_dirty_values(&self) -> HashMap<Key, Value> {
+        self.nodes
+            .iter()
+            .filter(|(_, n)| n.state().is_dirty())
+            .filter_map(|(k, n)| n.value().map(|v| (k.clone(), v.clone())))
+            .collect()
+    }
+
+    /// Collects dirty values entered by the user into a map.
+    ///
+    /// Like [`collect_dirty_values`](Self::collect_dirty_values), but skips
+    /// parameters flagged [`COMPUTED`](crate::core::Flags::COMPUTED), which
+    /// are derived and should not be persisted as input.
+    #[must_use]
+    pub fn collect_dirty_inputs(&self) -> HashMap<Key, Value> {
+        self.nodes
+            .iter()
+            .filter(|(_, n)| n.state().is_dirty() && !n.node().flags().is_computed())
+            .filter_map(|(k, n)| n.value().map(|v| (k.clone(), v.clone())))
+            .collect()
+    }
+
+    /// Returns an iterator over dirty values without cloning.
+    ///
+    /// This is a zero-allocation alternative to [`collect_dirty_values()`](Self::collect_dirty_values)
+    /// that returns references instead of owned values. Use this when you need to inspect
+    /// dirty values without collecting them into a new map.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::context::Context;
+    /// use paramdef::schema::Schema;
+    /// use paramdef::types::leaf::Text;
+    /// use paramdef::core::Value;
+    /// use std::sync::Arc;
+    ///
+    /// let schema = Arc::new(Schema::builder()
+    ///     .parameter(Text::builder("name").build())
+    ///     .parameter(Text::builder("email").build())
+    ///     .build());
+    ///
+    /// let mut ctx = Context::new(schema);
+    /// ctx.set("name", Value::text("Alice"));
+    ///
+    /// for (key, value) in ctx.dirty_values() {
+    ///     println!("{}: {:?}", key, value);
+    /// }
+    /// ```
+    pub fn dirty_values(&self) -> impl Iterator<Item = (&Key, &Value)> + '_ {
+        self.nodes
+            .iter()
+            .filter(|(_, n)| n.state().is_dirty())
+            .filter_map(|(k, n)| n.value().map(|v| (k, v)))
+    }
+
+    /// Returns the deprecated parameters that currently hold a value.
+    ///
+    /// Lists parameters flagged [`DEPRECATED`](crate::core::Flags::DEPRECATED)
+    /// whose value is not [empty](Value::is_empty), in schema order. Use
+    /// this to warn users or log a migration notice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::context::Context;
+    /// use paramdef::core::Value;
+    /// use paramdef::schema::Schema;
+    /// use paramdef::types::leaf::Text;
+    /// use std::sync::Arc;
+    ///
+    /// let schema = Arc::new(Schema::builder()
+    ///     .parameter(Text::builder("host").build())
+    ///     .parameter(Text::builder("hostname").deprecated().build())
+    ///     .build());
+    ///
+    /// let mut ctx = Context::new(schema);
+    /// assert!(ctx.deprecated_in_use().is_empty());
+    ///
+    /// ctx.set("hostname", Value::text("example.com"));
+    /// assert_eq!(ctx.deprecated_in_use(), ["hostname"]);
+    /// ```
+    #[must_use]
+    pub fn deprecated_in_use(&self) -> Vec<Key> {
+        self.schema
+            .iter()
+            .filter(|node| node.flags().is_deprecated())
+            .filter(|node| self.get(node.key()).is_some_and(|v| !v.is_empty()))
+            .map(|node| node.key().clone())
+            .collect()
+    }
+
+    /// Returns `true` if any parameter is dirty.
+    #[must_use]
+    pub fn is_dirty(&self) -> bool {
+        self.nodes.values().any(|n| n.state().is_dirty())
+    }
+
+    /// Returns `true` if all parameters are valid.
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.nodes.values().all(|n| n.state().is_valid())
+    }
+
+    /// Returns whether a parameter is visible given the current values.
+    ///
+    /// Evaluates the node's visibility expression (see [`crate::visibility`]
+    /// for the supported shape). Returns `true` when no expression is set
+    /// and `false` for unknown keys.
+    #[cfg(feature = "visibility")]
+    #[must_use]
+    pub fn is_visible(&self, key: &str) -> bool {
+        let Some(node) = self.nodes.get(key) else {
+            return false;
+        };
+
+        match node.node().visibility().and_then(|v| v.visibility_expr()) {
+            Some(expr) => crate::visibility::evaluate(expr, &|k| self.get(k)),
+            None => true,
+        }
+    }
+
+    /// Validates every parameter's current value and records the results.
+    ///
+    /// Each node's state gets its errors and `VALID` flag updated, and its
+    /// [stale flag](crate::runtime::ErasedRuntimeNode::is_validation_stale)
+    /// cleared. Valid values that [warrant a warning](crate::types::traits::Validatable::has_warning)
+    /// get the `WARNING` flag. Missing values are validated as `Null`. Nodes that cannot
+    /// validate are recorded as valid. Cross-field rules are not run; see
+    /// [`validate_rules`](Self::validate_rules).
+    ///
+    /// Returns `true` if every parameter is valid.
+    #[cfg(feature = "validation")]
+    pub fn validate_all(&mut self) -> bool {
+        for node in self.nodes.values_mut() {
+            validate_node(node);
+        }
+        self.is_valid()
+    }
+
+    /// Updates the parameters affected by a change to `key`.
+    ///
+    /// Call this after each edit. The affected set is `key` and everything
+    /// that depends on it, directly or indirectly, as found with
+    /// [`Schema::dependents_of`]; only those parameters are touched, in
+    /// [evaluation order](Schema::evaluation_order) (schema order if the
+    /// dependencies form a cycle). For each one:
+    ///
+    /// - With the `validation` feature, its value is validated as in
+    ///   [`validate_all`](Self::validate_all).
+    /// - With the `visibility` feature, its visibility is evaluated and
+    ///   compared with the one recorded in its
+    ///   [state](crate::runtime::State::is_visible), which starts out
+    ///   visible.
+    /// - A computed dependent is reported for recomputing; the context
+    ///   does not know how to compute it.
+    ///
+    /// Returns empty effects for unknown keys.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::context::Context;
+    /// use paramdef::core::Value;
+    /// use paramdef::schema::Schema;
+    /// use paramdef::types::leaf::Number;
+    /// use std::sync::Arc;
+    ///
+    /// let schema = Arc::new(Schema::builder()
+    ///     .parameter(Number::builder("price").build())
+    ///     .parameter(Number::builder("total").depends_on("price").computed().build())
+    ///     .build());
+    /// let mut ctx = Context::new(schema);
+    ///
+    /// ctx.set("price", Value::Int(10));
+    /// let effects = ctx.on_change("price");
+    /// for key in &effects.recomputed {
+    ///     let total = ctx.get_int("price").unwrap_or(0) * 2;
+    ///     ctx.set_computed(key, Value::Int(total));
+    /// }
+    /// assert_eq!(ctx.get_int("total"), Some(20));
+    /// ```
+    pub fn on_change(&mut self, key: &str) -> ChangeEffects {
+        let mut effects = ChangeEffects::default();
+        let Some((changed, _)) = self.nodes.get_key_value(key) else {
+            return effects;
+        };
+
+        let changed = changed.clone();
+        let mut affected = FxHashSet::default();
+        let mut pending = vec![changed.clone()];
+        while let Some(next) = pending.pop() {
+            for dependent in self.schema.dependents_of(&next) {
+                if affected.insert(dependent.clone()) {
+                    pending.push(dependent);
+                }
+            }
+        }
+        affected.insert(changed.clone());
+
+        let order = self
+            .schema
+            .evaluation_order()
+            .unwrap_or_else(|_| self.schema.keys().cloned().collect());
+        for key in order.into_iter().filter(|k| affected.contains(k)) {
+            #[cfg(feature = "visibility")]
+            let visible = self.is_visible(&key);
+            let Some(node) = self.nodes.get_mut(&key) else {
+                continue;
+            };
+            #[cfg(feature = "visibility")]
+            if node.state().is_visible() != visible {
+                node.state_mut().set_visible(visible);
+                effects.visibility_changed.push(key.clone());
+            }
+            #[cfg(feature = "validation")]
+            if validate_node(node) {
+                effects.revalidated.push(key.clone());
+            }
+            if key != changed && node.node().flags().is_computed() {
+                effects.recomputed.push(key);
+            }
+        }
+        effects
+    }
+
+    /// Resets every parameter whose value fails validation.
+    ///
+    /// Each failing parameter is [reset to its default](Self::reset_key),
+    /// or cleared if it has none. Use this after loading saved data that
+    /// may predate schema changes, to drop values that no longer fit
+    /// instead of rejecting the whole load. Parameters without a value are
+    /// left alone, and the defaults themselves are not validated.
+    ///
+    /// Returns the reset parameters in schema order, each with the error
+    /// that caused it.
+    #[cfg(feature = "validation")]
+    pub fn sanitize(&mut self) -> Vec<(Key, Error)> {
+        let failures: Vec<(Key, Error)> = self
+            .schema
+            .keys()
+            .filter_map(|key| {
+                let node = self.nodes.get(key)?;
+                let value = node.value()?;
+                let err = node.node().validatable()?.validate_sync(value).err()?;
+                Some((key.clone(), err))
+            })
+            .collect();
+
+        for (key, _) in &failures {
+            self.reset_key(key);
+        }
+        failures
+    }
+
+    /// Evaluates all cross-field rules from the schema.
+    ///
+    /// Every rule is run; the returned vector holds one error per failing
+    /// rule and is empty when all rules pass.
+    #[must_use]
+    pub fn validate_rules(&self) -> Vec<Error> {
+        self.schema
+            .rules()
+            .iter()
+            .filter_map(|rule| rule.check(self).err())
+            .collect()
+    }
+
+    /// Marks all parameters as clean.
+    ///
+    /// History is unaffected. Checkpoints keep the state they recorded, so
+    /// [undoing](Self::undo) to a checkpoint taken before this call brings
+    /// back its dirty flags.
+    pub fn mark_all_clean(&mut self) {
+        for node in self.nodes.values_mut() {
+            node.state_mut().mark_clean();
+        }
+    }
+
+    /// Resets all parameters to initial state.
+    ///
+    /// The cached visibility of each parameter is re-evaluated against the
+    /// reset values. History is kept: take a [checkpoint](Self::checkpoint)
+    /// first to make the reset undoable, or call
+    /// [`clear_history`](Self::clear_history) to discard it.
+    pub fn reset(&mut self) {
+        for node in self.nodes.values_mut() {
+            node.reset();
+        }
+        #[cfg(feature = "visibility")]
+        for key in Arc::clone(&self.schema).keys() {
+            self.refresh_visibility(key);
+        }
+    }
+
+    /// Reverts a single parameter to its schema default.
+    ///
+    /// Restores the node's [`initial_value`](crate::types::traits::Node::initial_value)
+    /// (a leaf's default value), or clears the value if there is none, as
+    /// [`with_defaults`](Self::with_defaults) would. The node's state is
+    /// reset: it is clean and untouched, with no validation errors, and its
+    /// cached visibility is re-evaluated.
+    ///
+    /// Returns `false` if the parameter does not exist.
+    pub fn reset_key(&mut self, key: &str) -> bool {
+        #[cfg(feature = "events")]
+        let tracked = self.events.is_some() || crate::event::is_watched(&self.watchers, key);
+        let Some(node) = self.nodes.get_mut(key) else {
+            return false;
+        };
+
+        #[cfg(feature = "events")]
+        let old = tracked.then(|| node.value().cloned());
+        node.reset();
+        if let Some(value) = node.node().initial_value() {
+            node.set_value(value);
+            node.state_mut().mark_clean();
+        }
+        #[cfg(feature = "visibility")]
+        self.refresh_visibility(key);
+        #[cfg(feature = "events")]
+        if let Some(old) = old {
+            self.emit_change(key, old);
+        }
+
+        true
+    }
+
+    /// Records the result of [`is_visible`](Self::is_visible) in the
+    /// parameter's state.
+    #[cfg(feature = "visibility")]
+    fn refresh_visibility(&mut self, key: &str) {
+        let visible = self.is_visible(key);
+        if let Some(node) = self.nodes.get_mut(key) {
+            node.state_mut().set_visible(visible);
+        }
+    }
+
+    /// Creates an independent copy of this context for "what-if" changes.
+    ///
+    /// The fork shares the schema and copies the values and state of every
+    /// parameter; collections inside [`Value`] are `Arc`-backed, so this is
+    /// cheap. After creation the two contexts are independent: changes to
+    /// one are never seen by the other. Change events are not inherited,
+    /// so the fork emits nothing until [`with_events`](Self::with_events)
+    /// is called on it.
+    ///
+    /// Use [`overlay`](Self::overlay) to bring a fork's changes back.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::context::Context;
+    /// use paramdef::core::Value;
+    /// use paramdef::schema::Schema;
+    /// use paramdef::types::leaf::Number;
+    /// use std::sync::Arc;
+    ///
+    /// let schema = Arc::new(Schema::builder()
+    ///     .parameter(Number::builder("workers").build())
+    ///     .build());
+    /// let mut ctx = Context::new(schema);
+    /// ctx.set("workers", Value::Int(4));
+    ///
+    /// let mut scenario = ctx.fork();
+    /// scenario.set("workers", Value::Int(16));
+    /// assert_eq!(ctx.get("workers"), Some(&Value::Int(4)));
+    ///
+    /// ctx.overlay(&scenario);
+    /// assert_eq!(ctx.get("workers"), Some(&Value::Int(16)));
+    /// ```
+    #[must_use]
+    pub fn fork(&self) -> Context {
+        Self {
+            schema: Arc::clone(&self.schema),
+            nodes: self.nodes.clone(),
+            #[cfg(feature = "events")]
+            events: None,
+            #[cfg(feature = "events")]
+            batch_keys: None,
+            #[cfg(feature = "events")]
+            watchers: SharedWatchers::default(),
+            ci_index: self.ci_index.clone(),
+            history: self.history.fresh(),
+        }
+    }
+
+    /// Copies the dirty values of `other` into this context.
+    ///
+    /// Each dirty parameter of `other` is set, or cleared if `other`
+    /// cleared it, through [`set`](Self::set) and [`clear`](Self::clear),
+    /// so the affected parameters become dirty here and change events fire
+    /// as usual. Keys this context does not know are skipped.
+    pub fn overlay(&mut self, other: &Context) {
+        for key in other.schema.keys() {
+            let Some(node) = other.nodes.get(key) else {
+                continue;
+            };
+            if !node.state().is_dirty() {
+                continue;
+            }
+
+            match node.value() {
+                Some(value) => self.set(key, value.clone()),
+                None => self.clear(key),
+            };
+        }
+    }
+
+    /// Returns an iterator over all runtime nodes.
+    pub fn iter(&self) -> impl Iterator<Item = (&Key, &ErasedRuntimeNode)> {
+        self.nodes.iter()
+    }
+
+    /// Returns an iterator over all keys.
+    pub fn keys(&self) -> impl Iterator<Item = &Key> {
+        self.nodes.keys()
+    }
+
+    /// Returns an iterator over keys and current values in schema order.
+    ///
+    /// Unlike [`iter`](Self::iter), the order is the order in which
+    /// parameters were added to the schema. Parameters without a value
+    /// yield `None`.
+    pub fn iter_ordered(&self) -> impl Iterator<Item = (&Key, Option<&Value>)> {
+        self.schema
+            .keys()
+            .map(|key| (key, self.nodes.get(key).and_then(|n| n.value())))
+    }
+}
+
+/// Ends a [`Context::batch`] even if its closure panics, so later changes
+/// emit events again.
+#[cfg(feature = "events")]
+struct BatchGuard<'a>(&'a mut Context);
+
+#[cfg(feature = "events")]
+impl Drop for BatchGuard<'_> {
+    fn drop(&mut self) {
+        self.0.batch_keys = None;
+    }
+}
+
+/// Validates a node's current value and records the result in its state.
+///
+/// Returns `false`, recording the node as valid, if it cannot validate.
+#[cfg(feature = "validation")]
+fn validate_node(node: &mut ErasedRuntimeNode) -> bool {
+    let (errors, warning, validated) = match node.node().validatable() {
+        Some(validatable) => {
+            let value = node.value().cloned().unwrap_or(Value::Null);
+            match validatable.validate_sync(&value) {
+                Ok(()) => (Vec::new(), validatable.has_warning(&value), true),
+                Err(err) => (vec![err], false, true),
+            }
+        }
+        None => (Vec::new(), false, false),
+    };
+    node.state_mut().set_validation_result(errors);
+    node.state_mut().set_warning(warning);
+    validated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::REDACTED;
+    use crate::types::group::Group;
+    use crate::types::leaf::{Number, Text};
+
+    #[cfg(feature = "events")]
+    use std::sync::Mutex;
+
+    fn create_test_schema() -> Arc<Schema> {
+        Arc::new(
+            Schema::builder()
+                .parameter(Text::builder("name").build())
+                .parameter(Text::builder("email").build())
+                .parameter(Number::builder("age").build())
+                .build(),
+        )
+    }
+
+    #[test]
+    fn test_context_from_schema() {
+        let schema = create_test_schema();
+        let ctx = Context::new(schema);
+
+        assert_eq!(ctx.len(), 3);
+        assert!(!ctx.is_empty());
+    }
+
+    #[test]
     fn test_context_set_value() {
         let schema = create_test_schema();
         let mut ctx = Context::new(schema);
 
-        let result = ctx.set("name", Value::text("Alice"));
+        let result = ctx.set("name", Value::text("Alice"));
+
+        assert!(result);
+        assert_eq!(ctx.get("name").and_then(|v| v.as_text()), Some("Alice"));
+    }
+
+    #[test]
+    fn test_context_resolve_interpolations() {
+        let schema = Arc::new(
+            Schema::builder()
+                .parameter(Text::builder("url").build())
+                .parameter(Text::builder("host").build())
+                .parameter(Number::builder("port").build())
+                .parameter(Text::builder("a").build())
+                .parameter(Text::builder("b").build())
+                .build(),
+        );
+        let mut ctx = Context::new(schema);
+        ctx.set("url", Value::text("http://${host}/$${raw}/${missing}"));
+        ctx.set("host", Value::text("${name}:${port}"));
+        ctx.set("port", Value::Int(8080));
+        ctx.set("a", Value::text("${b}"));
+        ctx.set("b", Value::text("${a}"));
+
+        assert_eq!(ctx.resolve_interpolations(), ["url", "host"]);
+        assert_eq!(
+            ctx.get_text("url"),
+            Some("http://${name}:8080/${raw}/${missing}")
+        );
+        assert_eq!(ctx.get_text("host"), Some("${name}:8080"));
+        assert_eq!(ctx.get_text("a"), Some("${b}"));
+        assert_eq!(ctx.get_text("b"), Some("${a}"));
+    }
+
+    #[test]
+    fn test_context_to_form_value() {
+        let schema = Arc::new(
+            Schema::builder()
+                .parameter(Text::builder("name").label("Name").required().build())
+                .parameter(Number::builder("age").group("details").order(1).build())
+                .parameter(Text::builder("email").group("details").order(0).build())
+                .build(),
+        );
+        let mut ctx = Context::new(schema);
+        ctx.set("age", Value::Int(30));
+
+        let form = ctx.to_form_value();
+        assert_eq!(form.as_array().map(<[Value]>::len), Some(2));
+        assert_eq!(form.get_pointer("/0/key"), Some(&Value::text("details")));
+        assert_eq!(
+            form.get_pointer("/0/fields/0/key"),
+            Some(&Value::text("email"))
+        );
+        assert_eq!(form.get_pointer("/0/fields/1/value"), Some(&Value::Int(30)));
+        assert_eq!(form.get_pointer("/0/fields/0/value"), Some(&Value::Null));
+        assert_eq!(form.get_pointer("/1/key"), Some(&Value::Null));
 
-        assert!(result);
-        assert_eq!(ctx.get("name").and_then(|v| v.as_text()), Some("Alice"));
+        let name = form.get_pointer("/1/fields/0").unwrap();
+        assert_eq!(name.get_pointer("/kind"), Some(&Value::text("leaf")));
+        assert_eq!(name.get_pointer("/label"), Some(&Value::text("Name")));
+        assert_eq!(name.get_pointer("/required"), Some(&Value::Bool(true)));
+        assert_eq!(
+            name.get_pointer("/display/hidden"),
+            Some(&Value::Bool(false))
+        );
+        assert_eq!(
+            name.get_pointer("/validation/valid"),
+            Some(&Value::Bool(true))
+        );
+        assert_eq!(
+            name.get_pointer("/validation/errors"),
+            Some(&Value::array([]))
+        );
+    }
+
+    #[test]
+    fn test_context_to_form_value_redacts_secrets() {
+        let schema = Arc::new(
+            Schema::builder()
+                .parameter(Text::builder("password").sensitive().build())
+                .parameter(Text::builder("user").build())
+                .build(),
+        );
+        let mut ctx = Context::new(schema);
+        ctx.set("password", Value::text("hunter2"));
+        ctx.set("user", Value::text("alice"));
+
+        let form = ctx.to_form_value();
+        assert_eq!(
+            form.get_pointer("/0/fields/0/value"),
+            Some(&Value::text(REDACTED))
+        );
+        assert_eq!(
+            form.get_pointer("/0/fields/1/value"),
+            Some(&Value::text("alice"))
+        );
+    }
+
+    #[test]
+    fn test_context_to_form_value_spliced_group() {
+        let connection = Group::builder("connection")
+            .child(Text::builder("host").group("connection").build())
+            .child(Number::builder("port").group("connection").build())
+            .build();
+        let schema = Arc::new(Schema::builder().group(connection).build());
+        let mut ctx = Context::new(schema);
+        assert!(ctx.set("host", Value::text("db.local")));
+
+        let form = ctx.to_form_value();
+        assert_eq!(form.get_pointer("/0/key"), Some(&Value::text("connection")));
+        assert_eq!(
+            form.get_pointer("/0/fields/0/value"),
+            Some(&Value::text("db.local"))
+        );
+        assert_eq!(
+            form.get_pointer("/0/fields/1/key"),
+            Some(&Value::text("port"))
+        );
+    }
+
+    #[cfg(feature = "validation")]
+    #[test]
+    fn test_context_to_form_value_validation() {
+        let schema = Arc::new(
+            Schema::builder()
+                .parameter(Text::builder("name").required().build())
+                .build(),
+        );
+        let mut ctx = Context::new(schema);
+        ctx.validate_all();
+
+        let form = ctx.to_form_value();
+        let validation = form.get_pointer("/0/fields/0/validation").unwrap();
+        assert_eq!(validation.get_pointer("/valid"), Some(&Value::Bool(false)));
+        assert_eq!(validation.get_pointer("/stale"), Some(&Value::Bool(false)));
+        assert_eq!(
+            validation.get_pointer("/errors/0/message"),
+            Some(&Value::text("required field 'name' is missing"))
+        );
+
+        ctx.set("name", Value::text("Ann"));
+        let form = ctx.to_form_value();
+        assert_eq!(
+            form.get_pointer("/0/fields/0/validation/stale"),
+            Some(&Value::Bool(true))
+        );
+    }
+
+    #[cfg(feature = "validation")]
+    #[test]
+    fn test_context_validate_all() {
+        let schema = Arc::new(
+            Schema::builder()
+                .parameter(Text::builder("name").required().build())
+                .parameter(Number::builder("age").range(0.0, 150.0).build())
+                .build(),
+        );
+        let mut ctx = Context::new(schema);
+
+        assert!(!ctx.validate_all());
+        assert_eq!(ctx.node("name").unwrap().state().errors().len(), 1);
+
+        ctx.set("name", Value::text("Alice"));
+        ctx.set("age", Value::Int(200));
+        ctx.mark_all_clean();
+        assert!(ctx.node("age").unwrap().is_validation_stale());
+
+        assert!(!ctx.validate_all());
+        assert!(ctx.node("name").unwrap().state().is_valid());
+        assert!(!ctx.node("age").unwrap().state().is_valid());
+        assert!(ctx.iter().all(|(_, node)| !node.is_validation_stale()));
+
+        ctx.set("age", Value::Int(30));
+        assert!(ctx.node("age").unwrap().is_validation_stale());
+        assert!(ctx.validate_all());
+    }
+
+    #[cfg(feature = "validation")]
+    #[test]
+    fn test_context_validate_all_soft_range_warning() {
+        let schema = Arc::new(
+            Schema::builder()
+                .parameter(
+                    Number::builder("gain")
+                        .range(-60.0, 12.0)
+                        .soft_range(-24.0, 6.0)
+                        .build(),
+                )
+                .build(),
+        );
+        let mut ctx = Context::new(schema);
+
+        ctx.set("gain", Value::Float(10.0));
+        assert!(ctx.validate_all());
+        assert!(ctx.node("gain").unwrap().state().has_warning());
+
+        ctx.set("gain", Value::Float(0.0));
+        assert!(ctx.validate_all());
+        assert!(!ctx.node("gain").unwrap().state().has_warning());
+    }
+
+    #[cfg(feature = "validation")]
+    #[test]
+    fn test_context_sanitize() {
+        let schema = Arc::new(
+            Schema::builder()
+                .parameter(
+                    Number::builder("port")
+                        .range(1.0, 65535.0)
+                        .default(8080.0)
+                        .build(),
+                )
+                .parameter(Number::builder("ratio").range(0.0, 1.0).build())
+                .parameter(Text::builder("name").required().build())
+                .parameter(Text::builder("host").build())
+                .build(),
+        );
+        let mut ctx = Context::new(schema);
+        ctx.set("port", Value::Int(70000));
+        ctx.set("ratio", Value::Float(2.0));
+        ctx.set("host", Value::text("localhost"));
+
+        let reset = ctx.sanitize();
+        let keys: Vec<&str> = reset.iter().map(|(key, _)| key.as_str()).collect();
+        assert_eq!(keys, ["port", "ratio"]);
+
+        assert_eq!(ctx.get_int("port"), Some(8080));
+        assert_eq!(ctx.get("ratio"), None);
+        assert_eq!(ctx.get_text("host"), Some("localhost"));
+        assert!(ctx.sanitize().is_empty());
+    }
+
+    #[test]
+    fn test_context_reset_key() {
+        let schema = Arc::new(
+            Schema::builder()
+                .parameter(Number::builder("port").default(8080.0).build())
+                .parameter(Text::builder("host").build())
+                .build(),
+        );
+        let mut ctx = Context::with_defaults(schema);
+        ctx.set("port", Value::Int(9000));
+        ctx.set("host", Value::text("example.com"));
+        ctx.node_mut("port")
+            .unwrap()
+            .state_mut()
+            .set_validation_result(vec![Error::custom("bad port")]);
+
+        assert!(ctx.reset_key("port"));
+        assert_eq!(ctx.get("port"), Some(&Value::Float(8080.0)));
+        let state = ctx.node("port").unwrap().state();
+        assert!(!state.is_dirty());
+        assert!(state.is_valid());
+        assert!(state.errors().is_empty());
+
+        assert!(ctx.reset_key("host"));
+        assert_eq!(ctx.get("host"), None);
+        assert!(!ctx.is_dirty());
+
+        assert!(!ctx.reset_key("missing"));
+    }
+
+    #[test]
+    fn test_context_typed_getters() {
+        use crate::types::leaf::Boolean;
+
+        let schema = Arc::new(
+            Schema::builder()
+                .parameter(Text::builder("name").build())
+                .parameter(Number::builder("age").build())
+                .parameter(Number::builder("ratio").build())
+                .parameter(Boolean::builder("active").build())
+                .build(),
+        );
+        let mut ctx = Context::new(schema);
+        ctx.set("name", Value::text("Alice"));
+        ctx.set("age", Value::Int(30));
+        ctx.set("ratio", Value::Float(2.5));
+        ctx.set("active", Value::Bool(true));
+
+        assert_eq!(ctx.get_text("name"), Some("Alice"));
+        assert_eq!(ctx.get_int("age"), Some(30));
+        assert_eq!(ctx.get_float("age"), Some(30.0));
+        assert_eq!(ctx.get_int("ratio"), Some(2));
+        assert_eq!(ctx.get_bool("active"), Some(true));
+        assert_eq!(ctx.get_int("name"), None);
+        assert_eq!(ctx.get_text("missing"), None);
+
+        assert_eq!(ctx.get_or("name", String::new()), "Alice");
+        assert!(!ctx.get_or("name", false));
+        assert_eq!(ctx.get_or("missing", 7_i64), 7);
     }
 
     #[test]
@@ -281,6 +1799,145 @@ mod tests {
         assert_eq!(values.get("age").and_then(|v| v.as_int()), Some(30));
     }
 
+    #[test]
+    fn test_context_collect_values_skips_structural_nodes() {
+        use crate::types::decoration::Notice;
+
+        let schema = Schema::builder()
+            .parameter(Text::builder("name").build())
+            .parameter(Notice::info("hint", "Enter your name"))
+            .build();
+        let mut ctx = Context::new(Arc::new(schema));
+        ctx.set("name", Value::text("Alice"));
+        ctx.set("hint", Value::text("stray"));
+
+        let values = ctx.collect_values();
+        assert_eq!(values.len(), 1);
+        assert!(!values.contains_key("hint"));
+        assert!(!ctx.collect_values_redacted().contains_key("hint"));
+    }
+
+    #[test]
+    fn test_context_collect_values_redacted() {
+        let schema = Arc::new(
+            Schema::builder()
+                .parameter(Text::builder("name").build())
+                .parameter(Text::password("password"))
+                .parameter(Text::builder("token").sensitive().build())
+                .build(),
+        );
+        let mut ctx = Context::new(schema);
+        ctx.set("name", Value::text("Alice"));
+        ctx.set("password", Value::text("hunter2"));
+
+        let values = ctx.collect_values_redacted();
+
+        assert_eq!(values.len(), 2);
+        assert_eq!(values["name"], Value::text("Alice"));
+        assert_eq!(values["password"], Value::text(REDACTED));
+        assert!(!format!("{ctx:?}").contains("hunter2"));
+    }
+
+    #[test]
+    fn test_context_collect_values_redacted_nested() {
+        use crate::types::container::{List, Mode, Object};
+
+        let account = Object::builder("account")
+            .field("user", Text::builder("user").build())
+            .field("password", Text::password("password"))
+            .build()
+            .unwrap();
+        let keys = List::builder("keys")
+            .item_template(Text::builder("key").sensitive().build())
+            .build()
+            .unwrap();
+        let auth = Mode::builder("auth")
+            .variant("token", "Token", Text::builder("token").sensitive().build())
+            .build()
+            .unwrap();
+        let schema = Arc::new(
+            Schema::builder()
+                .parameter(account)
+                .parameter(keys)
+                .parameter(auth)
+                .build(),
+        );
+        let mut ctx = Context::new(schema);
+        ctx.set(
+            "account",
+            Value::object([
+                ("user", Value::text("alice")),
+                ("password", Value::text("hunter2")),
+            ]),
+        );
+        ctx.set("keys", Value::array([Value::text("k1"), Value::text("k2")]));
+        ctx.set(
+            "auth",
+            Value::object([
+                ("mode", Value::text("token")),
+                ("value", Value::text("t0k")),
+            ]),
+        );
+
+        let values = ctx.collect_values_redacted();
+
+        assert_eq!(
+            values["account"].get_pointer("/user"),
+            Some(&Value::text("alice"))
+        );
+        assert_eq!(
+            values["account"].get_pointer("/password"),
+            Some(&Value::text(REDACTED))
+        );
+        assert_eq!(
+            values["keys"],
+            Value::array([Value::text(REDACTED), Value::text(REDACTED)])
+        );
+        assert_eq!(
+            values["auth"].get_pointer("/mode"),
+            Some(&Value::text("token"))
+        );
+        assert_eq!(
+            values["auth"].get_pointer("/value"),
+            Some(&Value::text(REDACTED))
+        );
+
+        let debug = format!("{ctx:?}");
+        for secret in ["hunter2", "k1", "t0k"] {
+            assert!(!debug.contains(secret), "{secret} leaked");
+        }
+    }
+
+    #[test]
+    fn test_context_case_insensitive() {
+        let schema = Arc::new(
+            Schema::builder()
+                .parameter(Text::builder("Host").build())
+                .parameter(Text::builder("port").build())
+                .parameter(Text::builder("PORT").build())
+                .build(),
+        );
+
+        for mut ctx in [
+            Context::new(Arc::clone(&schema)),
+            Context::new(Arc::clone(&schema)).with_case_insensitive_keys(),
+        ] {
+            assert!(ctx.set_ci("HOST", Value::text("example.com")).unwrap());
+            assert_eq!(ctx.get("Host"), Some(&Value::text("example.com")));
+            assert_eq!(ctx.get_ci("host"), Some(&Value::text("example.com")));
+            assert!(!ctx.set_ci("missing", Value::Null).unwrap());
+
+            // Exact matches are never ambiguous.
+            assert!(ctx.set_ci("port", Value::Int(80)).unwrap());
+            assert_eq!(ctx.get_ci("port"), Some(&Value::Int(80)));
+
+            assert!(ctx.get_ci("Port").is_none());
+            let err = ctx.set_ci("Port", Value::Int(1)).unwrap_err();
+            assert_eq!(err.code(), Some("ambiguous_key"));
+            assert_eq!(err.fields(), ["port", "PORT"]);
+        }
+    }
+
     #[test]
     fn test_context_collect_dirty_values() {
         let schema = create_test_schema();
@@ -295,6 +1952,82 @@ mod tests {
         assert!(dirty.contains_key("age"));
     }
 
+    #[test]
+    fn test_context_apply_string_map() {
+        use crate::types::leaf::{Boolean, Select, SelectOption};
+
+        let schema = Arc::new(
+            Schema::builder()
+                .parameter(Text::builder("name").build())
+                .parameter(Text::slug("slug"))
+                .parameter(Number::builder("ratio").build())
+                .parameter(Boolean::builder("agree").build())
+                .parameter(
+                    Select::multiple("tags")
+                        .options(vec![SelectOption::simple("a"), SelectOption::simple("b")])
+                        .build(),
+                )
+                .build(),
+        );
+        let mut ctx = Context::new(schema);
+
+        let input = [
+            ("name", "Alice"),
+            ("slug", "Hello-World"),
+            ("ratio", "0.5"),
+            ("agree", "on"),
+            ("tags", "a, b"),
+            ("missing", "x"),
+        ];
+        let report = ctx.apply_string_map(input.map(|(k, v)| (k.to_string(), v.to_string())));
+
+        assert_eq!(report.applied, ["name", "slug", "ratio", "agree", "tags"]);
+        assert_eq!(report.unknown, ["missing"]);
+        assert!(report.failed.is_empty());
+        assert_eq!(ctx.get("name"), Some(&Value::text("Alice")));
+        assert_eq!(ctx.get("slug"), Some(&Value::text("hello-world")));
+        assert_eq!(ctx.get("ratio"), Some(&Value::Float(0.5)));
+        assert_eq!(ctx.get("agree"), Some(&Value::Bool(true)));
+        assert_eq!(
+            ctx.get("tags"),
+            Some(&Value::array([Value::text("a"), Value::text("b")]))
+        );
+
+        let report = ctx.apply_string_map([
+            ("ratio".to_string(), "half".to_string()),
+            ("agree".to_string(), "maybe".to_string()),
+        ]);
+        assert!(report.applied.is_empty());
+        let failed: Vec<&str> = report.failed.iter().map(|f| f.key.as_str()).collect();
+        assert_eq!(failed, ["ratio", "agree"]);
+        assert_eq!(report.failed[0].code, "invalid_input");
+        assert_eq!(ctx.get("ratio"), Some(&Value::Float(0.5)));
+    }
+
+    #[test]
+    fn test_context_computed_fields() {
+        let schema = Arc::new(
+            Schema::builder()
+                .parameter(Number::builder("price").build())
+                .parameter(Number::builder("total").computed().build())
+                .build(),
+        );
+        let mut ctx = Context::new(schema);
+
+        assert!(!ctx.set("total", Value::Int(10)));
+        assert_eq!(ctx.get("total"), None);
+
+        assert!(ctx.set("price", Value::Int(5)));
+        assert!(ctx.set_computed("total", Value::Int(10)));
+        assert!(!ctx.set_computed("missing", Value::Int(1)));
+        assert_eq!(ctx.get("total"), Some(&Value::Int(10)));
+
+        assert_eq!(ctx.collect_dirty_values().len(), 2);
+        let inputs = ctx.collect_dirty_inputs();
+        assert_eq!(inputs.len(), 1);
+        assert!(inputs.contains_key("price"));
+    }
+
     #[test]
     fn test_context_is_dirty() {
         let schema = create_test_schema();
@@ -333,6 +2066,94 @@ mod tests {
         assert!(!ctx.node("name").unwrap().state().is_touched());
     }
 
+    #[test]
+    fn test_context_fork_is_independent() {
+        let mut ctx = Context::new(create_test_schema());
+        ctx.set("name", Value::text("Alice"));
+        ctx.mark_all_clean();
+
+        let mut fork = ctx.fork();
+        assert!(Arc::ptr_eq(fork.schema(), ctx.schema()));
+        assert_eq!(fork.get("name"), Some(&Value::text("Alice")));
+        assert!(!fork.is_dirty());
+
+        fork.set("name", Value::text("Bob"));
+        ctx.set("age", Value::Int(30));
+        assert_eq!(ctx.get("name"), Some(&Value::text("Alice")));
+        assert_eq!(fork.get("age"), None);
+    }
+
+    #[test]
+    fn test_context_overlay() {
+        let mut ctx = Context::new(create_test_schema());
+        ctx.set("name", Value::text("Alice"));
+        ctx.set("age", Value::Int(30));
+        ctx.mark_all_clean();
+
+        let mut fork = ctx.fork();
+        fork.set("age", Value::Int(31));
+        fork.clear("name");
+        ctx.set("email", Value::text("a@example.com"));
+
+        ctx.overlay(&fork);
+        assert_eq!(ctx.get("age"), Some(&Value::Int(31)));
+        assert_eq!(ctx.get("name"), None);
+        assert_eq!(ctx.get("email"), Some(&Value::text("a@example.com")));
+        assert!(ctx.node("age").unwrap().state().is_dirty());
+    }
+
+    #[test]
+    fn test_context_iter_ordered() {
+        let mut ctx = Context::new(create_test_schema());
+        ctx.set("age", Value::Int(30));
+
+        let entries: Vec<_> = ctx
+            .iter_ordered()
+            .map(|(k, v)| (k.as_str(), v.cloned()))
+            .collect();
+        assert_eq!(
+            entries,
+            [
+                ("name", None),
+                ("email", None),
+                ("age", Some(Value::Int(30)))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_context_with_defaults() {
+        use crate::types::container::Mode;
+        use crate::types::leaf::Boolean;
+
+        let schema = Schema::builder()
+            .parameter(Text::builder("name").default("guest").build())
+            .parameter(Boolean::builder("enabled").default(true).build())
+            .parameter(Text::builder("email").build())
+            .parameter(
+                Mode::builder("auth")
+                    .variant("none", "None", Text::builder("none").build())
+                    .variant("token", "Token", Text::builder("token").build())
+                    .default_variant("token")
+                    .build()
+                    .unwrap(),
+            )
+            .build();
+
+        let ctx = Context::with_defaults(Arc::new(schema));
+        assert_eq!(ctx.get("name"), Some(&Value::text("guest")));
+        assert_eq!(ctx.get("enabled"), Some(&Value::Bool(true)));
+        assert_eq!(ctx.get("email"), None);
+        assert_eq!(
+            ctx.get("auth"),
+            Some(&Value::object([("mode", Value::text("token"))]))
+        );
+        assert!(!ctx.is_dirty());
+
+        let blank = Context::new(ctx.schema().clone());
+        assert_eq!(blank.get("name"), None);
+    }
+
     #[test]
     fn test_context_node_access() {
         let schema = create_test_schema();
@@ -352,4 +2173,362 @@ mod tests {
 
         assert_eq!(keys.len(), 3);
     }
+
+    #[test]
+    fn test_context_validate_rules() {
+        let schema = Arc::new(
+            Schema::builder()
+                .parameter(Number::builder("start").build())
+                .parameter(Number::builder("end").build())
+                .rule(&["start", "end"], |ctx| {
+                    let start = ctx.get("start").and_then(Value::as_f64);
+                    let end = ctx.get("end").and_then(Value::as_f64);
+                    match (start, end) {
+                        (Some(s), Some(e)) if e < s => Err(Error::validation(
+                            "end_before_start",
+                            "end must not precede start",
+                        )),
+                        _ => Ok(()),
+                    }
+                })
+                .rule(&["start"], |ctx| {
+                    if ctx.get("start").is_some() {
+                        Ok(())
+                    } else {
+                        Err(Error::missing_required("start"))
+                    }
+                })
+                .build(),
+        );
+        let mut ctx = Context::new(schema);
+
+        assert_eq!(ctx.validate_rules().len(), 1);
+
+        ctx.set("start", Value::Int(10));
+        ctx.set("end", Value::Int(5));
+        let errors = ctx.validate_rules();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].code(), Some("end_before_start"));
+
+        ctx.set("end", Value::Int(20));
+        assert!(ctx.validate_rules().is_empty());
+    }
+
+    #[cfg(feature = "visibility")]
+    #[test]
+    fn test_context_is_visible() {
+        use crate::visibility::eq;
+
+        let schema = Arc::new(
+            Schema::builder()
+                .parameter(Text::builder("mode").build())
+                .parameter(
+                    Number::builder("level")
+                        .visible_when(eq("mode", "advanced"))
+                        .build(),
+                )
+                .build(),
+        );
+        let mut ctx = Context::new(schema);
+
+        assert!(ctx.is_visible("mode"));
+        assert!(!ctx.is_visible("level"));
+        assert!(!ctx.is_visible("unknown"));
+
+        ctx.set("mode", Value::text("advanced"));
+        assert!(ctx.is_visible("level"));
+    }
+
+    #[test]
+    fn test_context_on_change_recomputed() {
+        let schema = Arc::new(
+            Schema::builder()
+                .parameter(
+                    Number::builder("total")
+                        .depends_on("tax")
+                        .computed()
+                        .build(),
+                )
+                .parameter(Number::builder("tax").depends_on("net").computed().build())
+                .parameter(Number::builder("net").build())
+                .parameter(Number::builder("note").depends_on("total").build())
+                .parameter(Number::builder("other").computed().build())
+                .build(),
+        );
+        let mut ctx = Context::new(schema);
+
+        let effects = ctx.on_change("net");
+        assert_eq!(effects.recomputed, ["tax", "total"]);
+        assert!(effects.visibility_changed.is_empty());
+
+        assert_eq!(ctx.on_change("tax").recomputed, ["total"]);
+        assert_eq!(ctx.on_change("missing"), ChangeEffects::default());
+    }
+
+    #[cfg(all(feature = "visibility", feature = "validation"))]
+    #[test]
+    fn test_context_on_change() {
+        use crate::visibility::eq;
+
+        let schema = Arc::new(
+            Schema::builder()
+                .parameter(Text::builder("mode").build())
+                .parameter(
+                    Number::builder("level")
+                        .range(0.0, 10.0)
+                        .visible_when(eq("mode", "advanced"))
+                        .build(),
+                )
+                .parameter(Number::builder("limit").range(0.0, 5.0).build())
+                .build(),
+        );
+        let mut ctx = Context::new(schema);
+        ctx.set("level", Value::Int(20));
+        ctx.set("limit", Value::Int(20));
+
+        ctx.set("mode", Value::text("basic"));
+        let effects = ctx.on_change("mode");
+        assert_eq!(effects.revalidated, ["mode", "level"]);
+        assert_eq!(effects.visibility_changed, ["level"]);
+        assert!(!ctx.node("level").unwrap().state().is_valid());
+        assert!(!ctx.node("level").unwrap().state().is_visible());
+        // Unrelated parameters are left alone.
+        assert!(ctx.node("limit").unwrap().is_validation_stale());
+
+        ctx.set("mode", Value::text("advanced"));
+        assert_eq!(ctx.on_change("mode").visibility_changed, ["level"]);
+        assert!(ctx.on_change("mode").visibility_changed.is_empty());
+    }
+
+    #[cfg(feature = "visibility")]
+    #[test]
+    fn test_context_reset_refreshes_visibility() {
+        use crate::visibility::eq;
+
+        let schema = Arc::new(
+            Schema::builder()
+                .parameter(Text::builder("mode").default("basic").build())
+                .parameter(
+                    Number::builder("level")
+                        .visible_when(eq("mode", "advanced"))
+                        .build(),
+                )
+                .build(),
+        );
+        let mut ctx = Context::with_defaults(schema);
+        let visible = |ctx: &Context| ctx.node("level").unwrap().state().is_visible();
+
+        ctx.on_change("mode");
+        assert!(!visible(&ctx));
+        ctx.set("level", Value::Int(3));
+        ctx.reset_key("level");
+        assert!(!visible(&ctx));
+
+        ctx.set("mode", Value::text("advanced"));
+        ctx.on_change("mode");
+        assert!(visible(&ctx));
+        ctx.reset();
+        assert!(!visible(&ctx));
+    }
+
+    #[cfg(feature = "events")]
+    #[test]
+    fn test_context_change_events() {
+        let (mut ctx, mut rx) = Context::new(create_test_schema()).with_events();
+
+        ctx.set("name", Value::text("alice"));
+        assert_eq!(
+            rx.try_recv().unwrap(),
+            ChangeEvent::Changed {
+                key: Key::from("name"),
+                old: None,
+                new: Some(Value::text("alice")),
+            }
+        );
+
+        // Same value again: no event
+        ctx.set("name", Value::text("alice"));
+        assert!(rx.try_recv().is_err());
+
+        ctx.clear("name");
+        assert_eq!(
+            rx.try_recv().unwrap(),
+            ChangeEvent::Changed {
+                key: Key::from("name"),
+                old: Some(Value::text("alice")),
+                new: None,
+            }
+        );
+
+        ctx.set("name", Value::text("bob"));
+        let _ = rx.try_recv();
+        ctx.reset_key("name");
+        assert_eq!(
+            rx.try_recv().unwrap(),
+            ChangeEvent::Changed {
+                key: Key::from("name"),
+                old: Some(Value::text("bob")),
+                new: None,
+            }
+        );
+
+        // Unknown keys never emit
+        ctx.set("unknown", Value::Int(1));
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[cfg(feature = "events")]
+    #[test]
+    fn test_context_without_events_has_no_subscribers() {
+        let ctx = Context::new(create_test_schema());
+        assert!(ctx.subscribe().is_none());
+
+        let (ctx, _rx) = ctx.with_events();
+        assert!(ctx.subscribe().is_some());
+    }
+
+    #[cfg(feature = "events")]
+    #[test]
+    fn test_context_batch_coalesces_events() {
+        let (mut ctx, mut rx) = Context::new(create_test_schema()).with_events();
+
+        ctx.batch(|ctx| {
+            ctx.set("name", Value::text("alice"));
+            ctx.set("age", Value::Int(30));
+            ctx.set("name", Value::text("bob"));
+            ctx.batch(|ctx| {
+                ctx.set("email", Value::text("bob@example.com"));
+            });
+        });
+
+        let event = rx.try_recv().unwrap();
+        assert_eq!(
+            event,
+            ChangeEvent::Batch {
+                keys: vec![Key::from("name"), Key::from("age"), Key::from("email")],
+            }
+        );
+        assert!(event.affects("age"));
+        assert!(rx.try_recv().is_err());
+        assert!(ctx.node("age").unwrap().state().is_dirty());
+
+        // A batch without changes emits nothing
+        ctx.batch(|ctx| {
+            ctx.set("age", Value::Int(30));
+        });
+        assert!(rx.try_recv().is_err());
+    }
+
+    #[cfg(feature = "events")]
+    #[test]
+    fn test_context_batch_ends_on_panic() {
+        let (mut ctx, mut rx) = Context::new(create_test_schema()).with_events();
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            ctx.batch(|ctx| {
+                ctx.set("name", Value::text("alice"));
+                panic!("boom");
+            });
+        }));
+        assert!(result.is_err());
+        assert!(rx.try_recv().is_err());
+
+        ctx.set("age", Value::Int(30));
+        assert!(rx.try_recv().unwrap().affects("age"));
+    }
+
+    #[test]
+    fn test_context_value_snapshot_round_trip() {
+        use crate::types::container::List;
+        use crate::types::decoration::Notice;
+
+        let schema = Arc::new(
+            Schema::builder()
+                .parameter(Notice::builder("intro").build())
+                .parameter(Text::builder("name").build())
+                .parameter(
+                    List::builder("tags")
+                        .item_template(Text::builder("tag").build())
+                        .build()
+                        .unwrap(),
+                )
+                .parameter(Number::builder("age").build())
+                .build(),
+        );
+        let mut ctx = Context::new(Arc::clone(&schema));
+        ctx.set("tags", Value::array([Value::text("a"), Value::text("b")]));
+        ctx.set("name", Value::Null);
+
+        let snapshot = ctx.to_value();
+        assert_eq!(
+            snapshot,
+            Value::object([
+                ("name", Value::Null),
+                ("tags", Value::array([Value::text("a"), Value::text("b")])),
+            ])
+        );
+
+        let mut restored = Context::new(schema);
+        let report = restored.load_value(&snapshot).unwrap();
+        assert_eq!(report.applied, ["name", "tags"]);
+        assert_eq!(restored.to_value(), snapshot);
+        assert_eq!(restored.get("age"), None);
+
+        let report = restored
+            .load_value(&Value::object([
+                ("intro", Value::Null),
+                ("x", Value::Int(1)),
+            ]))
+            .unwrap();
+        assert_eq!(report.unknown, ["intro", "x"]);
+        assert!(restored.load_value(&Value::Int(1)).is_err());
+    }
+
+    #[test]
+    fn test_context_deprecated_in_use() {
+        let schema = Schema::builder()
+            .parameter(Text::builder("old").deprecated().build())
+            .parameter(Number::builder("legacy").deprecated().hidden().build())
+            .parameter(Text::builder("new").build())
+            .build();
+        let mut ctx = Context::new(Arc::new(schema));
+
+        ctx.set("old", Value::text(""));
+        ctx.set("new", Value::text("x"));
+        assert!(ctx.deprecated_in_use().is_empty());
+
+        ctx.set("legacy", Value::Int(1));
+        ctx.set("old", Value::text("y"));
+        assert_eq!(ctx.deprecated_in_use(), ["old", "legacy"]);
+    }
+
+    #[cfg(feature = "events")]
+    #[test]
+    fn test_context_watch() {
+        let mut ctx = Context::new(create_test_schema());
+        let seen = Arc::new(Mutex::new(Vec::new()));
+
+        let log = Arc::clone(&seen);
+        let first = ctx.watch("name", move |v| log.lock().unwrap().push(("a", v.clone())));
+        let log = Arc::clone(&seen);
+        let second = ctx.watch("name", move |v| log.lock().unwrap().push(("b", v.clone())));
+        assert_eq!(first.key(), "name");
+
+        ctx.set("name", Value::text("x"));
+        ctx.set("name", Value::text("x"));
+        ctx.set("email", Value::text("e"));
+        assert_eq!(
+            *seen.lock().unwrap(),
+            [("a", Value::text("x")), ("b", Value::text("x"))]
+        );
+
+        first.unsubscribe();
+        ctx.clear("name");
+        assert_eq!(seen.lock().unwrap().last(), Some(&("b", Value::Null)));
+        assert_eq!(seen.lock().unwrap().len(), 3);
+
+        second.unsubscribe();
+        ctx.set("name", Value::text("y"));
+        assert_eq!(seen.lock().unwrap().len(), 3);
+    }
 }
@@ -0,0 +1,231 @@
+//! Composable parameter queries.
+//!
+//! [`ParamQuery`] replaces ad-hoc filter loops over a context, such as
+//! "dirty and required" or "hidden and tagged advanced".
+
+use super::Context;
+use crate::core::{Flags, Key};
+use crate::runtime::ErasedRuntimeNode;
+use crate::types::kind::NodeKind;
+
+/// A filter over the parameters of a [`Context`].
+///
+/// Created by [`Context::query`]. Each criterion narrows the result, so a
+/// parameter must match all of them. Run the query with
+/// [`iter`](Self::iter).
+///
+/// # Example
+///
+/// ```
+/// use paramdef::context::Context;
+/// use paramdef::core::{Flags, Value};
+/// use paramdef::schema::Schema;
+/// use paramdef::types::leaf::{Number, Text};
+/// use std::sync::Arc;
+///
+/// let schema = Arc::new(Schema::builder()
+///     .parameter(Text::builder("name").required().build())
+///     .parameter(Text::builder("email").required().build())
+///     .parameter(Number::builder("retries").tag("advanced").build())
+///     .build());
+/// let mut ctx = Context::new(schema);
+/// ctx.set("email", Value::text("a@example.com"));
+///
+/// let keys: Vec<_> = ctx.query().with_flag(Flags::REQUIRED).dirty().keys();
+/// assert_eq!(keys, ["email"]);
+/// assert_eq!(ctx.query().with_tag("advanced").count(), 1);
+/// ```
+#[derive(Debug, Clone)]
+#[must_use = "queries do nothing until iterated"]
+pub struct ParamQuery<'a> {
+    context: &'a Context,
+    flags: Flags,
+    kinds: Vec<NodeKind>,
+    tags: Vec<&'a str>,
+    dirty: bool,
+    #[cfg(feature = "validation")]
+    invalid: bool,
+}
+
+impl<'a> ParamQuery<'a> {
+    fn new(context: &'a Context) -> Self {
+        Self {
+            context,
+            flags: Flags::empty(),
+            kinds: Vec::new(),
+            tags: Vec::new(),
+            dirty: false,
+            #[cfg(feature = "validation")]
+            invalid: false,
+        }
+    }
+
+    /// Keeps parameters that have all of `flags` set.
+    pub fn with_flag(mut self, flags: Flags) -> Self {
+        self.flags |= flags;
+        self
+    }
+
+    /// Keeps parameters of the given kind.
+    pub fn of_kind(mut self, kind: NodeKind) -> Self {
+        self.kinds.push(kind);
+        self
+    }
+
+    /// Keeps parameters whose metadata has `tag`.
+    pub fn with_tag(mut self, tag: &'a str) -> Self {
+        self.tags.push(tag);
+        self
+    }
+
+    /// Keeps parameters whose value changed since they were last marked
+    /// clean.
+    pub fn dirty(mut self) -> Self {
+        self.dirty = true;
+        self
+    }
+
+    /// Keeps parameters that failed their last validation.
+    ///
+    /// Uses the recorded state; run
+    /// [`Context::validate_all`] first for current results.
+    #[cfg(feature = "validation")]
+    pub fn invalid(mut self) -> Self {
+        self.invalid = true;
+        self
+    }
+
+    /// Returns the matching parameters in schema order.
+    pub fn iter(self) -> impl Iterator<Item = (&'a Key, &'a ErasedRuntimeNode)> + 'a {
+        let context = self.context;
+        context
+            .schema
+            .keys()
+            .filter_map(|key| context.nodes.get_key_value(key))
+            .filter(move |(_, node)| self.matches(node))
+    }
+
+    /// Returns the keys of the matching parameters in schema order.
+    #[must_use]
+    pub fn keys(self) -> Vec<Key> {
+        self.iter().map(|(key, _)| key.clone()).collect()
+    }
+
+    /// Returns the number of matching parameters.
+    #[must_use]
+    pub fn count(self) -> usize {
+        self.iter().count()
+    }
+
+    fn matches(&self, runtime: &ErasedRuntimeNode) -> bool {
+        let node = runtime.node();
+        let state = runtime.state();
+
+        #[cfg(feature = "validation")]
+        if self.invalid && state.is_valid() {
+            return false;
+        }
+
+        node.flags().contains(self.flags)
+            && self.kinds.iter().all(|&kind| node.kind() == kind)
+            && self.tags.iter().all(|tag| node.metadata().has_tag(tag))
+            && (!self.dirty || state.is_dirty())
+    }
+}
+
+impl Context {
+    /// Starts a query over the parameters.
+    ///
+    /// See [`ParamQuery`] for the available criteria.
+    pub fn query(&self) -> ParamQuery<'_> {
+        ParamQuery::new(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::core::Value;
+    use crate::schema::Schema;
+    use crate::types::container::Object;
+    use crate::types::decoration::Notice;
+    use crate::types::leaf::{Number, Text};
+
+    fn context() -> Context {
+        let schema = Schema::builder()
+            .parameter(Text::builder("name").required().build())
+            .parameter(Number::builder("port").required().tag("advanced").build())
+            .parameter(Text::builder("debug").hidden().tag("advanced").build())
+            .parameter(Notice::builder("tip").build())
+            .build();
+        Context::new(Arc::new(schema))
+    }
+
+    #[test]
+    fn test_query_tags_on_non_leaf_nodes() {
+        let schema = Schema::builder()
+            .parameter(Text::builder("name").build())
+            .parameter(
+                Object::builder("proxy")
+                    .tag("advanced")
+                    .field("host", Text::builder("host").build())
+                    .build()
+                    .unwrap(),
+            )
+            .parameter(Notice::builder("tip").tag("advanced").build())
+            .build();
+        let ctx = Context::new(Arc::new(schema));
+
+        assert_eq!(ctx.query().with_tag("advanced").keys(), ["proxy", "tip"]);
+    }
+
+    #[test]
+    fn test_query_criteria() {
+        let mut ctx = context();
+        ctx.set("port", Value::Int(80));
+        ctx.set("debug", Value::text("on"));
+
+        assert_eq!(ctx.query().count(), 4);
+        assert_eq!(
+            ctx.query().with_flag(Flags::REQUIRED).keys(),
+            ["name", "port"]
+        );
+        assert_eq!(
+            ctx.query().with_flag(Flags::REQUIRED).dirty().keys(),
+            ["port"]
+        );
+        assert_eq!(
+            ctx.query()
+                .with_flag(Flags::HIDDEN)
+                .with_tag("advanced")
+                .keys(),
+            ["debug"]
+        );
+        assert_eq!(
+            ctx.query().with_tag("advanced").with_tag("other").count(),
+            0
+        );
+        assert_eq!(ctx.query().of_kind(NodeKind::Decoration).keys(), ["tip"]);
+        assert_eq!(
+            ctx.query()
+                .of_kind(NodeKind::Leaf)
+                .of_kind(NodeKind::Decoration)
+                .count(),
+            0
+        );
+    }
+
+    #[cfg(feature = "validation")]
+    #[test]
+    fn test_query_invalid() {
+        let mut ctx = context();
+        ctx.set("port", Value::Int(80));
+        assert_eq!(ctx.query().invalid().count(), 0);
+
+        ctx.validate_all();
+        assert_eq!(ctx.query().invalid().keys(), ["name"]);
+        assert_eq!(ctx.query().invalid().dirty().count(), 0);
+    }
+}
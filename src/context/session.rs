@@ -0,0 +1,197 @@
+//! Saving and restoring editing sessions.
+//!
+//! A session holds each parameter's value together with its runtime
+//! [`State`], so a half-filled form reopens with the same dirty, touched,
+//! and validation state.
+
+use super::{ApplyFailure, ApplyReport, Context};
+use crate::core::{Error, Result, Value};
+use crate::runtime::State;
+
+impl Context {
+    /// Returns the values and runtime states of all parameters.
+    ///
+    /// The result is an object keyed by parameter in schema order. Each
+    /// entry holds the `"value"`, omitted if unset, and the serialized
+    /// [`State`] under `"state"`. Restore it with
+    /// [`load_session`](Self::load_session).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::context::Context;
+    /// use paramdef::core::Value;
+    /// use paramdef::schema::Schema;
+    /// use paramdef::types::leaf::Text;
+    /// use std::sync::Arc;
+    ///
+    /// let schema = Arc::new(Schema::builder()
+    ///     .parameter(Text::builder("title").build())
+    ///     .build());
+    ///
+    /// let mut ctx = Context::new(Arc::clone(&schema));
+    /// ctx.set("title", Value::text("Draft"));
+    /// let session = ctx.save_session();
+    ///
+    /// let mut restored = Context::new(schema);
+    /// restored.load_session(&session).unwrap();
+    /// assert_eq!(restored.get_text("title"), Some("Draft"));
+    /// assert!(restored.node("title").unwrap().state().is_dirty());
+    /// ```
+    #[must_use]
+    pub fn save_session(&self) -> Value {
+        Value::object(self.schema.keys().filter_map(|key| {
+            let node = self.nodes.get(key)?;
+            // States hold only flags and strings, which always serialize.
+            let state = serde_json::to_value(node.state()).map_or(Value::Null, Value::from);
+            let mut entry = vec![("state", state)];
+            if let Some(value) = node.value() {
+                entry.insert(0, ("value", value.clone()));
+            }
+            Some((key.clone(), Value::object(entry)))
+        }))
+    }
+
+    /// Restores values and runtime states saved by
+    /// [`save_session`](Self::save_session).
+    ///
+    /// Each entry replaces the parameter's value, clearing it if the entry
+    /// has no `"value"`, and then its state. Entries that name no
+    /// parameter are skipped and listed in the report; malformed entries
+    /// are listed as failed and leave their parameter unchanged.
+    /// Parameters missing from the session are left unchanged.
+    ///
+    /// # Errors
+    ///
+    /// Returns a type mismatch error if `session` is not an object. No
+    /// entries are applied in that case.
+    pub fn load_session(&mut self, session: &Value) -> Result<ApplyReport> {
+        let entries = session
+            .as_object()
+            .ok_or_else(|| Error::type_mismatch("object", session.type_name()))?;
+
+        let mut report = ApplyReport::default();
+        for (key, entry) in entries {
+            if !self.nodes.contains_key(key) {
+                report.unknown.push(key.clone());
+                continue;
+            }
+            let Some(fields) = entry.as_object() else {
+                let err = Error::type_mismatch("object", entry.type_name());
+                report.failed.push(ApplyFailure::new(key.clone(), err));
+                continue;
+            };
+
+            let state = fields.get("state").cloned().unwrap_or(Value::Null);
+            let state = match serde_json::from_value::<State>(state.into()) {
+                Ok(state) => state,
+                Err(e) => {
+                    let err = Error::validation_with_fields(
+                        "invalid_session",
+                        format!("invalid state for '{key}': {e}"),
+                        vec![key.to_string()],
+                    );
+                    report.failed.push(ApplyFailure::new(key.clone(), err));
+                    continue;
+                }
+            };
+
+            match fields.get("value") {
+                Some(value) => self.write(key, value.clone()),
+                None => self.clear(key),
+            };
+            if let Some(node) = self.nodes.get_mut(key) {
+                *node.state_mut() = state;
+            }
+            report.applied.push(key.clone());
+        }
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::schema::Schema;
+    use crate::types::leaf::{Number, Text};
+
+    fn context() -> Context {
+        let schema = Schema::builder()
+            .parameter(Text::builder("name").build())
+            .parameter(Number::builder("age").build())
+            .parameter(Text::builder("note").build())
+            .build();
+        Context::new(Arc::new(schema))
+    }
+
+    #[test]
+    fn test_session_round_trip() {
+        let mut ctx = context();
+        ctx.set("name", Value::text("Ann"));
+        ctx.set("age", Value::Int(-1));
+        ctx.mark_all_clean();
+        ctx.set("age", Value::Int(200));
+        ctx.node_mut("name").unwrap().state_mut().mark_touched();
+        ctx.node_mut("age")
+            .unwrap()
+            .state_mut()
+            .set_validation_result(vec![
+                Error::out_of_range(200.0, 0.0, 150.0),
+                Error::validation_at("custom", "inner", "bad"),
+            ]);
+
+        let session = ctx.save_session();
+        assert!(session.get_pointer("/note/value").is_none());
+
+        let mut restored = context();
+        restored.set("note", Value::text("stale"));
+        let report = restored.load_session(&session).unwrap();
+        assert_eq!(report.applied, ["name", "age", "note"]);
+
+        assert_eq!(restored.get_text("name"), Some("Ann"));
+        assert_eq!(restored.get("note"), None);
+        let name = restored.node("name").unwrap().state();
+        assert!(name.is_touched());
+        assert!(!name.is_dirty());
+
+        let age = restored.node("age").unwrap().state();
+        assert!(age.is_dirty());
+        assert!(!age.is_valid());
+        assert_eq!(age.errors().len(), 2);
+        assert_eq!(age.errors()[0].code(), Some("out_of_range"));
+        assert_eq!(
+            age.errors()[0].to_string(),
+            "validation failed: value 200 is out of range [0, 150]"
+        );
+        assert_eq!(age.errors()[1].field_path(), Some("inner"));
+    }
+
+    #[test]
+    fn test_session_unknown_and_malformed() {
+        let session = Value::object([
+            ("ghost", Value::object([("value", Value::Int(1))])),
+            ("name", Value::Int(1)),
+            (
+                "age",
+                Value::object([
+                    ("value", Value::Int(3)),
+                    ("state", Value::object([("flags", Value::Bool(true))])),
+                ]),
+            ),
+        ]);
+
+        let mut ctx = context();
+        let report = ctx.load_session(&session).unwrap();
+        assert_eq!(report.unknown, ["ghost"]);
+        assert!(report.applied.is_empty());
+        assert_eq!(report.failed.len(), 2);
+        assert_eq!(report.failed[0].code, "type_mismatch");
+        assert_eq!(report.failed[1].code, "invalid_session");
+        assert_eq!(ctx.get("age"), None);
+
+        assert!(ctx.load_session(&Value::Int(1)).is_err());
+    }
+}
@@ -0,0 +1,190 @@
+//! TOML import and export of context values.
+//!
+//! Values go through the `serde_json` conversion of [`Value`], so binary
+//! values are written as base64 strings and read back as text.
+
+use super::{ApplyReport, Context};
+use crate::core::{Error, Key, Result, Value};
+
+impl Context {
+    /// Serializes the current values to a TOML document.
+    ///
+    /// Parameters appear in schema order. Parameters without a value, or
+    /// with a `Null` value, are omitted. Binary values are encoded as
+    /// base64 strings.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `"toml_unrepresentable"` validation error naming the
+    /// parameter if its value contains a non-finite float or a nested
+    /// `Null`, neither of which TOML can represent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::context::Context;
+    /// use paramdef::core::Value;
+    /// use paramdef::schema::Schema;
+    /// use paramdef::types::leaf::{Number, Text};
+    /// use std::sync::Arc;
+    ///
+    /// let schema = Arc::new(Schema::builder()
+    ///     .parameter(Text::builder("name").build())
+    ///     .parameter(Number::builder("port").build())
+    ///     .build());
+    ///
+    /// let mut ctx = Context::new(schema);
+    /// ctx.set("name", Value::text("server"));
+    /// ctx.set("port", Value::Int(8080));
+    ///
+    /// assert_eq!(ctx.to_toml().unwrap(), "name = \"server\"\nport = 8080\n");
+    /// ```
+    pub fn to_toml(&self) -> Result<String> {
+        let mut table = toml::value::Table::new();
+
+        for key in self.schema.keys() {
+            let Some(value) = self.get(key).filter(|v| !v.is_null()) else {
+                continue;
+            };
+            if let Some(problem) = unrepresentable(value) {
+                return Err(Error::validation_with_fields(
+                    "toml_unrepresentable",
+                    format!("parameter '{key}' cannot be written as TOML: {problem}"),
+                    vec![key.to_string()],
+                ));
+            }
+
+            let json = serde_json::Value::from(value.clone());
+            let value = toml::Value::try_from(json)
+                .map_err(|e| Error::custom(format!("TOML serialization failed: {e}")))?;
+            table.insert(key.to_string(), value);
+        }
+
+        toml::to_string(&table)
+            .map_err(|e| Error::custom(format!("TOML serialization failed: {e}")))
+    }
+
+    /// Applies values from a TOML document.
+    ///
+    /// Each top-level key is set on the matching parameter; keys the schema
+    /// does not know are skipped and listed in the report. Datetimes are
+    /// read as text, as are base64 strings written for binary values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `"invalid_toml"` validation error if `s` is not valid
+    /// TOML. No values are applied in that case.
+    pub fn from_toml(&mut self, s: &str) -> Result<ApplyReport> {
+        let table: toml::value::Table =
+            toml::from_str(s).map_err(|e| Error::validation("invalid_toml", e.to_string()))?;
+
+        let mut report = ApplyReport::default();
+        for (key, value) in table {
+            let value = Value::from(toml_to_json(value));
+            if self.set(&key, value) {
+                report.applied.push(Key::from(key));
+            } else {
+                report.unknown.push(Key::from(key));
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+/// Describes why a value cannot be written as TOML, if it cannot.
+fn unrepresentable(value: &Value) -> Option<&'static str> {
+    match value {
+        Value::Null => Some("null values are not supported"),
+        Value::Float(f) if !f.is_finite() => Some("non-finite floats are not supported"),
+        Value::Array(items) => items.iter().find_map(unrepresentable),
+        Value::Object(fields) => fields.values().find_map(unrepresentable),
+        _ => None,
+    }
+}
+
+/// Converts a TOML value to JSON.
+///
+/// Non-finite floats become the strings that `Value`'s JSON conversion
+/// reads back as floats.
+fn toml_to_json(value: toml::Value) -> serde_json::Value {
+    match value {
+        toml::Value::String(s) => serde_json::Value::String(s),
+        toml::Value::Integer(i) => serde_json::Value::from(i),
+        toml::Value::Float(f) => serde_json::Number::from_f64(f)
+            .map_or_else(|| serde_json::Value::String(f.to_string()), Into::into),
+        toml::Value::Boolean(b) => serde_json::Value::Bool(b),
+        toml::Value::Datetime(dt) => serde_json::Value::String(dt.to_string()),
+        toml::Value::Array(items) => items.into_iter().map(toml_to_json).collect(),
+        toml::Value::Table(table) => serde_json::Value::Object(
+            table
+                .into_iter()
+                .map(|(k, v)| (k, toml_to_json(v)))
+                .collect(),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::schema::Schema;
+    use crate::types::leaf::{Boolean, Number, Text};
+
+    fn context() -> Context {
+        let schema = Schema::builder()
+            .parameter(Text::builder("name").build())
+            .parameter(Number::builder("ratio").build())
+            .parameter(Boolean::builder("enabled").build())
+            .parameter(Text::builder("data").build())
+            .build();
+        Context::new(Arc::new(schema))
+    }
+
+    #[test]
+    fn test_toml_round_trip() {
+        let mut ctx = context();
+        ctx.set("name", Value::text("server"));
+        ctx.set("ratio", Value::Float(0.5));
+        ctx.set("enabled", Value::Bool(true));
+        ctx.set("data", Value::binary([1, 2, 3]));
+
+        let toml = ctx.to_toml().unwrap();
+        assert!(toml.contains("data = \"AQID\""));
+
+        let mut restored = context();
+        let report = restored.from_toml(&toml).unwrap();
+        assert_eq!(report.applied.len(), 4);
+        assert!(report.unknown.is_empty());
+        assert_eq!(restored.get("name"), ctx.get("name"));
+        assert_eq!(restored.get("ratio"), ctx.get("ratio"));
+        assert_eq!(restored.get("data"), Some(&Value::text("AQID")));
+    }
+
+    #[test]
+    fn test_toml_non_finite_float() {
+        let mut ctx = context();
+        ctx.set("ratio", Value::Float(f64::NAN));
+
+        let err = ctx.to_toml().unwrap_err();
+        assert_eq!(err.code(), Some("toml_unrepresentable"));
+        assert_eq!(err.fields(), ["ratio"]);
+    }
+
+    #[test]
+    fn test_from_toml_unknown_and_invalid() {
+        let mut ctx = context();
+        let report = ctx
+            .from_toml("name = \"a\"\nextra = 1\nratio = inf\n")
+            .unwrap();
+
+        assert_eq!(report.applied, ["name", "ratio"]);
+        assert_eq!(report.unknown, ["extra"]);
+        assert_eq!(ctx.get("ratio"), Some(&Value::Float(f64::INFINITY)));
+
+        let err = ctx.from_toml("name = ").unwrap_err();
+        assert_eq!(err.code(), Some("invalid_toml"));
+    }
+}
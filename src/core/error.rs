@@ -28,6 +28,9 @@ pub enum Error {
         message: String,
         /// Fields involved in the validation error.
         fields: Vec<String>,
+        /// Dotted path to the failing value inside a nested value, such as
+        /// `"address.city"` or `"items.3"`.
+        field_path: Option<String>,
     },
 
     /// Required value is missing.
@@ -90,6 +93,21 @@ pub enum Error {
     /// Generic error with custom message.
     #[error("{0}")]
     Custom(String),
+
+    /// Several errors reported together.
+    ///
+    /// Created by [`Error::aggregate`]; may nest.
+    #[error("{} errors: {}", .0.len(), join_messages(.0))]
+    Multiple(Vec<Error>),
+}
+
+/// Joins error messages for the [`Error::Multiple`] display.
+fn join_messages(errors: &[Error]) -> String {
+    errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
 }
 
 impl Error {
@@ -108,6 +126,35 @@ impl Error {
             code: code.into(),
             message: message.into(),
             fields: Vec::new(),
+            field_path: None,
+        }
+    }
+
+    /// Creates a validation error for the value at a nested path.
+    ///
+    /// `path` is dotted, with list indices as segments: `"address.city"`,
+    /// `"items.3"`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::core::Error;
+    ///
+    /// let err = Error::validation_at("required", "items.3", "name is required");
+    /// assert_eq!(err.field_path(), Some("items.3"));
+    /// ```
+    #[must_use]
+    #[track_caller]
+    pub fn validation_at(
+        code: impl Into<String>,
+        path: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        Self::Validation {
+            code: code.into(),
+            message: message.into(),
+            fields: Vec::new(),
+            field_path: Some(path.into()),
         }
     }
 
@@ -123,6 +170,7 @@ impl Error {
             code: code.into(),
             message: message.into(),
             fields,
+            field_path: None,
         }
     }
 
@@ -190,6 +238,48 @@ impl Error {
         Self::Custom(message.into())
     }
 
+    /// Combines several errors into one result.
+    ///
+    /// Returns `Ok(())` if `errors` is empty, the error itself if there is
+    /// exactly one, and [`Error::Multiple`] otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error whenever `errors` is not empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::core::Error;
+    ///
+    /// assert!(Error::aggregate(Vec::new()).is_ok());
+    ///
+    /// let err = Error::aggregate(vec![
+    ///     Error::missing_required("name"),
+    ///     Error::missing_required("email"),
+    /// ])
+    /// .unwrap_err();
+    /// assert_eq!(err.iter_flat().count(), 2);
+    /// ```
+    pub fn aggregate(mut errors: Vec<Error>) -> Result<()> {
+        match errors.len() {
+            0 => Ok(()),
+            1 => Err(errors.remove(0)),
+            _ => Err(Self::Multiple(errors)),
+        }
+    }
+
+    /// Iterates over the individual errors, flattening nested
+    /// [`Error::Multiple`] aggregates.
+    ///
+    /// Yields just `self` for any other variant.
+    pub fn iter_flat(&self) -> Box<dyn Iterator<Item = &Error> + '_> {
+        match self {
+            Self::Multiple(errors) => Box::new(errors.iter().flat_map(Error::iter_flat)),
+            other => Box::new(std::iter::once(other)),
+        }
+    }
+
     /// Returns the error code if this is a validation error.
     #[must_use]
     pub fn code(&self) -> Option<&str> {
@@ -207,6 +297,76 @@ impl Error {
             _ => &[],
         }
     }
+
+    /// Returns the nested path of the failing value, if this is a
+    /// validation error that carries one.
+    #[must_use]
+    pub fn field_path(&self) -> Option<&str> {
+        match self {
+            Self::Validation { field_path, .. } => field_path.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Prefixes the field path with `segment`, for errors reported by a
+    /// child value.
+    ///
+    /// Containers call this as an error bubbles up, so a failure in
+    /// `city` inside `address` ends up at `"address.city"`. Aggregates
+    /// prefix each of their errors. Other errors become validation errors,
+    /// with a code naming the original variant and the original message.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::core::Error;
+    ///
+    /// let err = Error::missing_required("city").at("city").at("address");
+    /// assert_eq!(err.code(), Some("required"));
+    /// assert_eq!(err.field_path(), Some("address.city"));
+    /// ```
+    #[must_use]
+    pub fn at(self, segment: &str) -> Self {
+        match self {
+            Self::Validation {
+                code,
+                message,
+                fields,
+                field_path,
+            } => Self::Validation {
+                code,
+                message,
+                fields,
+                field_path: Some(match field_path {
+                    Some(path) => format!("{segment}.{path}"),
+                    None => segment.to_owned(),
+                }),
+            },
+            Self::Multiple(errors) => {
+                Self::Multiple(errors.into_iter().map(|e| e.at(segment)).collect())
+            }
+            other => other.into_validation().at(segment),
+        }
+    }
+
+    /// Converts the error into a validation error.
+    ///
+    /// Validation errors and aggregates are returned unchanged. Other
+    /// errors get a code naming the original variant and keep the original
+    /// message.
+    pub(crate) fn into_validation(self) -> Self {
+        let code = match &self {
+            Self::Validation { .. } | Self::Multiple(_) => return self,
+            Self::TypeMismatch { .. } => "type_mismatch",
+            Self::MissingRequired { .. } => "required",
+            Self::OutOfRange { .. } => "out_of_range",
+            Self::LengthOutOfBounds { .. } => "length_out_of_bounds",
+            Self::PatternMismatch { .. } => "pattern_mismatch",
+            Self::NotInAllowedValues { .. } => "not_in_allowed_values",
+            _ => "invalid",
+        };
+        Self::validation(code, self.to_string())
+    }
 }
 
 #[cfg(test)]
@@ -239,6 +399,42 @@ mod tests {
         );
 
         assert_eq!(err.fields(), &["password", "confirm"]);
+        assert_eq!(err.field_path(), None);
+    }
+
+    #[test]
+    fn test_validation_error_field_path() {
+        let err = Error::validation_at("too_short", "name", "name is too short");
+        assert_eq!(err.code(), Some("too_short"));
+        assert_eq!(err.field_path(), Some("name"));
+
+        let err = err.at("3").at("items");
+        assert_eq!(err.field_path(), Some("items.3.name"));
+        assert_eq!(err.to_string(), "validation failed: name is too short");
+
+        let err = Error::out_of_range(150.0, 0.0, 100.0).at("age");
+        assert_eq!(err.code(), Some("out_of_range"));
+        assert_eq!(err.field_path(), Some("age"));
+        assert!(err.to_string().contains("150"));
+        assert_eq!(Error::custom("x").field_path(), None);
+    }
+
+    #[test]
+    fn test_aggregate_errors() {
+        assert!(Error::aggregate(Vec::new()).is_ok());
+
+        let single = Error::aggregate(vec![Error::custom("only")]).unwrap_err();
+        assert!(matches!(single, Error::Custom(_)));
+
+        let nested = Error::aggregate(vec![Error::custom("b"), Error::custom("c")]).unwrap_err();
+        let err = Error::aggregate(vec![Error::custom("a"), nested]).unwrap_err();
+        assert_eq!(err.to_string(), "2 errors: a; 2 errors: b; c");
+
+        let messages: Vec<String> = err.iter_flat().map(ToString::to_string).collect();
+        assert_eq!(messages, ["a", "b", "c"]);
+
+        let err = err.at("items");
+        assert!(err.iter_flat().all(|e| e.field_path() == Some("items")));
     }
 
     #[test]
@@ -59,6 +59,9 @@ bitflags! {
         const REALTIME = 1 << 9;
 
         /// Parameter is deprecated and should show warning.
+        ///
+        /// Independent of `HIDDEN`: a deprecated parameter stays visible
+        /// unless also hidden. See `Context::deprecated_in_use`.
         const DEPRECATED = 1 << 10;
 
         /// Parameter is experimental/unstable.
@@ -72,6 +75,12 @@ bitflags! {
 
         /// Parameter is disabled (grayed out in UI).
         const DISABLED = 1 << 14;
+
+        /// Parameter value is derived by the evaluation engine.
+        ///
+        /// `Context::set` refuses to write computed parameters; the engine
+        /// writes them through `Context::set_computed`.
+        const COMPUTED = 1 << 15;
     }
 }
 
@@ -181,13 +190,23 @@ impl Flags {
         self.contains(Self::DISABLED)
     }
 
+    /// Returns `true` if the COMPUTED flag is set.
+    #[inline]
+    #[must_use]
+    pub const fn is_computed(self) -> bool {
+        self.contains(Self::COMPUTED)
+    }
+
     /// Returns flags for a computed/runtime value.
     ///
-    /// Combines `RUNTIME | READONLY | SKIP_SAVE`.
+    /// Combines `COMPUTED | RUNTIME | READONLY | SKIP_SAVE`.
     #[inline]
     #[must_use]
     pub const fn computed() -> Self {
-        Self::RUNTIME.union(Self::READONLY).union(Self::SKIP_SAVE)
+        Self::COMPUTED
+            .union(Self::RUNTIME)
+            .union(Self::READONLY)
+            .union(Self::SKIP_SAVE)
     }
 
     /// Returns flags for an animatable property.
@@ -209,6 +228,9 @@ impl Flags {
     }
 }
 
+/// Placeholder shown instead of the value of a [`Flags::SENSITIVE`] parameter.
+pub const REDACTED: &str = "***";
+
 bitflags! {
     /// Runtime parameter state flags.
     ///
@@ -231,6 +253,7 @@ bitflags! {
     /// assert!(state.is_dirty());
     /// ```
     #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
     pub struct StateFlags: u8 {
         /// Value has changed since last save/sync.
         const DIRTY = 1 << 0;
@@ -249,6 +272,13 @@ bitflags! {
 
         /// Parameter is currently readonly (runtime override).
         const READONLY = 1 << 5;
+
+        /// Value changed since the last validation, so VALID may be out
+        /// of date.
+        const NEEDS_REVALIDATION = 1 << 6;
+
+        /// Value is valid but outside its recommended bounds.
+        const WARNING = 1 << 7;
     }
 }
 
@@ -295,6 +325,20 @@ impl StateFlags {
         self.contains(Self::READONLY)
     }
 
+    /// Returns `true` if the `NEEDS_REVALIDATION` flag is set.
+    #[inline]
+    #[must_use]
+    pub const fn needs_revalidation(self) -> bool {
+        self.contains(Self::NEEDS_REVALIDATION)
+    }
+
+    /// Returns `true` if the WARNING flag is set.
+    #[inline]
+    #[must_use]
+    pub const fn has_warning(self) -> bool {
+        self.contains(Self::WARNING)
+    }
+
     /// Returns default initial state (VISIBLE | ENABLED).
     #[inline]
     #[must_use]
@@ -346,6 +390,7 @@ mod tests {
     #[test]
     fn test_flags_computed_convenience() {
         let flags = Flags::computed();
+        assert!(flags.is_computed());
         assert!(flags.is_runtime());
         assert!(flags.is_readonly());
         assert!(flags.is_skip_save());
@@ -22,6 +22,8 @@ use std::ops::Deref;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use smartstring::{LazyCompact, SmartString};
 
+use super::Error;
+
 /// A parameter identifier using stack-optimized strings.
 ///
 /// Keys are typically short identifiers like `"username"`, `"port"`, or `"enabled"`.
@@ -68,6 +70,62 @@ impl Key {
         Self(s.as_ref().into())
     }
 
+    /// Creates a key, checking it against the identifier grammar.
+    ///
+    /// See [`is_valid`](Self::is_valid) for the grammar. The infallible
+    /// [`new`](Self::new) and `From` conversions accept any string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `"invalid_key"` validation error if `s` is not a valid key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paramdef::core::Key;
+    ///
+    /// assert!(Key::try_new("server.port").is_ok());
+    /// assert!(Key::try_new("2fa").is_err());
+    /// ```
+    pub fn try_new(s: &str) -> super::Result<Self> {
+        if Self::is_valid(s) {
+            Ok(Self::new(s))
+        } else {
+            Err(Error::validation(
+                "invalid_key",
+                format!(
+                    "invalid key '{s}': keys must start with a letter or '_' \
+                     and contain only letters, digits, '_', '.' and '-'"
+                ),
+            ))
+        }
+    }
+
+    /// Returns `true` if `s` is a valid key.
+    ///
+    /// A valid key starts with an ASCII letter or `_`, followed by ASCII
+    /// letters, digits, `_`, `.` or `-`. Dotted path lookups treat `.` as a
+    /// separator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paramdef::core::Key;
+    ///
+    /// assert!(Key::is_valid("_private"));
+    /// assert!(Key::is_valid("db.max-connections"));
+    /// assert!(!Key::is_valid(""));
+    /// assert!(!Key::is_valid("has space"));
+    /// ```
+    #[must_use]
+    pub fn is_valid(s: &str) -> bool {
+        let mut chars = s.chars();
+        chars
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'))
+    }
+
     /// Returns the key as a string slice.
     ///
     /// # Examples
@@ -302,4 +360,18 @@ mod tests {
         assert!(empty.is_empty());
         assert!(!non_empty.is_empty());
     }
+
+    #[test]
+    fn test_key_validation() {
+        for valid in ["a", "_", "user_name", "Port2", "db.host", "max-size"] {
+            assert!(Key::is_valid(valid), "{valid}");
+            assert_eq!(Key::try_new(valid).unwrap(), valid);
+        }
+
+        for invalid in ["", "1st", ".hidden", "-flag", "a b", "ключ", "a/b"] {
+            assert!(!Key::is_valid(invalid), "{invalid}");
+            let err = Key::try_new(invalid).unwrap_err();
+            assert_eq!(err.code(), Some("invalid_key"));
+        }
+    }
 }
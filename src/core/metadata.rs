@@ -2,8 +2,9 @@
 //!
 //! Metadata contains display information for parameters like labels, descriptions,
 //! grouping, and tags. It uses the builder pattern for ergonomic construction.
+//! Free-form attributes carry hints for specific UI frameworks.
 
-use super::Key;
+use super::{IndexMap, Key, Value};
 use smallvec::SmallVec;
 
 /// Display and organizational metadata for a parameter.
@@ -28,23 +29,75 @@ use smallvec::SmallVec;
 ///     .tag("validated")
 ///     .build();
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Metadata {
     /// Unique identifier for the parameter.
     key: Key,
 
     /// Human-readable label for display.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     label: Option<Key>,
 
     /// Longer description or help text.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     description: Option<Key>,
 
     /// Grouping category for organization.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
     group: Option<Key>,
 
     /// Tags for filtering and categorization.
     /// Uses `SmallVec` to avoid heap allocation for small tag counts.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "SmallVec::is_empty")
+    )]
     tags: SmallVec<[Key; 4]>,
+
+    /// Free-form attributes, such as UI hints, in insertion order.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "IndexMap::is_empty")
+    )]
+    attributes: IndexMap<Key, Value>,
+
+    /// Layout position hint; lower values come first.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    order: Option<i32>,
+
+    /// Keys of the parameters this one is derived from.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "SmallVec::is_empty")
+    )]
+    depends_on: SmallVec<[Key; 4]>,
+
+    /// Link to further documentation.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    help_url: Option<Key>,
+
+    /// Example value, for placeholders such as "e.g. …".
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    example: Option<Value>,
 }
 
 impl Metadata {
@@ -65,6 +118,11 @@ impl Metadata {
             description: None,
             group: None,
             tags: SmallVec::new(),
+            attributes: IndexMap::new(),
+            order: None,
+            depends_on: SmallVec::new(),
+            help_url: None,
+            example: None,
         }
     }
 
@@ -124,6 +182,63 @@ impl Metadata {
         self.tags.iter().any(|t| t.as_str() == tag)
     }
 
+    /// Returns an attribute value by name, if set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paramdef::core::{Metadata, Value};
+    ///
+    /// let meta = Metadata::builder("volume")
+    ///     .attribute("widget", Value::text("slider"))
+    ///     .build();
+    ///
+    /// assert_eq!(meta.attribute("widget"), Some(&Value::text("slider")));
+    /// assert_eq!(meta.attribute("step"), None);
+    /// ```
+    #[must_use]
+    pub fn attribute(&self, name: &str) -> Option<&Value> {
+        self.attributes.get(name)
+    }
+
+    /// Returns all attributes in insertion order.
+    #[inline]
+    #[must_use]
+    pub fn attributes(&self) -> &IndexMap<Key, Value> {
+        &self.attributes
+    }
+
+    /// Returns the layout order hint, if set.
+    ///
+    /// Lower values are shown first. See
+    /// [`GroupNode::children_sorted_by_order`](crate::types::traits::GroupNode::children_sorted_by_order).
+    #[inline]
+    #[must_use]
+    pub fn order(&self) -> Option<i32> {
+        self.order
+    }
+
+    /// Returns the keys of the parameters this one is derived from.
+    #[inline]
+    #[must_use]
+    pub fn dependencies(&self) -> &[Key] {
+        &self.depends_on
+    }
+
+    /// Returns the documentation link, if set.
+    #[inline]
+    #[must_use]
+    pub fn help_url(&self) -> Option<&str> {
+        self.help_url.as_deref()
+    }
+
+    /// Returns the example value, if set.
+    #[inline]
+    #[must_use]
+    pub fn example(&self) -> Option<&Value> {
+        self.example.as_ref()
+    }
+
     /// Returns the display label or falls back to the key.
     #[inline]
     #[must_use]
@@ -158,6 +273,41 @@ impl Metadata {
         self.tags.push(tag.into());
         self
     }
+
+    /// Returns a new Metadata with the given attribute set.
+    #[must_use]
+    pub fn with_attribute(mut self, name: impl Into<Key>, value: impl Into<Value>) -> Self {
+        self.attributes.insert(name.into(), value.into());
+        self
+    }
+
+    /// Returns a new Metadata with the given order hint.
+    #[must_use]
+    pub fn with_order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Returns a new Metadata with the given documentation link.
+    #[must_use]
+    pub fn with_help_url(mut self, url: impl Into<Key>) -> Self {
+        self.help_url = Some(url.into());
+        self
+    }
+
+    /// Returns a new Metadata with the given example value.
+    #[must_use]
+    pub fn with_example(mut self, value: Value) -> Self {
+        self.example = Some(value);
+        self
+    }
+
+    /// Returns a new Metadata with an added dependency.
+    #[must_use]
+    pub fn with_dependency(mut self, key: impl Into<Key>) -> Self {
+        self.depends_on.push(key.into());
+        self
+    }
 }
 
 /// Builder for constructing [`Metadata`].
@@ -181,6 +331,11 @@ pub struct MetadataBuilder {
     description: Option<Key>,
     group: Option<Key>,
     tags: SmallVec<[Key; 4]>,
+    attributes: IndexMap<Key, Value>,
+    order: Option<i32>,
+    depends_on: SmallVec<[Key; 4]>,
+    help_url: Option<Key>,
+    example: Option<Value>,
 }
 
 impl MetadataBuilder {
@@ -192,6 +347,11 @@ impl MetadataBuilder {
             description: None,
             group: None,
             tags: SmallVec::new(),
+            attributes: IndexMap::new(),
+            order: None,
+            depends_on: SmallVec::new(),
+            help_url: None,
+            example: None,
         }
     }
 
@@ -230,6 +390,45 @@ impl MetadataBuilder {
         self
     }
 
+    /// Sets a free-form attribute, replacing any previous value.
+    #[must_use]
+    pub fn attribute(mut self, name: impl Into<Key>, value: impl Into<Value>) -> Self {
+        self.attributes.insert(name.into(), value.into());
+        self
+    }
+
+    /// Sets the layout order hint; lower values come first.
+    #[must_use]
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Declares that this parameter is derived from `key`.
+    ///
+    /// See [`Schema::evaluation_order`](crate::schema::Schema::evaluation_order).
+    #[must_use]
+    pub fn depends_on(mut self, key: impl Into<Key>) -> Self {
+        self.depends_on.push(key.into());
+        self
+    }
+
+    /// Sets a link to further documentation, such as a "learn more" page.
+    #[must_use]
+    pub fn help_url(mut self, url: impl Into<Key>) -> Self {
+        self.help_url = Some(url.into());
+        self
+    }
+
+    /// Sets an example value.
+    ///
+    /// Independent of the default: the example is only shown as a hint.
+    #[must_use]
+    pub fn example(mut self, value: Value) -> Self {
+        self.example = Some(value);
+        self
+    }
+
     /// Builds the metadata.
     #[must_use]
     pub fn build(self) -> Metadata {
@@ -239,6 +438,11 @@ impl MetadataBuilder {
             description: self.description,
             group: self.group,
             tags: self.tags,
+            attributes: self.attributes,
+            order: self.order,
+            depends_on: self.depends_on,
+            help_url: self.help_url,
+            example: self.example,
         }
     }
 }
@@ -337,4 +541,135 @@ mod tests {
 
         assert_eq!(meta1, meta2);
     }
+
+    #[test]
+    fn test_metadata_attributes() {
+        let meta = Metadata::builder("volume")
+            .attribute("widget", "slider")
+            .attribute("step", 0.5)
+            .attribute("widget", "knob")
+            .build();
+
+        assert_eq!(meta.attributes().len(), 2);
+        assert_eq!(meta.attribute("widget"), Some(&Value::text("knob")));
+        assert_eq!(meta.attribute("step"), Some(&Value::Float(0.5)));
+        assert!(meta.attribute("missing").is_none());
+
+        let meta = Metadata::new("key").with_attribute("hidden_in", "mobile");
+        assert_eq!(meta.attribute("hidden_in"), Some(&Value::text("mobile")));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_metadata_serde() {
+        let meta = Metadata::builder("volume")
+            .label("Volume")
+            .attribute("widget", "slider")
+            .build();
+
+        let json = serde_json::to_value(&meta).unwrap();
+        assert_eq!(json["label"], "Volume");
+        assert_eq!(json["attributes"]["widget"], "slider");
+        assert!(json.get("tags").is_none());
+
+        let restored: Metadata = serde_json::from_value(json).unwrap();
+        assert_eq!(restored, meta);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_metadata_attributes_serialize_in_order() {
+        let meta = Metadata::builder("volume")
+            .attribute("widget", "slider")
+            .attribute("step", 0.5)
+            .attribute("accent", "blue")
+            .build();
+
+        let json = serde_json::to_string(&meta).unwrap();
+        assert!(json.contains(r#""attributes":{"widget":"slider","step":0.5,"accent":"blue"}"#));
+    }
+
+    #[test]
+    fn test_metadata_eq_compares_attributes() {
+        let meta = Metadata::builder("x").attribute("scale", 2.0).build();
+
+        assert_eq!(meta, meta.clone());
+        assert_ne!(meta, Metadata::new("x"));
+        // Attribute order does not affect equality
+        let a = Metadata::builder("x")
+            .attribute("a", 1)
+            .attribute("b", 2)
+            .build();
+        let b = Metadata::builder("x")
+            .attribute("b", 2)
+            .attribute("a", 1)
+            .build();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_metadata_help_url_and_example() {
+        let meta = Metadata::new("port");
+        assert_eq!(meta.help_url(), None);
+        assert_eq!(meta.example(), None);
+
+        let meta = Metadata::builder("port")
+            .description("Port to listen on")
+            .help_url("https://example.com/docs/port")
+            .example(Value::Int(8080))
+            .build();
+        assert_eq!(meta.help_url(), Some("https://example.com/docs/port"));
+        assert_eq!(meta.example(), Some(&Value::Int(8080)));
+        assert_eq!(meta.description(), Some("Port to listen on"));
+
+        let meta = Metadata::new("host")
+            .with_help_url("https://example.com")
+            .with_example(Value::text("localhost"));
+        assert_eq!(meta.help_url(), Some("https://example.com"));
+        assert_eq!(meta.example(), Some(&Value::text("localhost")));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_metadata_serde_help_url_and_example() {
+        let meta = Metadata::builder("port")
+            .help_url("https://example.com")
+            .example(Value::Int(8080))
+            .build();
+
+        let json = serde_json::to_value(&meta).unwrap();
+        assert_eq!(json["help_url"], "https://example.com");
+        assert_eq!(json["example"], 8080);
+        assert!(
+            serde_json::to_value(Metadata::new("x"))
+                .unwrap()
+                .get("example")
+                .is_none()
+        );
+
+        let restored: Metadata = serde_json::from_value(json).unwrap();
+        assert_eq!(restored, meta);
+    }
+
+    #[test]
+    fn test_metadata_order() {
+        assert_eq!(Metadata::new("key").order(), None);
+        assert_eq!(Metadata::builder("key").order(-1).build().order(), Some(-1));
+        assert_eq!(Metadata::new("key").with_order(3).order(), Some(3));
+    }
+
+    #[test]
+    fn test_metadata_dependencies() {
+        assert!(Metadata::new("key").dependencies().is_empty());
+
+        let meta = Metadata::builder("total")
+            .depends_on("net")
+            .depends_on("tax")
+            .build();
+        assert_eq!(meta.dependencies(), ["net", "tax"]);
+        assert_eq!(
+            Metadata::new("key").with_dependency("a").dependencies(),
+            ["a"]
+        );
+    }
 }
@@ -18,10 +18,12 @@ mod metadata;
 mod value;
 
 pub use error::{Error, Result};
-pub use flags::{Flags, StateFlags};
+pub use flags::{Flags, REDACTED, StateFlags};
 pub use key::Key;
 pub use metadata::{Metadata, MetadataBuilder};
-pub use value::Value;
+pub use value::{Value, ValueDiff};
+
+pub(crate) use value::Fnv1a;
 
 /// Stack-optimized string for display text (labels, descriptions, messages).
 ///
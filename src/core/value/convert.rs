@@ -1,6 +1,7 @@
 //! Type conversion and accessor methods for Value.
 
 use super::{IndexMap, Key, Value};
+use crate::core::{Error, Result};
 
 impl Value {
     // === Type checking methods ===
@@ -163,6 +164,164 @@ impl Value {
             _ => None,
         }
     }
+
+    /// Parses RFC 3339 text as a UTC timestamp.
+    ///
+    /// Offsets are converted to UTC. Returns `None` for non-text values and
+    /// unparseable text.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use chrono::{TimeZone, Utc};
+    /// use paramdef::core::Value;
+    ///
+    /// let at = Utc.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+    /// let value = Value::from(at);
+    /// assert_eq!(value.as_text(), Some("2024-01-01T12:00:00Z"));
+    /// assert_eq!(value.as_datetime(), Some(at));
+    /// ```
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    pub fn as_datetime(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        let parsed = chrono::DateTime::parse_from_rfc3339(self.as_text()?).ok()?;
+        Some(parsed.with_timezone(&chrono::Utc))
+    }
+
+    /// Returns a number of seconds as a duration.
+    ///
+    /// `Int` values are whole seconds; `Float` values keep millisecond
+    /// precision. Returns `None` for other values and for floats that are
+    /// not finite or out of range.
+    #[cfg(feature = "chrono")]
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn as_duration(&self) -> Option<chrono::Duration> {
+        match self {
+            Self::Int(secs) => chrono::Duration::try_seconds(*secs),
+            Self::Float(secs) => {
+                let millis = (secs * 1000.0).round();
+                if !float_fits_i64(millis) {
+                    return None;
+                }
+                chrono::Duration::try_milliseconds(millis as i64)
+            }
+            _ => None,
+        }
+    }
+
+    // === Fallible conversions ===
+
+    /// Converts a clone of this value with `T`'s [`TryFrom<Value>`] impl.
+    ///
+    /// A `?`-friendly alternative to the `as_*` accessors.
+    ///
+    /// # Errors
+    ///
+    /// Returns a type mismatch error naming the expected and actual types
+    /// if the value cannot be converted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::core::{Result, Value};
+    ///
+    /// fn port(value: &Value) -> Result<i64> {
+    ///     let port: i64 = value.try_as()?;
+    ///     Ok(port)
+    /// }
+    ///
+    /// assert_eq!(port(&Value::Int(8080)).unwrap(), 8080);
+    /// assert!(port(&Value::text("8080")).is_err());
+    /// ```
+    pub fn try_as<T>(&self) -> Result<T>
+    where
+        T: TryFrom<Value, Error = Error>,
+    {
+        T::try_from(self.clone())
+    }
+
+    /// Returns the value as an `i64`, rejecting floats with a fractional
+    /// part.
+    ///
+    /// Unlike `i64::try_from`, which truncates floats toward zero, this
+    /// only accepts floats that convert exactly.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `"lossy_conversion"` validation error for a float that
+    /// has a fractional part or lies outside the `i64` range, and a type
+    /// mismatch error for non-numeric values.
+    #[allow(clippy::cast_possible_truncation, clippy::float_cmp)]
+    pub fn try_as_i64_exact(&self) -> Result<i64> {
+        match self {
+            Self::Int(i) => Ok(*i),
+            Self::Float(f) if f.fract() == 0.0 && float_fits_i64(*f) => Ok(*f as i64),
+            Self::Float(f) => Err(Error::validation(
+                "lossy_conversion",
+                format!("{f} cannot be converted to an integer exactly"),
+            )),
+            other => Err(Error::type_mismatch("int", other.type_name())),
+        }
+    }
+}
+
+/// Returns `true` if `f` truncates to a value within the `i64` range.
+#[allow(clippy::cast_precision_loss)]
+fn float_fits_i64(f: f64) -> bool {
+    // `i64::MAX as f64` rounds up to 2^63, which is itself out of range.
+    f.is_finite() && f >= i64::MIN as f64 && f < i64::MAX as f64
+}
+
+// === TryFrom implementations ===
+
+impl TryFrom<Value> for bool {
+    type Error = Error;
+
+    fn try_from(value: Value) -> Result<Self> {
+        value
+            .as_bool()
+            .ok_or_else(|| Error::type_mismatch("bool", value.type_name()))
+    }
+}
+
+/// Accepts `Int` and `Float`; floats are truncated toward zero.
+///
+/// Non-finite floats and floats outside the `i64` range are rejected. Use
+/// [`Value::try_as_i64_exact`] to reject fractional floats as well.
+impl TryFrom<Value> for i64 {
+    type Error = Error;
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn try_from(value: Value) -> Result<Self> {
+        match value {
+            Value::Int(i) => Ok(i),
+            Value::Float(f) if float_fits_i64(f) => Ok(f as i64),
+            other => Err(Error::type_mismatch("int", other.type_name())),
+        }
+    }
+}
+
+/// Accepts `Float` and `Int`, widening integers.
+impl TryFrom<Value> for f64 {
+    type Error = Error;
+
+    fn try_from(value: Value) -> Result<Self> {
+        value
+            .as_f64()
+            .ok_or_else(|| Error::type_mismatch("float", value.type_name()))
+    }
+}
+
+impl TryFrom<Value> for String {
+    type Error = Error;
+
+    fn try_from(value: Value) -> Result<Self> {
+        match value {
+            Value::Text(s) => Ok(s.into()),
+            other => Err(Error::type_mismatch("text", other.type_name())),
+        }
+    }
 }
 
 // === From implementations ===
@@ -215,6 +374,23 @@ impl<T: Into<Value>> From<Vec<T>> for Value {
     }
 }
 
+/// Stores the timestamp as RFC 3339 text in UTC (`Z` suffix), keeping
+/// sub-second precision so [`Value::as_datetime`] round-trips exactly.
+#[cfg(feature = "chrono")]
+impl From<chrono::DateTime<chrono::Utc>> for Value {
+    fn from(v: chrono::DateTime<chrono::Utc>) -> Self {
+        Self::text(v.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true))
+    }
+}
+
+/// Stores the duration as whole seconds, truncating toward zero.
+#[cfg(feature = "chrono")]
+impl From<chrono::Duration> for Value {
+    fn from(v: chrono::Duration) -> Self {
+        Self::Int(v.num_seconds())
+    }
+}
+
 impl<T: Into<Value>> From<Option<T>> for Value {
     fn from(v: Option<T>) -> Self {
         match v {
@@ -321,4 +497,79 @@ mod tests {
         let none: Value = Option::<i64>::None.into();
         assert!(none.is_null());
     }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_value_try_from() {
+        assert!(bool::try_from(Value::Bool(true)).unwrap());
+        assert_eq!(i64::try_from(Value::Int(7)).unwrap(), 7);
+        assert_eq!(i64::try_from(Value::Float(-2.9)).unwrap(), -2);
+        assert_eq!(f64::try_from(Value::Int(3)).unwrap(), 3.0);
+        assert_eq!(String::try_from(Value::text("hi")).unwrap(), "hi");
+
+        assert!(i64::try_from(Value::Float(f64::NAN)).is_err());
+        assert!(i64::try_from(Value::Float(1e19)).is_err());
+
+        let err = String::try_from(Value::Int(1)).unwrap_err();
+        assert!(matches!(
+            err,
+            Error::TypeMismatch {
+                expected: "text",
+                actual: "int"
+            }
+        ));
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_value_try_as() {
+        let value = Value::Float(2.0);
+        assert_eq!(value.try_as::<f64>().unwrap(), 2.0);
+        assert_eq!(value.try_as::<i64>().unwrap(), 2);
+        assert!(value.try_as::<bool>().is_err());
+
+        assert_eq!(value.try_as_i64_exact().unwrap(), 2);
+        let err = Value::Float(2.5).try_as_i64_exact().unwrap_err();
+        assert_eq!(err.code(), Some("lossy_conversion"));
+        assert!(matches!(
+            Value::text("2").try_as_i64_exact(),
+            Err(Error::TypeMismatch { .. })
+        ));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_value_chrono_datetime() {
+        use chrono::{TimeZone, Utc};
+
+        let at = Utc.with_ymd_and_hms(2024, 3, 1, 8, 0, 0).unwrap()
+            + chrono::Duration::microseconds(123_456);
+        let value = Value::from(at);
+        assert_eq!(value.as_text(), Some("2024-03-01T08:00:00.123456Z"));
+        assert_eq!(value.as_datetime(), Some(at));
+
+        let offset = Value::text("2024-03-01T10:00:00+02:00");
+        assert_eq!(
+            offset.as_datetime(),
+            Some(Utc.with_ymd_and_hms(2024, 3, 1, 8, 0, 0).unwrap())
+        );
+        assert_eq!(Value::text("soon").as_datetime(), None);
+        assert_eq!(Value::Int(0).as_datetime(), None);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_value_chrono_duration() {
+        use chrono::Duration;
+
+        let value = Value::from(Duration::milliseconds(90_500));
+        assert_eq!(value, Value::Int(90));
+        assert_eq!(value.as_duration(), Some(Duration::seconds(90)));
+        assert_eq!(
+            Value::Float(1.25).as_duration(),
+            Some(Duration::milliseconds(1250))
+        );
+        assert_eq!(Value::Float(f64::NAN).as_duration(), None);
+        assert_eq!(Value::text("1").as_duration(), None);
+    }
 }
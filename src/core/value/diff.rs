@@ -0,0 +1,210 @@
+//! Structural differences between values.
+
+use std::collections::BTreeMap;
+
+use super::Value;
+use crate::core::{IndexMap, Key};
+
+/// The differences between two values, produced by [`Value::diff`].
+///
+/// Objects and arrays that differ are compared child by child, so the
+/// result only holds the parts that changed. With the `serde` feature the
+/// diff serializes to a compact change set, such as
+/// `{"object": {"port": {"changed": {"from": 80, "to": 8080}}}}`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum ValueDiff {
+    /// The values are equal.
+    Unchanged,
+    /// The child exists only in the new value.
+    Added(Value),
+    /// The child exists only in the old value.
+    Removed(Value),
+    /// The value was replaced.
+    Changed {
+        /// The old value.
+        from: Value,
+        /// The new value.
+        to: Value,
+    },
+    /// Both values are objects; holds the fields that differ, in the old
+    /// value's key order followed by added keys.
+    Object(IndexMap<Key, ValueDiff>),
+    /// Both values are arrays; holds the elements that differ by index.
+    /// Elements past the end of the shorter array are added or removed.
+    Array(BTreeMap<usize, ValueDiff>),
+}
+
+impl ValueDiff {
+    /// Returns `true` if the values are equal.
+    #[must_use]
+    pub fn is_unchanged(&self) -> bool {
+        matches!(self, Self::Unchanged)
+    }
+}
+
+impl Value {
+    /// Compares this value with `other`.
+    ///
+    /// Equal values give [`ValueDiff::Unchanged`] without walking their
+    /// children. Two objects or two arrays give a diff of their children;
+    /// any other difference, including a change of type, is
+    /// [`ValueDiff::Changed`]. Arrays are compared by index, so an
+    /// insertion shows up as changes to every later element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paramdef::core::{Value, ValueDiff};
+    ///
+    /// let old = Value::object([("host", Value::text("a")), ("port", Value::Int(80))]);
+    /// let new = Value::object([("host", Value::text("a")), ("port", Value::Int(8080))]);
+    ///
+    /// let ValueDiff::Object(fields) = old.diff(&new) else { unreachable!() };
+    /// assert_eq!(fields.len(), 1);
+    /// assert_eq!(
+    ///     fields["port"],
+    ///     ValueDiff::Changed { from: Value::Int(80), to: Value::Int(8080) }
+    /// );
+    /// assert!(old.diff(&old.clone()).is_unchanged());
+    /// ```
+    #[must_use]
+    pub fn diff(&self, other: &Value) -> ValueDiff {
+        if self == other {
+            return ValueDiff::Unchanged;
+        }
+
+        match (self, other) {
+            (Self::Object(old), Self::Object(new)) => {
+                let mut fields = IndexMap::default();
+                for (key, from) in old.iter() {
+                    let diff = match new.get(key) {
+                        Some(to) => from.diff(to),
+                        None => ValueDiff::Removed(from.clone()),
+                    };
+                    if !diff.is_unchanged() {
+                        fields.insert(key.clone(), diff);
+                    }
+                }
+                for (key, to) in new.iter() {
+                    if !old.contains_key(key) {
+                        fields.insert(key.clone(), ValueDiff::Added(to.clone()));
+                    }
+                }
+                ValueDiff::Object(fields)
+            }
+            (Self::Array(old), Self::Array(new)) => {
+                let mut items = BTreeMap::new();
+                for index in 0..old.len().max(new.len()) {
+                    let diff = match (old.get(index), new.get(index)) {
+                        (Some(from), Some(to)) => from.diff(to),
+                        (Some(from), None) => ValueDiff::Removed(from.clone()),
+                        (None, Some(to)) => ValueDiff::Added(to.clone()),
+                        (None, None) => ValueDiff::Unchanged,
+                    };
+                    if !diff.is_unchanged() {
+                        items.insert(index, diff);
+                    }
+                }
+                ValueDiff::Array(items)
+            }
+            _ => ValueDiff::Changed {
+                from: self.clone(),
+                to: other.clone(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value_diff_scalars() {
+        assert!(Value::Int(1).diff(&Value::Int(1)).is_unchanged());
+        assert_eq!(
+            Value::Int(1).diff(&Value::text("1")),
+            ValueDiff::Changed {
+                from: Value::Int(1),
+                to: Value::text("1"),
+            }
+        );
+        assert_eq!(
+            Value::array([]).diff(&Value::object([("a", Value::Null)])),
+            ValueDiff::Changed {
+                from: Value::array([]),
+                to: Value::object([("a", Value::Null)]),
+            }
+        );
+    }
+
+    #[test]
+    fn test_value_diff_nested() {
+        let old = Value::object([
+            ("same", Value::Int(1)),
+            ("gone", Value::Bool(true)),
+            (
+                "list",
+                Value::array([Value::Int(1), Value::Int(2), Value::Int(3)]),
+            ),
+            ("inner", Value::object([("x", Value::Int(1))])),
+        ]);
+        let new = Value::object([
+            ("same", Value::Int(1)),
+            ("list", Value::array([Value::Int(1), Value::Int(20)])),
+            ("inner", Value::object([("x", Value::Int(2))])),
+            ("added", Value::text("new")),
+        ]);
+
+        let ValueDiff::Object(fields) = old.diff(&new) else {
+            panic!("expected an object diff");
+        };
+        let keys: Vec<&str> = fields.keys().map(Key::as_str).collect();
+        assert_eq!(keys, ["gone", "list", "inner", "added"]);
+        assert_eq!(fields["gone"], ValueDiff::Removed(Value::Bool(true)));
+        assert_eq!(fields["added"], ValueDiff::Added(Value::text("new")));
+        assert_eq!(
+            fields["list"],
+            ValueDiff::Array(BTreeMap::from([
+                (
+                    1,
+                    ValueDiff::Changed {
+                        from: Value::Int(2),
+                        to: Value::Int(20),
+                    }
+                ),
+                (2, ValueDiff::Removed(Value::Int(3))),
+            ]))
+        );
+        let ValueDiff::Object(inner) = &fields["inner"] else {
+            panic!("expected an object diff");
+        };
+        assert_eq!(inner.len(), 1);
+
+        let grown = Value::array([Value::Int(1)]).diff(&Value::array([Value::Int(1), Value::Null]));
+        assert_eq!(
+            grown,
+            ValueDiff::Array(BTreeMap::from([(1, ValueDiff::Added(Value::Null))]))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_value_diff_serialize() {
+        let old = Value::object([("port", Value::Int(80)), ("debug", Value::Bool(true))]);
+        let new = Value::object([("port", Value::Int(8080))]);
+
+        let json = serde_json::to_value(old.diff(&new)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "object": {
+                    "port": { "changed": { "from": 80, "to": 8080 } },
+                    "debug": { "removed": true },
+                }
+            })
+        );
+    }
+}
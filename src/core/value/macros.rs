@@ -0,0 +1,194 @@
+//! The [`value!`](crate::value) macro for building [`Value`](super::Value)
+//! literals.
+
+/// Builds a [`Value`](crate::core::Value) from a JSON-like literal.
+///
+/// Objects and arrays nest freely. `null` becomes [`Value::Null`]; any
+/// other element is an expression converted with `Value::from`, so
+/// variables and arbitrary expressions can be interpolated. Object keys
+/// are string literals or any expression convertible to a
+/// [`Key`](crate::core::Key); wrap multi-token key expressions in
+/// parentheses. Duplicate keys keep the last value at the first key's
+/// position.
+///
+/// Works without the `serde` feature.
+///
+/// [`Value::Null`]: crate::core::Value::Null
+///
+/// # Example
+///
+/// ```
+/// use paramdef::core::Value;
+/// use paramdef::value;
+///
+/// let age = 30;
+/// let user = value!({
+///     "name": "Alice",
+///     "tags": ["a", "b"],
+///     "age": age,
+///     "manager": null,
+///     "address": { "city": "Paris" }
+/// });
+///
+/// let fields = user.as_object().unwrap();
+/// assert_eq!(fields["age"], Value::Int(30));
+/// assert_eq!(fields["tags"].as_array().map(|a| a.len()), Some(2));
+/// assert_eq!(fields["manager"], Value::Null);
+/// ```
+#[macro_export]
+macro_rules! value {
+    // ---------------------------------------------------------------------
+    // Arrays: `@array [finished elements,] remaining tokens`
+    // ---------------------------------------------------------------------
+
+    (@array [$($elems:expr,)*]) => {
+        $crate::core::Value::array([$($elems,)*])
+    };
+    (@array [$($elems:expr),*]) => {
+        $crate::core::Value::array([$($elems),*])
+    };
+    (@array [$($elems:expr,)*] null $($rest:tt)*) => {
+        $crate::value!(@array [$($elems,)* $crate::value!(null)] $($rest)*)
+    };
+    (@array [$($elems:expr,)*] [$($array:tt)*] $($rest:tt)*) => {
+        $crate::value!(@array [$($elems,)* $crate::value!([$($array)*])] $($rest)*)
+    };
+    (@array [$($elems:expr,)*] {$($object:tt)*} $($rest:tt)*) => {
+        $crate::value!(@array [$($elems,)* $crate::value!({$($object)*})] $($rest)*)
+    };
+    (@array [$($elems:expr,)*] $next:expr, $($rest:tt)*) => {
+        $crate::value!(@array [$($elems,)* $crate::value!($next),] $($rest)*)
+    };
+    (@array [$($elems:expr,)*] $last:expr) => {
+        $crate::value!(@array [$($elems,)* $crate::value!($last)])
+    };
+    (@array [$($elems:expr),*] , $($rest:tt)*) => {
+        $crate::value!(@array [$($elems,)*] $($rest)*)
+    };
+
+    // ---------------------------------------------------------------------
+    // Objects: `@object map (key tokens) (remaining tokens)`, then
+    // `@object map [key] (value) remaining tokens` once a value is parsed.
+    // ---------------------------------------------------------------------
+
+    (@object $object:ident () ()) => {};
+    (@object $object:ident [$($key:tt)+] ($value:expr) , $($rest:tt)*) => {
+        let _ = $object.insert($crate::core::Key::from($($key)+), $value);
+        $crate::value!(@object $object () ($($rest)*));
+    };
+    (@object $object:ident [$($key:tt)+] ($value:expr)) => {
+        let _ = $object.insert($crate::core::Key::from($($key)+), $value);
+    };
+    (@object $object:ident ($($key:tt)+) (: null $($rest:tt)*)) => {
+        $crate::value!(@object $object [$($key)+] ($crate::value!(null)) $($rest)*);
+    };
+    (@object $object:ident ($($key:tt)+) (: [$($array:tt)*] $($rest:tt)*)) => {
+        $crate::value!(@object $object [$($key)+] ($crate::value!([$($array)*])) $($rest)*);
+    };
+    (@object $object:ident ($($key:tt)+) (: {$($map:tt)*} $($rest:tt)*)) => {
+        $crate::value!(@object $object [$($key)+] ($crate::value!({$($map)*})) $($rest)*);
+    };
+    (@object $object:ident ($($key:tt)+) (: $value:expr , $($rest:tt)*)) => {
+        $crate::value!(@object $object [$($key)+] ($crate::value!($value)) , $($rest)*);
+    };
+    (@object $object:ident ($($key:tt)+) (: $value:expr)) => {
+        $crate::value!(@object $object [$($key)+] ($crate::value!($value)));
+    };
+    (@object $object:ident () (($key:expr) : $($rest:tt)*)) => {
+        $crate::value!(@object $object ($key) (: $($rest)*));
+    };
+    (@object $object:ident ($($key:tt)*) ($tt:tt $($rest:tt)*)) => {
+        $crate::value!(@object $object ($($key)* $tt) ($($rest)*));
+    };
+
+    // ---------------------------------------------------------------------
+    // Entry points
+    // ---------------------------------------------------------------------
+
+    (null) => {
+        $crate::core::Value::Null
+    };
+    ([]) => {
+        $crate::core::Value::array([])
+    };
+    ([ $($tt:tt)+ ]) => {
+        $crate::value!(@array [] $($tt)+)
+    };
+    ({}) => {
+        $crate::core::Value::object(::std::iter::empty::<(&str, $crate::core::Value)>())
+    };
+    ({ $($tt:tt)+ }) => {{
+        let mut object = $crate::core::IndexMap::new();
+        $crate::value!(@object object () ($($tt)+));
+        $crate::core::Value::Object(::std::sync::Arc::new(object))
+    }};
+    ($other:expr) => {
+        $crate::core::Value::from($other)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::Value;
+
+    #[test]
+    fn test_value_macro_scalars() {
+        assert_eq!(value!(null), Value::Null);
+        assert_eq!(value!(true), Value::Bool(true));
+        assert_eq!(value!(42), Value::Int(42));
+        assert_eq!(value!(1.5), Value::Float(1.5));
+        assert_eq!(value!("hi"), Value::text("hi"));
+        assert_eq!(value!(Value::Int(1)), Value::Int(1));
+    }
+
+    #[test]
+    fn test_value_macro_arrays() {
+        assert_eq!(value!([]), Value::array([]));
+        assert_eq!(
+            value!([1, "a", null, [true], {}]),
+            Value::array([
+                Value::Int(1),
+                Value::text("a"),
+                Value::Null,
+                Value::array([Value::Bool(true)]),
+                Value::object(std::iter::empty::<(&str, Value)>()),
+            ])
+        );
+        assert_eq!(
+            value!([1, 2,]),
+            Value::array([Value::Int(1), Value::Int(2)])
+        );
+    }
+
+    #[test]
+    fn test_value_macro_objects() {
+        let name = String::from("Alice");
+        let key = "dynamic";
+        let value = value!({
+            "name": name,
+            "tags": ["a", "b"],
+            "age": 20 + 10,
+            (key): { "nested": null },
+        });
+
+        assert_eq!(
+            value,
+            Value::object([
+                ("name", Value::text("Alice")),
+                ("tags", Value::array([Value::text("a"), Value::text("b")])),
+                ("age", Value::Int(30)),
+                ("dynamic", Value::object([("nested", Value::Null)])),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_value_macro_duplicate_key() {
+        let value = value!({ "a": 1, "b": 2, "a": 3 });
+        let fields = value.as_object().unwrap();
+        let keys: Vec<&str> = fields.keys().map(crate::core::Key::as_str).collect();
+
+        assert_eq!(keys, ["a", "b"]);
+        assert_eq!(fields["a"], Value::Int(3));
+    }
+}
@@ -8,9 +8,13 @@
 //! - [`Value`] - Main enum definition and constructors
 //! - [`convert`] - Type conversion methods (as_*, From impls)
 //! - [`ops`] - Utility operations
+//! - [`diff`] - Structural differences ([`ValueDiff`])
+//! - [`macros`] - The [`value!`](crate::value) literal macro
 //! - [`serde`] - Serialization support (feature-gated)
 
 mod convert;
+mod diff;
+mod macros;
 mod ops;
 
 #[cfg(feature = "serde")]
@@ -23,8 +27,8 @@ use super::{IndexMap, Key, SmartStr};
 // Re-export conversion traits (used by inherent methods and external users)
 #[allow(unused_imports)]
 pub use convert::*;
-#[allow(unused_imports)]
-pub use ops::*;
+pub use diff::ValueDiff;
+pub(crate) use ops::Fnv1a;
 
 /// Unified runtime representation for all parameter values.
 ///
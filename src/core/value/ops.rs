@@ -1,6 +1,17 @@
 //! Utility operations for Value.
 
+use std::cmp::Ordering;
+#[cfg(feature = "serde")]
+use std::collections::BTreeMap;
+use std::hash::Hasher;
+use std::mem::size_of;
+use std::sync::Arc;
+
+use smartstring::MAX_INLINE;
+
 use super::Value;
+use crate::core::{Error, FxHashSet, IndexMap, Key, REDACTED, Result};
+use crate::subtype::NumberUnit;
 
 impl Value {
     /// Returns `true` if this value is considered empty.
@@ -58,38 +69,2193 @@ impl Value {
             Self::Binary(_) => "binary",
         }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::core::Key;
+    /// Compares two values, treating integers and floats numerically.
+    ///
+    /// Like `==`, except that `Int(1)` equals `Float(1.0)`, `NaN` equals
+    /// `NaN`, and the rule applies inside arrays and objects. Object key
+    /// order is ignored, as with `==`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paramdef::core::Value;
+    ///
+    /// assert!(Value::Int(1).numeric_eq(&Value::Float(1.0)));
+    /// assert!(Value::Float(f64::NAN).numeric_eq(&Value::Float(f64::NAN)));
+    /// assert!(!Value::Int(1).numeric_eq(&Value::text("1")));
+    /// ```
+    #[must_use]
+    #[allow(clippy::float_cmp, clippy::cast_precision_loss)]
+    pub fn numeric_eq(&self, other: &Value) -> bool {
+        match (self, other) {
+            (Self::Int(a), Self::Int(b)) => a == b,
+            (Self::Int(i), Self::Float(f)) | (Self::Float(f), Self::Int(i)) => *i as f64 == *f,
+            (Self::Float(a), Self::Float(b)) => a == b || (a.is_nan() && b.is_nan()),
+            (Self::Array(a), Self::Array(b)) => {
+                a.len() == b.len() && a.iter().zip(b.iter()).all(|(x, y)| x.numeric_eq(y))
+            }
+            (Self::Object(a), Self::Object(b)) => {
+                a.len() == b.len()
+                    && a.iter()
+                        .all(|(k, x)| b.get(k).is_some_and(|y| x.numeric_eq(y)))
+            }
+            _ => self == other,
+        }
+    }
 
-    // Utility tests
-    #[test]
-    fn test_value_is_empty() {
-        assert!(Value::Null.is_empty());
-        assert!(Value::text("").is_empty());
-        assert!(Value::array([]).is_empty());
-        assert!(Value::object(std::iter::empty::<(Key, Value)>()).is_empty());
-        assert!(Value::binary([]).is_empty());
+    /// Adds two numeric values.
+    ///
+    /// Two `Int`s add as integers; any `Float` operand promotes both to
+    /// `Float`. Returns `None` if either operand is not numeric, if `Int`
+    /// addition overflows `i64` (it never wraps), or if adding finite
+    /// floats overflows to infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paramdef::core::Value;
+    ///
+    /// assert_eq!(Value::Int(2).checked_add(&Value::Int(3)), Some(Value::Int(5)));
+    /// assert_eq!(Value::Int(2).checked_add(&Value::Float(0.5)), Some(Value::Float(2.5)));
+    /// assert_eq!(Value::Int(i64::MAX).checked_add(&Value::Int(1)), None);
+    /// assert_eq!(Value::Int(1).checked_add(&Value::text("1")), None);
+    /// ```
+    #[must_use]
+    pub fn checked_add(&self, other: &Value) -> Option<Value> {
+        self.numeric_op(other, i64::checked_add, |a, b| a + b)
+    }
 
-        assert!(!Value::text("hello").is_empty());
-        assert!(!Value::Bool(false).is_empty());
+    /// Multiplies two numeric values.
+    ///
+    /// Follows the same promotion and overflow rules as
+    /// [`checked_add`](Self::checked_add): `Int` overflow returns `None`
+    /// rather than wrapping.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paramdef::core::Value;
+    ///
+    /// assert_eq!(Value::Int(4).checked_mul(&Value::Int(3)), Some(Value::Int(12)));
+    /// assert_eq!(Value::Float(1.5).checked_mul(&Value::Int(2)), Some(Value::Float(3.0)));
+    /// assert_eq!(Value::Int(i64::MAX).checked_mul(&Value::Int(2)), None);
+    /// ```
+    #[must_use]
+    pub fn checked_mul(&self, other: &Value) -> Option<Value> {
+        self.numeric_op(other, i64::checked_mul, |a, b| a * b)
     }
 
-    #[test]
-    fn test_value_type_name() {
-        assert_eq!(Value::Null.type_name(), "null");
-        assert_eq!(Value::Bool(true).type_name(), "bool");
-        assert_eq!(Value::Int(1).type_name(), "int");
-        assert_eq!(Value::Float(1.0).type_name(), "float");
-        assert_eq!(Value::text("").type_name(), "text");
-        assert_eq!(Value::array([]).type_name(), "array");
-        assert_eq!(
-            Value::object(std::iter::empty::<(Key, Value)>()).type_name(),
-            "object"
-        );
-        assert_eq!(Value::binary([]).type_name(), "binary");
+    /// Returns the smaller of two numeric values.
+    ///
+    /// Mixed `Int` and `Float` operands are compared and returned as
+    /// `Float`. As with [`f64::min`], a `NaN` operand yields the other one.
+    /// Returns `None` if either operand is not numeric.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paramdef::core::Value;
+    ///
+    /// assert_eq!(Value::Int(2).min(&Value::Int(3)), Some(Value::Int(2)));
+    /// assert_eq!(Value::Int(2).min(&Value::Float(1.5)), Some(Value::Float(1.5)));
+    /// assert_eq!(Value::Null.min(&Value::Int(1)), None);
+    /// ```
+    #[must_use]
+    pub fn min(&self, other: &Value) -> Option<Value> {
+        self.numeric_op(other, |a, b| Some(a.min(b)), f64::min)
+    }
+
+    /// Returns the larger of two numeric values.
+    ///
+    /// Follows the same promotion rules as [`min`](Self::min).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paramdef::core::Value;
+    ///
+    /// assert_eq!(Value::Int(2).max(&Value::Int(3)), Some(Value::Int(3)));
+    /// assert_eq!(Value::Float(2.5).max(&Value::Int(2)), Some(Value::Float(2.5)));
+    /// ```
+    #[must_use]
+    pub fn max(&self, other: &Value) -> Option<Value> {
+        self.numeric_op(other, |a, b| Some(a.max(b)), f64::max)
+    }
+
+    /// Applies an integer or float operation, promoting mixed operands.
+    #[allow(clippy::cast_precision_loss)]
+    fn numeric_op(
+        &self,
+        other: &Value,
+        int_op: impl FnOnce(i64, i64) -> Option<i64>,
+        float_op: impl FnOnce(f64, f64) -> f64,
+    ) -> Option<Value> {
+        let (a, b) = match (self, other) {
+            (Self::Int(a), Self::Int(b)) => return int_op(*a, *b).map(Self::Int),
+            (Self::Int(a), Self::Float(b)) => (*a as f64, *b),
+            (Self::Float(a), Self::Int(b)) => (*a, *b as f64),
+            (Self::Float(a), Self::Float(b)) => (*a, *b),
+            _ => return None,
+        };
+
+        let result = float_op(a, b);
+        let overflowed = result.is_infinite() && a.is_finite() && b.is_finite();
+        (!overflowed).then_some(Self::Float(result))
+    }
+
+    /// Compares two values in a total order across all variants.
+    ///
+    /// Values of different kinds order by variant:
+    ///
+    /// `Null` < `Bool` < numbers < `Text` < `Array` < `Object` < `Binary`
+    ///
+    /// Within a kind:
+    ///
+    /// - `false` < `true`
+    /// - `Int` and `Float` compare by numeric value, exactly even for
+    ///   integers beyond `f64` precision; `NaN` is greater than every other
+    ///   number and equal to itself, and `-0.0` equals `0.0`
+    /// - text compares lexicographically by UTF-8 bytes
+    /// - arrays compare element by element, then by length
+    /// - objects compare their entries sorted by key, each by key and then
+    ///   value, then by length, so field order does not matter
+    /// - binary compares lexicographically by byte
+    ///
+    /// Numerically equal values such as `Int(1)` and `Float(1.0)` compare
+    /// as equal, so sorting by this order never panics but is only as
+    /// fine-grained as [`numeric_eq`](Self::numeric_eq).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paramdef::core::Value;
+    ///
+    /// let mut values = vec![
+    ///     Value::text("b"),
+    ///     Value::Float(f64::NAN),
+    ///     Value::Int(2),
+    ///     Value::Null,
+    ///     Value::Float(1.5),
+    ///     Value::Bool(true),
+    /// ];
+    /// values.sort_by(Value::cmp_canonical);
+    ///
+    /// assert_eq!(values[0], Value::Null);
+    /// assert_eq!(values[2], Value::Float(1.5));
+    /// assert!(values[4].as_f64().is_some_and(f64::is_nan));
+    /// assert_eq!(values[5], Value::text("b"));
+    /// ```
+    #[must_use]
+    pub fn cmp_canonical(&self, other: &Value) -> Ordering {
+        match (self, other) {
+            (Self::Bool(a), Self::Bool(b)) => a.cmp(b),
+            (Self::Int(a), Self::Int(b)) => a.cmp(b),
+            (Self::Float(a), Self::Float(b)) => cmp_floats(*a, *b),
+            (Self::Int(i), Self::Float(f)) => cmp_int_float(*i, *f),
+            (Self::Float(f), Self::Int(i)) => cmp_int_float(*i, *f).reverse(),
+            (Self::Text(a), Self::Text(b)) => a.as_str().cmp(b.as_str()),
+            (Self::Array(a), Self::Array(b)) => a
+                .iter()
+                .zip(b.iter())
+                .map(|(x, y)| x.cmp_canonical(y))
+                .find(|ordering| ordering.is_ne())
+                .unwrap_or_else(|| a.len().cmp(&b.len())),
+            (Self::Object(a), Self::Object(b)) => {
+                let (a, b) = (sorted_entries(a), sorted_entries(b));
+                a.iter()
+                    .zip(b.iter())
+                    .map(|((ka, va), (kb, vb))| {
+                        ka.as_str()
+                            .cmp(kb.as_str())
+                            .then_with(|| va.cmp_canonical(vb))
+                    })
+                    .find(|ordering| ordering.is_ne())
+                    .unwrap_or_else(|| a.len().cmp(&b.len()))
+            }
+            (Self::Binary(a), Self::Binary(b)) => a.cmp(b),
+            _ => self.canonical_rank().cmp(&other.canonical_rank()),
+        }
+    }
+
+    /// Returns the position of this value's kind in
+    /// [`cmp_canonical`](Self::cmp_canonical) order.
+    fn canonical_rank(&self) -> u8 {
+        match self {
+            Self::Null => 0,
+            Self::Bool(_) => 1,
+            Self::Int(_) | Self::Float(_) => 2,
+            Self::Text(_) => 3,
+            Self::Array(_) => 4,
+            Self::Object(_) => 5,
+            Self::Binary(_) => 6,
+        }
+    }
+
+    /// Returns a copy with the values that `keep` rejects replaced by
+    /// [`REDACTED`].
+    ///
+    /// Walks arrays and objects, calling `keep` with the key path of each
+    /// nested value. Array elements use their index as the path segment.
+    /// A rejected value is replaced whole, so its children are not visited.
+    /// The value itself sits at the empty path and is always kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paramdef::core::{Key, REDACTED, Value};
+    ///
+    /// let config = Value::object([
+    ///     ("host", Value::text("db.local")),
+    ///     ("auth", Value::object([("password", Value::text("hunter2"))])),
+    /// ]);
+    ///
+    /// let safe = config.redact_by(|path| path.last().is_none_or(|k| k != "password"));
+    /// let auth = safe.as_object().unwrap()["auth"].as_object().unwrap();
+    /// assert_eq!(auth["password"], Value::text(REDACTED));
+    /// ```
+    #[must_use]
+    pub fn redact_by(&self, keep: impl Fn(&[Key]) -> bool) -> Value {
+        self.redact_at(&mut Vec::new(), &keep)
+    }
+
+    fn redact_at(&self, path: &mut Vec<Key>, keep: &impl Fn(&[Key]) -> bool) -> Value {
+        let mut visit = |segment: Key, value: &Value| {
+            path.push(segment);
+            let redacted = if keep(path) {
+                value.redact_at(path, keep)
+            } else {
+                Value::text(REDACTED)
+            };
+            path.pop();
+            redacted
+        };
+
+        match self {
+            Self::Array(items) => Value::array(
+                items
+                    .iter()
+                    .enumerate()
+                    .map(|(i, item)| visit(Key::from(i.to_string()), item)),
+            ),
+            Self::Object(fields) => Value::object(
+                fields
+                    .iter()
+                    .map(|(k, v)| (k.clone(), visit(k.clone(), v)))
+                    .collect::<Vec<_>>(),
+            ),
+            _ => self.clone(),
+        }
+    }
+
+    /// Flattens nested objects and arrays into dotted-path entries.
+    ///
+    /// Each scalar, and each empty object or array, becomes one entry keyed
+    /// by the path to it: object keys and array indices joined with `.`.
+    /// A value that is not a non-empty container becomes a single entry
+    /// under the empty path. Entries follow object and array order.
+    ///
+    /// Object keys are escaped with `\` so that [`unflatten`](Self::unflatten)
+    /// restores the exact structure:
+    ///
+    /// - `.` and `\` inside a key become `\.` and `\\`
+    /// - a key that reads as an array index, such as `"0"`, becomes `\0`
+    /// - the empty key becomes `\_`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paramdef::core::Value;
+    ///
+    /// let value = Value::object([
+    ///     ("a", Value::object([("b", Value::Int(1))])),
+    ///     ("c", Value::array([Value::Int(2), Value::Int(3)])),
+    /// ]);
+    ///
+    /// let flat = value.flatten();
+    /// let keys: Vec<&str> = flat.keys().map(String::as_str).collect();
+    /// assert_eq!(keys, ["a.b", "c.0", "c.1"]);
+    /// assert_eq!(Value::unflatten(flat), value);
+    /// ```
+    #[must_use]
+    pub fn flatten(&self) -> IndexMap<String, Value> {
+        let mut out = IndexMap::new();
+        self.flatten_into(String::new(), &mut out);
+        out
+    }
+
+    fn flatten_into(&self, path: String, out: &mut IndexMap<String, Value>) {
+        let join = |segment: &str| {
+            if path.is_empty() {
+                segment.to_string()
+            } else {
+                format!("{path}.{segment}")
+            }
+        };
+
+        match self {
+            Self::Array(items) if !items.is_empty() => {
+                for (i, item) in items.iter().enumerate() {
+                    item.flatten_into(join(&i.to_string()), out);
+                }
+            }
+            Self::Object(fields) if !fields.is_empty() => {
+                for (key, value) in fields.iter() {
+                    value.flatten_into(join(&escape_segment(key)), out);
+                }
+            }
+            _ => {
+                out.insert(path, self.clone());
+            }
+        }
+    }
+
+    /// Rebuilds a nested value from dotted-path entries.
+    ///
+    /// The inverse of [`flatten`](Self::flatten), including its key
+    /// escapes. A level whose segments are all unescaped array indices,
+    /// none greater than the number of segments at that level, becomes an
+    /// array, with missing indices filled with `Null`; any other level
+    /// becomes an object. When entries conflict, such as `a` and `a.b`, the
+    /// later one wins. No entries give an empty object.
+    #[must_use]
+    pub fn unflatten<P: AsRef<str>>(pairs: impl IntoIterator<Item = (P, Value)>) -> Value {
+        let mut root = FlatSlot::Branch(IndexMap::new());
+        for (path, value) in pairs {
+            let path = path.as_ref();
+            let segments = if path.is_empty() {
+                Vec::new()
+            } else {
+                split_path(path)
+            };
+            root.insert(&segments, value);
+        }
+        root.into_value()
+    }
+
+    /// Deep-merges `other` over this value.
+    ///
+    /// When both are objects, keys from `other` override keys here, and
+    /// values that are objects on both sides are merged recursively. Keys
+    /// keep this value's order, with keys only in `other` appended in its
+    /// order. Anything else, including arrays and `Null`, replaces the
+    /// value here wholesale; a `Null` does not remove a key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paramdef::core::Value;
+    ///
+    /// let base = Value::object([
+    ///     ("db", Value::object([("host", Value::text("localhost")), ("port", Value::Int(5432))])),
+    ///     ("tags", Value::array([Value::text("a")])),
+    /// ]);
+    /// let overrides = Value::object([
+    ///     ("db", Value::object([("port", Value::Int(6432))])),
+    ///     ("tags", Value::array([Value::text("b")])),
+    ///     ("debug", Value::Bool(true)),
+    /// ]);
+    ///
+    /// let merged = base.merge(&overrides);
+    /// assert_eq!(merged.get_pointer("/db/host"), Some(&Value::text("localhost")));
+    /// assert_eq!(merged.get_pointer("/db/port"), Some(&Value::Int(6432)));
+    /// assert_eq!(merged.get_pointer("/tags"), Some(&Value::array([Value::text("b")])));
+    ///
+    /// let keys: Vec<_> = merged.as_object().unwrap().keys().map(|k| k.as_str()).collect();
+    /// assert_eq!(keys, ["db", "tags", "debug"]);
+    /// ```
+    #[must_use]
+    pub fn merge(&self, other: &Value) -> Value {
+        match (self, other) {
+            (Self::Object(base), Self::Object(overlay)) => {
+                let mut merged = (**base).clone();
+                for (key, value) in overlay.iter() {
+                    let value = match merged.get(key) {
+                        Some(existing) => existing.merge(value),
+                        None => value.clone(),
+                    };
+                    merged.insert(key.clone(), value);
+                }
+                Self::Object(Arc::new(merged))
+            }
+            _ => other.clone(),
+        }
+    }
+
+    /// Returns the value at a JSON Pointer (RFC 6901).
+    ///
+    /// The pointer is a sequence of `/`-prefixed reference tokens, with
+    /// `~1` standing for `/` and `~0` for `~` inside a token. The empty
+    /// pointer refers to the value itself. Unlike the dotted paths of
+    /// [`flatten`](Self::flatten), pointers need no knowledge of the data
+    /// to escape keys, which matches JSON Patch tooling.
+    ///
+    /// Returns `None` if the pointer is malformed or does not resolve,
+    /// including when an array is indexed with anything but a decimal
+    /// index without a leading zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paramdef::core::Value;
+    ///
+    /// let value = Value::object([
+    ///     ("items", Value::array([Value::object([("name", Value::text("a"))])])),
+    ///     ("a/b", Value::Int(1)),
+    /// ]);
+    ///
+    /// assert_eq!(value.get_pointer("/items/0/name"), Some(&Value::text("a")));
+    /// assert_eq!(value.get_pointer("/a~1b"), Some(&Value::Int(1)));
+    /// assert_eq!(value.get_pointer(""), Some(&value));
+    /// assert_eq!(value.get_pointer("/items/x"), None);
+    /// ```
+    #[must_use]
+    pub fn get_pointer(&self, pointer: &str) -> Option<&Value> {
+        self.pointer_get(&pointer_tokens(pointer)?)
+    }
+
+    /// Returns a mutable reference to the value at a JSON Pointer
+    /// (RFC 6901).
+    ///
+    /// Arrays and objects along the path are copied with `Arc::make_mut`
+    /// if they are shared, so clones of this value are unaffected. Returns
+    /// `None` under the same conditions as
+    /// [`get_pointer`](Self::get_pointer).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paramdef::core::Value;
+    ///
+    /// let mut config = Value::object([(
+    ///     "servers",
+    ///     Value::array([Value::object([("port", Value::Int(80))])]),
+    /// )]);
+    /// let original = config.clone();
+    ///
+    /// *config.get_pointer_mut("/servers/0/port").unwrap() = Value::Int(8080);
+    ///
+    /// assert_eq!(config.get_pointer("/servers/0/port"), Some(&Value::Int(8080)));
+    /// assert_eq!(original.get_pointer("/servers/0/port"), Some(&Value::Int(80)));
+    /// ```
+    #[must_use]
+    pub fn get_pointer_mut(&mut self, pointer: &str) -> Option<&mut Value> {
+        self.pointer_get_mut(&pointer_tokens(pointer)?)
+    }
+
+    /// Returns the value at a JSON Pointer (RFC 6901).
+    ///
+    /// Same as [`get_pointer`](Self::get_pointer), named after
+    /// `serde_json::Value::pointer`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paramdef::core::Value;
+    ///
+    /// let value = Value::object([("port", Value::Int(80))]);
+    /// assert_eq!(value.pointer("/port"), Some(&Value::Int(80)));
+    /// ```
+    #[must_use]
+    pub fn pointer(&self, pointer: &str) -> Option<&Value> {
+        self.get_pointer(pointer)
+    }
+
+    /// Returns a mutable reference to the value at a JSON Pointer
+    /// (RFC 6901).
+    ///
+    /// Same as [`get_pointer_mut`](Self::get_pointer_mut), named after
+    /// `serde_json::Value::pointer_mut`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paramdef::core::Value;
+    ///
+    /// let mut value = Value::object([("port", Value::Int(80))]);
+    /// *value.pointer_mut("/port").unwrap() = Value::Int(8080);
+    /// assert_eq!(value.pointer("/port"), Some(&Value::Int(8080)));
+    /// ```
+    #[must_use]
+    pub fn pointer_mut(&mut self, pointer: &str) -> Option<&mut Value> {
+        self.get_pointer_mut(pointer)
+    }
+
+    fn pointer_get(&self, tokens: &[String]) -> Option<&Value> {
+        tokens.iter().try_fold(self, |value, token| match value {
+            Self::Array(items) => items.get(array_index(token)?),
+            Self::Object(fields) => fields.get(token.as_str()),
+            _ => None,
+        })
+    }
+
+    fn pointer_get_mut(&mut self, tokens: &[String]) -> Option<&mut Value> {
+        tokens.iter().try_fold(self, |value, token| match value {
+            Self::Array(items) => {
+                let index = array_index(token).filter(|&i| i < items.len())?;
+                Some(&mut Arc::make_mut(items)[index])
+            }
+            Self::Object(fields) => Arc::make_mut(fields).get_mut(token.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Sets the value at a JSON Pointer (RFC 6901).
+    ///
+    /// Every token but the last must resolve, as in JSON Patch `add`. The
+    /// last token names an object key, which is inserted or replaced, or
+    /// an array index, which is replaced; an index equal to the array
+    /// length, or `-`, appends. The empty pointer replaces the value
+    /// itself. See [`get_pointer`](Self::get_pointer) for the syntax.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `"invalid_pointer"` validation error if the pointer is
+    /// malformed, a parent does not resolve, or an array index is out of
+    /// bounds. The value is unchanged on error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paramdef::core::Value;
+    ///
+    /// let mut value = Value::object([("tags", Value::array([Value::text("a")]))]);
+    /// value.set_pointer("/tags/-", Value::text("b")).unwrap();
+    /// value.set_pointer("/name", Value::text("x")).unwrap();
+    ///
+    /// assert_eq!(value.get_pointer("/tags/1"), Some(&Value::text("b")));
+    /// assert!(value.set_pointer("/missing/key", Value::Null).is_err());
+    /// ```
+    pub fn set_pointer(&mut self, pointer: &str, value: Value) -> Result<()> {
+        let invalid = |reason: &str| {
+            Error::validation("invalid_pointer", format!("pointer '{pointer}' {reason}"))
+        };
+        let tokens = pointer_tokens(pointer).ok_or_else(|| invalid("is malformed"))?;
+        let Some((last, parents)) = tokens.split_last() else {
+            *self = value;
+            return Ok(());
+        };
+
+        let target = self
+            .pointer_get_mut(parents)
+            .ok_or_else(|| invalid("does not resolve"))?;
+        match target {
+            Self::Array(items) => {
+                let len = items.len();
+                let index = if last == "-" {
+                    len
+                } else {
+                    array_index(last)
+                        .filter(|&i| i <= len)
+                        .ok_or_else(|| invalid("has an invalid array index"))?
+                };
+                if index == len {
+                    let mut extended = items.to_vec();
+                    extended.push(value);
+                    *items = Arc::from(extended);
+                } else {
+                    Arc::make_mut(items)[index] = value;
+                }
+            }
+            Self::Object(fields) => {
+                Arc::make_mut(fields).insert(Key::from(last.as_str()), value);
+            }
+            _ => return Err(invalid("does not resolve")),
+        }
+        Ok(())
+    }
+
+    /// Applies a JSON Patch (RFC 6902) and returns the patched value.
+    ///
+    /// `patch` is an array of operation objects, each with an `op` and a
+    /// `path`, plus `value` for `add`, `replace`, and `test`, or `from` for
+    /// `move` and `copy`. Paths are JSON Pointers, as in
+    /// [`get_pointer`](Self::get_pointer). Operations run in order, and
+    /// `add` inserts into arrays rather than replacing.
+    ///
+    /// `self` is never modified: if any operation fails, no changes apply.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error naming the failed operation:
+    ///
+    /// - `"invalid_patch"` if the patch or an operation is malformed
+    /// - `"invalid_pointer"` if a path is malformed or does not resolve
+    /// - `"patch_test_failed"` if a `test` operation does not match
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paramdef::core::Value;
+    ///
+    /// let doc = Value::object([("tags", Value::array([Value::text("a")]))]);
+    /// let patch = Value::array([
+    ///     Value::object([("op", Value::text("test")), ("path", Value::text("/tags/0")), ("value", Value::text("a"))]),
+    ///     Value::object([("op", Value::text("add")), ("path", Value::text("/tags/0")), ("value", Value::text("z"))]),
+    ///     Value::object([("op", Value::text("copy")), ("from", Value::text("/tags")), ("path", Value::text("/old"))]),
+    /// ]);
+    ///
+    /// let patched = doc.apply_patch(&patch).unwrap();
+    /// assert_eq!(patched.get_pointer("/tags"), Some(&Value::array([Value::text("z"), Value::text("a")])));
+    /// assert_eq!(patched.get_pointer("/old/1"), Some(&Value::text("a")));
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn apply_patch(&self, patch: &Value) -> Result<Value> {
+        let operations = patch.as_array().ok_or_else(|| {
+            Error::validation("invalid_patch", "patch must be an array of operations")
+        })?;
+
+        let mut doc = self.clone();
+        for (index, operation) in operations.iter().enumerate() {
+            doc.apply_patch_operation(index, operation)?;
+        }
+        Ok(doc)
+    }
+
+    #[cfg(feature = "serde")]
+    fn apply_patch_operation(&mut self, index: usize, operation: &Value) -> Result<()> {
+        let error = |code: &str, reason: &str| {
+            Error::validation(code, format!("patch operation {index}: {reason}"))
+        };
+        let fields = operation
+            .as_object()
+            .ok_or_else(|| error("invalid_patch", "not an object"))?;
+        let text = |name: &str| {
+            fields
+                .get(name)
+                .and_then(Value::as_text)
+                .ok_or_else(|| error("invalid_patch", &format!("missing '{name}'")))
+        };
+        let pointer = |name: &str| {
+            let raw = text(name)?;
+            pointer_tokens(raw)
+                .ok_or_else(|| error("invalid_pointer", &format!("'{raw}' is malformed")))
+                .map(|tokens| (raw, tokens))
+        };
+        let value = || {
+            fields
+                .get("value")
+                .cloned()
+                .ok_or_else(|| error("invalid_patch", "missing 'value'"))
+        };
+
+        let op = text("op")?;
+        let (raw_path, path) = pointer("path")?;
+        let (raw, resolved) = match op {
+            "add" => (raw_path, self.pointer_add(&path, value()?)),
+            "remove" => (raw_path, self.pointer_remove(&path).map(drop)),
+            "replace" => {
+                let value = value()?;
+                let target = self.pointer_get_mut(&path);
+                (raw_path, target.map(|target| *target = value))
+            }
+            "move" => {
+                let (raw_from, from) = pointer("from")?;
+                if path.len() > from.len() && path.starts_with(&from) {
+                    return Err(error(
+                        "invalid_patch",
+                        &format!("cannot move '{raw_from}' into its own child"),
+                    ));
+                }
+                match self.pointer_remove(&from) {
+                    Some(moved) => (raw_path, self.pointer_add(&path, moved)),
+                    None => (raw_from, None),
+                }
+            }
+            "copy" => {
+                let (raw_from, from) = pointer("from")?;
+                match self.pointer_get(&from).cloned() {
+                    Some(copied) => (raw_path, self.pointer_add(&path, copied)),
+                    None => (raw_from, None),
+                }
+            }
+            "test" => {
+                let expected = value()?;
+                return match self.pointer_get(&path) {
+                    Some(actual) if *actual == expected => Ok(()),
+                    Some(_) => Err(error(
+                        "patch_test_failed",
+                        &format!("value at '{raw_path}' does not match"),
+                    )),
+                    None => Err(error(
+                        "invalid_pointer",
+                        &format!("'{raw_path}' does not resolve"),
+                    )),
+                };
+            }
+            other => {
+                return Err(error("invalid_patch", &format!("unknown op '{other}'")));
+            }
+        };
+        resolved.ok_or_else(|| error("invalid_pointer", &format!("'{raw}' does not resolve")))
+    }
+
+    /// Adds `value` at `tokens` with JSON Patch `add` semantics.
+    #[cfg(feature = "serde")]
+    fn pointer_add(&mut self, tokens: &[String], value: Value) -> Option<()> {
+        let Some((last, parents)) = tokens.split_last() else {
+            *self = value;
+            return Some(());
+        };
+        match self.pointer_get_mut(parents)? {
+            Self::Array(items) => {
+                let index = if last == "-" {
+                    items.len()
+                } else {
+                    array_index(last).filter(|&i| i <= items.len())?
+                };
+                let mut updated = items.to_vec();
+                updated.insert(index, value);
+                *items = Arc::from(updated);
+            }
+            Self::Object(fields) => {
+                Arc::make_mut(fields).insert(Key::from(last.as_str()), value);
+            }
+            _ => return None,
+        }
+        Some(())
+    }
+
+    /// Removes and returns the value at `tokens`. The root cannot be
+    /// removed.
+    #[cfg(feature = "serde")]
+    fn pointer_remove(&mut self, tokens: &[String]) -> Option<Value> {
+        let (last, parents) = tokens.split_last()?;
+        match self.pointer_get_mut(parents)? {
+            Self::Array(items) => {
+                let index = array_index(last).filter(|&i| i < items.len())?;
+                let mut updated = items.to_vec();
+                let removed = updated.remove(index);
+                *items = Arc::from(updated);
+                Some(removed)
+            }
+            Self::Object(fields) => Arc::make_mut(fields).shift_remove(last.as_str()),
+            _ => None,
+        }
+    }
+
+    /// Replaces `${name}` references in text with values from `lookup`.
+    ///
+    /// Walks arrays and objects and rewrites every text value; object keys
+    /// are left alone. Each `${name}` is replaced with `lookup(name)`, or
+    /// kept as written if the lookup returns `None`. `$$` stands for a
+    /// literal `$`, so `$${name}` produces `${name}`. A `$` followed by
+    /// anything else, including an unclosed `${`, is kept as is.
+    ///
+    /// Substituted text is not scanned again. Use
+    /// [`try_interpolate`](Self::try_interpolate) to reject unknown names.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paramdef::core::Value;
+    ///
+    /// let config = Value::object([
+    ///     ("endpoint", Value::text("${base_url}/api")),
+    ///     ("price", Value::text("$$5 per ${unit}")),
+    /// ]);
+    /// let lookup = |name: &str| (name == "base_url").then(|| "https://x.io".to_string());
+    ///
+    /// let resolved = config.interpolate(lookup);
+    /// assert_eq!(resolved.get_pointer("/endpoint"), Some(&Value::text("https://x.io/api")));
+    /// assert_eq!(resolved.get_pointer("/price"), Some(&Value::text("$5 per ${unit}")));
+    /// ```
+    #[must_use]
+    pub fn interpolate(&self, lookup: impl Fn(&str) -> Option<String>) -> Value {
+        self.interpolate_with(&mut |name: &str| lookup(name), false)
+            .unwrap_or_else(|_| self.clone())
+    }
+
+    /// Replaces `${name}` references like [`interpolate`](Self::interpolate),
+    /// failing on names that `lookup` does not resolve.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `"unresolved_reference"` validation error naming the
+    /// first reference for which `lookup` returns `None`.
+    pub fn try_interpolate(&self, lookup: impl Fn(&str) -> Option<String>) -> Result<Value> {
+        self.interpolate_with(&mut |name: &str| lookup(name), true)
+            .map_err(|name| {
+                Error::validation_with_fields(
+                    "unresolved_reference",
+                    format!("no value for '${{{name}}}'"),
+                    vec![name],
+                )
+            })
+    }
+
+    /// Interpolates text values, returning the first unresolved name if
+    /// `strict`.
+    pub(crate) fn interpolate_with(
+        &self,
+        lookup: &mut impl FnMut(&str) -> Option<String>,
+        strict: bool,
+    ) -> std::result::Result<Value, String> {
+        match self {
+            Self::Text(text) if text.contains('$') => {
+                interpolate_text(text, lookup, strict).map(Value::text)
+            }
+            Self::Array(items) => items
+                .iter()
+                .map(|item| item.interpolate_with(lookup, strict))
+                .collect::<std::result::Result<Vec<_>, _>>()
+                .map(Value::array),
+            Self::Object(fields) => fields
+                .iter()
+                .map(|(key, value)| Ok((key.clone(), value.interpolate_with(lookup, strict)?)))
+                .collect::<std::result::Result<Vec<_>, _>>()
+                .map(Value::object),
+            _ => Ok(self.clone()),
+        }
+    }
+
+    /// Converts text with a unit suffix, such as `"512MB"`, to a number in
+    /// `expected`.
+    ///
+    /// The text is parsed with [`NumberUnit::parse`] and converted with
+    /// [`NumberUnit::convert_to`]. The result is an `Int` if it is a whole
+    /// number within the `i64` range, and a `Float` otherwise.
+    ///
+    /// Returns `None` for non-text values, text without a recognized unit
+    /// suffix (including bare numbers), and units of a different
+    /// [category](NumberUnit::category) than `expected`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paramdef::core::Value;
+    /// use paramdef::subtype::NumberUnit;
+    ///
+    /// let size = Value::text("512MB").coerce_with_units(NumberUnit::Kilobytes);
+    /// assert_eq!(size, Some(Value::Int(524_288)));
+    ///
+    /// let timeout = Value::text("1.5 min").coerce_with_units(NumberUnit::Seconds);
+    /// assert_eq!(timeout, Some(Value::Int(90)));
+    ///
+    /// assert_eq!(Value::text("512").coerce_with_units(NumberUnit::Bytes), None);
+    /// assert_eq!(Value::text("5 kg").coerce_with_units(NumberUnit::Bytes), None);
+    /// ```
+    #[must_use]
+    pub fn coerce_with_units(&self, expected: NumberUnit) -> Option<Value> {
+        let (number, unit) = NumberUnit::parse(self.as_text()?)?;
+        if unit == NumberUnit::None || unit.category() != expected.category() {
+            return None;
+        }
+
+        let converted = Value::Float(unit.convert_to(number, expected));
+        Some(converted.try_as_i64_exact().map_or(converted, Value::Int))
+    }
+
+    /// Converts the value into environment variables.
+    ///
+    /// Nested objects and arrays are flattened: each scalar becomes one
+    /// variable named by its path, with the uppercased `prefix`, object
+    /// keys, and array indices joined by `_`. Characters other than ASCII
+    /// letters and digits in the prefix or keys become `_`. An empty
+    /// prefix adds nothing.
+    ///
+    /// Scalars are written as text: booleans as `true`/`false`, numbers in
+    /// their usual form, `Null` as an empty string, and binary as base64.
+    /// Empty objects and arrays produce no variables.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paramdef::core::Value;
+    ///
+    /// let config = Value::object([
+    ///     ("db", Value::object([("host", Value::text("localhost")), ("port", Value::Int(5432))])),
+    ///     ("tags", Value::array([Value::text("a"), Value::text("b")])),
+    /// ]);
+    ///
+    /// let env = config.to_env_map("app");
+    /// assert_eq!(env["APP_DB_HOST"], "localhost");
+    /// assert_eq!(env["APP_DB_PORT"], "5432");
+    /// assert_eq!(env["APP_TAGS_1"], "b");
+    /// ```
+    #[cfg(feature = "serde")]
+    #[must_use]
+    pub fn to_env_map(&self, prefix: &str) -> BTreeMap<String, String> {
+        let mut out = BTreeMap::new();
+        self.env_into(env_name(prefix), &mut out);
+        out
+    }
+
+    #[cfg(feature = "serde")]
+    fn env_into(&self, name: String, out: &mut BTreeMap<String, String>) {
+        let join = |segment: &str| {
+            if name.is_empty() {
+                env_name(segment)
+            } else {
+                format!("{name}_{}", env_name(segment))
+            }
+        };
+
+        let text = match self {
+            Self::Array(items) => {
+                for (i, item) in items.iter().enumerate() {
+                    item.env_into(join(&i.to_string()), out);
+                }
+                return;
+            }
+            Self::Object(fields) => {
+                for (key, value) in fields.iter() {
+                    value.env_into(join(key), out);
+                }
+                return;
+            }
+            Self::Null => String::new(),
+            Self::Bool(b) => b.to_string(),
+            Self::Int(i) => i.to_string(),
+            Self::Float(f) => f.to_string(),
+            Self::Text(s) => s.to_string(),
+            Self::Binary(bytes) => {
+                use base64::Engine;
+                base64::engine::general_purpose::STANDARD.encode(&**bytes)
+            }
+        };
+        out.insert(name, text);
+    }
+
+    /// Rebuilds a value from environment variables.
+    ///
+    /// The counterpart of [`to_env_map`](Self::to_env_map). Only variables
+    /// starting with the uppercased `prefix` and `_` are read; an empty
+    /// prefix reads them all. The rest of each name is lowercased and split
+    /// on `_` into object keys, with numeric segments becoming array
+    /// indices as in [`unflatten`](Self::unflatten). Its bound on indices
+    /// applies here too, so a variable name with a huge index yields an
+    /// object rather than a huge array.
+    ///
+    /// Values are parsed as `true`/`false`, integers, and finite floats;
+    /// an empty string becomes `Null` and anything else stays text.
+    ///
+    /// The conversion is lossy: keys come back lowercased, keys that
+    /// contained `_` come back split into nested objects, and base64 for
+    /// binary values comes back as text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paramdef::core::Value;
+    ///
+    /// let vars = [
+    ///     ("APP_DB_HOST", "localhost"),
+    ///     ("APP_DB_PORT", "5432"),
+    ///     ("HOME", "/root"),
+    /// ];
+    ///
+    /// let config = Value::from_env_map("app", vars);
+    /// let db = config.as_object().unwrap()["db"].as_object().unwrap();
+    /// assert_eq!(db["host"], Value::text("localhost"));
+    /// assert_eq!(db["port"], Value::Int(5432));
+    /// ```
+    #[cfg(feature = "serde")]
+    #[must_use]
+    pub fn from_env_map<K: AsRef<str>, V: AsRef<str>>(
+        prefix: &str,
+        vars: impl IntoIterator<Item = (K, V)>,
+    ) -> Value {
+        let prefix = env_name(prefix);
+        let pairs = vars.into_iter().filter_map(|(name, value)| {
+            let name = name.as_ref();
+            let rest = if prefix.is_empty() {
+                name
+            } else {
+                name.strip_prefix(prefix.as_str())?.strip_prefix('_')?
+            };
+            let path = rest.to_lowercase().replace('_', ".");
+            Some((path, parse_env_value(value.as_ref())))
+        });
+        Value::unflatten(pairs)
+    }
+
+    /// Returns a deterministic hash of the value's content.
+    ///
+    /// `Value` cannot implement [`Hash`](std::hash::Hash) because it holds
+    /// `f64`; this is a purpose-built hash for memoization and change
+    /// detection. It agrees with [`numeric_eq`](Self::numeric_eq), not with
+    /// `==`: values that are `numeric_eq` hash the same, so `Int(1)` and
+    /// `Float(1.0)` collide on purpose, as do objects that differ only in
+    /// key order. All `NaN`s share one hash, and `-0.0` hashes as `0.0`.
+    ///
+    /// The hash uses 64-bit FNV-1a and does not depend on the process,
+    /// platform or Rust version, so it can be persisted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paramdef::core::Value;
+    ///
+    /// let a = Value::object([("x", Value::Int(1)), ("y", Value::Bool(true))]);
+    /// let b = Value::object([("y", Value::Bool(true)), ("x", Value::Float(1.0))]);
+    ///
+    /// assert!(a.numeric_eq(&b));
+    /// assert_eq!(a.content_hash(), b.content_hash());
+    /// ```
+    #[must_use]
+    pub fn content_hash(&self) -> u64 {
+        let mut hasher = Fnv1a::default();
+        self.hash_content(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Estimates the heap memory held by this value, in bytes.
+    ///
+    /// Counts spilled text and key bytes, array and object allocations
+    /// including their `Arc` headers, nested values, and binary data. The
+    /// `Value` itself is not counted, since it usually lives inline in a
+    /// map or array.
+    ///
+    /// An `Arc` reachable several times from this value is counted once.
+    /// Sharing between separate calls is not tracked, so summing the sizes
+    /// of values that share data over-counts. Allocator overhead and spare
+    /// capacity are approximated, so treat the result as an estimate for
+    /// cache accounting, not an exact figure.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paramdef::core::Value;
+    ///
+    /// assert_eq!(Value::Int(7).deep_size(), 0);
+    /// assert_eq!(Value::text("short").deep_size(), 0);
+    ///
+    /// let bytes = Value::binary(vec![0u8; 1024]);
+    /// assert!(bytes.deep_size() >= 1024);
+    ///
+    /// // Shared data is counted once per call.
+    /// let pair = Value::array([bytes.clone(), bytes.clone()]);
+    /// assert!(pair.deep_size() < 2 * 1024);
+    /// ```
+    #[must_use]
+    pub fn deep_size(&self) -> usize {
+        self.deep_size_with(&mut FxHashSet::default())
+    }
+
+    fn deep_size_with(&self, seen: &mut FxHashSet<*const ()>) -> usize {
+        const ARC_HEADER: usize = 2 * size_of::<usize>();
+
+        match self {
+            Self::Null | Self::Bool(_) | Self::Int(_) | Self::Float(_) => 0,
+            Self::Text(s) => spilled_len(s.len()),
+            Self::Array(items) => {
+                if !seen.insert(items.as_ptr().cast()) {
+                    return 0;
+                }
+                ARC_HEADER
+                    + size_of_val::<[Value]>(items)
+                    + items.iter().map(|v| v.deep_size_with(seen)).sum::<usize>()
+            }
+            Self::Object(fields) => {
+                if !seen.insert(std::ptr::from_ref::<IndexMap<Key, Value>>(fields).cast()) {
+                    return 0;
+                }
+                // Entries store a cached hash next to the key and value, and
+                // the index table holds one `usize` per slot.
+                let slot = size_of::<u64>() + size_of::<Key>() + size_of::<Value>();
+                ARC_HEADER
+                    + size_of::<IndexMap<Key, Value>>()
+                    + fields.capacity() * (slot + size_of::<usize>())
+                    + fields
+                        .iter()
+                        .map(|(k, v)| spilled_len(k.len()) + v.deep_size_with(seen))
+                        .sum::<usize>()
+            }
+            Self::Binary(bytes) => {
+                if !seen.insert(bytes.as_ptr().cast()) {
+                    return 0;
+                }
+                ARC_HEADER + bytes.len()
+            }
+        }
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn hash_content(&self, h: &mut Fnv1a) {
+        match self {
+            Self::Null => h.write_u8(0),
+            Self::Bool(b) => {
+                h.write_u8(1);
+                h.write_u8(u8::from(*b));
+            }
+            // Integers and floats share a tag so that numerically equal
+            // values hash the same.
+            Self::Int(i) => hash_number(*i as f64, h),
+            Self::Float(f) => hash_number(*f, h),
+            Self::Text(s) => {
+                h.write_u8(3);
+                h.write_usize(s.len());
+                h.write(s.as_bytes());
+            }
+            Self::Array(items) => {
+                h.write_u8(4);
+                h.write_usize(items.len());
+                for item in items.iter() {
+                    item.hash_content(h);
+                }
+            }
+            Self::Object(fields) => {
+                let mut entries: Vec<_> = fields.iter().collect();
+                entries.sort_unstable_by(|(a, _), (b, _)| a.as_str().cmp(b.as_str()));
+
+                h.write_u8(5);
+                h.write_usize(entries.len());
+                for (key, value) in entries {
+                    h.write_usize(key.len());
+                    h.write(key.as_bytes());
+                    value.hash_content(h);
+                }
+            }
+            Self::Binary(bytes) => {
+                h.write_u8(6);
+                h.write_usize(bytes.len());
+                h.write(bytes);
+            }
+        }
+    }
+}
+
+/// One segment of a flattened path.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum FlatSegment {
+    Index(usize),
+    Key(String),
+}
+
+/// A partially rebuilt value in [`Value::unflatten`].
+enum FlatSlot {
+    Leaf(Value),
+    Branch(IndexMap<FlatSegment, FlatSlot>),
+}
+
+impl FlatSlot {
+    fn insert(&mut self, segments: &[FlatSegment], value: Value) {
+        let Some((first, rest)) = segments.split_first() else {
+            *self = Self::Leaf(value);
+            return;
+        };
+        if !matches!(self, Self::Branch(_)) {
+            *self = Self::Branch(IndexMap::new());
+        }
+        if let Self::Branch(children) = self {
+            children
+                .entry(first.clone())
+                .or_insert_with(|| Self::Branch(IndexMap::new()))
+                .insert(rest, value);
+        }
+    }
+
+    fn into_value(self) -> Value {
+        let children = match self {
+            Self::Leaf(value) => return value,
+            Self::Branch(children) => children,
+        };
+
+        // An index past the number of entries makes the level an object, so
+        // the array allocated here never outgrows the input.
+        let len = children
+            .keys()
+            .try_fold(0, |len: usize, segment| match segment {
+                FlatSegment::Index(i) => Some(len.max(i.checked_add(1)?)),
+                FlatSegment::Key(_) => None,
+            })
+            .filter(|&len| len > 0 && len <= children.len() + 1);
+        if let Some(len) = len {
+            let mut items = vec![Value::Null; len];
+            for (segment, child) in children {
+                if let FlatSegment::Index(i) = segment {
+                    items[i] = child.into_value();
+                }
+            }
+            return Value::array(items);
+        }
+
+        Value::object(
+            children
+                .into_iter()
+                .map(|(segment, child)| {
+                    let key = match segment {
+                        FlatSegment::Index(i) => i.to_string(),
+                        FlatSegment::Key(key) => key,
+                    };
+                    (key, child.into_value())
+                })
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
+/// Returns `true` if `s` is written the way [`Value::flatten`] writes an
+/// array index: decimal digits without a leading zero.
+fn is_index_segment(s: &str) -> bool {
+    !s.is_empty()
+        && s.bytes().all(|b| b.is_ascii_digit())
+        && (s == "0" || !s.starts_with('0'))
+        && s.parse::<usize>().is_ok()
+}
+
+/// Escapes an object key for use as a flattened path segment.
+fn escape_segment(key: &str) -> String {
+    if key.is_empty() {
+        return "\\_".to_string();
+    }
+
+    let mut out = String::with_capacity(key.len() + 1);
+    if is_index_segment(key) {
+        out.push('\\');
+    }
+    for c in key.chars() {
+        if c == '.' || c == '\\' {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// Splits a flattened path on unescaped dots and decodes each segment.
+fn split_path(path: &str) -> Vec<FlatSegment> {
+    let mut segments = Vec::new();
+    let mut raw = String::new();
+    let mut chars = path.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                raw.push(c);
+                if let Some(next) = chars.next() {
+                    raw.push(next);
+                }
+            }
+            '.' => segments.push(decode_segment(&std::mem::take(&mut raw))),
+            _ => raw.push(c),
+        }
+    }
+    segments.push(decode_segment(&raw));
+    segments
+}
+
+fn decode_segment(raw: &str) -> FlatSegment {
+    if raw == "\\_" {
+        return FlatSegment::Key(String::new());
+    }
+    if is_index_segment(raw) {
+        if let Ok(index) = raw.parse() {
+            return FlatSegment::Index(index);
+        }
+    }
+
+    let mut key = String::with_capacity(raw.len());
+    let mut chars = raw.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            key.extend(chars.next());
+        } else {
+            key.push(c);
+        }
+    }
+    FlatSegment::Key(key)
+}
+
+/// Returns an object's entries sorted by key.
+fn sorted_entries(fields: &IndexMap<Key, Value>) -> Vec<(&Key, &Value)> {
+    let mut entries: Vec<(&Key, &Value)> = fields.iter().collect();
+    entries.sort_unstable_by(|a, b| a.0.as_str().cmp(b.0.as_str()));
+    entries
+}
+
+/// Compares floats with `NaN` greatest and equal to itself.
+fn cmp_floats(a: f64, b: f64) -> Ordering {
+    a.partial_cmp(&b)
+        .unwrap_or_else(|| a.is_nan().cmp(&b.is_nan()))
+}
+
+/// Compares an integer with a float exactly, with `NaN` greatest.
+#[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+fn cmp_int_float(i: i64, f: f64) -> Ordering {
+    // 2^63 is exactly representable; i64 covers [-2^63, 2^63).
+    const LIMIT: f64 = 9_223_372_036_854_775_808.0;
+    if f.is_nan() || f >= LIMIT {
+        return Ordering::Less;
+    }
+    if f < -LIMIT {
+        return Ordering::Greater;
+    }
+
+    // The truncated float fits in i64 exactly, so only the fraction is left.
+    let whole = f.trunc();
+    i.cmp(&(whole as i64))
+        .then_with(|| cmp_floats(0.0, f - whole))
+}
+
+/// Replaces `${name}` references and `$$` escapes in `text`.
+///
+/// Returns the first name `lookup` cannot resolve if `strict`; otherwise
+/// unresolved references are kept as written.
+fn interpolate_text(
+    text: &str,
+    lookup: &mut impl FnMut(&str) -> Option<String>,
+    strict: bool,
+) -> std::result::Result<String, String> {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(pos) = rest.find('$') {
+        out.push_str(&rest[..pos]);
+        let after = &rest[pos + 1..];
+        if let Some(tail) = after.strip_prefix('$') {
+            out.push('$');
+            rest = tail;
+            continue;
+        }
+
+        let reference = after
+            .strip_prefix('{')
+            .and_then(|body| Some((body, body.find('}')?)));
+        let Some((body, end)) = reference else {
+            out.push('$');
+            rest = after;
+            continue;
+        };
+        let name = &body[..end];
+        match lookup(name) {
+            Some(value) => out.push_str(&value),
+            None if strict => return Err(name.to_string()),
+            // `$`, `{`, the name, and `}`.
+            None => out.push_str(&rest[pos..pos + end + 3]),
+        }
+        rest = &body[end + 1..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+/// Splits a JSON Pointer into unescaped reference tokens.
+///
+/// Returns `None` if the pointer is neither empty nor starts with `/`, or
+/// if `~` is followed by anything but `0` or `1`.
+fn pointer_tokens(pointer: &str) -> Option<Vec<String>> {
+    if pointer.is_empty() {
+        return Some(Vec::new());
+    }
+    pointer
+        .strip_prefix('/')?
+        .split('/')
+        .map(|raw| {
+            let mut token = String::with_capacity(raw.len());
+            let mut chars = raw.chars();
+            while let Some(c) = chars.next() {
+                if c == '~' {
+                    match chars.next() {
+                        Some('0') => token.push('~'),
+                        Some('1') => token.push('/'),
+                        _ => return None,
+                    }
+                } else {
+                    token.push(c);
+                }
+            }
+            Some(token)
+        })
+        .collect()
+}
+
+/// Parses a JSON Pointer array index: decimal digits without a leading
+/// zero.
+fn array_index(token: &str) -> Option<usize> {
+    if is_index_segment(token) {
+        token.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// Uppercases `s` and replaces characters that are not ASCII letters or
+/// digits with `_`.
+#[cfg(feature = "serde")]
+fn env_name(s: &str) -> String {
+    s.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_uppercase()
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+/// Parses an environment variable value for [`Value::from_env_map`].
+#[cfg(feature = "serde")]
+fn parse_env_value(s: &str) -> Value {
+    match s {
+        "" => Value::Null,
+        "true" => Value::Bool(true),
+        "false" => Value::Bool(false),
+        _ => {
+            if let Ok(i) = s.parse() {
+                Value::Int(i)
+            } else if let Some(f) = s
+                .parse::<f64>()
+                .ok()
+                .filter(|f| f.is_finite() && s.bytes().any(|b| b.is_ascii_digit()))
+            {
+                Value::Float(f)
+            } else {
+                Value::text(s)
+            }
+        }
+    }
+}
+
+/// Heap bytes used by a string of `len` bytes in `SmartString` storage.
+fn spilled_len(len: usize) -> usize {
+    if len > MAX_INLINE { len } else { 0 }
+}
+
+fn hash_number(f: f64, h: &mut Fnv1a) {
+    const NAN_SENTINEL: u64 = 0x7ff8_0000_0000_0000;
+
+    h.write_u8(2);
+    let bits = if f.is_nan() {
+        NAN_SENTINEL
+    } else if f == 0.0 {
+        0
+    } else {
+        f.to_bits()
+    };
+    h.write_u64(bits);
+}
+
+/// 64-bit FNV-1a, used for [`Value::content_hash`].
+///
+/// Integers are written little-endian so the hash is platform independent.
+pub(crate) struct Fnv1a(u64);
+
+impl Default for Fnv1a {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for Fnv1a {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn write_u16(&mut self, n: u16) {
+        self.write(&n.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, n: u32) {
+        self.write(&n.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, n: u64) {
+        self.write(&n.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, n: usize) {
+        self.write_u64(n as u64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Key;
+
+    #[test]
+    fn test_value_deep_size() {
+        assert_eq!(Value::Null.deep_size(), 0);
+        assert_eq!(Value::Float(1.5).deep_size(), 0);
+        assert_eq!(Value::text("inline").deep_size(), 0);
+
+        let long = "x".repeat(100);
+        assert_eq!(Value::text(long.as_str()).deep_size(), 100);
+
+        let bytes = Value::binary(vec![0u8; 64]);
+        let single = bytes.deep_size();
+        assert!(single >= 64);
+
+        // The shared binary is counted once.
+        let shared = Value::array([bytes.clone(), bytes.clone()]);
+        let distinct = Value::array([bytes.clone(), Value::binary(vec![0u8; 64])]);
+        assert_eq!(distinct.deep_size(), shared.deep_size() + single);
+
+        let object = Value::object([(long.as_str(), Value::text(long.as_str()))]);
+        assert!(object.deep_size() >= 200);
+    }
+
+    // Utility tests
+    #[test]
+    fn test_value_is_empty() {
+        assert!(Value::Null.is_empty());
+        assert!(Value::text("").is_empty());
+        assert!(Value::array([]).is_empty());
+        assert!(Value::object(std::iter::empty::<(Key, Value)>()).is_empty());
+        assert!(Value::binary([]).is_empty());
+
+        assert!(!Value::text("hello").is_empty());
+        assert!(!Value::Bool(false).is_empty());
+    }
+
+    #[test]
+    fn test_value_type_name() {
+        assert_eq!(Value::Null.type_name(), "null");
+        assert_eq!(Value::Bool(true).type_name(), "bool");
+        assert_eq!(Value::Int(1).type_name(), "int");
+        assert_eq!(Value::Float(1.0).type_name(), "float");
+        assert_eq!(Value::text("").type_name(), "text");
+        assert_eq!(Value::array([]).type_name(), "array");
+        assert_eq!(
+            Value::object(std::iter::empty::<(Key, Value)>()).type_name(),
+            "object"
+        );
+        assert_eq!(Value::binary([]).type_name(), "binary");
+    }
+
+    #[test]
+    fn test_value_numeric_eq() {
+        assert!(Value::Int(2).numeric_eq(&Value::Float(2.0)));
+        assert!(!Value::Int(2).numeric_eq(&Value::Float(2.5)));
+        assert!(Value::Float(f64::NAN).numeric_eq(&Value::Float(f64::NAN)));
+        assert!(Value::array([Value::Int(1)]).numeric_eq(&Value::array([Value::Float(1.0)])));
+        assert!(!Value::array([Value::Int(1)]).numeric_eq(&Value::array([])));
+        assert!(!Value::Null.numeric_eq(&Value::Bool(false)));
+    }
+
+    #[test]
+    fn test_value_checked_arithmetic() {
+        assert_eq!(
+            Value::Int(-2).checked_add(&Value::Int(5)),
+            Some(Value::Int(3))
+        );
+        assert_eq!(
+            Value::Float(0.5).checked_add(&Value::Float(0.25)),
+            Some(Value::Float(0.75))
+        );
+        assert_eq!(Value::Int(i64::MIN).checked_add(&Value::Int(-1)), None);
+        assert_eq!(
+            Value::Float(f64::MAX).checked_add(&Value::Float(f64::MAX)),
+            None
+        );
+        assert_eq!(
+            Value::Float(f64::INFINITY).checked_add(&Value::Int(1)),
+            Some(Value::Float(f64::INFINITY))
+        );
+        assert_eq!(Value::Bool(true).checked_add(&Value::Int(1)), None);
+
+        assert_eq!(
+            Value::Int(-4).checked_mul(&Value::Float(0.5)),
+            Some(Value::Float(-2.0))
+        );
+        assert_eq!(Value::Float(f64::MAX).checked_mul(&Value::Int(2)), None);
+        assert_eq!(Value::array([]).checked_mul(&Value::Int(2)), None);
+    }
+
+    #[test]
+    fn test_value_min_max() {
+        assert_eq!(Value::Int(5).max(&Value::Int(-5)), Some(Value::Int(5)));
+        assert_eq!(Value::Int(5).min(&Value::Int(-5)), Some(Value::Int(-5)));
+        assert_eq!(
+            Value::Int(1).max(&Value::Float(0.5)),
+            Some(Value::Float(1.0))
+        );
+        assert_eq!(
+            Value::Float(f64::NAN).min(&Value::Int(3)),
+            Some(Value::Float(3.0))
+        );
+        assert_eq!(Value::text("a").max(&Value::text("b")), None);
+    }
+
+    #[test]
+    fn test_value_cmp_canonical_kinds() {
+        let ordered = [
+            Value::Null,
+            Value::Bool(false),
+            Value::Bool(true),
+            Value::Float(f64::NEG_INFINITY),
+            Value::Int(-1),
+            Value::Float(0.5),
+            Value::Int(1),
+            Value::Float(f64::NAN),
+            Value::text(""),
+            Value::text("a"),
+            Value::array([]),
+            Value::array([Value::Int(1)]),
+            Value::array([Value::Int(1), Value::Null]),
+            Value::array([Value::Int(2)]),
+            Value::object([("a", Value::Int(1))]),
+            Value::object([("a", Value::Int(2))]),
+            Value::object([("b", Value::Int(0))]),
+            Value::binary([0u8]),
+            Value::binary([1u8]),
+        ];
+
+        for (i, a) in ordered.iter().enumerate() {
+            for (j, b) in ordered.iter().enumerate() {
+                assert_eq!(a.cmp_canonical(b), i.cmp(&j), "{a:?} vs {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_value_cmp_canonical_numbers() {
+        use std::cmp::Ordering::{Equal, Greater, Less};
+
+        let cmp = |a: Value, b: Value| a.cmp_canonical(&b);
+        assert_eq!(cmp(Value::Int(1), Value::Float(1.0)), Equal);
+        assert_eq!(cmp(Value::Float(-0.0), Value::Float(0.0)), Equal);
+        assert_eq!(cmp(Value::Float(f64::NAN), Value::Float(f64::NAN)), Equal);
+        assert_eq!(
+            cmp(Value::Float(f64::NAN), Value::Float(f64::INFINITY)),
+            Greater
+        );
+        assert_eq!(cmp(Value::Int(i64::MAX), Value::Float(f64::NAN)), Less);
+
+        // Beyond f64 precision, integers still compare exactly.
+        let big = 1_i64 << 60;
+        assert_eq!(
+            cmp(Value::Int(big + 1), Value::Float(2f64.powi(60))),
+            Greater
+        );
+        assert_eq!(cmp(Value::Int(i64::MAX), Value::Float(2f64.powi(63))), Less);
+        assert_eq!(
+            cmp(Value::Int(i64::MIN), Value::Float(-(2f64.powi(63)))),
+            Equal
+        );
+        assert_eq!(cmp(Value::Int(-2), Value::Float(-1.5)), Less);
+        assert_eq!(cmp(Value::Int(-1), Value::Float(-1.5)), Greater);
+
+        // Objects ignore field order.
+        let ab = Value::object([("a", Value::Int(1)), ("b", Value::Int(2))]);
+        let ba = Value::object([("b", Value::Int(2)), ("a", Value::Int(1))]);
+        assert_eq!(ab.cmp_canonical(&ba), Equal);
+    }
+
+    #[test]
+    fn test_value_redact_by() {
+        let value = Value::object([
+            ("user", Value::text("alice")),
+            ("tokens", Value::array([Value::text("a"), Value::text("b")])),
+            ("db", Value::object([("password", Value::Int(1))])),
+        ]);
+
+        let redacted = value.redact_by(|path| {
+            let path: Vec<&str> = path.iter().map(Key::as_str).collect();
+            !matches!(path.as_slice(), ["tokens", "1"] | [.., "password"])
+        });
+
+        assert_eq!(
+            redacted,
+            Value::object([
+                ("user", Value::text("alice")),
+                (
+                    "tokens",
+                    Value::array([Value::text("a"), Value::text(REDACTED)]),
+                ),
+                ("db", Value::object([("password", Value::text(REDACTED))])),
+            ])
+        );
+        assert_eq!(Value::Int(3).redact_by(|_| false), Value::Int(3));
+    }
+
+    #[test]
+    fn test_value_flatten() {
+        let value = Value::object([
+            ("a", Value::object([("b", Value::Int(1))])),
+            ("c", Value::array([Value::Int(2), Value::Int(3)])),
+            ("empty", Value::array([])),
+        ]);
+
+        let flat = value.flatten();
+        assert_eq!(flat.len(), 4);
+        assert_eq!(flat["a.b"], Value::Int(1));
+        assert_eq!(flat["c.1"], Value::Int(3));
+        assert_eq!(flat["empty"], Value::array([]));
+
+        assert_eq!(Value::Int(5).flatten()[""], Value::Int(5));
+    }
+
+    #[test]
+    fn test_value_flatten_escapes_keys() {
+        let value = Value::object([
+            ("host.name", Value::text("x")),
+            ("back\\slash", Value::Int(1)),
+            ("0", Value::Bool(true)),
+            ("", Value::Null),
+            ("07", Value::Int(7)),
+        ]);
+
+        let flat = value.flatten();
+        let keys: Vec<&str> = flat.keys().map(String::as_str).collect();
+        assert_eq!(keys, ["host\\.name", "back\\\\slash", "\\0", "\\_", "07"]);
+    }
+
+    #[test]
+    fn test_value_flatten_round_trip() {
+        let values = [
+            Value::Null,
+            Value::text("scalar"),
+            Value::object(std::iter::empty::<(&str, Value)>()),
+            Value::array([Value::array([]), Value::object([("x", Value::Int(1))])]),
+            Value::object([
+                (
+                    "a.b",
+                    Value::object([("0", Value::Int(1)), ("1", Value::Int(2))]),
+                ),
+                ("", Value::array([Value::Float(1.5), Value::Null])),
+                ("\\", Value::Bool(false)),
+            ]),
+        ];
+
+        for value in values {
+            assert_eq!(Value::unflatten(value.flatten()), value);
+        }
+    }
+
+    #[test]
+    fn test_value_unflatten() {
+        let value = Value::unflatten([
+            ("list.1", Value::Int(2)),
+            ("obj.x", Value::Int(1)),
+            ("obj", Value::Int(0)),
+            ("mixed.0", Value::Int(0)),
+            ("mixed.k", Value::Int(1)),
+        ]);
+
+        assert_eq!(
+            value,
+            Value::object([
+                ("list", Value::array([Value::Null, Value::Int(2)])),
+                ("obj", Value::Int(0)),
+                (
+                    "mixed",
+                    Value::object([("0", Value::Int(0)), ("k", Value::Int(1))])
+                ),
+            ])
+        );
+        assert_eq!(
+            Value::unflatten(Vec::<(String, Value)>::new()),
+            Value::object(std::iter::empty::<(&str, Value)>())
+        );
+    }
+
+    #[test]
+    fn test_value_unflatten_large_index() {
+        assert_eq!(
+            Value::unflatten([("a.5000000000", Value::Int(1))]),
+            Value::object([("a", Value::object([("5000000000", Value::Int(1))]))])
+        );
+        assert_eq!(
+            Value::unflatten([("18446744073709551615", Value::Int(1))]),
+            Value::object([("18446744073709551615", Value::Int(1))])
+        );
+        assert_eq!(
+            Value::unflatten([("0", Value::Int(0)), ("3", Value::Int(3))]),
+            Value::object([("0", Value::Int(0)), ("3", Value::Int(3))])
+        );
+    }
+
+    #[test]
+    fn test_value_coerce_with_units() {
+        let bytes = |s: &str| Value::text(s).coerce_with_units(NumberUnit::Bytes);
+
+        assert_eq!(bytes("2 KB"), Some(Value::Int(2_048)));
+        assert_eq!(bytes("1.5gb"), Some(Value::Int(1_610_612_736)));
+        assert_eq!(bytes("7 B"), Some(Value::Int(7)));
+        assert_eq!(bytes("5 s"), None);
+        assert_eq!(bytes("10"), None);
+        assert_eq!(bytes("lots"), None);
+        assert_eq!(Value::Int(512).coerce_with_units(NumberUnit::Bytes), None);
+
+        assert_eq!(
+            Value::text("1500 ms").coerce_with_units(NumberUnit::Seconds),
+            Some(Value::Float(1.5))
+        );
+        assert_eq!(
+            Value::text("1e200 TB").coerce_with_units(NumberUnit::Bytes),
+            Some(Value::Float(1e200 * 1_099_511_627_776.0))
+        );
+    }
+
+    #[test]
+    fn test_value_interpolate() {
+        let lookup = |name: &str| match name {
+            "host" => Some("example.com".to_string()),
+            "port" => Some("8080".to_string()),
+            _ => None,
+        };
+        let text = |s: &str| Value::text(s).interpolate(lookup);
+
+        assert_eq!(
+            text("http://${host}:${port}/"),
+            Value::text("http://example.com:8080/")
+        );
+        assert_eq!(
+            text("${missing}@${host}"),
+            Value::text("${missing}@example.com")
+        );
+        assert_eq!(
+            text("$$${host} $${host} $$$$"),
+            Value::text("$example.com ${host} $$")
+        );
+        assert_eq!(text("cost: $5, ${host"), Value::text("cost: $5, ${host"));
+        assert_eq!(text("trailing $"), Value::text("trailing $"));
+        assert_eq!(text("${}"), Value::text("${}"));
+
+        let nested = Value::object([(
+            "${host}",
+            Value::array([Value::text("${port}"), Value::Int(1)]),
+        )]);
+        assert_eq!(
+            nested.interpolate(lookup),
+            Value::object([(
+                "${host}",
+                Value::array([Value::text("8080"), Value::Int(1)])
+            )])
+        );
+    }
+
+    #[test]
+    fn test_value_try_interpolate() {
+        let lookup = |name: &str| (name == "a").then(|| "1".to_string());
+
+        let value = Value::array([Value::text("${a}$${b}")]);
+        assert_eq!(
+            value.try_interpolate(lookup).unwrap(),
+            Value::array([Value::text("1${b}")])
+        );
+
+        let err = Value::text("${a}${b}").try_interpolate(lookup).unwrap_err();
+        assert_eq!(err.code(), Some("unresolved_reference"));
+        assert_eq!(err.fields(), ["b"]);
+    }
+
+    #[test]
+    fn test_value_get_pointer() {
+        let value = Value::object([
+            (
+                "items",
+                Value::array([Value::Int(1), Value::object([("name", Value::text("b"))])]),
+            ),
+            ("m~n", Value::Int(2)),
+            ("", Value::Int(3)),
+        ]);
+
+        assert_eq!(value.get_pointer(""), Some(&value));
+        assert_eq!(value.get_pointer("/items/0"), Some(&Value::Int(1)));
+        assert_eq!(value.get_pointer("/items/1/name"), Some(&Value::text("b")));
+        assert_eq!(value.get_pointer("/m~0n"), Some(&Value::Int(2)));
+        assert_eq!(value.get_pointer("/"), Some(&Value::Int(3)));
+
+        assert_eq!(value.get_pointer("items"), None);
+        assert_eq!(value.get_pointer("/items/01"), None);
+        assert_eq!(value.get_pointer("/items/-"), None);
+        assert_eq!(value.get_pointer("/items/2"), None);
+        assert_eq!(value.get_pointer("/items/0/x"), None);
+        assert_eq!(value.get_pointer("/m~2n"), None);
+    }
+
+    #[test]
+    fn test_value_merge() {
+        let base = Value::object([
+            ("a", Value::Int(1)),
+            (
+                "nested",
+                Value::object([("x", Value::Int(1)), ("y", Value::Int(2))]),
+            ),
+            ("list", Value::array([Value::Int(1), Value::Int(2)])),
+            ("obj", Value::object([("k", Value::Int(1))])),
+        ]);
+        let overlay = Value::object([
+            ("new", Value::Bool(true)),
+            (
+                "nested",
+                Value::object([("y", Value::Null), ("z", Value::Int(3))]),
+            ),
+            ("list", Value::array([Value::Int(3)])),
+            ("obj", Value::text("flat")),
+        ]);
+
+        let merged = base.merge(&overlay);
+        assert_eq!(
+            merged,
+            Value::object([
+                ("a", Value::Int(1)),
+                (
+                    "nested",
+                    Value::object([
+                        ("x", Value::Int(1)),
+                        ("y", Value::Null),
+                        ("z", Value::Int(3)),
+                    ]),
+                ),
+                ("list", Value::array([Value::Int(3)])),
+                ("obj", Value::text("flat")),
+                ("new", Value::Bool(true)),
+            ])
+        );
+
+        assert_eq!(Value::Int(1).merge(&base), base);
+        assert_eq!(base.merge(&Value::Null), Value::Null);
+        assert_eq!(
+            base.merge(&Value::object(Vec::<(&str, Value)>::new())),
+            base
+        );
+    }
+
+    #[test]
+    fn test_value_get_pointer_mut() {
+        let mut value = Value::object([
+            ("items", Value::array([Value::Int(1), Value::Int(2)])),
+            ("a~b", Value::object([("c", Value::Null)])),
+        ]);
+        let shared = value.clone();
+
+        *value.get_pointer_mut("/items/1").unwrap() = Value::Int(20);
+        *value.get_pointer_mut("/a~0b/c").unwrap() = Value::Bool(true);
+        assert_eq!(value.get_pointer("/items/1"), Some(&Value::Int(20)));
+        assert_eq!(value.get_pointer("/a~0b/c"), Some(&Value::Bool(true)));
+        assert_eq!(shared.get_pointer("/items/1"), Some(&Value::Int(2)));
+
+        assert!(value.get_pointer_mut("/items/2").is_none());
+        assert!(value.get_pointer_mut("/items/0/x").is_none());
+        assert!(value.get_pointer_mut("items").is_none());
+        *value.get_pointer_mut("").unwrap() = Value::Null;
+        assert_eq!(value, Value::Null);
+    }
+
+    #[test]
+    fn test_value_set_pointer() {
+        let original = Value::object([("list", Value::array([Value::Int(1)]))]);
+        let mut value = original.clone();
+
+        value.set_pointer("/list/0", Value::Int(10)).unwrap();
+        value.set_pointer("/list/1", Value::Int(20)).unwrap();
+        value.set_pointer("/list/-", Value::Int(30)).unwrap();
+        value.set_pointer("/a~1b", Value::Bool(true)).unwrap();
+        assert_eq!(
+            value,
+            Value::object([
+                (
+                    "list",
+                    Value::array([Value::Int(10), Value::Int(20), Value::Int(30)])
+                ),
+                ("a/b", Value::Bool(true)),
+            ])
+        );
+        // Shared data is copied on write.
+        assert_eq!(original.get_pointer("/list/0"), Some(&Value::Int(1)));
+
+        for bad in ["list", "/list/5", "/list/x", "/missing/a", "/list/0/a"] {
+            let err = value.set_pointer(bad, Value::Null).unwrap_err();
+            assert_eq!(err.code(), Some("invalid_pointer"), "{bad}");
+        }
+
+        value.set_pointer("", Value::Int(0)).unwrap();
+        assert_eq!(value, Value::Int(0));
+    }
+
+    #[cfg(feature = "serde")]
+    fn patch_op(op: &str, path: &str, extra: Option<(&str, Value)>) -> Value {
+        let mut fields = vec![("op", Value::text(op)), ("path", Value::text(path))];
+        fields.extend(extra);
+        Value::object(fields)
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_value_apply_patch() {
+        let doc = Value::object([
+            ("a", Value::Int(1)),
+            ("list", Value::array([Value::Int(1), Value::Int(2)])),
+        ]);
+        let patch = Value::array([
+            patch_op("add", "/list/1", Some(("value", Value::Int(9)))),
+            patch_op("remove", "/list/0", None),
+            patch_op("replace", "/a", Some(("value", Value::Int(5)))),
+            patch_op("move", "/b", Some(("from", Value::text("/a")))),
+            patch_op("copy", "/list/-", Some(("from", Value::text("/b")))),
+            patch_op(
+                "test",
+                "/list",
+                Some((
+                    "value",
+                    Value::array([Value::Int(9), Value::Int(2), Value::Int(5)]),
+                )),
+            ),
+        ]);
+
+        let patched = doc.apply_patch(&patch).unwrap();
+        assert_eq!(
+            patched,
+            Value::object([
+                (
+                    "list",
+                    Value::array([Value::Int(9), Value::Int(2), Value::Int(5)])
+                ),
+                ("b", Value::Int(5)),
+            ])
+        );
+        assert_eq!(doc.get_pointer("/a"), Some(&Value::Int(1)));
+
+        let replaced = doc
+            .apply_patch(&Value::array([patch_op(
+                "add",
+                "",
+                Some(("value", Value::Null)),
+            )]))
+            .unwrap();
+        assert_eq!(replaced, Value::Null);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_value_apply_patch_errors() {
+        let doc = Value::object([("a", Value::object([("b", Value::Int(1))]))]);
+        let code = |patch: Value| {
+            doc.apply_patch(&patch)
+                .unwrap_err()
+                .code()
+                .map(str::to_owned)
+        };
+
+        assert_eq!(code(Value::Null).as_deref(), Some("invalid_patch"));
+        for (op, expected) in [
+            (
+                patch_op("test", "/a/b", Some(("value", Value::Int(2)))),
+                "patch_test_failed",
+            ),
+            (patch_op("remove", "/x", None), "invalid_pointer"),
+            (
+                patch_op("replace", "/x", Some(("value", Value::Null))),
+                "invalid_pointer",
+            ),
+            (
+                patch_op("add", "/x/y", Some(("value", Value::Null))),
+                "invalid_pointer",
+            ),
+            (
+                patch_op("copy", "/c", Some(("from", Value::text("/x")))),
+                "invalid_pointer",
+            ),
+            (
+                patch_op("add", "a", Some(("value", Value::Null))),
+                "invalid_pointer",
+            ),
+            (
+                patch_op("move", "/a/b/c", Some(("from", Value::text("/a")))),
+                "invalid_patch",
+            ),
+            (patch_op("add", "/x", None), "invalid_patch"),
+            (patch_op("remove", "", None), "invalid_pointer"),
+            (patch_op("frobnicate", "/a", None), "invalid_patch"),
+        ] {
+            assert_eq!(code(Value::array([op])).as_deref(), Some(expected));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_value_to_env_map() {
+        let value = Value::object([
+            ("log-level", Value::text("debug")),
+            ("ratio", Value::Float(0.5)),
+            ("debug", Value::Bool(true)),
+            ("token", Value::Null),
+            ("key", Value::binary(vec![1u8, 2, 3])),
+            ("empty", Value::array([])),
+        ]);
+
+        let env = value.to_env_map("my.app");
+        let expected: Vec<(&str, &str)> = vec![
+            ("MY_APP_DEBUG", "true"),
+            ("MY_APP_KEY", "AQID"),
+            ("MY_APP_LOG_LEVEL", "debug"),
+            ("MY_APP_RATIO", "0.5"),
+            ("MY_APP_TOKEN", ""),
+        ];
+        let actual: Vec<(&str, &str)> = env.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
+        assert_eq!(actual, expected);
+
+        assert_eq!(Value::Int(1).to_env_map("port")["PORT"], "1");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_value_env_round_trip() {
+        let value = Value::object([
+            ("name", Value::text("svc")),
+            ("port", Value::Int(8080)),
+            ("ratio", Value::Float(1.5)),
+            ("tls", Value::Bool(false)),
+            ("hosts", Value::array([Value::text("a"), Value::text("b")])),
+            ("db", Value::object([("user", Value::text("admin"))])),
+        ]);
+
+        let env = value.to_env_map("APP");
+        assert_eq!(Value::from_env_map("APP", &env), value);
+        assert_eq!(
+            Value::from_env_map("OTHER", &env),
+            Value::object(std::iter::empty::<(&str, Value)>())
+        );
+        assert_eq!(
+            Value::from_env_map("", [("X", "inf"), ("Y", "")]),
+            Value::object([("x", Value::text("inf")), ("y", Value::Null)])
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_value_from_env_map_large_index() {
+        let value = Value::from_env_map("APP", [("APP_PORTS_3000000000", "1")]);
+        assert_eq!(
+            value,
+            Value::object([("ports", Value::object([("3000000000", Value::Int(1))]))])
+        );
+    }
+
+    #[test]
+    fn test_value_content_hash() {
+        let a = Value::object([
+            ("n", Value::Int(3)),
+            ("list", Value::array([Value::text("a"), Value::Float(-0.0)])),
+        ]);
+        let b = Value::object([
+            ("list", Value::array([Value::text("a"), Value::Float(0.0)])),
+            ("n", Value::Float(3.0)),
+        ]);
+
+        assert_eq!(a.content_hash(), b.content_hash());
+        assert_eq!(
+            Value::Float(f64::NAN).content_hash(),
+            Value::Float(-f64::NAN).content_hash()
+        );
+        assert_ne!(
+            Value::text("1").content_hash(),
+            Value::Int(1).content_hash()
+        );
+        assert_ne!(
+            Value::array([Value::text("ab")]).content_hash(),
+            Value::array([Value::text("a"), Value::text("b")]).content_hash()
+        );
+        assert_ne!(
+            Value::Null.content_hash(),
+            Value::Bool(false).content_hash()
+        );
+    }
+
+    #[test]
+    fn test_value_content_hash_is_stable() {
+        // FNV-1a offset basis followed by the null tag byte.
+        assert_eq!(Value::Null.content_hash(), 0xaf63_bd4c_8601_b7df);
     }
 }
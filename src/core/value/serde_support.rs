@@ -122,6 +122,83 @@ impl FromStr for Value {
     }
 }
 
+impl Value {
+    /// Serializes the value to a byte-stable JSON string.
+    ///
+    /// Equal values always produce identical output, which makes the result
+    /// suitable for hashing and change detection. Unlike [`Display`], object
+    /// keys are sorted lexicographically rather than kept in insertion order,
+    /// so `{"b":1,"a":2}` and `{"a":2,"b":1}` serialize the same way. The
+    /// output is compact, `-0.0` is written as `0.0`, and non-finite floats
+    /// and binary data use the same string encodings as the
+    /// `serde_json::Value` conversion.
+    ///
+    /// [`Display`]: fmt::Display
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use paramdef::core::Value;
+    ///
+    /// let a = Value::object([("b", Value::Int(1)), ("a", Value::Float(-0.0))]);
+    /// let b = Value::object([("a", Value::Float(0.0)), ("b", Value::Int(1))]);
+    ///
+    /// assert_eq!(a.to_canonical_json(), r#"{"a":0.0,"b":1}"#);
+    /// assert_eq!(a.to_canonical_json(), b.to_canonical_json());
+    /// ```
+    #[must_use]
+    pub fn to_canonical_json(&self) -> String {
+        let mut out = String::new();
+        write_canonical(self, &mut out);
+        out
+    }
+}
+
+fn write_canonical(value: &Value, out: &mut String) {
+    use std::fmt::Write;
+
+    match value {
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out);
+            }
+            out.push(']');
+        }
+        Value::Object(fields) => {
+            let mut entries: Vec<_> = fields.iter().collect();
+            entries.sort_unstable_by(|(a, _), (b, _)| a.as_str().cmp(b.as_str()));
+
+            out.push('{');
+            for (i, (key, item)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_json_string(key, out);
+                out.push(':');
+                write_canonical(item, out);
+            }
+            out.push('}');
+        }
+        Value::Float(f) if *f == 0.0 => out.push_str("0.0"),
+        Value::Text(s) => write_json_string(s, out),
+        // Scalars and binary have a single JSON form already.
+        other => {
+            let json = serde_json::Value::from(other.clone());
+            let _ = write!(out, "{json}");
+        }
+    }
+}
+
+fn write_json_string(s: &str, out: &mut String) {
+    use std::fmt::Write;
+
+    let _ = write!(out, "{}", serde_json::Value::from(s));
+}
+
 impl fmt::Display for Value {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let json: serde_json::Value = self.clone().into();
@@ -273,4 +350,32 @@ mod tests {
         let restored: Value = json.into();
         assert_eq!(restored.as_float(), Some(f64::NEG_INFINITY));
     }
+
+    #[test]
+    fn test_value_canonical_json() {
+        let a = Value::object([
+            ("z", Value::array([Value::Int(1), Value::Float(1.5)])),
+            (
+                "a",
+                Value::object([("y", Value::Null), ("x", Value::Bool(true))]),
+            ),
+            ("m", Value::binary([1, 2, 3])),
+        ]);
+        let b = Value::object([
+            ("m", Value::binary([1, 2, 3])),
+            (
+                "a",
+                Value::object([("x", Value::Bool(true)), ("y", Value::Null)]),
+            ),
+            ("z", Value::array([Value::Int(1), Value::Float(1.5)])),
+        ]);
+
+        assert_eq!(
+            a.to_canonical_json(),
+            r#"{"a":{"x":true,"y":null},"m":"AQID","z":[1,1.5]}"#
+        );
+        assert_eq!(a.to_canonical_json(), b.to_canonical_json());
+        assert_eq!(Value::text("a\"b").to_canonical_json(), r#""a\"b""#);
+        assert_eq!(Value::Float(f64::NAN).to_canonical_json(), r#""NaN""#);
+    }
 }
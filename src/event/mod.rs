@@ -0,0 +1,146 @@
+//! Change events emitted by [`Context`](crate::context::Context).
+//!
+//! Events are delivered over a `tokio::sync::broadcast` channel created by
+//! [`Context::with_events`](crate::context::Context::with_events). The channel
+//! is bounded: a receiver that falls more than [`CHANNEL_CAPACITY`] events
+//! behind loses the oldest ones and is told so by
+//! [`RecvError::Lagged`](tokio::sync::broadcast::error::RecvError::Lagged).
+//! Sending never blocks the context.
+
+use std::fmt;
+use std::sync::{Arc, Mutex, PoisonError, Weak};
+
+use crate::core::{FxHashMap, Key, Value};
+
+pub use tokio::sync::broadcast::Receiver;
+
+/// Number of events buffered per receiver before the oldest are dropped.
+pub const CHANNEL_CAPACITY: usize = 256;
+
+/// A change to parameter values.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChangeEvent {
+    /// A single parameter value changed.
+    ///
+    /// Emitted only when the value actually changes; `None` means the
+    /// parameter had no value (before) or was cleared (after).
+    Changed {
+        /// Key of the changed parameter.
+        key: Key,
+        /// Value before the change.
+        old: Option<Value>,
+        /// Value after the change.
+        new: Option<Value>,
+    },
+    /// Several parameters changed inside
+    /// [`Context::batch`](crate::context::Context::batch).
+    Batch {
+        /// Keys of the changed parameters, in first-change order.
+        keys: Vec<Key>,
+    },
+}
+
+impl ChangeEvent {
+    /// Returns `true` if this event reports a change to `key`.
+    #[must_use]
+    pub fn affects(&self, key: &str) -> bool {
+        match self {
+            Self::Changed { key: k, .. } => k == key,
+            Self::Batch { keys } => keys.iter().any(|k| k == key),
+        }
+    }
+}
+
+/// Callback registered by [`Context::watch`](crate::context::Context::watch).
+type WatchFn = Arc<dyn Fn(&Value) + Send + Sync>;
+
+/// Per-key watcher callbacks, shared between a context and its handles.
+#[derive(Default)]
+pub(crate) struct Watchers {
+    next_id: u64,
+    entries: FxHashMap<Key, Vec<(u64, WatchFn)>>,
+}
+
+impl fmt::Debug for Watchers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Watchers")
+            .field("keys", &self.entries.keys().collect::<Vec<_>>())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Watcher registry owned by a context.
+pub(crate) type SharedWatchers = Arc<Mutex<Watchers>>;
+
+/// Locks the registry, ignoring poisoning from a panicking callback.
+fn lock(watchers: &Mutex<Watchers>) -> std::sync::MutexGuard<'_, Watchers> {
+    watchers.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Registers `f` for `key`, returning its handle.
+pub(crate) fn add_watcher(watchers: &SharedWatchers, key: Key, f: WatchFn) -> WatchHandle {
+    let mut guard = lock(watchers);
+    let id = guard.next_id;
+    guard.next_id += 1;
+    guard.entries.entry(key.clone()).or_default().push((id, f));
+
+    WatchHandle {
+        watchers: Arc::downgrade(watchers),
+        key,
+        id,
+    }
+}
+
+/// Returns `true` if any watcher is registered for `key`.
+pub(crate) fn is_watched(watchers: &SharedWatchers, key: &str) -> bool {
+    lock(watchers).entries.contains_key(key)
+}
+
+/// Calls every watcher of `key` with `value`.
+///
+/// Callbacks run after the registry is unlocked, so they may unsubscribe.
+pub(crate) fn notify(watchers: &SharedWatchers, key: &str, value: &Value) {
+    let callbacks: Vec<WatchFn> = match lock(watchers).entries.get(key) {
+        Some(entries) => entries.iter().map(|(_, f)| Arc::clone(f)).collect(),
+        None => return,
+    };
+    for f in callbacks {
+        f(value);
+    }
+}
+
+/// Handle to a watcher registered by
+/// [`Context::watch`](crate::context::Context::watch).
+///
+/// Dropping the handle leaves the watcher registered for the lifetime of
+/// the context; call [`unsubscribe`](Self::unsubscribe) to remove it.
+#[derive(Debug)]
+pub struct WatchHandle {
+    watchers: Weak<Mutex<Watchers>>,
+    key: Key,
+    id: u64,
+}
+
+impl WatchHandle {
+    /// Returns the key being watched.
+    #[must_use]
+    pub fn key(&self) -> &Key {
+        &self.key
+    }
+
+    /// Removes the watcher. Other watchers of the same key are unaffected.
+    ///
+    /// Does nothing if the context has been dropped.
+    pub fn unsubscribe(self) {
+        let Some(watchers) = self.watchers.upgrade() else {
+            return;
+        };
+        let mut guard = lock(&watchers);
+        if let Some(entries) = guard.entries.get_mut(self.key.as_str()) {
+            entries.retain(|(id, _)| *id != self.id);
+            if entries.is_empty() {
+                guard.entries.remove(self.key.as_str());
+            }
+        }
+    }
+}
@@ -182,11 +182,11 @@ pub mod schema;
 // #[cfg(feature = "validation")]
 // pub mod validation;
 
-// #[cfg(feature = "visibility")]
-// pub mod visibility;
+#[cfg(feature = "visibility")]
+pub mod visibility;
 
-// #[cfg(feature = "events")]
-// pub mod event;
+#[cfg(feature = "events")]
+pub mod event;
 
 // #[cfg(feature = "i18n")]
 // pub mod i18n;
@@ -31,7 +31,7 @@
 //!         .build())
 //!     .parameter(Number::builder("age")
 //!         .label("Age")
-//!         .range(0.0..=150.0)
+//!         .range(0.0, 150.0)
 //!         .build())
 //!     .parameter(Boolean::builder("active")
 //!         .label("Active")
@@ -8,5 +8,6 @@
 mod node;
 mod state;
 
+pub(crate) use node::redact_value;
 pub use node::{ErasedRuntimeNode, RuntimeNode};
 pub use state::State;
@@ -1,8 +1,10 @@
 //! Runtime node wrapper combining schema with mutable state.
 
+use std::fmt;
 use std::sync::Arc;
 
-use crate::core::Value;
+use crate::core::{REDACTED, Value};
+use crate::types::container::{Expirable, ExtensibleConfig, List, Mode, Object};
 use crate::types::traits::Node;
 
 use super::State;
@@ -36,7 +38,6 @@ use super::State;
 /// assert!(runtime.state().is_dirty());
 /// assert_eq!(runtime.value().and_then(|v| v.as_text()), Some("alice"));
 /// ```
-#[derive(Debug)]
 pub struct RuntimeNode<T: Node> {
     /// Immutable node definition (shared).
     node: Arc<T>,
@@ -46,6 +47,16 @@ pub struct RuntimeNode<T: Node> {
     value: Option<Value>,
 }
 
+impl<T: Node> fmt::Debug for RuntimeNode<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RuntimeNode")
+            .field("node", &self.node)
+            .field("state", &self.state)
+            .field("value", &DebugValue::new(&*self.node, self.value.as_ref()))
+            .finish()
+    }
+}
+
 impl<T: Node> Clone for RuntimeNode<T> {
     fn clone(&self) -> Self {
         Self {
@@ -91,16 +102,28 @@ impl<T: Node> RuntimeNode<T> {
         self.value.as_ref()
     }
 
-    /// Sets the value and marks the state as dirty.
+    /// Returns `true` if the value changed since the last validation.
+    ///
+    /// See [`State::needs_revalidation`].
+    #[must_use]
+    pub fn is_validation_stale(&self) -> bool {
+        self.state.needs_revalidation()
+    }
+
+    /// Sets the value, marking the state as dirty and in need of
+    /// revalidation.
     pub fn set_value(&mut self, value: Value) {
         self.value = Some(value);
         self.state.mark_dirty();
+        self.state.mark_needs_revalidation();
     }
 
-    /// Clears the value.
+    /// Clears the value, marking the state as dirty and in need of
+    /// revalidation.
     pub fn clear_value(&mut self) {
         self.value = None;
         self.state.mark_dirty();
+        self.state.mark_needs_revalidation();
     }
 
     /// Resets the runtime node to its initial state.
@@ -118,7 +141,10 @@ impl<T: Node> RuntimeNode<T> {
 ///
 /// This wrapper allows storing `RuntimeNode<T>` for different `T` types
 /// in a single collection (e.g., `HashMap<Key, ErasedRuntimeNode>`).
-#[derive(Debug, Clone)]
+///
+/// Like [`RuntimeNode`], its `Debug` output hides the values of
+/// [`Flags::SENSITIVE`] parameters.
+#[derive(Clone)]
 pub struct ErasedRuntimeNode {
     /// Immutable node definition (type-erased).
     node: Arc<dyn Node>,
@@ -128,6 +154,16 @@ pub struct ErasedRuntimeNode {
     value: Option<Value>,
 }
 
+impl fmt::Debug for ErasedRuntimeNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ErasedRuntimeNode")
+            .field("node", &self.node)
+            .field("state", &self.state)
+            .field("value", &DebugValue::new(&*self.node, self.value.as_ref()))
+            .finish()
+    }
+}
+
 impl ErasedRuntimeNode {
     /// Creates a new erased runtime node from a typed runtime node.
     #[must_use]
@@ -155,6 +191,26 @@ impl ErasedRuntimeNode {
         &self.node
     }
 
+    /// Returns the underlying schema node as `T`, if it is one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::runtime::ErasedRuntimeNode;
+    /// use paramdef::subtype::GenericNumber;
+    /// use paramdef::types::leaf::{Number, Text};
+    /// use std::sync::Arc;
+    ///
+    /// let node = ErasedRuntimeNode::from_arc(Arc::new(Number::builder("port").build()));
+    ///
+    /// assert!(node.node_as::<Number<GenericNumber>>().is_some());
+    /// assert!(node.node_as::<Text>().is_none());
+    /// ```
+    #[must_use]
+    pub fn node_as<T: Node + 'static>(&self) -> Option<&T> {
+        self.node.as_any().downcast_ref::<T>()
+    }
+
     /// Returns a reference to the runtime state.
     #[must_use]
     pub fn state(&self) -> &State {
@@ -173,16 +229,28 @@ impl ErasedRuntimeNode {
         self.value.as_ref()
     }
 
-    /// Sets the value and marks the state as dirty.
+    /// Returns `true` if the value changed since the last validation.
+    ///
+    /// See [`State::needs_revalidation`].
+    #[must_use]
+    pub fn is_validation_stale(&self) -> bool {
+        self.state.needs_revalidation()
+    }
+
+    /// Sets the value, marking the state as dirty and in need of
+    /// revalidation.
     pub fn set_value(&mut self, value: Value) {
         self.value = Some(value);
         self.state.mark_dirty();
+        self.state.mark_needs_revalidation();
     }
 
-    /// Clears the value.
+    /// Clears the value, marking the state as dirty and in need of
+    /// revalidation.
     pub fn clear_value(&mut self) {
         self.value = None;
         self.state.mark_dirty();
+        self.state.mark_needs_revalidation();
     }
 
     /// Resets the runtime node to its initial state.
@@ -192,6 +260,83 @@ impl ErasedRuntimeNode {
     }
 }
 
+/// Returns `value` with the parts belonging to sensitive nodes replaced by
+/// [`REDACTED`].
+///
+/// Recurses through the fields of objects, the items of lists, the active
+/// variant of modes, and the wrapped value of expirables, so sensitive
+/// fields nested in containers are hidden too.
+pub(crate) fn redact_value(node: &dyn Node, value: &Value) -> Value {
+    if node.flags().is_sensitive() {
+        return Value::text(REDACTED);
+    }
+
+    let any = node.as_any();
+    if let Some(object) = any.downcast_ref::<Object>() {
+        if let Value::Object(fields) = value {
+            let template = object
+                .extensible_config()
+                .map(ExtensibleConfig::value_template);
+            return Value::object(fields.iter().map(|(key, field)| {
+                let redacted = match object.get_field(key).or(template) {
+                    Some(child) => redact_value(&**child, field),
+                    None => field.clone(),
+                };
+                (key.clone(), redacted)
+            }));
+        }
+    } else if let Some(list) = any.downcast_ref::<List>() {
+        if let Value::Array(items) = value {
+            let template = &**list.item_template();
+            return Value::array(items.iter().map(|item| redact_value(template, item)));
+        }
+    } else if let Some(mode) = any.downcast_ref::<Mode>() {
+        let content = value
+            .get_pointer("/mode")
+            .and_then(Value::as_text)
+            .and_then(|variant| mode.get_variant(variant));
+        if let (Some(variant), Some(inner)) = (content, value.get_pointer("/value")) {
+            return with_field(value, "value", redact_value(&*variant.content, inner));
+        }
+    } else if let Some(expirable) = any.downcast_ref::<Expirable>() {
+        if let (Some(child), Some(inner)) = (expirable.child(), value.get_pointer("/value")) {
+            return with_field(value, "value", redact_value(&**child, inner));
+        }
+    }
+
+    value.clone()
+}
+
+/// Returns a copy of the object `value` with `name` replaced.
+fn with_field(value: &Value, name: &str, replacement: Value) -> Value {
+    let mut value = value.clone();
+    if let Some(slot) = value.get_pointer_mut(&format!("/{name}")) {
+        *slot = replacement;
+    }
+    value
+}
+
+/// Formats a node's value with sensitive parts replaced by [`REDACTED`].
+///
+/// See [`redact_value`].
+struct DebugValue {
+    value: Option<Value>,
+}
+
+impl DebugValue {
+    fn new(node: &dyn Node, value: Option<&Value>) -> Self {
+        Self {
+            value: value.map(|value| redact_value(node, value)),
+        }
+    }
+}
+
+impl fmt::Debug for DebugValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.value.fmt(f)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -232,6 +377,23 @@ mod tests {
         assert!(runtime.value().is_none());
     }
 
+    #[test]
+    fn test_runtime_node_validation_stale() {
+        let schema = Arc::new(Text::builder("name").build());
+        let mut runtime = RuntimeNode::new(schema);
+        assert!(!runtime.is_validation_stale());
+
+        runtime.set_value(Value::text("hello"));
+        runtime.state_mut().mark_clean();
+        assert!(runtime.is_validation_stale());
+
+        runtime.state_mut().set_validation_result(vec![]);
+        assert!(!runtime.is_validation_stale());
+
+        runtime.clear_value();
+        assert!(runtime.is_validation_stale());
+    }
+
     #[test]
     fn test_runtime_node_reset() {
         let schema = Arc::new(Text::builder("name").build());
@@ -290,6 +452,24 @@ mod tests {
         assert!(erased.value().is_none());
     }
 
+    #[test]
+    fn test_erased_runtime_node_node_as() {
+        use crate::subtype::GenericNumber;
+        use crate::types::leaf::Number;
+
+        let erased = ErasedRuntimeNode::from_arc(Arc::new(Number::builder("port").build()));
+
+        assert_eq!(
+            erased
+                .node_as::<Number<GenericNumber>>()
+                .unwrap()
+                .key()
+                .as_str(),
+            "port"
+        );
+        assert!(erased.node_as::<Text>().is_none());
+    }
+
     #[test]
     fn test_erased_runtime_node_set_value() {
         let schema: Arc<dyn Node> = Arc::new(Text::builder("name").build());
@@ -300,4 +480,22 @@ mod tests {
         assert!(erased.state().is_dirty());
         assert_eq!(erased.value().and_then(|v| v.as_text()), Some("world"));
     }
+
+    #[test]
+    fn test_runtime_node_debug_redacts_sensitive() {
+        let mut password = RuntimeNode::new(Arc::new(Text::password("secret")));
+        password.set_value(Value::text("hunter2"));
+
+        let debug = format!("{password:?}");
+        assert!(!debug.contains("hunter2"));
+        assert!(debug.contains(REDACTED));
+
+        let mut erased = ErasedRuntimeNode::new(password);
+        erased.set_value(Value::text("hunter3"));
+        assert!(!format!("{erased:?}").contains("hunter3"));
+
+        let mut name = ErasedRuntimeNode::from_arc(Arc::new(Text::builder("name").build()));
+        name.set_value(Value::text("alice"));
+        assert!(format!("{name:?}").contains("alice"));
+    }
 }
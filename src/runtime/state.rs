@@ -8,13 +8,22 @@ use crate::core::{Error, StateFlags};
 ///
 /// Tracks the current state flags, validation errors, and when the value
 /// was last modified.
+///
+/// With the `serde` feature, the flags and errors are serialized; the
+/// modification time is not. Errors are written as validation errors
+/// (code, message, fields, and path), so other error variants come back
+/// as validation errors with a code naming the variant and the same
+/// message.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct State {
     /// State flags (dirty, touched, valid, etc.).
     flags: StateFlags,
     /// Validation errors from the last validation run.
+    #[cfg_attr(feature = "serde", serde(default, with = "serde_errors"))]
     errors: Vec<Error>,
     /// Timestamp of last modification.
+    #[cfg_attr(feature = "serde", serde(skip))]
     modified_at: Option<Instant>,
 }
 
@@ -29,7 +38,8 @@ impl State {
     #[must_use]
     pub fn new() -> Self {
         Self {
-            flags: StateFlags::VALID, // Initially valid (no value set yet)
+            // Initially valid (no value set yet), visible and enabled
+            flags: StateFlags::initial().union(StateFlags::VALID),
             errors: Vec::new(),
             modified_at: None,
         }
@@ -59,6 +69,33 @@ impl State {
         self.flags.contains(StateFlags::VALID)
     }
 
+    /// Returns `true` if the value changed since the last validation.
+    ///
+    /// While this is set, [`is_valid`](Self::is_valid) and
+    /// [`errors`](Self::errors) describe an earlier value.
+    #[must_use]
+    pub fn needs_revalidation(&self) -> bool {
+        self.flags.contains(StateFlags::NEEDS_REVALIDATION)
+    }
+
+    /// Returns `true` unless the parameter was last recorded as hidden.
+    ///
+    /// The flag is a cached result, updated by
+    /// [`Context::on_change`](crate::context::Context::on_change).
+    #[must_use]
+    pub fn is_visible(&self) -> bool {
+        self.flags.contains(StateFlags::VISIBLE)
+    }
+
+    /// Returns `true` if the last validation raised a warning.
+    ///
+    /// A warning marks a valid value that is outside its recommended
+    /// bounds, such as a number's soft range.
+    #[must_use]
+    pub fn has_warning(&self) -> bool {
+        self.flags.contains(StateFlags::WARNING)
+    }
+
     /// Returns the validation errors.
     #[must_use]
     pub fn errors(&self) -> &[Error] {
@@ -82,13 +119,25 @@ impl State {
         self.flags.insert(StateFlags::TOUCHED);
     }
 
+    /// Marks the validation result as out of date.
+    pub fn mark_needs_revalidation(&mut self) {
+        self.flags.insert(StateFlags::NEEDS_REVALIDATION);
+    }
+
     /// Marks the parameter as clean (not dirty).
+    ///
+    /// Does not affect [`needs_revalidation`](Self::needs_revalidation):
+    /// saving a value does not validate it.
     pub fn mark_clean(&mut self) {
         self.flags.remove(StateFlags::DIRTY);
     }
 
     /// Sets the validation result.
+    ///
+    /// The result describes the current value, so this also clears
+    /// [`needs_revalidation`](Self::needs_revalidation).
     pub fn set_validation_result(&mut self, errors: Vec<Error>) {
+        self.flags.remove(StateFlags::NEEDS_REVALIDATION);
         self.errors = errors;
         if self.errors.is_empty() {
             self.flags.insert(StateFlags::VALID);
@@ -97,14 +146,87 @@ impl State {
         }
     }
 
+    /// Sets or clears the warning flag.
+    pub fn set_warning(&mut self, warning: bool) {
+        self.flags.set(StateFlags::WARNING, warning);
+    }
+
+    /// Records whether the parameter is visible.
+    pub fn set_visible(&mut self, visible: bool) {
+        self.flags.set(StateFlags::VISIBLE, visible);
+    }
+
     /// Resets the state to initial values.
     pub fn reset(&mut self) {
-        self.flags = StateFlags::VALID;
+        self.flags = StateFlags::initial().union(StateFlags::VALID);
         self.errors.clear();
         self.modified_at = None;
     }
 }
 
+/// Serializes errors in their validation form.
+#[cfg(feature = "serde")]
+mod serde_errors {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::core::Error;
+
+    #[derive(Serialize, Deserialize)]
+    struct ErrorRepr {
+        code: String,
+        message: String,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        fields: Vec<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        field_path: Option<String>,
+    }
+
+    pub(super) fn serialize<S: Serializer>(
+        errors: &[Error],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        errors
+            .iter()
+            .flat_map(Error::iter_flat)
+            .map(|err| match err.clone().into_validation() {
+                Error::Validation {
+                    code,
+                    message,
+                    fields,
+                    field_path,
+                } => ErrorRepr {
+                    code,
+                    message,
+                    fields,
+                    field_path,
+                },
+                other => ErrorRepr {
+                    code: "invalid".to_owned(),
+                    message: other.to_string(),
+                    fields: Vec::new(),
+                    field_path: None,
+                },
+            })
+            .collect::<Vec<_>>()
+            .serialize(serializer)
+    }
+
+    pub(super) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Error>, D::Error> {
+        let errors = Vec::<ErrorRepr>::deserialize(deserializer)?;
+        Ok(errors
+            .into_iter()
+            .map(|e| Error::Validation {
+                code: e.code,
+                message: e.message,
+                fields: e.fields,
+                field_path: e.field_path,
+            })
+            .collect())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -169,18 +291,35 @@ mod tests {
         assert_eq!(state.errors().len(), 1);
     }
 
+    #[test]
+    fn test_state_needs_revalidation() {
+        let mut state = State::new();
+        assert!(!state.needs_revalidation());
+
+        state.mark_dirty();
+        state.mark_needs_revalidation();
+        state.mark_clean();
+        assert!(state.needs_revalidation());
+
+        state.set_validation_result(vec![]);
+        assert!(!state.needs_revalidation());
+    }
+
     #[test]
     fn test_state_reset() {
         let mut state = State::new();
         state.mark_dirty();
         state.mark_touched();
         state.set_validation_result(vec![Error::missing_required("test")]);
+        state.set_visible(false);
+        assert!(!state.is_visible());
 
         state.reset();
 
         assert!(!state.is_dirty());
         assert!(!state.is_touched());
         assert!(state.is_valid());
+        assert!(state.is_visible());
         assert!(state.errors().is_empty());
         assert!(state.modified_at().is_none());
     }
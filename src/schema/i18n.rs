@@ -0,0 +1,162 @@
+//! Translation coverage helpers.
+
+use std::collections::BTreeSet;
+
+use super::Schema;
+use crate::types::container::Mode;
+use crate::types::decoration::Notice;
+use crate::types::leaf::{Boolean, Select};
+use crate::types::traits::Node;
+
+impl Schema {
+    /// Returns the Fluent message IDs referenced by the schema.
+    ///
+    /// Walks every node and collects labels, descriptions, `Select` option
+    /// labels and descriptions, `Mode` variant labels and descriptions,
+    /// `Boolean` captions, and `Notice` messages that look like message IDs: a Fluent identifier
+    /// such as `settings-title`, optionally followed by `.attribute`, with
+    /// no uppercase letters. Plain text such as `"User name"` is skipped.
+    ///
+    /// The result is deduplicated and sorted, so it can be diffed against
+    /// `.ftl` files.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::schema::Schema;
+    /// use paramdef::types::leaf::Text;
+    ///
+    /// let schema = Schema::builder()
+    ///     .parameter(Text::builder("name").label("user-name").build())
+    ///     .parameter(Text::builder("email").label("Email").build())
+    ///     .build();
+    ///
+    /// assert_eq!(schema.message_ids(), ["user-name"]);
+    /// ```
+    #[must_use]
+    pub fn message_ids(&self) -> Vec<String> {
+        let mut ids = BTreeSet::new();
+        for node in self.iter() {
+            collect_ids(node.as_ref(), &mut ids);
+        }
+        ids.into_iter().map(str::to_owned).collect()
+    }
+}
+
+/// Adds the message IDs of `node` and its descendants to `ids`.
+fn collect_ids<'a>(node: &'a dyn Node, ids: &mut BTreeSet<&'a str>) {
+    let metadata = node.metadata();
+    let mut texts: Vec<&str> = metadata
+        .label()
+        .into_iter()
+        .chain(metadata.description())
+        .collect();
+
+    let any = node.as_any();
+    if let Some(select) = any.downcast_ref::<Select>() {
+        for option in select.options() {
+            texts.push(&option.label);
+            texts.extend(option.description.as_deref());
+        }
+    } else if let Some(mode) = any.downcast_ref::<Mode>() {
+        for variant in mode.variants() {
+            texts.push(&variant.label);
+            texts.extend(variant.description.as_deref());
+        }
+    } else if let Some(boolean) = any.downcast_ref::<Boolean>() {
+        texts.extend(
+            boolean
+                .true_label()
+                .into_iter()
+                .chain(boolean.false_label()),
+        );
+    } else if let Some(notice) = any.downcast_ref::<Notice>() {
+        texts.push(notice.message());
+    }
+
+    ids.extend(texts.into_iter().filter(|text| is_message_id(text)));
+    for child in node.child_nodes() {
+        collect_ids(child.as_ref(), ids);
+    }
+}
+
+/// Returns `true` if `text` looks like a Fluent message ID.
+///
+/// Accepts a lowercase identifier (`[a-z][a-z0-9_-]*`), optionally with
+/// one `.attribute` of the same form.
+fn is_message_id(text: &str) -> bool {
+    let is_identifier = |part: &str| {
+        let mut chars = part.chars();
+        chars.next().is_some_and(|c| c.is_ascii_lowercase())
+            && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+    };
+
+    match text.split_once('.') {
+        Some((id, attribute)) => is_identifier(id) && is_identifier(attribute),
+        None => is_identifier(text),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::container::Object;
+    use crate::types::group::Group;
+    use crate::types::leaf::{SelectOption, Text};
+
+    #[test]
+    fn test_is_message_id() {
+        assert!(is_message_id("settings-title"));
+        assert!(is_message_id("login.placeholder"));
+        assert!(is_message_id("name"));
+        assert!(!is_message_id("Name"));
+        assert!(!is_message_id("User name"));
+        assert!(!is_message_id("a.b.c"));
+        assert!(!is_message_id("-term"));
+        assert!(!is_message_id(""));
+    }
+
+    #[test]
+    fn test_schema_message_ids() {
+        let select = Select::single("format")
+            .label("format-label")
+            .options(vec![
+                SelectOption::new("json", "format-json"),
+                SelectOption::new("yaml", "YAML"),
+            ])
+            .build();
+        let object = Object::builder("address")
+            .field(
+                "city",
+                Text::builder("city")
+                    .label("city-label")
+                    .description("city-label")
+                    .build(),
+            )
+            .build()
+            .unwrap();
+        let schema = Schema::builder()
+            .parameter(
+                Group::builder("settings")
+                    .label("settings-title")
+                    .child(Notice::info("hint", "settings-hint"))
+                    .child(select)
+                    .child(Boolean::builder("sync").captions("sync-on", "Off").build())
+                    .build(),
+            )
+            .parameter(object)
+            .build();
+
+        assert_eq!(
+            schema.message_ids(),
+            [
+                "city-label",
+                "format-json",
+                "format-label",
+                "settings-hint",
+                "settings-title",
+                "sync-on"
+            ]
+        );
+    }
+}
@@ -0,0 +1,960 @@
+//! JSON Schema (draft 2020-12) export and import.
+//!
+//! For export, each node describes its own value shape through
+//! [`Node::json_schema`]; this module assembles the document and holds the
+//! helpers shared by the node implementations. Import maps a subset of
+//! JSON Schema back onto leaf and container builders.
+
+use std::sync::Arc;
+
+use serde_json::{Map, Value as Json};
+
+use super::Schema;
+use crate::core::{Error, Flags, Key, Result, Value};
+use crate::subtype::Integer;
+use crate::types::container::{ExtensibleConfig, List, Object};
+use crate::types::group::{Group, Panel};
+use crate::types::leaf::{Boolean, Number, NumberBuilder, Select, SelectOption, Text};
+use crate::types::traits::{GroupNode, Layout, Node};
+
+/// The `$schema` URI of the emitted dialect.
+pub const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+impl Schema {
+    /// Exports the parameter definitions as a JSON Schema (draft 2020-12).
+    ///
+    /// The root is an object schema with one property per parameter:
+    ///
+    /// | Node         | JSON Schema                                              |
+    /// |--------------|----------------------------------------------------------|
+    /// | `Text`       | `string` with `minLength`, `maxLength`, `pattern`, `format` |
+    /// | `Number`     | `number` or `integer` with `minimum`, `maximum`          |
+    /// | `Boolean`    | `boolean`                                                |
+    /// | `Select`     | `enum` of option values (an array for multiple selection) |
+    /// | `Vector`     | fixed-size `array` of numbers                            |
+    /// | `File`       | `string` (an array for multiple files)                   |
+    /// | `Object`     | `object` with `properties` and `required`                |
+    /// | `List`       | `array` with `items`, `minItems`, `maxItems`             |
+    /// | `Mode`       | `oneOf` over `{ mode, value }` with a `discriminator`     |
+    /// | `Routing`, `Expirable` | the wrapped child's schema                     |
+    ///
+    /// Labels, descriptions and defaults become `title`, `description` and
+    /// `default`. Children of groups and panels are flattened into the
+    /// enclosing object. Decorations, `Reference` and `Matrix` are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::schema::Schema;
+    /// use paramdef::types::leaf::{Number, Text};
+    ///
+    /// let schema = Schema::builder()
+    ///     .parameter(Text::builder("name").required().max_length(64).build())
+    ///     .parameter(Number::builder("age").range(0.0, 150.0).build())
+    ///     .build();
+    ///
+    /// let json = schema.to_json_schema();
+    /// assert_eq!(json["properties"]["name"]["type"], "string");
+    /// assert_eq!(json["properties"]["age"]["maximum"], 150.0);
+    /// assert_eq!(json["required"][0], "name");
+    /// ```
+    #[must_use]
+    pub fn to_json_schema(&self) -> Json {
+        let mut doc = Map::new();
+        doc.insert("$schema".into(), JSON_SCHEMA_DIALECT.into());
+        doc.extend(object_schema(self.parameters.iter()));
+        Json::Object(doc)
+    }
+}
+
+impl Schema {
+    /// Builds a schema from a subset of JSON Schema.
+    ///
+    /// The root must be an object schema; each of its properties becomes a
+    /// parameter. Supported keywords:
+    ///
+    /// | Schema                           | Node      | Keywords                                        |
+    /// |----------------------------------|-----------|-------------------------------------------------|
+    /// | `"type": "string"`               | `Text`    | `minLength`, `maxLength`, `pattern`, `format`, `writeOnly` |
+    /// | `"type": "number"` / `"integer"` | `Number`  | `minimum`, `maximum`                            |
+    /// | `"type": "boolean"`              | `Boolean` |                                                 |
+    /// | `enum` of strings                | `Select`  | `type` (must be `"string"`)                     |
+    /// | `"type": "array"` with an `enum` in `items` | `Select` (multiple) | `uniqueItems`               |
+    /// | `"type": "array"`                | `List`    | `items`, `minItems`, `maxItems`, `uniqueItems`  |
+    /// | `"type": "object"`               | `Object`  | `properties`, `required`, `additionalProperties`, `propertyNames` (`pattern` only), `minProperties`, `maxProperties` |
+    ///
+    /// Every schema may also carry `title`, `description`, `readOnly`,
+    /// `examples`, `$comment`, `$id` and `$schema`; leaves may carry
+    /// `default`. `format` is treated as an annotation and not validated.
+    /// `"type": "integer"` produces a `Number` with the
+    /// [`Integer`](crate::subtype::Integer) subtype.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `"unsupported_json_schema"` validation error for any other
+    /// keyword or construct (`$ref`, `oneOf`, type arrays, ...), and for
+    /// keywords with values of the wrong type. The error's field names the
+    /// offending location as a JSON Pointer fragment such as
+    /// `#/properties/name`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::schema::Schema;
+    /// use serde_json::json;
+    ///
+    /// let schema = Schema::from_json_schema(&json!({
+    ///     "type": "object",
+    ///     "properties": {
+    ///         "name": { "type": "string", "maxLength": 64 },
+    ///         "format": { "enum": ["json", "yaml"] }
+    ///     },
+    ///     "required": ["name"]
+    /// }))
+    /// .unwrap();
+    ///
+    /// assert_eq!(schema.len(), 2);
+    /// assert!(schema.get("name").unwrap().flags().is_required());
+    /// ```
+    pub fn from_json_schema(value: &Json) -> Result<Schema> {
+        let root = as_schema_object(value, "#")?;
+        check_keywords(root, OBJECT_KEYWORDS, "#")?;
+        check_type(root, &["object"], "#")?;
+        if let Some(additional) = root.get("additionalProperties") {
+            if additional != &Json::Bool(false) {
+                return Err(unsupported("#", "root 'additionalProperties'"));
+            }
+        }
+
+        let mut builder = Schema::builder();
+        for (_, node) in parse_properties(root, "#")? {
+            builder = builder.parameter_arc(node);
+        }
+        Ok(builder.build())
+    }
+}
+
+/// Builds an object schema from named child nodes.
+///
+/// Groups and panels are flattened; nodes without a JSON Schema
+/// representation are skipped.
+pub(crate) fn object_schema<'a>(
+    nodes: impl IntoIterator<Item = (&'a Key, &'a Arc<dyn Node>)>,
+) -> Map<String, Json> {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    for (key, node) in nodes {
+        add_property(key, node.as_ref(), &mut properties, &mut required);
+    }
+
+    let mut schema = Map::new();
+    schema.insert("type".into(), "object".into());
+    schema.insert("properties".into(), Json::Object(properties));
+    if !required.is_empty() {
+        schema.insert("required".into(), Json::from(required));
+    }
+    schema
+}
+
+fn add_property(
+    key: &Key,
+    node: &dyn Node,
+    properties: &mut Map<String, Json>,
+    required: &mut Vec<String>,
+) {
+    if let Some(children) = layout_children(node) {
+        for child in children {
+            add_property(child.key(), child.as_ref(), properties, required);
+        }
+        return;
+    }
+
+    let Some(schema) = node.json_schema() else {
+        return;
+    };
+    if node.flags().is_required() {
+        required.push(key.to_string());
+    }
+    properties.insert(key.to_string(), schema);
+}
+
+/// Returns the children of a group or panel, whose values live in the
+/// enclosing object.
+fn layout_children(node: &dyn Node) -> Option<&[Arc<dyn Node>]> {
+    let any = node.as_any();
+    any.downcast_ref::<Group>()
+        .map(GroupNode::children)
+        .or_else(|| any.downcast_ref::<Panel>().map(Layout::children))
+}
+
+/// Returns the schema of a child node, accepting anything if it has none.
+pub(crate) fn child_schema(node: &dyn Node) -> Json {
+    node.json_schema().unwrap_or(Json::Bool(true))
+}
+
+/// Adds the annotations shared by all nodes and wraps the schema.
+///
+/// Sets `title`, `description` and `default`, plus `readOnly` and
+/// `writeOnly` from the node's flags.
+pub(crate) fn annotated(
+    node: &dyn Node,
+    mut schema: Map<String, Json>,
+    default: Option<Value>,
+) -> Json {
+    let metadata = node.metadata();
+    if let Some(label) = metadata.label() {
+        schema.insert("title".into(), label.into());
+    }
+    if let Some(description) = metadata.description() {
+        schema.insert("description".into(), description.into());
+    }
+    if let Some(default) = default {
+        schema.insert("default".into(), default.into());
+    }
+    if let Some(example) = metadata.example() {
+        schema.insert("examples".into(), Json::Array(vec![example.clone().into()]));
+    }
+
+    let flags = node.flags();
+    if flags.is_readonly() {
+        schema.insert("readOnly".into(), true.into());
+    }
+    if flags.is_sensitive() || flags.is_write_only() {
+        schema.insert("writeOnly".into(), true.into());
+    }
+
+    Json::Object(schema)
+}
+
+/// Maps a text subtype name to a JSON Schema `format`, if one applies.
+pub(crate) fn string_format(subtype: &str) -> Option<&'static str> {
+    match subtype {
+        "email" => Some("email"),
+        "url" => Some("uri"),
+        "hostname" => Some("hostname"),
+        "ip_v4" => Some("ipv4"),
+        "ip_v6" => Some("ipv6"),
+        "uuid" => Some("uuid"),
+        "datetime" => Some("date-time"),
+        "date" => Some("date"),
+        "time" => Some("time"),
+        "iso8601_duration" => Some("duration"),
+        "regex" => Some("regex"),
+        _ => None,
+    }
+}
+
+// =============================================================================
+// Import
+// =============================================================================
+
+/// Keywords accepted on every schema.
+const ANNOTATION_KEYWORDS: &[&str] = &[
+    "$schema",
+    "$id",
+    "$comment",
+    "title",
+    "description",
+    "readOnly",
+    "examples",
+];
+
+const STRING_KEYWORDS: &[&str] = &[
+    "type",
+    "default",
+    "minLength",
+    "maxLength",
+    "pattern",
+    "format",
+    "writeOnly",
+];
+const NUMBER_KEYWORDS: &[&str] = &["type", "default", "minimum", "maximum"];
+const BOOLEAN_KEYWORDS: &[&str] = &["type", "default"];
+const ENUM_KEYWORDS: &[&str] = &["type", "default", "enum"];
+const MULTI_ENUM_KEYWORDS: &[&str] = &["type", "default", "items", "uniqueItems"];
+const ARRAY_KEYWORDS: &[&str] = &["type", "items", "minItems", "maxItems", "uniqueItems"];
+const OBJECT_KEYWORDS: &[&str] = &[
+    "type",
+    "properties",
+    "required",
+    "additionalProperties",
+    "propertyNames",
+    "minProperties",
+    "maxProperties",
+];
+
+/// Creates the error returned for constructs outside the supported subset.
+fn unsupported(path: &str, what: impl std::fmt::Display) -> Error {
+    Error::validation_with_fields(
+        "unsupported_json_schema",
+        format!("unsupported JSON Schema at '{path}': {what}"),
+        vec![path.to_owned()],
+    )
+}
+
+fn as_schema_object<'a>(value: &'a Json, path: &str) -> Result<&'a Map<String, Json>> {
+    value
+        .as_object()
+        .ok_or_else(|| unsupported(path, "schema must be an object"))
+}
+
+fn check_keywords(schema: &Map<String, Json>, allowed: &[&str], path: &str) -> Result<()> {
+    match schema
+        .keys()
+        .find(|k| !allowed.contains(&k.as_str()) && !ANNOTATION_KEYWORDS.contains(&k.as_str()))
+    {
+        Some(keyword) => Err(unsupported(path, format!("keyword '{keyword}'"))),
+        None => Ok(()),
+    }
+}
+
+/// Checks that `type`, if present, is one of `allowed`.
+fn check_type(schema: &Map<String, Json>, allowed: &[&str], path: &str) -> Result<()> {
+    match schema.get("type") {
+        None => Ok(()),
+        Some(Json::String(ty)) if allowed.contains(&ty.as_str()) => Ok(()),
+        Some(other) => Err(unsupported(path, format!("type {other}"))),
+    }
+}
+
+fn str_keyword<'a>(
+    schema: &'a Map<String, Json>,
+    name: &str,
+    path: &str,
+) -> Result<Option<&'a str>> {
+    match schema.get(name) {
+        None => Ok(None),
+        Some(Json::String(s)) => Ok(Some(s)),
+        Some(_) => Err(unsupported(path, format!("'{name}' must be a string"))),
+    }
+}
+
+fn bool_keyword(schema: &Map<String, Json>, name: &str, path: &str) -> Result<bool> {
+    match schema.get(name) {
+        None => Ok(false),
+        Some(Json::Bool(b)) => Ok(*b),
+        Some(_) => Err(unsupported(path, format!("'{name}' must be a boolean"))),
+    }
+}
+
+fn usize_keyword(schema: &Map<String, Json>, name: &str, path: &str) -> Result<Option<usize>> {
+    match schema.get(name) {
+        None => Ok(None),
+        Some(value) => value
+            .as_u64()
+            .and_then(|n| usize::try_from(n).ok())
+            .map(Some)
+            .ok_or_else(|| unsupported(path, format!("'{name}' must be a non-negative integer"))),
+    }
+}
+
+fn f64_keyword(schema: &Map<String, Json>, name: &str, path: &str) -> Result<Option<f64>> {
+    match schema.get(name) {
+        None => Ok(None),
+        Some(value) => value
+            .as_f64()
+            .map(Some)
+            .ok_or_else(|| unsupported(path, format!("'{name}' must be a number"))),
+    }
+}
+
+/// Returns the `enum` of a schema as strings.
+fn string_enum<'a>(schema: &'a Map<String, Json>, path: &str) -> Result<Vec<&'a str>> {
+    schema
+        .get("enum")
+        .and_then(Json::as_array)
+        .ok_or_else(|| unsupported(path, "'enum' must be an array"))?
+        .iter()
+        .map(|v| {
+            v.as_str()
+                .ok_or_else(|| unsupported(path, "only string 'enum' values are supported"))
+        })
+        .collect()
+}
+
+/// Label, description and flags shared by all builders.
+#[derive(Clone, Copy)]
+struct Common<'a> {
+    label: Option<&'a str>,
+    description: Option<&'a str>,
+    flags: Flags,
+}
+
+impl<'a> Common<'a> {
+    fn parse(schema: &'a Map<String, Json>, required: bool, path: &str) -> Result<Self> {
+        let mut flags = Flags::empty();
+        if required {
+            flags |= Flags::REQUIRED;
+        }
+        if bool_keyword(schema, "readOnly", path)? {
+            flags |= Flags::READONLY;
+        }
+
+        Ok(Self {
+            label: str_keyword(schema, "title", path)?,
+            description: str_keyword(schema, "description", path)?,
+            flags,
+        })
+    }
+}
+
+/// Applies [`Common`] to a leaf builder, which has `readonly()` but no
+/// `flags()` setter.
+macro_rules! with_common {
+    ($builder:expr, $common:expr) => {{
+        let common = $common;
+        let mut builder = $builder;
+        if let Some(label) = common.label {
+            builder = builder.label(label);
+        }
+        if let Some(description) = common.description {
+            builder = builder.description(description);
+        }
+        if common.flags.is_required() {
+            builder = builder.required();
+        }
+        if common.flags.is_readonly() {
+            builder = builder.readonly();
+        }
+        builder
+    }};
+}
+
+/// Parses the `properties` and `required` keywords of an object schema.
+fn parse_properties(
+    schema: &Map<String, Json>,
+    path: &str,
+) -> Result<Vec<(String, Arc<dyn Node>)>> {
+    let required = match schema.get("required") {
+        None => Vec::new(),
+        Some(Json::Array(items)) => items
+            .iter()
+            .map(|v| {
+                v.as_str()
+                    .ok_or_else(|| unsupported(path, "'required' must list strings"))
+            })
+            .collect::<Result<Vec<_>>>()?,
+        Some(_) => return Err(unsupported(path, "'required' must be an array")),
+    };
+
+    let properties = match schema.get("properties") {
+        None => return Ok(Vec::new()),
+        Some(Json::Object(properties)) => properties,
+        Some(_) => return Err(unsupported(path, "'properties' must be an object")),
+    };
+
+    properties
+        .iter()
+        .map(|(key, value)| {
+            let node = parse_node(
+                key,
+                value,
+                required.contains(&key.as_str()),
+                &format!("{path}/properties/{key}"),
+            )?;
+            Ok((key.clone(), node))
+        })
+        .collect()
+}
+
+fn parse_node(key: &str, value: &Json, required: bool, path: &str) -> Result<Arc<dyn Node>> {
+    let schema = as_schema_object(value, path)?;
+    let common = Common::parse(schema, required, path)?;
+
+    // Report keywords no node type understands before guessing at a type.
+    let known = [
+        STRING_KEYWORDS,
+        NUMBER_KEYWORDS,
+        ENUM_KEYWORDS,
+        MULTI_ENUM_KEYWORDS,
+        ARRAY_KEYWORDS,
+        OBJECT_KEYWORDS,
+    ]
+    .concat();
+    check_keywords(schema, &known, path)?;
+
+    if schema.contains_key("enum") {
+        return parse_select(key, schema, common, path);
+    }
+
+    let ty = match schema.get("type") {
+        Some(Json::String(ty)) => ty.as_str(),
+        Some(Json::Array(_)) => return Err(unsupported(path, "type arrays")),
+        Some(_) => return Err(unsupported(path, "'type' must be a string")),
+        None => return Err(unsupported(path, "schema without 'type' or 'enum'")),
+    };
+
+    match ty {
+        "string" => parse_text(key, schema, common, path),
+        "number" => parse_number(Number::builder(key), schema, common, path),
+        "integer" => parse_number(NumberBuilder::new(key, Integer), schema, common, path),
+        "boolean" => parse_boolean(key, schema, common, path),
+        "array" => {
+            let items_enum = schema
+                .get("items")
+                .and_then(Json::as_object)
+                .is_some_and(|items| items.contains_key("enum"));
+            if items_enum {
+                parse_multi_select(key, schema, common, path)
+            } else {
+                parse_list(key, schema, common, path)
+            }
+        }
+        "object" => parse_object(key, schema, common, path),
+        other => Err(unsupported(path, format!("type \"{other}\""))),
+    }
+}
+
+fn parse_text(
+    key: &str,
+    schema: &Map<String, Json>,
+    common: Common<'_>,
+    path: &str,
+) -> Result<Arc<dyn Node>> {
+    check_keywords(schema, STRING_KEYWORDS, path)?;
+
+    let mut builder = with_common!(Text::builder(key), common);
+    if let Some(min) = usize_keyword(schema, "minLength", path)? {
+        builder = builder.min_length(min);
+    }
+    if let Some(max) = usize_keyword(schema, "maxLength", path)? {
+        builder = builder.max_length(max);
+    }
+    if let Some(pattern) = str_keyword(schema, "pattern", path)? {
+        builder = builder.pattern(pattern);
+    }
+    if let Some(default) = str_keyword(schema, "default", path)? {
+        builder = builder.default(default);
+    }
+    str_keyword(schema, "format", path)?;
+    if bool_keyword(schema, "writeOnly", path)? {
+        builder = builder.sensitive();
+    }
+
+    Ok(Arc::new(builder.try_build()?))
+}
+
+fn parse_number<S: crate::subtype::NumberSubtype>(
+    builder: NumberBuilder<S>,
+    schema: &Map<String, Json>,
+    common: Common<'_>,
+    path: &str,
+) -> Result<Arc<dyn Node>> {
+    check_keywords(schema, NUMBER_KEYWORDS, path)?;
+
+    let mut builder = with_common!(builder, common);
+    let min = f64_keyword(schema, "minimum", path)?;
+    let max = f64_keyword(schema, "maximum", path)?;
+    if min.is_some() || max.is_some() {
+        builder = builder.range(
+            min.unwrap_or(f64::NEG_INFINITY),
+            max.unwrap_or(f64::INFINITY),
+        );
+    }
+    if let Some(default) = f64_keyword(schema, "default", path)? {
+        builder = builder.default(default);
+    }
+
+    Ok(Arc::new(builder.try_build()?))
+}
+
+fn parse_boolean(
+    key: &str,
+    schema: &Map<String, Json>,
+    common: Common<'_>,
+    path: &str,
+) -> Result<Arc<dyn Node>> {
+    check_keywords(schema, BOOLEAN_KEYWORDS, path)?;
+
+    let mut builder = with_common!(Boolean::builder(key), common);
+    match schema.get("default") {
+        None => {}
+        Some(Json::Bool(default)) => builder = builder.default(*default),
+        Some(_) => return Err(unsupported(path, "'default' must be a boolean")),
+    }
+
+    Ok(Arc::new(builder.build()))
+}
+
+fn parse_select(
+    key: &str,
+    schema: &Map<String, Json>,
+    common: Common<'_>,
+    path: &str,
+) -> Result<Arc<dyn Node>> {
+    check_keywords(schema, ENUM_KEYWORDS, path)?;
+    check_type(schema, &["string"], path)?;
+
+    let options = string_enum(schema, path)?
+        .into_iter()
+        .map(SelectOption::simple)
+        .collect();
+    let mut builder = with_common!(Select::single(key).options(options), common);
+    if let Some(default) = str_keyword(schema, "default", path)? {
+        builder = builder.default_single(default);
+    }
+
+    Ok(Arc::new(builder.build()))
+}
+
+fn parse_multi_select(
+    key: &str,
+    schema: &Map<String, Json>,
+    common: Common<'_>,
+    path: &str,
+) -> Result<Arc<dyn Node>> {
+    check_keywords(schema, MULTI_ENUM_KEYWORDS, path)?;
+    bool_keyword(schema, "uniqueItems", path)?;
+
+    let items_path = format!("{path}/items");
+    let items = as_schema_object(&schema["items"], &items_path)?;
+    check_keywords(items, &["type", "enum"], &items_path)?;
+    check_type(items, &["string"], &items_path)?;
+
+    let options = string_enum(items, &items_path)?
+        .into_iter()
+        .map(SelectOption::simple)
+        .collect();
+    let mut builder = with_common!(Select::multiple(key).options(options), common);
+    match schema.get("default") {
+        None => {}
+        Some(Json::Array(values)) => {
+            let defaults = values
+                .iter()
+                .map(|v| {
+                    v.as_str()
+                        .ok_or_else(|| unsupported(path, "'default' must list strings"))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            builder = builder.default_multiple(defaults);
+        }
+        Some(_) => return Err(unsupported(path, "'default' must be an array")),
+    }
+
+    Ok(Arc::new(builder.build()))
+}
+
+fn parse_list(
+    key: &str,
+    schema: &Map<String, Json>,
+    common: Common<'_>,
+    path: &str,
+) -> Result<Arc<dyn Node>> {
+    check_keywords(schema, ARRAY_KEYWORDS, path)?;
+
+    let items = schema
+        .get("items")
+        .ok_or_else(|| unsupported(path, "array without 'items'"))?;
+    let template = parse_node("item", items, false, &format!("{path}/items"))?;
+
+    let mut builder = List::builder(key)
+        .flags(common.flags)
+        .item_template_arc(template)
+        .unique(bool_keyword(schema, "uniqueItems", path)?);
+    if let Some(label) = common.label {
+        builder = builder.label(label);
+    }
+    if let Some(description) = common.description {
+        builder = builder.description(description);
+    }
+    if let Some(min) = usize_keyword(schema, "minItems", path)? {
+        builder = builder.min_items(min);
+    }
+    if let Some(max) = usize_keyword(schema, "maxItems", path)? {
+        builder = builder.max_items(max);
+    }
+
+    Ok(Arc::new(builder.build()?))
+}
+
+fn parse_object(
+    key: &str,
+    schema: &Map<String, Json>,
+    common: Common<'_>,
+    path: &str,
+) -> Result<Arc<dyn Node>> {
+    check_keywords(schema, OBJECT_KEYWORDS, path)?;
+
+    let mut builder = Object::builder(key).flags(common.flags);
+    if let Some(label) = common.label {
+        builder = builder.label(label);
+    }
+    if let Some(description) = common.description {
+        builder = builder.description(description);
+    }
+    for (field, node) in parse_properties(schema, path)? {
+        builder = builder.field_arc(field, node);
+    }
+
+    match schema.get("additionalProperties") {
+        None | Some(Json::Bool(false)) => {
+            for keyword in ["propertyNames", "minProperties", "maxProperties"] {
+                if schema.contains_key(keyword) {
+                    return Err(unsupported(
+                        path,
+                        format!("'{keyword}' without an 'additionalProperties' schema"),
+                    ));
+                }
+            }
+        }
+        Some(additional @ Json::Object(_)) => {
+            let template = parse_node(
+                "value",
+                additional,
+                false,
+                &format!("{path}/additionalProperties"),
+            )?;
+            let mut config = ExtensibleConfig::with_arc(template);
+            if let Some(names) = schema.get("propertyNames") {
+                let names_path = format!("{path}/propertyNames");
+                let names = as_schema_object(names, &names_path)?;
+                check_keywords(names, &["pattern"], &names_path)?;
+                if let Some(pattern) = str_keyword(names, "pattern", &names_path)? {
+                    config = config.key_pattern(pattern);
+                }
+            }
+            if let Some(min) = usize_keyword(schema, "minProperties", path)? {
+                config = config.min_properties(min);
+            }
+            if let Some(max) = usize_keyword(schema, "maxProperties", path)? {
+                config = config.max_properties(max);
+            }
+            builder = builder.extensible_config(config);
+        }
+        Some(_) => {
+            return Err(unsupported(
+                path,
+                "'additionalProperties' must be false or a schema",
+            ));
+        }
+    }
+
+    Ok(Arc::new(builder.build()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::container::{List, Mode, Object};
+    use crate::types::decoration::Notice;
+    use crate::types::leaf::{Boolean, Number, Select, SelectOption, Text};
+
+    #[test]
+    fn test_json_schema_leaves() {
+        let schema = Schema::builder()
+            .parameter(
+                Text::builder("name")
+                    .label("Name")
+                    .min_length(1)
+                    .max_length(32)
+                    .pattern("^[a-z]+$")
+                    .default("bob")
+                    .required()
+                    .build(),
+            )
+            .parameter(Text::email("email"))
+            .parameter(Number::port("port").build())
+            .parameter(Boolean::builder("enabled").build())
+            .parameter(
+                Select::single("format")
+                    .options(vec![
+                        SelectOption::new("json", "JSON"),
+                        SelectOption::new("yaml", "YAML"),
+                    ])
+                    .build(),
+            )
+            .build();
+
+        let json = schema.to_json_schema();
+        assert_eq!(json["$schema"], JSON_SCHEMA_DIALECT);
+        assert_eq!(json["type"], "object");
+        assert_eq!(json["required"], serde_json::json!(["name"]));
+
+        let name = &json["properties"]["name"];
+        assert_eq!(name["type"], "string");
+        assert_eq!(name["title"], "Name");
+        assert_eq!(name["minLength"], 1);
+        assert_eq!(name["maxLength"], 32);
+        assert_eq!(name["pattern"], "^[a-z]+$");
+        assert_eq!(name["default"], "bob");
+
+        assert_eq!(json["properties"]["email"]["format"], "email");
+
+        let port = &json["properties"]["port"];
+        assert_eq!(port["type"], "integer");
+        assert_eq!(port["minimum"], 1.0);
+        assert_eq!(port["maximum"], 65535.0);
+
+        assert_eq!(json["properties"]["enabled"]["type"], "boolean");
+        assert_eq!(
+            json["properties"]["format"]["enum"],
+            serde_json::json!(["json", "yaml"])
+        );
+    }
+
+    #[test]
+    fn test_json_schema_containers() {
+        let address = Object::builder("address")
+            .field("city", Text::builder("city").required().build())
+            .build()
+            .unwrap();
+        let tags = List::builder("tags")
+            .item_template(Text::builder("tag").build())
+            .min_items(1)
+            .build()
+            .unwrap();
+        let auth = Mode::builder("auth")
+            .variant("none", "None", Object::empty("none"))
+            .variant(
+                "token",
+                "Token",
+                Object::builder("token")
+                    .field("token", Text::builder("token").build())
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+
+        let schema = Schema::builder()
+            .parameter(address)
+            .parameter(tags)
+            .parameter(auth)
+            .parameter(Notice::builder("hint").build())
+            .build();
+
+        let json = schema.to_json_schema();
+        let props = &json["properties"];
+
+        assert_eq!(props["address"]["type"], "object");
+        assert_eq!(props["address"]["properties"]["city"]["type"], "string");
+        assert_eq!(props["address"]["required"], serde_json::json!(["city"]));
+
+        assert_eq!(props["tags"]["type"], "array");
+        assert_eq!(props["tags"]["items"]["type"], "string");
+        assert_eq!(props["tags"]["minItems"], 1);
+
+        let variants = props["auth"]["oneOf"].as_array().unwrap();
+        assert_eq!(variants.len(), 2);
+        assert_eq!(variants[1]["properties"]["mode"]["const"], "token");
+        assert_eq!(
+            variants[1]["properties"]["value"]["properties"]["token"]["type"],
+            "string"
+        );
+        assert_eq!(props["auth"]["discriminator"]["propertyName"], "mode");
+
+        // Decorations are skipped
+        assert!(props.get("hint").is_none());
+    }
+
+    #[test]
+    fn test_from_json_schema() {
+        let json = serde_json::json!({
+            "$schema": JSON_SCHEMA_DIALECT,
+            "type": "object",
+            "properties": {
+                "name": {
+                    "type": "string",
+                    "title": "Name",
+                    "minLength": 1,
+                    "maxLength": 32,
+                    "default": "bob"
+                },
+                "port": { "type": "integer", "minimum": 1, "maximum": 65535 },
+                "ratio": { "type": "number", "minimum": 0 },
+                "enabled": { "type": "boolean", "default": true },
+                "format": { "type": "string", "enum": ["json", "yaml"] },
+                "features": {
+                    "type": "array",
+                    "items": { "enum": ["a", "b"] },
+                    "uniqueItems": true
+                },
+                "tags": { "type": "array", "items": { "type": "string" }, "minItems": 1 },
+                "address": {
+                    "type": "object",
+                    "properties": { "city": { "type": "string" } },
+                    "required": ["city"]
+                }
+            },
+            "required": ["name"]
+        });
+
+        let schema = Schema::from_json_schema(&json).unwrap();
+        assert_eq!(schema.len(), 8);
+
+        let name = schema.get("name").unwrap();
+        assert!(name.flags().is_required());
+        assert_eq!(name.metadata().label(), Some("Name"));
+        let name = name.as_any().downcast_ref::<Text>().unwrap();
+        assert_eq!(name.min_length(), Some(1));
+        assert_eq!(name.max_length(), Some(32));
+        assert_eq!(name.default_str(), Some("bob"));
+
+        let port = schema.get("port").unwrap();
+        let port = port.as_any().downcast_ref::<Number<Integer>>().unwrap();
+        assert_eq!(port.range(), Some((1.0, 65535.0)));
+
+        let ratio = schema.get("ratio").unwrap();
+        let ratio = ratio
+            .as_any()
+            .downcast_ref::<Number<crate::subtype::GenericNumber>>()
+            .unwrap();
+        assert_eq!(ratio.range(), Some((0.0, f64::INFINITY)));
+
+        let format = schema.get("format").unwrap();
+        let format = format.as_any().downcast_ref::<Select>().unwrap();
+        assert_eq!(format.options().len(), 2);
+
+        let features = schema.get("features").unwrap();
+        let features = features.as_any().downcast_ref::<Select>().unwrap();
+        assert_eq!(
+            features.selection_mode(),
+            crate::types::leaf::SelectionMode::Multiple
+        );
+
+        let tags = schema.get("tags").unwrap();
+        assert_eq!(
+            tags.as_any().downcast_ref::<List>().unwrap().min_items(),
+            Some(1)
+        );
+
+        let address = schema.get("address").unwrap();
+        let address = address.as_any().downcast_ref::<Object>().unwrap();
+        assert!(address.fields()[0].1.flags().is_required());
+    }
+
+    #[test]
+    fn test_from_json_schema_round_trip() {
+        let schema = Schema::builder()
+            .parameter(Text::builder("name").required().max_length(8).build())
+            .parameter(Number::port("port").build())
+            .parameter(Boolean::builder("enabled").default(true).build())
+            .build();
+
+        let exported = schema.to_json_schema();
+        let imported = Schema::from_json_schema(&exported).unwrap();
+        assert_eq!(imported.to_json_schema(), exported);
+    }
+
+    #[test]
+    fn test_from_json_schema_unsupported() {
+        let cases = [
+            serde_json::json!({ "type": "array" }),
+            serde_json::json!({ "properties": { "a": { "$ref": "#/$defs/a" } } }),
+            serde_json::json!({ "properties": { "a": { "oneOf": [] } } }),
+            serde_json::json!({ "properties": { "a": { "type": ["string", "null"] } } }),
+            serde_json::json!({ "properties": { "a": { "type": "number", "exclusiveMinimum": 0 } } }),
+            serde_json::json!({ "properties": { "a": { "enum": [1, 2] } } }),
+            serde_json::json!({ "properties": { "a": { "type": "string", "maxLength": "8" } } }),
+        ];
+
+        for case in &cases {
+            let err = Schema::from_json_schema(case).unwrap_err();
+            assert_eq!(err.code(), Some("unsupported_json_schema"), "{case}");
+        }
+
+        let err = Schema::from_json_schema(&cases[2]).unwrap_err();
+        assert_eq!(err.fields(), ["#/properties/a"]);
+        assert!(err.to_string().contains("oneOf"));
+    }
+}
@@ -3,11 +3,42 @@
 //! Schema holds the structure of parameters shared via `Arc`.
 //! Multiple [`Context`](crate::context::Context) instances can share the same schema.
 
+#[cfg(feature = "i18n")]
+mod i18n;
+#[cfg(feature = "serde")]
+pub(crate) mod json_schema;
+mod rule;
+
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
-use crate::core::{IndexMap, Key};
+use crate::context::Context;
+use crate::core::{Error, Fnv1a, FxHashMap, FxHashSet, IndexMap, Key, Metadata, Result, Value};
+use crate::types::group::Group;
+use crate::types::kind::NodeKind;
 use crate::types::traits::Node;
 
+#[cfg(feature = "serde")]
+pub use json_schema::JSON_SCHEMA_DIALECT;
+pub use rule::Rule;
+
+/// Flat summary of one node, produced by [`Schema::descriptors`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FieldDescriptor {
+    /// Dotted keys from the root parameter down to this node, such as
+    /// `"address.city"`.
+    pub path: String,
+    /// The node's kind.
+    pub kind: NodeKind,
+    /// The display label, if set.
+    pub label: Option<String>,
+    /// Whether the node is flagged [`REQUIRED`](crate::core::Flags::REQUIRED).
+    pub required: bool,
+    /// The node's [initial value](Node::initial_value), if any.
+    pub default: Option<Value>,
+}
+
 /// Immutable parameter definitions shared across contexts.
 ///
 /// Schema defines the structure of parameters and is designed to be shared
@@ -32,6 +63,8 @@ pub struct Schema {
     /// Root parameters indexed by key, preserving insertion order.
     /// `IndexMap` provides O(1) lookup while maintaining order.
     parameters: IndexMap<Key, Arc<dyn Node>>,
+    /// Cross-field validation rules.
+    rules: Vec<Rule>,
 }
 
 impl Schema {
@@ -59,21 +92,422 @@ impl Schema {
         self.parameters.get(key)
     }
 
+    /// Finds a node by key at any depth.
+    ///
+    /// Searches the root parameters first, then their descendants
+    /// breadth-first; see [`find_node`](crate::types::traits::find_node).
+    /// When a key appears in several branches, the first breadth-first hit
+    /// wins.
+    #[must_use]
+    pub fn find(&self, key: &str) -> Option<&dyn Node> {
+        crate::types::traits::find_breadth_first(self.parameters.values().map(|node| &**node), key)
+    }
+
     /// Returns an iterator over all parameters in insertion order.
     pub fn iter(&self) -> impl Iterator<Item = &Arc<dyn Node>> {
         self.parameters.values()
     }
 
+    /// Returns the parameters sorted by their [`Metadata::order`] hint.
+    ///
+    /// Parameters with an order come first, lowest value first; the rest
+    /// follow in insertion order.
+    ///
+    /// [`Metadata::order`]: crate::core::Metadata::order
+    #[must_use]
+    pub fn children_sorted_by_order(&self) -> Vec<&Arc<dyn Node>> {
+        crate::types::traits::sorted_by_order(self.parameters.values())
+    }
+
     /// Returns an iterator over parameter keys in insertion order.
     pub fn keys(&self) -> impl Iterator<Item = &Key> {
         self.parameters.keys()
     }
+
+    /// Returns the cross-field validation rules.
+    #[must_use]
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+
+    /// Returns an iterator over the rules that read the given parameter.
+    ///
+    /// Use this to re-run only the affected rules when a field changes.
+    pub fn rules_for<'a>(&'a self, key: &'a str) -> impl Iterator<Item = &'a Rule> + 'a {
+        self.rules.iter().filter(move |r| r.depends_on(key))
+    }
+
+    /// Returns the root parameters that directly depend on `key`, in
+    /// insertion order.
+    ///
+    /// Uses the same dependencies as [`evaluation_order`](Self::evaluation_order).
+    /// Follow the result repeatedly to find indirect dependents.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::schema::Schema;
+    /// use paramdef::types::leaf::Number;
+    ///
+    /// let schema = Schema::builder()
+    ///     .parameter(Number::builder("subtotal").build())
+    ///     .parameter(Number::builder("tax").depends_on("subtotal").build())
+    ///     .parameter(Number::builder("total").depends_on("tax").build())
+    ///     .build();
+    ///
+    /// assert_eq!(schema.dependents_of("subtotal"), ["tax"]);
+    /// assert!(schema.dependents_of("total").is_empty());
+    /// ```
+    #[must_use]
+    pub fn dependents_of(&self, key: &str) -> Vec<Key> {
+        self.parameters
+            .iter()
+            .filter(|(_, node)| {
+                self.declared_dependencies(node.as_ref())
+                    .iter()
+                    .any(|dep| dep == key)
+            })
+            .map(|(dependent, _)| dependent.clone())
+            .collect()
+    }
+
+    /// Returns the visibility dependency graph.
+    ///
+    /// Maps each parameter with a visibility expression to the keys its
+    /// expression references, so a UI can look up which dependents to
+    /// re-evaluate when a field changes.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `"visibility_cycle"` validation error if the visibility
+    /// conditions reference each other in a loop. The error's fields list
+    /// the keys along the cycle.
+    #[cfg(feature = "visibility")]
+    pub fn visibility_dependencies(&self) -> Result<FxHashMap<Key, Vec<Key>>> {
+        let graph: FxHashMap<Key, Vec<Key>> = self
+            .parameters
+            .iter()
+            .filter_map(|(key, node)| {
+                let deps = node.visibility()?.dependencies();
+                if deps.is_empty() {
+                    return None;
+                }
+                Some((key.clone(), deps.into_iter().map(Key::from).collect()))
+            })
+            .collect();
+
+        let mut done = FxHashSet::default();
+        let mut path = Vec::new();
+        for key in self.parameters.keys() {
+            if let Some(cycle) = find_cycle(&graph, key, &mut done, &mut path) {
+                let fields: Vec<String> = cycle.iter().map(ToString::to_string).collect();
+                return Err(Error::validation_with_fields(
+                    "visibility_cycle",
+                    format!("circular visibility condition: {}", fields.join(" -> ")),
+                    fields,
+                ));
+            }
+        }
+
+        Ok(graph)
+    }
+
+    /// Returns the root parameter keys ordered so that every parameter
+    /// comes after the parameters it depends on.
+    ///
+    /// Dependencies are those declared with
+    /// [`MetadataBuilder::depends_on`](crate::core::MetadataBuilder::depends_on)
+    /// (or the leaf builders' `depends_on`) and, with the `visibility`
+    /// feature, the keys referenced by visibility expressions. Cross-field
+    /// [rules](Self::rules) only read fields, so they add no ordering.
+    /// Dependencies on keys that are not root parameters are ignored.
+    ///
+    /// The order is deterministic: parameters without mutual dependencies
+    /// keep their insertion order as far as possible. Recompute derived
+    /// values in this order after each change.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `"cycle"` validation error if the dependencies form a
+    /// loop. The error's fields list the keys along the cycle.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::schema::Schema;
+    /// use paramdef::types::leaf::Number;
+    ///
+    /// let schema = Schema::builder()
+    ///     .parameter(Number::builder("total").depends_on("subtotal").depends_on("tax").build())
+    ///     .parameter(Number::builder("tax").depends_on("subtotal").build())
+    ///     .parameter(Number::builder("subtotal").build())
+    ///     .build();
+    ///
+    /// let order: Vec<_> = schema.evaluation_order().unwrap();
+    /// assert_eq!(order, ["subtotal", "tax", "total"]);
+    /// ```
+    pub fn evaluation_order(&self) -> Result<Vec<Key>> {
+        let graph: FxHashMap<Key, Vec<Key>> = self
+            .parameters
+            .iter()
+            .map(|(key, node)| (key.clone(), self.declared_dependencies(node.as_ref())))
+            .collect();
+
+        let mut done = FxHashSet::default();
+        let mut path = Vec::new();
+        for key in self.parameters.keys() {
+            if let Some(cycle) = find_cycle(&graph, key, &mut done, &mut path) {
+                let fields: Vec<String> = cycle.iter().map(ToString::to_string).collect();
+                return Err(Error::validation_with_fields(
+                    "cycle",
+                    format!("circular dependency: {}", fields.join(" -> ")),
+                    fields,
+                ));
+            }
+        }
+
+        let mut visited = FxHashSet::default();
+        let mut order = Vec::with_capacity(self.parameters.len());
+        for key in self.parameters.keys() {
+            post_order(&graph, key, &mut visited, &mut order);
+        }
+        Ok(order)
+    }
+
+    /// Returns a flat list describing every node in the tree.
+    ///
+    /// Nodes are listed depth-first in schema order, each parent before
+    /// its children, so groups and containers can be rendered as headers.
+    /// Decorations are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::schema::Schema;
+    /// use paramdef::types::group::Group;
+    /// use paramdef::types::kind::NodeKind;
+    /// use paramdef::types::leaf::Text;
+    ///
+    /// let schema = Schema::builder()
+    ///     .parameter(Group::builder("server")
+    ///         .child(Text::builder("host").label("Host").required().build())
+    ///         .build())
+    ///     .build();
+    ///
+    /// let fields = schema.descriptors();
+    /// assert_eq!(fields[0].path, "server");
+    /// assert_eq!(fields[0].kind, NodeKind::Group);
+    /// assert_eq!(fields[1].path, "server.host");
+    /// assert_eq!(fields[1].label.as_deref(), Some("Host"));
+    /// assert!(fields[1].required);
+    /// ```
+    #[must_use]
+    pub fn descriptors(&self) -> Vec<FieldDescriptor> {
+        let mut out = Vec::new();
+        for node in self.parameters.values() {
+            collect_descriptors(node.as_ref(), "", &mut out);
+        }
+        out
+    }
+
+    /// Returns a deterministic hash of the schema definition.
+    ///
+    /// Covers every node's key, kind, flags, metadata, visibility expression
+    /// and [settings](Node::hash_settings), the order of parameters and
+    /// children, and the rules' dependencies. Two schemas built the same way
+    /// hash equal; reordering fields or changing a constraint changes the
+    /// hash. Rule checks and custom validators are closures and are not
+    /// hashed.
+    ///
+    /// The hash uses 64-bit FNV-1a and is stable across processes, so it
+    /// can key persisted caches. Use [`structural_hash`](Self::structural_hash)
+    /// to ignore presentation-only metadata such as labels.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::schema::Schema;
+    /// use paramdef::types::leaf::Number;
+    ///
+    /// let build = |max| Schema::builder()
+    ///     .parameter(Number::builder("volume").range(0.0, max).build())
+    ///     .build();
+    ///
+    /// assert_eq!(build(10.0).version_hash(), build(10.0).version_hash());
+    /// assert_ne!(build(10.0).version_hash(), build(11.0).version_hash());
+    /// ```
+    #[must_use]
+    pub fn version_hash(&self) -> u64 {
+        self.definition_hash(false)
+    }
+
+    /// Returns a deterministic hash of the schema structure.
+    ///
+    /// Like [`version_hash`](Self::version_hash), but a node's metadata only
+    /// contributes its key, order and dependencies. Changing labels,
+    /// descriptions, groups, tags, attributes, help URLs or examples leaves
+    /// the hash unchanged.
+    #[must_use]
+    pub fn structural_hash(&self) -> u64 {
+        self.definition_hash(true)
+    }
+
+    fn definition_hash(&self, structural: bool) -> u64 {
+        let mut hasher = Fnv1a::default();
+        for node in self.parameters.values() {
+            hash_node(node.as_ref(), structural, &mut hasher);
+        }
+        hasher.write_usize(self.rules.len());
+        for rule in &self.rules {
+            rule.deps().hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+
+    /// Returns the root parameters that `node` declares it depends on.
+    fn declared_dependencies(&self, node: &dyn Node) -> Vec<Key> {
+        #[allow(unused_mut)]
+        let mut deps: Vec<Key> = node.metadata().dependencies().to_vec();
+        #[cfg(feature = "visibility")]
+        if let Some(visibility) = node.visibility() {
+            deps.extend(visibility.dependencies().into_iter().map(Key::from));
+        }
+
+        let mut seen = FxHashSet::default();
+        deps.retain(|dep| self.parameters.contains_key(dep) && seen.insert(dep.clone()));
+        deps
+    }
+}
+
+/// Appends descriptors for `node` and its descendants below `prefix`.
+fn collect_descriptors(node: &dyn Node, prefix: &str, out: &mut Vec<FieldDescriptor>) {
+    if node.kind() == NodeKind::Decoration {
+        return;
+    }
+
+    let path = if prefix.is_empty() {
+        node.key().to_string()
+    } else {
+        format!("{prefix}.{}", node.key())
+    };
+    out.push(FieldDescriptor {
+        path: path.clone(),
+        kind: node.kind(),
+        label: node.metadata().label().map(ToString::to_string),
+        required: node.flags().is_required(),
+        default: node.initial_value(),
+    });
+
+    for child in node.child_nodes() {
+        collect_descriptors(child.as_ref(), &path, out);
+    }
+}
+
+/// Hashes a node definition and its children in depth-first order.
+///
+/// Writes the kind, flags, metadata and visibility expression, then the
+/// node's own [settings](Node::hash_settings), then each child. With
+/// `structural`, the metadata only contributes its key, order and
+/// dependencies.
+fn hash_node(node: &dyn Node, structural: bool, state: &mut Fnv1a) {
+    node.kind().hash(state);
+    node.flags().hash(state);
+    hash_metadata(node.metadata(), structural, state);
+    #[cfg(feature = "visibility")]
+    node.visibility()
+        .and_then(|visibility| visibility.visibility_expr())
+        .map(Value::content_hash)
+        .hash(state);
+    node.hash_settings(state);
+
+    let children = node.child_nodes();
+    state.write_usize(children.len());
+    for child in children {
+        hash_node(child.as_ref(), structural, state);
+    }
+}
+
+/// Hashes the metadata fields of one node.
+fn hash_metadata(metadata: &Metadata, structural: bool, state: &mut Fnv1a) {
+    metadata.key().hash(state);
+    metadata.order().hash(state);
+    metadata.dependencies().hash(state);
+    if structural {
+        return;
+    }
+
+    metadata.label().hash(state);
+    metadata.description().hash(state);
+    metadata.group().hash(state);
+    metadata.tags().hash(state);
+    state.write_usize(metadata.attributes().len());
+    for (name, value) in metadata.attributes() {
+        name.hash(state);
+        state.write_u64(value.content_hash());
+    }
+    metadata.help_url().hash(state);
+    metadata.example().map(Value::content_hash).hash(state);
+}
+
+/// Appends `key` to `order` after everything it depends on.
+///
+/// The graph must be acyclic.
+fn post_order<'a>(
+    graph: &'a FxHashMap<Key, Vec<Key>>,
+    key: &'a Key,
+    visited: &mut FxHashSet<&'a Key>,
+    order: &mut Vec<Key>,
+) {
+    if !visited.insert(key) {
+        return;
+    }
+    for dep in graph.get(key).into_iter().flatten() {
+        post_order(graph, dep, visited, order);
+    }
+    order.push(key.clone());
+}
+
+/// Depth-first search for a cycle reachable from `key`.
+///
+/// `path` holds the keys on the current search path; `done` holds keys whose
+/// reachable subgraph is known to be acyclic. Returns the cycle, closed by
+/// repeating its first key.
+fn find_cycle<'a>(
+    graph: &'a FxHashMap<Key, Vec<Key>>,
+    key: &'a Key,
+    done: &mut FxHashSet<&'a Key>,
+    path: &mut Vec<&'a Key>,
+) -> Option<Vec<Key>> {
+    if done.contains(key) {
+        return None;
+    }
+    if let Some(start) = path.iter().position(|k| *k == key) {
+        let mut cycle: Vec<Key> = path[start..].iter().map(|k| (*k).clone()).collect();
+        cycle.push(key.clone());
+        return Some(cycle);
+    }
+
+    path.push(key);
+    for dep in graph.get(key).into_iter().flatten() {
+        if let Some(cycle) = find_cycle(graph, dep, done, path) {
+            return Some(cycle);
+        }
+    }
+    path.pop();
+    done.insert(key);
+
+    None
 }
 
 /// Builder for constructing a [`Schema`].
 #[derive(Debug, Default)]
 pub struct SchemaBuilder {
     parameters: IndexMap<Key, Arc<dyn Node>>,
+    rules: Vec<Rule>,
+    /// Keys that replaced an earlier parameter, reported by `build_checked`.
+    duplicates: Vec<Key>,
+    /// Whether `group` adds groups whole instead of their children.
+    keep_groups: bool,
 }
 
 impl SchemaBuilder {
@@ -85,36 +519,305 @@ impl SchemaBuilder {
 
     /// Adds a parameter to the schema.
     ///
-    /// If a parameter with the same key already exists, it will be replaced.
+    /// If a parameter with the same key already exists, it will be replaced;
+    /// [`build_checked`](Self::build_checked) reports this as an error.
     #[must_use]
-    pub fn parameter(mut self, node: impl Node + 'static) -> Self {
-        let key = node.key().clone();
-        self.parameters.insert(key, Arc::new(node));
-        self
+    pub fn parameter(self, node: impl Node + 'static) -> Self {
+        self.parameter_arc(Arc::new(node))
     }
 
     /// Adds a parameter wrapped in Arc.
     #[must_use]
     pub fn parameter_arc(mut self, node: Arc<dyn Node>) -> Self {
         let key = node.key().clone();
-        self.parameters.insert(key, node);
+        if self.parameters.insert(key.clone(), node).is_some() {
+            self.duplicates.push(key);
+        }
+        self
+    }
+
+    /// Adds several parameters in order.
+    ///
+    /// Each is added as with [`parameter_arc`](Self::parameter_arc), so a
+    /// repeated key replaces the earlier parameter and is reported by
+    /// [`build_checked`](Self::build_checked).
+    #[must_use]
+    pub fn parameters(self, nodes: impl IntoIterator<Item = Arc<dyn Node>>) -> Self {
+        nodes.into_iter().fold(self, Self::parameter_arc)
+    }
+
+    /// Adds the direct children of `group` as root parameters.
+    ///
+    /// Children are added as with [`parameters`](Self::parameters), so a
+    /// key already in the schema is replaced and reported by
+    /// [`build_checked`](Self::build_checked). The group's own label,
+    /// layout and flags are not kept. Use this to splice a reusable group
+    /// of fields into several schemas. After
+    /// [`keep_groups(true)`](Self::keep_groups), the group is added whole
+    /// as a root node instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::schema::Schema;
+    /// use paramdef::types::group::Group;
+    /// use paramdef::types::leaf::{Number, Text};
+    ///
+    /// let connection = Group::builder("connection")
+    ///     .child(Text::builder("host").build())
+    ///     .child(Number::builder("port").build())
+    ///     .build();
+    ///
+    /// let schema = Schema::builder()
+    ///     .parameter(Text::builder("name").build())
+    ///     .group(connection)
+    ///     .build();
+    ///
+    /// let keys: Vec<_> = schema.keys().map(|k| k.as_str()).collect();
+    /// assert_eq!(keys, ["name", "host", "port"]);
+    /// ```
+    #[must_use]
+    pub fn group(self, group: Group) -> Self {
+        if self.keep_groups {
+            self.parameter(group)
+        } else {
+            self.parameters(group.child_nodes().iter().cloned())
+        }
+    }
+
+    /// Sets whether [`group`](Self::group) keeps groups as root nodes.
+    ///
+    /// Groups are flattened into their children by default. The setting
+    /// applies to groups added after this call.
+    #[must_use]
+    pub fn keep_groups(mut self, keep: bool) -> Self {
+        self.keep_groups = keep;
         self
     }
 
+    /// Adds a cross-field validation rule.
+    ///
+    /// `deps` lists the parameters the rule reads; the check receives
+    /// read access to the whole context.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::schema::Schema;
+    /// use paramdef::types::leaf::Number;
+    /// use paramdef::core::Error;
+    ///
+    /// let schema = Schema::builder()
+    ///     .parameter(Number::integer("start").build())
+    ///     .parameter(Number::integer("end").build())
+    ///     .rule(&["start", "end"], |ctx| {
+    ///         let start = ctx.get("start").and_then(|v| v.as_i64());
+    ///         let end = ctx.get("end").and_then(|v| v.as_i64());
+    ///         match (start, end) {
+    ///             (Some(s), Some(e)) if e < s => {
+    ///                 Err(Error::validation("end_before_start", "end must not precede start"))
+    ///             }
+    ///             _ => Ok(()),
+    ///         }
+    ///     })
+    ///     .build();
+    ///
+    /// assert_eq!(schema.rules().len(), 1);
+    /// ```
+    #[must_use]
+    pub fn rule(
+        mut self,
+        deps: &[&str],
+        f: impl Fn(&Context) -> crate::core::Result<()> + Send + Sync + 'static,
+    ) -> Self {
+        self.rules.push(Rule::new(deps, f));
+        self
+    }
+
+    /// Builds the schema, rejecting malformed keys.
+    ///
+    /// Every key in the tree, root parameters and their descendants, must
+    /// satisfy [`Key::is_valid`]. Use this when keys come from outside the
+    /// program, since dotted path lookups assume `.` is a separator.
+    ///
+    /// # Errors
+    ///
+    /// Returns the `"invalid_key"` error from [`Key::try_new`] for the
+    /// first invalid key, walking the tree depth-first in insertion order.
+    pub fn try_build(self) -> crate::core::Result<Schema> {
+        for node in self.parameters.values() {
+            check_keys(node.as_ref())?;
+        }
+        Ok(self.build())
+    }
+
+    /// Builds the schema, rejecting trees that break the node-kind rules.
+    ///
+    /// Use this when composing schemas from parts; [`build`](Self::build)
+    /// skips these checks for trusted callers. The key checks of
+    /// [`try_build`](Self::try_build) run first.
+    ///
+    /// # Errors
+    ///
+    /// Returns a validation error naming the offending key for the first
+    /// violation found, walking the tree depth-first in insertion order:
+    ///
+    /// - `"invalid_key"`: a key does not satisfy [`Key::is_valid`].
+    /// - `"duplicate_key"`: a root parameter replaced an earlier one with
+    ///   the same key.
+    /// - `"decoration_has_children"` / `"leaf_has_children"`: a decoration
+    ///   or leaf reports child nodes.
+    /// - `"layout_contains_group"`: a layout has a group as a child.
+    /// - `"duplicate_key"`: two children of the same node share a key.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::schema::Schema;
+    /// use paramdef::types::group::Group;
+    /// use paramdef::types::leaf::{Number, Text};
+    ///
+    /// let result = Schema::builder()
+    ///     .parameter(
+    ///         Group::builder("server")
+    ///             .child(Text::builder("port").build())
+    ///             .child(Number::builder("port").build())
+    ///             .build(),
+    ///     )
+    ///     .build_checked();
+    ///
+    /// assert_eq!(result.unwrap_err().code(), Some("duplicate_key"));
+    /// ```
+    pub fn build_checked(self) -> Result<Schema> {
+        for node in self.parameters.values() {
+            check_keys(node.as_ref())?;
+        }
+        if let Some(key) = self.duplicates.first() {
+            return Err(Error::validation_with_fields(
+                "duplicate_key",
+                format!("duplicate parameter key '{key}'"),
+                vec![key.to_string()],
+            ));
+        }
+        for node in self.parameters.values() {
+            check_structure(node.as_ref())?;
+        }
+        Ok(self.build())
+    }
+
     /// Builds the schema.
     #[must_use]
     pub fn build(self) -> Schema {
         Schema {
             parameters: self.parameters,
+            rules: self.rules,
+        }
+    }
+}
+
+/// Checks that `node` and its descendants have valid keys.
+fn check_keys(node: &dyn Node) -> Result<()> {
+    Key::try_new(node.key())?;
+    node.child_nodes()
+        .iter()
+        .try_for_each(|child| check_keys(child.as_ref()))
+}
+
+/// Checks the node-kind rules for `node` and its descendants.
+fn check_structure(node: &dyn Node) -> Result<()> {
+    let children = node.child_nodes();
+    let kind = node.kind();
+
+    if !children.is_empty() && matches!(kind, NodeKind::Decoration | NodeKind::Leaf) {
+        return Err(Error::validation_with_fields(
+            format!("{kind}_has_children"),
+            format!("{kind} '{}' cannot have children", node.key()),
+            vec![node.key().to_string()],
+        ));
+    }
+
+    let mut seen = FxHashSet::default();
+    for child in children {
+        if kind == NodeKind::Layout && child.kind() == NodeKind::Group {
+            return Err(Error::validation_with_fields(
+                "layout_contains_group",
+                format!(
+                    "layout '{}' cannot contain group '{}'",
+                    node.key(),
+                    child.key()
+                ),
+                vec![child.key().to_string()],
+            ));
+        }
+        if !seen.insert(child.key()) {
+            return Err(Error::validation_with_fields(
+                "duplicate_key",
+                format!("duplicate key '{}' in '{}'", child.key(), node.key()),
+                vec![child.key().to_string()],
+            ));
         }
+        check_structure(child.as_ref())?;
     }
+
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::core::{Flags, Metadata};
+    use crate::types::group::{Group, Panel};
     use crate::types::leaf::{Boolean, Number, Text};
 
+    /// A node whose kind and children are set directly, bypassing the
+    /// builders' own checks.
+    #[derive(Debug)]
+    struct RawNode {
+        metadata: Metadata,
+        kind: NodeKind,
+        children: Vec<Arc<dyn Node>>,
+    }
+
+    impl RawNode {
+        fn new(key: &str, kind: NodeKind, children: Vec<Arc<dyn Node>>) -> Self {
+            Self {
+                metadata: Metadata::new(key),
+                kind,
+                children,
+            }
+        }
+    }
+
+    impl Node for RawNode {
+        fn metadata(&self) -> &Metadata {
+            &self.metadata
+        }
+
+        fn key(&self) -> &Key {
+            self.metadata.key()
+        }
+
+        fn flags(&self) -> Flags {
+            Flags::empty()
+        }
+
+        fn kind(&self) -> NodeKind {
+            self.kind
+        }
+
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
+
+        fn child_nodes(&self) -> &[Arc<dyn Node>] {
+            &self.children
+        }
+    }
+
     #[test]
     fn test_schema_builder() {
         let schema = Schema::builder()
@@ -147,6 +850,23 @@ mod tests {
         assert_eq!(keys, vec!["a", "b", "c"]);
     }
 
+    #[test]
+    fn test_schema_children_sorted_by_order() {
+        let schema = Schema::builder()
+            .parameter(Text::builder("a").build())
+            .parameter(Text::builder("b").order(2).build())
+            .parameter(Text::builder("c").build())
+            .parameter(Number::builder("d").order(-1).build())
+            .build();
+
+        let keys: Vec<_> = schema
+            .children_sorted_by_order()
+            .iter()
+            .map(|n| n.key().as_str())
+            .collect();
+        assert_eq!(keys, vec!["d", "b", "a", "c"]);
+    }
+
     #[test]
     fn test_schema_empty() {
         let schema = Schema::builder().build();
@@ -169,6 +889,77 @@ mod tests {
         assert_eq!(param.metadata().label(), Some("Second"));
     }
 
+    #[test]
+    fn test_schema_parameters_and_group() {
+        let fields = Group::builder("common")
+            .label("Common")
+            .child(Text::builder("host").build())
+            .child(Number::builder("port").build())
+            .build();
+        let extra: Vec<Arc<dyn Node>> = vec![
+            Arc::new(Boolean::builder("debug").build()),
+            Arc::new(Text::builder("user").build()),
+        ];
+
+        let schema = Schema::builder()
+            .group(fields.clone())
+            .parameters(extra)
+            .build_checked()
+            .unwrap();
+        let keys: Vec<_> = schema.keys().map(|k| k.as_str()).collect();
+        assert_eq!(keys, ["host", "port", "debug", "user"]);
+
+        let err = Schema::builder()
+            .parameter(Text::builder("port").build())
+            .group(fields.clone())
+            .build_checked()
+            .unwrap_err();
+        assert_eq!(err.code(), Some("duplicate_key"));
+        assert_eq!(err.fields(), ["port"]);
+
+        let schema = Schema::builder()
+            .keep_groups(true)
+            .group(fields)
+            .build_checked()
+            .unwrap();
+        let keys: Vec<_> = schema.keys().map(|k| k.as_str()).collect();
+        assert_eq!(keys, ["common"]);
+        assert_eq!(schema.get("common").unwrap().child_nodes().len(), 2);
+    }
+
+    #[test]
+    fn test_schema_try_build_rejects_invalid_keys() {
+        let schema = Schema::builder()
+            .parameter(Text::builder("server.host").build())
+            .try_build();
+        assert!(schema.is_ok());
+
+        let err = Schema::builder()
+            .parameter(Text::builder("name").build())
+            .parameter(Text::builder("bad key").build())
+            .try_build()
+            .unwrap_err();
+        assert_eq!(err.code(), Some("invalid_key"));
+
+        // Nested keys are checked too
+        let err = Schema::builder()
+            .parameter(
+                Group::builder("settings")
+                    .child(Text::builder("2fa").build())
+                    .build(),
+            )
+            .try_build()
+            .unwrap_err();
+        assert_eq!(err.code(), Some("invalid_key"));
+
+        // Structure is left to `build_checked`
+        let schema = Schema::builder()
+            .parameter(Text::builder("name").build())
+            .parameter(Number::builder("name").build())
+            .try_build();
+        assert!(schema.is_ok());
+    }
+
     #[test]
     fn test_schema_multiple_types() {
         let schema = Schema::builder()
@@ -179,4 +970,387 @@ mod tests {
 
         assert_eq!(schema.len(), 3);
     }
+
+    #[test]
+    fn test_schema_build_checked() {
+        let schema = Schema::builder()
+            .parameter(
+                Group::builder("settings")
+                    .child(
+                        Panel::builder("general")
+                            .child(Text::builder("name").build())
+                            .build(),
+                    )
+                    .build(),
+            )
+            .build_checked()
+            .unwrap();
+        assert_eq!(schema.len(), 1);
+
+        let err = Schema::builder()
+            .parameter(
+                Group::builder("settings")
+                    .child(Text::builder("name").build())
+                    .child(Number::builder("name").build())
+                    .build(),
+            )
+            .build_checked()
+            .unwrap_err();
+        assert_eq!(err.code(), Some("duplicate_key"));
+        assert_eq!(err.fields(), ["name"]);
+
+        let group: Arc<dyn Node> = Arc::new(Group::builder("inner").build());
+        let err = Schema::builder()
+            .parameter(RawNode::new("outer", NodeKind::Layout, vec![group]))
+            .build_checked()
+            .unwrap_err();
+        assert_eq!(err.code(), Some("layout_contains_group"));
+        assert_eq!(err.fields(), ["inner"]);
+
+        let child: Arc<dyn Node> = Arc::new(Text::builder("x").build());
+        for (kind, code) in [
+            (NodeKind::Leaf, "leaf_has_children"),
+            (NodeKind::Decoration, "decoration_has_children"),
+        ] {
+            let err = Schema::builder()
+                .parameter(RawNode::new("bad", kind, vec![child.clone()]))
+                .build_checked()
+                .unwrap_err();
+            assert_eq!(err.code(), Some(code));
+            assert_eq!(err.fields(), ["bad"]);
+        }
+
+        let err = Schema::builder()
+            .parameter(Text::builder("bad key").build())
+            .build_checked()
+            .unwrap_err();
+        assert_eq!(err.code(), Some("invalid_key"));
+    }
+
+    #[test]
+    fn test_schema_find() {
+        let schema = Schema::builder()
+            .parameter(Text::builder("title").build())
+            .parameter(
+                Group::builder("first")
+                    .child(
+                        Panel::builder("panel")
+                            .child(Text::builder("name").label("Deep").build())
+                            .build(),
+                    )
+                    .build(),
+            )
+            .parameter(
+                Group::builder("second")
+                    .child(Text::builder("name").label("Shallow").build())
+                    .build(),
+            )
+            .build();
+
+        assert_eq!(schema.find("title").unwrap().key().as_str(), "title");
+        assert_eq!(schema.find("panel").unwrap().key().as_str(), "panel");
+        // The shallower duplicate wins even though it is in a later branch.
+        assert_eq!(
+            schema.find("name").unwrap().metadata().label(),
+            Some("Shallow")
+        );
+        assert!(schema.find("missing").is_none());
+    }
+
+    #[test]
+    fn test_schema_rules_for() {
+        let schema = Schema::builder()
+            .parameter(Number::builder("start").build())
+            .parameter(Number::builder("end").build())
+            .parameter(Text::builder("name").build())
+            .rule(&["start", "end"], |_| Ok(()))
+            .rule(&["name"], |_| Ok(()))
+            .build();
+
+        assert_eq!(schema.rules().len(), 2);
+        assert_eq!(schema.rules_for("end").count(), 1);
+        assert_eq!(schema.rules_for("name").count(), 1);
+        assert_eq!(schema.rules_for("unknown").count(), 0);
+    }
+
+    #[cfg(feature = "visibility")]
+    #[test]
+    fn test_schema_visibility_dependencies() {
+        use crate::visibility::{and, eq, gt};
+
+        let schema = Schema::builder()
+            .parameter(Text::builder("mode").build())
+            .parameter(
+                Number::builder("level")
+                    .visible_when(eq("mode", "advanced"))
+                    .build(),
+            )
+            .parameter(
+                Boolean::builder("expert")
+                    .visible_when(and([eq("mode", "advanced"), gt("level", 2)]))
+                    .build(),
+            )
+            .build();
+
+        let graph = schema.visibility_dependencies().unwrap();
+        assert_eq!(graph.len(), 2);
+        assert_eq!(graph["level"], vec![Key::from("mode")]);
+        assert_eq!(graph["expert"], vec![Key::from("mode"), Key::from("level")]);
+        assert!(!graph.contains_key("mode"));
+    }
+
+    #[cfg(feature = "visibility")]
+    #[test]
+    fn test_schema_visibility_cycle() {
+        use crate::visibility::eq;
+
+        let schema = Schema::builder()
+            .parameter(Text::builder("a").visible_when(eq("b", "x")).build())
+            .parameter(Text::builder("b").visible_when(eq("a", "y")).build())
+            .build();
+
+        let err = schema.visibility_dependencies().unwrap_err();
+        assert_eq!(err.code(), Some("visibility_cycle"));
+        assert_eq!(err.fields(), ["a", "b", "a"]);
+    }
+
+    #[test]
+    fn test_schema_evaluation_order() {
+        let schema = Schema::builder()
+            .parameter(
+                Number::builder("total")
+                    .depends_on("net")
+                    .depends_on("tax")
+                    .build(),
+            )
+            .parameter(Text::builder("note").build())
+            .parameter(
+                Number::builder("tax")
+                    .depends_on("net")
+                    .depends_on("unknown")
+                    .build(),
+            )
+            .parameter(Number::builder("net").build())
+            .build();
+
+        let order = schema.evaluation_order().unwrap();
+        assert_eq!(order, ["net", "tax", "total", "note"]);
+    }
+
+    #[test]
+    fn test_schema_dependents_of() {
+        let schema = Schema::builder()
+            .parameter(Number::builder("net").build())
+            .parameter(Number::builder("total").depends_on("net").build())
+            .parameter(Number::builder("tax").depends_on("net").build())
+            .parameter(Number::builder("gross").depends_on("tax").build())
+            .build();
+
+        assert_eq!(schema.dependents_of("net"), ["total", "tax"]);
+        assert_eq!(schema.dependents_of("tax"), ["gross"]);
+        assert!(schema.dependents_of("gross").is_empty());
+        assert!(schema.dependents_of("missing").is_empty());
+    }
+
+    #[test]
+    fn test_schema_evaluation_order_cycle() {
+        let schema = Schema::builder()
+            .parameter(Number::builder("a").depends_on("b").build())
+            .parameter(Number::builder("b").depends_on("a").build())
+            .build();
+
+        let err = schema.evaluation_order().unwrap_err();
+        assert_eq!(err.code(), Some("cycle"));
+        assert_eq!(err.fields(), ["a", "b", "a"]);
+    }
+
+    #[test]
+    fn test_schema_descriptors() {
+        use crate::types::container::Object;
+        use crate::types::decoration::Separator;
+
+        let address = Object::builder("address")
+            .label("Address")
+            .field("city", Text::builder("city").required().build())
+            .build()
+            .unwrap();
+        let schema = Schema::builder()
+            .parameter(Number::builder("age").default(18.0).build())
+            .parameter(Separator::builder("divider").build())
+            .parameter(address)
+            .build();
+
+        let fields = schema.descriptors();
+        let paths: Vec<&str> = fields.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(paths, ["age", "address", "address.city"]);
+
+        assert_eq!(fields[0].kind, NodeKind::Leaf);
+        assert_eq!(fields[0].default, Some(Value::Float(18.0)));
+        assert!(!fields[0].required);
+        assert_eq!(fields[1].kind, NodeKind::Container);
+        assert_eq!(fields[1].label.as_deref(), Some("Address"));
+        assert!(fields[2].required);
+    }
+
+    #[test]
+    fn test_schema_version_hash() {
+        let build = |label: &str, max: f64| {
+            Schema::builder()
+                .parameter(Text::builder("name").label(label).build())
+                .parameter(Number::builder("age").range(0.0, max).build())
+                .build()
+        };
+
+        let base = build("Name", 100.0);
+        assert_eq!(base.version_hash(), build("Name", 100.0).version_hash());
+        assert_ne!(base.version_hash(), build("Name", 120.0).version_hash());
+        assert_ne!(
+            base.version_hash(),
+            build("Full name", 100.0).version_hash()
+        );
+
+        assert_eq!(
+            base.structural_hash(),
+            build("Full name", 100.0).structural_hash()
+        );
+        assert_ne!(
+            base.structural_hash(),
+            build("Name", 120.0).structural_hash()
+        );
+
+        let reordered = Schema::builder()
+            .parameter(Number::builder("age").range(0.0, 100.0).build())
+            .parameter(Text::builder("name").label("Name").build())
+            .build();
+        assert_ne!(base.version_hash(), reordered.version_hash());
+        assert_ne!(base.structural_hash(), reordered.structural_hash());
+    }
+
+    #[test]
+    fn test_schema_structural_hash_ignores_nested_labels() {
+        let build = |label: &str| {
+            Schema::builder()
+                .parameter(
+                    Group::builder("settings")
+                        .child(Text::builder("host").label(label).build())
+                        .build(),
+                )
+                .build()
+        };
+
+        assert_ne!(build("Host").version_hash(), build("Server").version_hash());
+        assert_eq!(
+            build("Host").structural_hash(),
+            build("Server").structural_hash()
+        );
+    }
+
+    #[test]
+    fn test_schema_version_hash_covers_container_settings() {
+        use crate::types::container::{ExtensibleConfig, Matrix, Mode, Object};
+
+        let hash =
+            |node: Arc<dyn Node>| Schema::builder().parameter_arc(node).build().version_hash();
+
+        let headers = |max: usize| -> Arc<dyn Node> {
+            let config = ExtensibleConfig::new(Text::builder("value").build()).max_properties(max);
+            Arc::new(
+                Object::builder("headers")
+                    .extensible_config(config)
+                    .build()
+                    .unwrap(),
+            )
+        };
+        assert_eq!(hash(headers(10)), hash(headers(10)));
+        assert_ne!(hash(headers(10)), hash(headers(20)));
+
+        let survey = |row: &str, column: &str| -> Arc<dyn Node> {
+            Arc::new(
+                Matrix::builder("survey")
+                    .row(row, "Speed")
+                    .column(column, "Good")
+                    .build()
+                    .unwrap(),
+            )
+        };
+        assert_ne!(hash(survey("speed", "good")), hash(survey("pace", "good")));
+        assert_ne!(
+            hash(survey("speed", "good")),
+            hash(survey("speed", "great"))
+        );
+
+        let auth = |variant: &str| -> Arc<dyn Node> {
+            Arc::new(
+                Mode::builder("auth")
+                    .variant(variant, "Token", Object::empty("config"))
+                    .build()
+                    .unwrap(),
+            )
+        };
+        assert_ne!(hash(auth("token")), hash(auth("bearer")));
+    }
+
+    #[cfg(feature = "visibility")]
+    #[test]
+    fn test_schema_version_hash_covers_visibility() {
+        use crate::visibility::eq;
+
+        let panel = |collapse: &str, visible: &str| {
+            let host = Text::builder("host")
+                .visible_when(eq("mode", visible))
+                .build();
+            Schema::builder()
+                .parameter(Text::builder("mode").build())
+                .parameter(
+                    Panel::builder("connection")
+                        .collapse_when(eq("mode", collapse))
+                        .child(host)
+                        .build(),
+                )
+                .build()
+        };
+        let base = panel("local", "remote");
+        assert_eq!(base.version_hash(), panel("local", "remote").version_hash());
+        assert_ne!(base.version_hash(), panel("none", "remote").version_hash());
+        assert_ne!(base.version_hash(), panel("local", "cloud").version_hash());
+        assert_ne!(
+            base.structural_hash(),
+            panel("local", "cloud").structural_hash()
+        );
+
+        let group = |visible: &str| {
+            Schema::builder()
+                .parameter(
+                    Group::builder("settings")
+                        .child(
+                            Text::builder("host")
+                                .visible_when(eq("mode", visible))
+                                .build(),
+                        )
+                        .build(),
+                )
+                .build()
+        };
+        assert_ne!(
+            group("remote").version_hash(),
+            group("cloud").version_hash()
+        );
+    }
+
+    #[cfg(feature = "visibility")]
+    #[test]
+    fn test_schema_evaluation_order_visibility() {
+        use crate::visibility::eq;
+
+        let schema = Schema::builder()
+            .parameter(
+                Text::builder("details")
+                    .visible_when(eq("mode", "custom"))
+                    .build(),
+            )
+            .parameter(Text::builder("mode").build())
+            .build();
+
+        assert_eq!(schema.evaluation_order().unwrap(), ["mode", "details"]);
+    }
 }
@@ -0,0 +1,68 @@
+//! Cross-field validation rules.
+//!
+//! Rules express constraints that span several parameters, such as
+//! `end_date >= start_date`. They are attached to a [`Schema`](super::Schema)
+//! and evaluated against a [`Context`] with
+//! [`Context::validate_rules`](crate::context::Context::validate_rules).
+
+use std::fmt;
+use std::sync::Arc;
+
+use crate::context::Context;
+use crate::core::{Key, Result};
+
+/// Signature of a cross-field rule check.
+type RuleFn = dyn Fn(&Context) -> Result<()> + Send + Sync;
+
+/// A validation rule spanning multiple parameters.
+///
+/// The rule's dependency list names the parameters it reads, so a UI can
+/// re-run only the rules affected by a change.
+#[derive(Clone)]
+pub struct Rule {
+    deps: Vec<Key>,
+    check: Arc<RuleFn>,
+}
+
+impl fmt::Debug for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Rule")
+            .field("deps", &self.deps)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Rule {
+    /// Creates a new rule from its dependencies and check function.
+    #[must_use]
+    pub fn new(
+        deps: &[&str],
+        check: impl Fn(&Context) -> Result<()> + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            deps: deps.iter().map(|&d| Key::from(d)).collect(),
+            check: Arc::new(check),
+        }
+    }
+
+    /// Returns the keys of the parameters this rule reads.
+    #[must_use]
+    pub fn deps(&self) -> &[Key] {
+        &self.deps
+    }
+
+    /// Returns `true` if this rule reads the given parameter.
+    #[must_use]
+    pub fn depends_on(&self, key: &str) -> bool {
+        self.deps.iter().any(|d| d == key)
+    }
+
+    /// Evaluates the rule against a context.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error produced by the rule's check function.
+    pub fn check(&self, ctx: &Context) -> Result<()> {
+        (self.check)(ctx)
+    }
+}
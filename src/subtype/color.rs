@@ -0,0 +1,116 @@
+//! Color conversions between hex strings and RGBA vectors.
+//!
+//! Color parameters come in two shapes: [`HexColor`](super::HexColor) text
+//! and [`ColorRgba`](super::ColorRgba) vectors with channels in `0.0..=1.0`.
+//! [`Color`] converts between them.
+
+/// Conversions between hex color strings and RGBA channel arrays.
+///
+/// # Example
+///
+/// ```
+/// use paramdef::subtype::Color;
+///
+/// assert_eq!(Color::parse_hex("#f00"), Some([1.0, 0.0, 0.0, 1.0]));
+/// assert_eq!(Color::to_hex([1.0, 0.0, 0.0, 1.0]), "#FF0000");
+/// assert_eq!(Color::to_hex([0.0, 0.0, 1.0, 0.5]), "#0000FF80");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Color;
+
+impl Color {
+    /// Parses `#RGB`, `#RRGGBB`, or `#RRGGBBAA` into RGBA channels in
+    /// `0.0..=1.0`.
+    ///
+    /// Hex digits are case-insensitive. Alpha defaults to `1.0` when
+    /// omitted. Returns `None` for any other shape.
+    #[must_use]
+    pub fn parse_hex(s: &str) -> Option<[f64; 4]> {
+        let digits = s.strip_prefix('#')?;
+        if !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        let channel = |i: usize, width: usize| {
+            let raw = u8::from_str_radix(&digits[i * width..(i + 1) * width], 16).ok()?;
+            // Short form repeats each digit: `f` means `ff`.
+            let byte = if width == 1 { raw * 0x11 } else { raw };
+            Some(f64::from(byte) / 255.0)
+        };
+
+        match digits.len() {
+            3 => Some([channel(0, 1)?, channel(1, 1)?, channel(2, 1)?, 1.0]),
+            6 => Some([channel(0, 2)?, channel(1, 2)?, channel(2, 2)?, 1.0]),
+            8 => Some([
+                channel(0, 2)?,
+                channel(1, 2)?,
+                channel(2, 2)?,
+                channel(3, 2)?,
+            ]),
+            _ => None,
+        }
+    }
+
+    /// Formats RGBA channels as an uppercase hex string.
+    ///
+    /// Channels are clamped to `0.0..=1.0` (NaN becomes `0.0`). Opaque
+    /// colors use `#RRGGBB`; others use `#RRGGBBAA`.
+    #[must_use]
+    pub fn to_hex(rgba: [f64; 4]) -> String {
+        let [r, g, b, a] = rgba.map(Self::channel_byte);
+        if a == u8::MAX {
+            format!("#{r:02X}{g:02X}{b:02X}")
+        } else {
+            format!("#{r:02X}{g:02X}{b:02X}{a:02X}")
+        }
+    }
+
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn channel_byte(value: f64) -> u8 {
+        let clamped = if value.is_nan() {
+            0.0
+        } else {
+            value.clamp(0.0, 1.0)
+        };
+        // In range after clamping.
+        (clamped * 255.0).round() as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_hex_forms() {
+        assert_eq!(Color::parse_hex("#fff"), Some([1.0, 1.0, 1.0, 1.0]));
+        assert_eq!(Color::parse_hex("#00FF00"), Some([0.0, 1.0, 0.0, 1.0]));
+        assert_eq!(Color::parse_hex("#000000ff"), Some([0.0, 0.0, 0.0, 1.0]));
+
+        let [r, _, _, a] = Color::parse_hex("#80000000").unwrap();
+        assert!((r - 128.0 / 255.0).abs() < f64::EPSILON);
+        assert!(a.abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_parse_hex_rejects_invalid() {
+        assert_eq!(Color::parse_hex("fff"), None);
+        assert_eq!(Color::parse_hex("#ffff"), None);
+        assert_eq!(Color::parse_hex("#ggg"), None);
+        assert_eq!(Color::parse_hex("#+ff"), None);
+        assert_eq!(Color::parse_hex("#é0"), None);
+    }
+
+    #[test]
+    fn test_to_hex_clamps_channels() {
+        assert_eq!(Color::to_hex([2.0, -1.0, f64::NAN, 1.0]), "#FF0000");
+        assert_eq!(Color::to_hex([0.0, 0.0, 0.0, 0.0]), "#00000000");
+    }
+
+    #[test]
+    fn test_hex_round_trip() {
+        for hex in ["#1A2B3C", "#FFFFFF", "#12345678"] {
+            assert_eq!(Color::to_hex(Color::parse_hex(hex).unwrap()), hex);
+        }
+    }
+}
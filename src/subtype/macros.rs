@@ -24,6 +24,18 @@
 /// ```
 #[macro_export]
 macro_rules! define_number_subtype {
+    // Shared by every arm: lets the subtype start a `Number` builder that
+    // records its descriptor.
+    (@into_builder $name:ident) => {
+        impl $crate::subtype::IntoBuilder for $name {
+            type Builder = $crate::types::leaf::NumberBuilder<$name>;
+
+            fn into_builder(key: impl Into<$crate::core::Key>) -> Self::Builder {
+                $crate::types::leaf::NumberBuilder::from_subtype(key, $name)
+            }
+        }
+    };
+
     // Integer-only with range
     ($name:ident, int_only, $value:ty, $str_name:literal, range: ($min:expr, $max:expr)) => {
         /// Number subtype (integer-only).
@@ -41,6 +53,8 @@ macro_rules! define_number_subtype {
                 Some(($min, $max))
             }
         }
+
+        $crate::define_number_subtype!(@into_builder $name);
     };
 
     // Integer-only without range
@@ -56,6 +70,8 @@ macro_rules! define_number_subtype {
                 $str_name
             }
         }
+
+        $crate::define_number_subtype!(@into_builder $name);
     };
 
     // Float-only with range
@@ -75,6 +91,8 @@ macro_rules! define_number_subtype {
                 Some(($min, $max))
             }
         }
+
+        $crate::define_number_subtype!(@into_builder $name);
     };
 
     // Float-only without range
@@ -90,6 +108,8 @@ macro_rules! define_number_subtype {
                 $str_name
             }
         }
+
+        $crate::define_number_subtype!(@into_builder $name);
     };
 
     // Universal with range
@@ -109,6 +129,8 @@ macro_rules! define_number_subtype {
                 Some(($min, $max))
             }
         }
+
+        $crate::define_number_subtype!(@into_builder $name);
     };
 
     // Universal without range
@@ -124,6 +146,8 @@ macro_rules! define_number_subtype {
                 $str_name
             }
         }
+
+        $crate::define_number_subtype!(@into_builder $name);
     };
 }
 
@@ -207,11 +231,23 @@ macro_rules! define_vector_subtype {
 /// use paramdef::define_text_subtype;
 ///
 /// define_text_subtype!(Email, "email", pattern: r"^[^@]+@[^@]+\.[^@]+$", placeholder: "user@example.com");
+/// define_text_subtype!(Slug, "slug", pattern: r"^[a-z0-9]+(?:-[a-z0-9]+)*$", placeholder: "my-slug", lowercase: true);
 /// define_text_subtype!(Password, "password", sensitive: true);
 /// define_text_subtype!(Json, "json", multiline: true);
 /// ```
 #[macro_export]
 macro_rules! define_text_subtype {
+    // Shared by every arm: lets the subtype start a `Text` builder.
+    (@into_builder $name:ident) => {
+        impl $crate::subtype::IntoBuilder for $name {
+            type Builder = $crate::types::leaf::TextBuilder<$name>;
+
+            fn into_builder(key: impl Into<$crate::core::Key>) -> Self::Builder {
+                $crate::types::leaf::Text::builder(key).subtype($name)
+            }
+        }
+    };
+
     // Basic
     ($name:ident, $str_name:literal) => {
         /// Text subtype.
@@ -223,6 +259,8 @@ macro_rules! define_text_subtype {
                 $str_name
             }
         }
+
+        $crate::define_text_subtype!(@into_builder $name);
     };
 
     // With pattern
@@ -240,6 +278,8 @@ macro_rules! define_text_subtype {
                 Some($pattern)
             }
         }
+
+        $crate::define_text_subtype!(@into_builder $name);
     };
 
     // With pattern and placeholder
@@ -261,6 +301,35 @@ macro_rules! define_text_subtype {
                 Some($placeholder)
             }
         }
+
+        $crate::define_text_subtype!(@into_builder $name);
+    };
+
+    // With pattern, placeholder, and lowercase normalization
+    ($name:ident, $str_name:literal, pattern: $pattern:literal, placeholder: $placeholder:literal, lowercase: true) => {
+        /// Text subtype with pattern and placeholder, normalized to lowercase.
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+        pub struct $name;
+
+        impl $crate::subtype::TextSubtype for $name {
+            fn name() -> &'static str {
+                $str_name
+            }
+
+            fn pattern() -> Option<&'static str> {
+                Some($pattern)
+            }
+
+            fn placeholder() -> Option<&'static str> {
+                Some($placeholder)
+            }
+
+            fn is_lowercase() -> bool {
+                true
+            }
+        }
+
+        $crate::define_text_subtype!(@into_builder $name);
     };
 
     // Sensitive
@@ -278,6 +347,8 @@ macro_rules! define_text_subtype {
                 true
             }
         }
+
+        $crate::define_text_subtype!(@into_builder $name);
     };
 
     // Multiline
@@ -295,6 +366,8 @@ macro_rules! define_text_subtype {
                 true
             }
         }
+
+        $crate::define_text_subtype!(@into_builder $name);
     };
 
     // Code with language
@@ -316,6 +389,8 @@ macro_rules! define_text_subtype {
                 Some($lang)
             }
         }
+
+        $crate::define_text_subtype!(@into_builder $name);
     };
 
     // Placeholder only
@@ -333,6 +408,64 @@ macro_rules! define_text_subtype {
                 Some($placeholder)
             }
         }
+
+        $crate::define_text_subtype!(@into_builder $name);
+    };
+}
+
+/// Defines a text subtype restricted to a fixed set of values.
+///
+/// The generated subtype reports the values through
+/// [`TextSubtype::allowed_values`](crate::subtype::TextSubtype::allowed_values),
+/// rejects any other non-empty value with an `invalid_<name>` validation
+/// error, and implements [`IntoBuilder`](crate::subtype::IntoBuilder).
+///
+/// # Example
+///
+/// ```
+/// use paramdef::define_enum_subtype;
+/// use paramdef::subtype::{IntoBuilder, TextSubtype};
+///
+/// define_enum_subtype!(HttpMethod, "http_method", ["GET", "POST", "PUT", "DELETE"]);
+///
+/// assert_eq!(HttpMethod::allowed_values(), ["GET", "POST", "PUT", "DELETE"]);
+/// assert!(HttpMethod::check_format("PATCH").is_err());
+///
+/// let method = HttpMethod::into_builder("method").default("GET").build();
+/// ```
+#[macro_export]
+macro_rules! define_enum_subtype {
+    ($name:ident, $str_name:literal, [$($value:literal),+ $(,)?]) => {
+        /// Text subtype restricted to a fixed set of values.
+        #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+        pub struct $name;
+
+        impl $crate::subtype::TextSubtype for $name {
+            fn name() -> &'static str {
+                $str_name
+            }
+
+            fn allowed_values() -> &'static [&'static str] {
+                &[$($value),+]
+            }
+
+            fn check_format(value: &str) -> ::std::result::Result<(), ::std::string::String> {
+                let allowed = <Self as $crate::subtype::TextSubtype>::allowed_values();
+                if allowed.contains(&value) {
+                    Ok(())
+                } else {
+                    Err(::std::format!("'{value}' is not one of: {}", allowed.join(", ")))
+                }
+            }
+        }
+
+        impl $crate::subtype::IntoBuilder for $name {
+            type Builder = $crate::types::leaf::TextBuilder<$name>;
+
+            fn into_builder(key: impl Into<$crate::core::Key>) -> Self::Builder {
+                $crate::types::leaf::Text::builder(key).subtype($name)
+            }
+        }
     };
 }
 
@@ -419,6 +552,7 @@ macro_rules! define_file_subtype {
 }
 
 // Re-export macros at crate level
+pub use define_enum_subtype;
 pub use define_file_subtype;
 pub use define_number_subtype;
 pub use define_text_subtype;
@@ -426,7 +560,8 @@ pub use define_vector_subtype;
 
 #[cfg(test)]
 mod tests {
-    use crate::subtype::{NumberSubtype, TextSubtype, VectorSubtype};
+    use crate::subtype::{IntoBuilder, NumberSubtype, TextSubtype, VectorSubtype};
+    use crate::types::traits::Node;
 
     define_number_subtype!(TestPort, int_only, i32, "test_port", range: (1, 65535));
     define_number_subtype!(TestFactor, float_only, f64, "test_factor", range: (0.0, 1.0));
@@ -469,6 +604,7 @@ mod tests {
 
     define_text_subtype!(TestPlain, "test_plain");
     define_text_subtype!(TestEmail, "test_email", pattern: r"^[^@]+@[^@]+$", placeholder: "user@example.com");
+    define_text_subtype!(TestSlug, "test_slug", pattern: r"^[a-z]+$", placeholder: "abc", lowercase: true);
     define_text_subtype!(TestSecret, "test_secret", sensitive: true);
     define_text_subtype!(TestMulti, "test_multi", multiline: true);
     define_text_subtype!(TestRust, "test_rust", code: "rust");
@@ -487,6 +623,25 @@ mod tests {
         assert_eq!(TestEmail::placeholder(), Some("user@example.com"));
     }
 
+    #[test]
+    fn test_define_text_subtype_lowercase() {
+        assert!(TestSlug::is_lowercase());
+        assert_eq!(TestSlug::pattern(), Some(r"^[a-z]+$"));
+        assert!(!TestEmail::is_lowercase());
+
+        let text = TestSlug::into_builder("code").build();
+        assert_eq!(text.normalize("ABC"), "abc");
+    }
+
+    #[test]
+    fn test_define_text_subtype_into_builder() {
+        let text = TestPlain::into_builder("plain").build();
+        assert_eq!(text.key(), "plain");
+
+        let text: crate::types::leaf::Text<TestSecret> = TestSecret::into_builder("token").build();
+        assert_eq!(text.key(), "token");
+    }
+
     #[test]
     fn test_define_text_subtype_sensitive() {
         assert!(TestSecret::is_sensitive());
@@ -502,4 +657,43 @@ mod tests {
         assert!(TestRust::is_multiline());
         assert_eq!(TestRust::code_language(), Some("rust"));
     }
+
+    define_enum_subtype!(TestMethod, "test_method", ["GET", "POST"]);
+
+    #[test]
+    fn test_define_enum_subtype() {
+        assert_eq!(TestMethod::name(), "test_method");
+        assert_eq!(TestMethod::allowed_values(), ["GET", "POST"]);
+        assert!(TestPlain::allowed_values().is_empty());
+
+        assert!(TestMethod::check_format("POST").is_ok());
+        let message = TestMethod::check_format("get").unwrap_err();
+        assert!(message.contains("GET, POST"));
+    }
+
+    #[cfg(feature = "validation")]
+    #[test]
+    fn test_define_enum_subtype_validation() {
+        use crate::core::Value;
+        use crate::types::traits::Validatable;
+
+        let method = TestMethod::into_builder("method").build();
+        assert!(method.validate_sync(&Value::text("GET")).is_ok());
+        assert!(method.validate_sync(&Value::text("")).is_ok());
+
+        let err = method.validate_sync(&Value::text("PUT")).unwrap_err();
+        assert_eq!(err.code(), Some("invalid_test_method"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_define_enum_subtype_json_schema() {
+        use crate::types::traits::Node;
+
+        let schema = TestMethod::into_builder("method")
+            .build()
+            .json_schema()
+            .unwrap();
+        assert_eq!(schema["enum"], serde_json::json!(["GET", "POST"]));
+    }
 }
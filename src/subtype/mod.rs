@@ -10,6 +10,7 @@
 //! # Organization
 //!
 //! - [`number`] - Number subtypes and traits
+//! - [`color`] - Hex and RGBA color conversions
 //! - [`vector`] - Vector subtypes
 //! - [`text`] - Text subtypes
 //! - [`mod@file`] - File subtypes
@@ -30,6 +31,7 @@
 //!     .build();
 //! ```
 
+pub mod color;
 pub mod file;
 pub mod macros;
 pub mod number;
@@ -39,11 +41,14 @@ pub mod unit;
 pub mod vector;
 
 // Re-export commonly used items
+pub use color::Color;
 pub use macros::{
-    define_file_subtype, define_number_subtype, define_text_subtype, define_vector_subtype,
+    define_enum_subtype, define_file_subtype, define_number_subtype, define_text_subtype,
+    define_vector_subtype,
 };
 pub use traits::{
-    FileSubtype, IntoBuilder, NumberSubtype, Numeric, NumericKind, TextSubtype, VectorSubtype,
+    FileSubtype, IntoBuilder, NumberSubtype, NumberSubtypeInfo, Numeric, NumericKind, TextSubtype,
+    VectorSubtype,
 };
 pub use unit::NumberUnit;
 
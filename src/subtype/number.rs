@@ -16,6 +16,8 @@
 //! - [`Second`] - Second (0-59)
 //! - [`Priority`] - Priority level (1-10)
 //! - [`Pixels`] - Pixel count
+//! - [`Integer`] - Unconstrained integer
+//! - [`Money`] - Monetary amount in minor units (cents)
 //!
 //! ## Float-Only Subtypes
 //! - [`Factor`] - Multiplicative factor (0-1)
@@ -51,6 +53,146 @@ define_number_subtype!(Minute, int_only, u8, "minute", range: (0, 59));
 define_number_subtype!(Second, int_only, u8, "second", range: (0, 59));
 define_number_subtype!(Priority, int_only, u8, "priority", range: (1, 10));
 define_number_subtype!(Pixels, int_only, u32, "pixels");
+define_number_subtype!(Integer, int_only, i64, "integer");
+
+/// Monetary amount stored as an integer count of minor units (cents).
+///
+/// Integer storage avoids float rounding on prices: `$12.34` is stored as
+/// `1234`. The subtype carries its ISO 4217 currency code and whether
+/// negative amounts are accepted; negative values are rejected by default.
+///
+/// # Example
+///
+/// ```
+/// use paramdef::subtype::Money;
+///
+/// assert_eq!(Money::format(1234, "USD"), "$12.34");
+/// assert_eq!(Money::format(-5, "EUR"), "-€0.05");
+/// assert_eq!(Money::format(1500, "JPY"), "¥1500");
+/// assert_eq!(Money::format(1234, "CHF"), "12.34 CHF");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Money {
+    currency: [u8; 3],
+    allow_negative: bool,
+}
+
+impl Money {
+    /// Creates a money subtype for an ISO 4217 currency code.
+    ///
+    /// The code is case-insensitive and stored uppercase. Returns `None`
+    /// unless `currency` is exactly three ASCII letters.
+    #[must_use]
+    pub fn new(currency: &str) -> Option<Self> {
+        let bytes: [u8; 3] = currency.as_bytes().try_into().ok()?;
+        if !bytes.iter().all(u8::is_ascii_alphabetic) {
+            return None;
+        }
+        Some(Self {
+            currency: bytes.map(|b| b.to_ascii_uppercase()),
+            allow_negative: false,
+        })
+    }
+
+    /// Returns a copy that accepts or rejects negative amounts.
+    #[must_use]
+    pub fn with_negative(mut self, allow: bool) -> Self {
+        self.allow_negative = allow;
+        self
+    }
+
+    /// Returns the uppercase ISO 4217 currency code.
+    #[must_use]
+    pub fn currency(&self) -> &str {
+        // Only ASCII letters are ever stored.
+        std::str::from_utf8(&self.currency).unwrap_or("XXX")
+    }
+
+    /// Returns whether negative amounts are accepted.
+    #[must_use]
+    pub fn allows_negative(&self) -> bool {
+        self.allow_negative
+    }
+
+    /// Returns the number of minor-unit digits for a currency.
+    ///
+    /// Most currencies use two; a few use zero (`JPY`, `KRW`) or three
+    /// (`BHD`, `KWD`).
+    #[must_use]
+    pub fn minor_digits(currency: &str) -> u32 {
+        match currency.to_ascii_uppercase().as_str() {
+            "JPY" | "KRW" | "VND" | "CLP" | "ISK" => 0,
+            "BHD" | "KWD" | "OMR" | "JOD" | "TND" => 3,
+            _ => 2,
+        }
+    }
+
+    /// Formats an amount in minor units for display.
+    ///
+    /// Well-known currencies use their symbol as a prefix (`"$12.34"`);
+    /// others get the code as a suffix (`"12.34 CHF"`).
+    #[must_use]
+    pub fn format(value: i64, currency: &str) -> String {
+        let code = currency.to_ascii_uppercase();
+        let digits = Self::minor_digits(&code);
+        let sign = if value < 0 { "-" } else { "" };
+        let magnitude = value.unsigned_abs();
+
+        let amount = if digits == 0 {
+            magnitude.to_string()
+        } else {
+            let scale = 10_u64.pow(digits);
+            format!(
+                "{}.{:0width$}",
+                magnitude / scale,
+                magnitude % scale,
+                width = digits as usize
+            )
+        };
+
+        let symbol = match code.as_str() {
+            "USD" => Some("$"),
+            "EUR" => Some("€"),
+            "GBP" => Some("£"),
+            "JPY" => Some("¥"),
+            "INR" => Some("₹"),
+            _ => None,
+        };
+        match symbol {
+            Some(symbol) => format!("{sign}{symbol}{amount}"),
+            None => format!("{sign}{amount} {code}"),
+        }
+    }
+}
+
+impl Default for Money {
+    fn default() -> Self {
+        Self {
+            currency: *b"USD",
+            allow_negative: false,
+        }
+    }
+}
+
+impl super::NumberSubtype for Money {
+    type Value = i64;
+
+    fn name() -> &'static str {
+        "money"
+    }
+
+    fn check_value(&self, value: f64) -> Result<(), String> {
+        if value.fract() != 0.0 {
+            return Err(format!(
+                "amount must be a whole number of minor units, got {value}"
+            ));
+        }
+        if value < 0.0 && !self.allow_negative {
+            return Err(format!("negative amounts are not allowed, got {value}"));
+        }
+        Ok(())
+    }
+}
 
 // === Float-Only Subtypes ===
 
@@ -121,6 +263,39 @@ mod tests {
         assert_eq!(Index::name(), "index");
     }
 
+    #[test]
+    fn test_money_subtype() {
+        assert_eq!(Money::name(), "money");
+        assert_eq!(Money::default().currency(), "USD");
+
+        let eur = Money::new("eur").unwrap();
+        assert_eq!(eur.currency(), "EUR");
+        assert!(!eur.allows_negative());
+        assert!(eur.with_negative(true).allows_negative());
+
+        assert!(Money::new("EURO").is_none());
+        assert!(Money::new("E1R").is_none());
+    }
+
+    #[test]
+    fn test_money_format() {
+        assert_eq!(Money::format(1234, "USD"), "$12.34");
+        assert_eq!(Money::format(5, "usd"), "$0.05");
+        assert_eq!(Money::format(-1234, "GBP"), "-£12.34");
+        assert_eq!(Money::format(1500, "JPY"), "¥1500");
+        assert_eq!(Money::format(12345, "KWD"), "12.345 KWD");
+        assert_eq!(Money::format(i64::MIN, "CHF"), "-92233720368547758.08 CHF");
+    }
+
+    #[test]
+    fn test_money_check_value() {
+        let money = Money::default();
+        assert!(money.check_value(100.0).is_ok());
+        assert!(money.check_value(-1.0).is_err());
+        assert!(money.check_value(1.5).is_err());
+        assert!(money.with_negative(true).check_value(-1.0).is_ok());
+    }
+
     // === Float-Only Tests ===
 
     #[test]
@@ -12,6 +12,8 @@
 //! - [`Domain`] - Domain name
 //! - [`IpAddressV4`] - IPv4 address
 //! - [`IpAddressV6`] - IPv6 address
+//! - [`IpAddress`] - IPv4 or IPv6 address
+//! - [`Cidr`] - IP network in CIDR notation
 //! - [`Hostname`] - Hostname
 //! - [`MacAddress`] - MAC address
 //!
@@ -55,7 +57,8 @@
 //! - [`Rust`] - Rust code
 //!
 //! ## Contact
-//! - [`PhoneNumber`] - Phone number (E.164)
+//! - [`Phone`] - Phone number, validated as E.164
+//! - [`PhoneNumber`] - Phone number placeholder, without validation
 //!
 //! ## Color
 //! - [`HexColor`] - Hex color (#RRGGBB)
@@ -68,6 +71,8 @@
 //! ## Versioning
 //! - [`Semver`] - Semantic version
 
+use std::net::IpAddr;
+
 use crate::define_text_subtype;
 
 // === Basic ===
@@ -85,6 +90,118 @@ define_text_subtype!(IpAddressV6, "ip_v6", placeholder: "::1");
 define_text_subtype!(Hostname, "hostname", placeholder: "localhost");
 define_text_subtype!(MacAddress, "mac_address", pattern: r"^([0-9A-Fa-f]{2}[:-]){5}([0-9A-Fa-f]{2})$", placeholder: "00:1A:2B:3C:4D:5E");
 
+/// Text subtype for IPv4 or IPv6 addresses, such as `192.168.1.1` or `::1`.
+///
+/// Values are checked by parsing them as [`IpAddr`], so out-of-range
+/// octets like `999.1.1.1` are rejected. No name resolution is done.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct IpAddress;
+
+impl IpAddress {
+    /// Returns `true` if `s` is a valid IPv4 address.
+    #[must_use]
+    pub fn is_v4(s: &str) -> bool {
+        s.parse::<IpAddr>().is_ok_and(|ip| ip.is_ipv4())
+    }
+
+    /// Returns `true` if `s` is a valid IPv6 address.
+    #[must_use]
+    pub fn is_v6(s: &str) -> bool {
+        s.parse::<IpAddr>().is_ok_and(|ip| ip.is_ipv6())
+    }
+}
+
+impl crate::subtype::TextSubtype for IpAddress {
+    fn name() -> &'static str {
+        "ip_address"
+    }
+
+    fn placeholder() -> Option<&'static str> {
+        Some("192.168.1.1")
+    }
+
+    fn check_format(value: &str) -> Result<(), String> {
+        value
+            .parse::<IpAddr>()
+            .map(|_| ())
+            .map_err(|_| format!("'{value}' is not an IP address"))
+    }
+}
+
+/// Text subtype for IP networks in CIDR notation, such as `10.0.0.0/8`.
+///
+/// Values are an IPv4 or IPv6 address, a `/`, and a prefix length of at
+/// most 32 or 128 respectively. Host bits may be set, as in interface
+/// addresses like `192.168.1.5/24`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Cidr;
+
+impl Cidr {
+    /// Splits a CIDR block into its address and prefix length.
+    ///
+    /// Returns `None` if `s` is not valid CIDR notation.
+    #[must_use]
+    pub fn parse(s: &str) -> Option<(IpAddr, u8)> {
+        let (addr, prefix) = s.split_once('/')?;
+        let addr: IpAddr = addr.parse().ok()?;
+        if prefix.is_empty() || !prefix.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let prefix: u8 = prefix.parse().ok()?;
+        let max = if addr.is_ipv4() { 32 } else { 128 };
+        (prefix <= max).then_some((addr, prefix))
+    }
+
+    /// Returns whether the network `cidr` contains the address `ip`.
+    ///
+    /// An address of the other IP version is never contained. Returns
+    /// `None` if either argument fails to parse.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::subtype::Cidr;
+    ///
+    /// assert_eq!(Cidr::contains("10.0.0.0/8", "10.1.2.3"), Some(true));
+    /// assert_eq!(Cidr::contains("10.0.0.0/8", "11.0.0.1"), Some(false));
+    /// assert_eq!(Cidr::contains("10.0.0.0/8", "::1"), Some(false));
+    /// assert_eq!(Cidr::contains("10.0.0.0/33", "10.0.0.1"), None);
+    /// ```
+    #[must_use]
+    pub fn contains(cidr: &str, ip: &str) -> Option<bool> {
+        let (network, prefix) = Self::parse(cidr)?;
+        let ip: IpAddr = ip.parse().ok()?;
+        let contained = match (network, ip) {
+            (IpAddr::V4(network), IpAddr::V4(ip)) => {
+                let mask = u32::MAX.checked_shl(32 - u32::from(prefix)).unwrap_or(0);
+                u32::from(network) & mask == u32::from(ip) & mask
+            }
+            (IpAddr::V6(network), IpAddr::V6(ip)) => {
+                let mask = u128::MAX.checked_shl(128 - u32::from(prefix)).unwrap_or(0);
+                u128::from(network) & mask == u128::from(ip) & mask
+            }
+            _ => false,
+        };
+        Some(contained)
+    }
+}
+
+impl crate::subtype::TextSubtype for Cidr {
+    fn name() -> &'static str {
+        "cidr"
+    }
+
+    fn placeholder() -> Option<&'static str> {
+        Some("10.0.0.0/8")
+    }
+
+    fn check_format(value: &str) -> Result<(), String> {
+        Self::parse(value)
+            .map(|_| ())
+            .ok_or_else(|| format!("'{value}' is not a CIDR block"))
+    }
+}
+
 // === Paths ===
 
 define_text_subtype!(FilePath, "file_path", placeholder: "/path/to/file");
@@ -100,12 +217,33 @@ define_text_subtype!(BearerToken, "bearer_token", sensitive: true);
 
 // === Identifiers ===
 
-define_text_subtype!(Uuid, "uuid", pattern: r"^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$", placeholder: "00000000-0000-0000-0000-000000000000");
-define_text_subtype!(Slug, "slug", pattern: r"^[a-z0-9]+(?:-[a-z0-9]+)*$", placeholder: "my-slug");
+define_text_subtype!(Uuid, "uuid", pattern: r"^[0-9a-fA-F]{8}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{4}-[0-9a-fA-F]{12}$", placeholder: "00000000-0000-0000-0000-000000000000", lowercase: true);
+define_text_subtype!(Slug, "slug", pattern: r"^[a-z0-9]+(?:-[a-z0-9]+)*$", placeholder: "my-slug", lowercase: true);
 
 // === Date/Time ===
 
-define_text_subtype!(DateTime, "datetime", placeholder: "2024-01-01T00:00:00Z");
+/// Text subtype for RFC 3339 timestamps, such as `2024-01-01T00:00:00Z`.
+///
+/// With the `chrono` feature, values are checked by parsing them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct DateTime;
+
+impl crate::subtype::TextSubtype for DateTime {
+    fn name() -> &'static str {
+        "datetime"
+    }
+
+    fn placeholder() -> Option<&'static str> {
+        Some("2024-01-01T00:00:00Z")
+    }
+
+    #[cfg(feature = "chrono")]
+    fn check_format(value: &str) -> Result<(), String> {
+        chrono::DateTime::parse_from_rfc3339(value)
+            .map(|_| ())
+            .map_err(|e| format!("'{value}' is not an RFC 3339 date-time: {e}"))
+    }
+}
 define_text_subtype!(Date, "date", pattern: r"^\d{4}-\d{2}-\d{2}$", placeholder: "2024-01-01");
 define_text_subtype!(Time, "time", pattern: r"^\d{2}:\d{2}(:\d{2})?$", placeholder: "12:00:00");
 define_text_subtype!(Iso8601Duration, "iso8601_duration", pattern: r"^P", placeholder: "PT1H30M");
@@ -134,6 +272,122 @@ define_text_subtype!(Rust, "rust", code: "rust");
 
 define_text_subtype!(PhoneNumber, "phone_number", placeholder: "+1 555 123 4567");
 
+/// Text subtype for phone numbers, normalized to E.164 (`+15551234567`).
+///
+/// Values must be international numbers: `+` or `00`, then 7 to 15 digits
+/// not starting with `0`. Spaces, `-`, `.`, and parentheses are ignored.
+///
+/// With the `phonenumber` feature, numbers are checked against the
+/// numbering plan of their country instead, and national numbers such as
+/// `030 1234567` are accepted when a [region](Self::with_region) is set.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Phone {
+    region: Option<[u8; 2]>,
+}
+
+impl Phone {
+    /// Creates a phone subtype that reads national numbers as `region`.
+    ///
+    /// The ISO 3166 code is case-insensitive and stored uppercase. Returns
+    /// `None` unless `region` is exactly two ASCII letters.
+    #[must_use]
+    pub fn with_region(region: &str) -> Option<Self> {
+        let bytes: [u8; 2] = region.as_bytes().try_into().ok()?;
+        if !bytes.iter().all(u8::is_ascii_alphabetic) {
+            return None;
+        }
+        Some(Self {
+            region: Some(bytes.map(|b| b.to_ascii_uppercase())),
+        })
+    }
+
+    /// Returns the uppercase ISO 3166 region, if set.
+    #[must_use]
+    pub fn region(&self) -> Option<&str> {
+        // Only ASCII letters are ever stored.
+        self.region
+            .as_ref()
+            .and_then(|region| std::str::from_utf8(region).ok())
+    }
+
+    /// Normalizes a phone number to E.164, such as `+4930123456`.
+    ///
+    /// `region` is the ISO 3166 code used for national numbers; it only
+    /// has an effect with the `phonenumber` feature. Returns `None` if `s`
+    /// is not a valid number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::subtype::Phone;
+    ///
+    /// assert_eq!(
+    ///     Phone::normalize("+1 (415) 555-2671", None).as_deref(),
+    ///     Some("+14155552671")
+    /// );
+    /// assert_eq!(Phone::normalize("+44 20 7183 8750", None).as_deref(), Some("+442071838750"));
+    /// assert_eq!(Phone::normalize("call me", None), None);
+    /// ```
+    #[must_use]
+    pub fn normalize(s: &str, region: Option<&str>) -> Option<String> {
+        #[cfg(feature = "phonenumber")]
+        {
+            let country = match region {
+                Some(region) => Some(region.to_ascii_uppercase().parse().ok()?),
+                None => None,
+            };
+            let number = phonenumber::parse(country, s.trim()).ok()?;
+            number
+                .is_valid()
+                .then(|| number.format().mode(phonenumber::Mode::E164).to_string())
+        }
+        #[cfg(not(feature = "phonenumber"))]
+        {
+            let _ = region;
+            normalize_e164(s)
+        }
+    }
+}
+
+/// Normalizes an international number by its shape alone.
+#[cfg(not(feature = "phonenumber"))]
+fn normalize_e164(s: &str) -> Option<String> {
+    let s = s.trim();
+    let rest = s.strip_prefix('+').or_else(|| s.strip_prefix("00"))?;
+    let mut out = String::with_capacity(16);
+    out.push('+');
+    for c in rest.chars() {
+        match c {
+            '0'..='9' => out.push(c),
+            ' ' | '-' | '.' | '(' | ')' => {}
+            _ => return None,
+        }
+    }
+
+    let digits = out.len() - 1;
+    ((7..=15).contains(&digits) && !out[1..].starts_with('0')).then_some(out)
+}
+
+impl crate::subtype::TextSubtype for Phone {
+    fn name() -> &'static str {
+        "phone"
+    }
+
+    fn placeholder() -> Option<&'static str> {
+        Some("+1 555 123 4567")
+    }
+
+    fn check_format(value: &str) -> Result<(), String> {
+        Self::default().check_value(value)
+    }
+
+    fn check_value(&self, value: &str) -> Result<(), String> {
+        Self::normalize(value, self.region())
+            .map(|_| ())
+            .ok_or_else(|| format!("'{value}' is not a valid phone number"))
+    }
+}
+
 // === Color ===
 
 define_text_subtype!(HexColor, "hex_color", pattern: r"^#([0-9A-Fa-f]{3}|[0-9A-Fa-f]{6}|[0-9A-Fa-f]{8})$", placeholder: "#FF5733");
@@ -201,6 +455,94 @@ mod tests {
         assert_eq!(IpAddressV6::name(), "ip_v6");
     }
 
+    #[test]
+    fn test_ip_address() {
+        assert_eq!(IpAddress::name(), "ip_address");
+        assert!(IpAddress::check_format("192.168.1.1").is_ok());
+        assert!(IpAddress::check_format("fe80::1").is_ok());
+        assert!(IpAddress::check_format("999.1.1.1").is_err());
+        assert!(IpAddress::check_format("localhost").is_err());
+
+        assert!(IpAddress::is_v4("127.0.0.1"));
+        assert!(!IpAddress::is_v4("::1"));
+        assert!(IpAddress::is_v6("::1"));
+        assert!(!IpAddress::is_v6("1.2.3"));
+    }
+
+    #[test]
+    fn test_cidr() {
+        assert_eq!(Cidr::name(), "cidr");
+        assert!(Cidr::check_format("192.168.1.5/24").is_ok());
+        assert!(Cidr::check_format("2001:db8::/32").is_ok());
+        for bad in [
+            "10.0.0.0",
+            "10.0.0.0/",
+            "10.0.0.0/33",
+            "10.0.0.0/+8",
+            "::/129",
+            "x/8",
+        ] {
+            assert!(Cidr::check_format(bad).is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn test_cidr_contains() {
+        assert_eq!(
+            Cidr::contains("192.168.1.0/24", "192.168.1.200"),
+            Some(true)
+        );
+        assert_eq!(Cidr::contains("192.168.1.0/24", "192.168.2.1"), Some(false));
+        assert_eq!(Cidr::contains("0.0.0.0/0", "8.8.8.8"), Some(true));
+        assert_eq!(Cidr::contains("10.0.0.1/32", "10.0.0.1"), Some(true));
+        assert_eq!(Cidr::contains("2001:db8::/32", "2001:db8:1::1"), Some(true));
+        assert_eq!(Cidr::contains("::/0", "10.0.0.1"), Some(false));
+        assert_eq!(Cidr::contains("10.0.0.0/8", "10.0.0"), None);
+    }
+
+    #[test]
+    fn test_phone() {
+        assert_eq!(Phone::name(), "phone");
+        assert!(Phone::check_format("+1 415 555 2671").is_ok());
+        assert!(Phone::check_format("555-1234").is_err());
+        assert!(Phone::check_format("+1 415 CALL").is_err());
+
+        let de = Phone::with_region("de").unwrap();
+        assert_eq!(de.region(), Some("DE"));
+        assert_eq!(Phone::default().region(), None);
+        assert!(Phone::with_region("DEU").is_none());
+        assert!(Phone::with_region("1A").is_none());
+    }
+
+    #[cfg(not(feature = "phonenumber"))]
+    #[test]
+    fn test_phone_normalize_shape() {
+        assert_eq!(
+            Phone::normalize("+49 (30) 123.456", None).as_deref(),
+            Some("+4930123456")
+        );
+        assert_eq!(Phone::normalize("+0 123 456 789", None), None);
+        assert_eq!(Phone::normalize("+123456", None), None);
+        assert_eq!(Phone::normalize("+1234567890123456", None), None);
+        assert_eq!(Phone::normalize("030 1234567", Some("DE")), None);
+    }
+
+    #[cfg(feature = "phonenumber")]
+    #[test]
+    fn test_phone_normalize_region() {
+        assert_eq!(
+            Phone::normalize("030 12345678", Some("de")).as_deref(),
+            Some("+493012345678")
+        );
+        assert_eq!(Phone::normalize("030 12345678", None), None);
+        assert_eq!(Phone::normalize("+1 555 000 0000", None), None);
+        assert_eq!(Phone::normalize("030 12345678", Some("ZZ")), None);
+
+        let de = Phone::with_region("DE").unwrap();
+        assert!(de.check_value("030 12345678").is_ok());
+        assert!(Phone::default().check_value("030 12345678").is_err());
+    }
+
     #[test]
     fn test_hostname() {
         assert_eq!(Hostname::name(), "hostname");
@@ -256,12 +598,14 @@ mod tests {
     fn test_uuid() {
         assert_eq!(Uuid::name(), "uuid");
         assert!(Uuid::pattern().is_some());
+        assert!(Uuid::is_lowercase());
     }
 
     #[test]
     fn test_slug() {
         assert_eq!(Slug::name(), "slug");
         assert!(Slug::pattern().is_some());
+        assert!(Slug::is_lowercase());
     }
 
     // === Date/Time Tests ===
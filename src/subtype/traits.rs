@@ -246,6 +246,49 @@ pub trait NumberSubtype: Debug + Clone + Copy + Default + Send + Sync + 'static
     fn recommended_unit() -> Option<super::NumberUnit> {
         None
     }
+
+    /// Checks an in-range value against subtype-specific rules.
+    ///
+    /// Returns a message describing the problem if the value is invalid.
+    /// Number validation reports it under the code `invalid_<name>`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of why `value` is invalid.
+    fn check_value(&self, value: f64) -> Result<(), String> {
+        let _ = value;
+        Ok(())
+    }
+}
+
+/// Type-erased description of a number subtype.
+///
+/// Lets renderers pick a widget (port spinner, percentage slider) without
+/// naming the concrete [`NumberSubtype`]. See
+/// [`Number::subtype_descriptor`](crate::types::leaf::Number::subtype_descriptor).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumberSubtypeInfo {
+    /// The subtype [`name`](NumberSubtype::name).
+    pub name: &'static str,
+    /// The numeric type the subtype works with.
+    pub kind: NumericKind,
+    /// The allowed range, if any.
+    pub range: Option<(f64, f64)>,
+    /// The unit, if any.
+    pub unit: Option<super::NumberUnit>,
+}
+
+impl NumberSubtypeInfo {
+    /// Describes `S` using its default range and recommended unit.
+    #[must_use]
+    pub fn of<S: NumberSubtype>() -> Self {
+        Self {
+            name: S::name(),
+            kind: S::Value::kind(),
+            range: S::default_range().map(|(min, max)| (min.to_f64(), max.to_f64())),
+            unit: S::recommended_unit(),
+        }
+    }
 }
 
 /// Trait for vector subtypes with size constraints.
@@ -336,6 +379,51 @@ pub trait TextSubtype: Debug + Clone + Copy + Default + Send + Sync + 'static {
     fn code_language() -> Option<&'static str> {
         None
     }
+
+    /// Returns whether values are normalized to lowercase.
+    ///
+    /// See [`Text::normalize`](crate::types::leaf::Text::normalize).
+    #[must_use]
+    fn is_lowercase() -> bool {
+        false
+    }
+
+    /// Returns the closed set of accepted values, if any.
+    ///
+    /// Empty means any value is accepted. Subtypes defined with
+    /// [`define_enum_subtype!`](crate::define_enum_subtype) reject other
+    /// values in [`check_format`](Self::check_format).
+    #[must_use]
+    fn allowed_values() -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Checks a non-empty value beyond what [`pattern`](Self::pattern)
+    /// can express.
+    ///
+    /// Returns a message describing the problem if the value is invalid.
+    /// Text validation reports it under the code `invalid_<name>`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of why `value` is invalid.
+    fn check_format(value: &str) -> Result<(), String> {
+        let _ = value;
+        Ok(())
+    }
+
+    /// Checks a non-empty value using this subtype's configuration.
+    ///
+    /// Text validation calls this rather than
+    /// [`check_format`](Self::check_format). The default delegates to it;
+    /// configurable subtypes such as [`Phone`](super::Phone) override it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a description of why `value` is invalid.
+    fn check_value(&self, value: &str) -> Result<(), String> {
+        Self::check_format(value)
+    }
 }
 
 /// Trait for file subtypes with MIME type constraints.
@@ -99,6 +99,85 @@ pub enum NumberUnit {
 }
 
 impl NumberUnit {
+    /// Every unit, grouped by category in declaration order.
+    ///
+    /// [`units_in_category`](Self::units_in_category) returns sub-slices of
+    /// this list, so each category must stay contiguous.
+    const ALL: &'static [NumberUnit] = &[
+        Self::Millimeters,
+        Self::Centimeters,
+        Self::Meters,
+        Self::Kilometers,
+        Self::Inches,
+        Self::Feet,
+        Self::Miles,
+        Self::Milliseconds,
+        Self::Seconds,
+        Self::Minutes,
+        Self::Hours,
+        Self::Days,
+        Self::Degrees,
+        Self::Radians,
+        Self::Turns,
+        Self::Bytes,
+        Self::Kilobytes,
+        Self::Megabytes,
+        Self::Gigabytes,
+        Self::Terabytes,
+        Self::Celsius,
+        Self::Fahrenheit,
+        Self::Kelvin,
+        Self::Percent,
+        Self::Factor,
+        Self::None,
+    ];
+
+    /// Returns every unit, grouped by category.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::subtype::NumberUnit;
+    ///
+    /// assert!(NumberUnit::all().contains(&NumberUnit::Kelvin));
+    /// ```
+    #[must_use]
+    pub const fn all() -> &'static [NumberUnit] {
+        Self::ALL
+    }
+
+    /// Returns the units of a [`category`](Self::category), base unit
+    /// included, in declaration order.
+    ///
+    /// Returns an empty slice for an unknown category.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::subtype::NumberUnit;
+    ///
+    /// let rotation = NumberUnit::units_in_category("rotation");
+    /// assert_eq!(
+    ///     rotation,
+    ///     [NumberUnit::Degrees, NumberUnit::Radians, NumberUnit::Turns]
+    /// );
+    /// assert!(NumberUnit::units_in_category("mass").is_empty());
+    /// ```
+    #[must_use]
+    pub fn units_in_category(category: &str) -> &'static [NumberUnit] {
+        let range = match category {
+            "length" => 0..7,
+            "time" => 7..12,
+            "rotation" => 12..15,
+            "data" => 15..20,
+            "temperature" => 20..23,
+            "percentage" => 23..25,
+            "none" => 25..26,
+            _ => return &[],
+        };
+        &Self::ALL[range]
+    }
+
     /// Returns the display suffix for this unit.
     ///
     /// # Example
@@ -153,6 +232,134 @@ impl NumberUnit {
         }
     }
 
+    /// Formats a value with this unit's suffix for display.
+    ///
+    /// The value uses its shortest exact representation. Degrees and
+    /// percentages attach directly to the number; other suffixes are
+    /// separated by a space. Units without a suffix format the bare number.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::subtype::NumberUnit;
+    ///
+    /// assert_eq!(NumberUnit::Meters.format(1.5), "1.5 m");
+    /// assert_eq!(NumberUnit::Degrees.format(90.0), "90°");
+    /// assert_eq!(NumberUnit::Percent.format(50.0), "50%");
+    /// assert_eq!(NumberUnit::None.format(3.0), "3");
+    /// ```
+    #[must_use]
+    pub fn format(&self, value: f64) -> String {
+        self.with_suffix(&value.to_string())
+    }
+
+    /// Formats a value with a fixed number of decimal places.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::subtype::NumberUnit;
+    ///
+    /// assert_eq!(NumberUnit::Seconds.format_with_precision(2.0 / 3.0, 2), "0.67 s");
+    /// assert_eq!(NumberUnit::Celsius.format_with_precision(21.0, 1), "21.0 °C");
+    /// ```
+    #[must_use]
+    pub fn format_with_precision(&self, value: f64, decimals: usize) -> String {
+        self.with_suffix(&format!("{value:.decimals$}"))
+    }
+
+    /// Formats a byte count using the largest data unit that keeps the
+    /// value at or above 1.
+    ///
+    /// Units step by 1024, matching the data conversions. The value keeps
+    /// at most two decimals, without trailing zeros.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::subtype::NumberUnit;
+    ///
+    /// assert_eq!(NumberUnit::format_auto(512.0), "512 B");
+    /// assert_eq!(NumberUnit::format_auto(1536.0), "1.5 KB");
+    /// assert_eq!(NumberUnit::format_auto(3.0 * 1024.0 * 1024.0 * 1024.0), "3 GB");
+    /// ```
+    #[must_use]
+    pub fn format_auto(bytes: f64) -> String {
+        const UNITS: [NumberUnit; 5] = [
+            NumberUnit::Bytes,
+            NumberUnit::Kilobytes,
+            NumberUnit::Megabytes,
+            NumberUnit::Gigabytes,
+            NumberUnit::Terabytes,
+        ];
+
+        let unit = UNITS
+            .iter()
+            .rev()
+            .find(|unit| bytes.abs() >= unit.to_base(1.0))
+            .unwrap_or(&NumberUnit::Bytes);
+        let value = format!("{:.2}", unit.from_base(bytes));
+        let value = value.trim_end_matches('0').trim_end_matches('.');
+        unit.with_suffix(value)
+    }
+
+    /// Appends the display suffix to an already formatted number.
+    fn with_suffix(self, number: &str) -> String {
+        match self {
+            Self::Degrees | Self::Percent => format!("{number}{}", self.display_suffix()),
+            Self::Factor | Self::None => number.to_owned(),
+            _ => format!("{number} {}", self.display_suffix()),
+        }
+    }
+
+    /// Parses a number followed by a unit suffix, such as `"150 cm"`.
+    ///
+    /// The suffix is matched against [`display_suffix`](Self::display_suffix).
+    /// Whitespace around the number and suffix is ignored, and suffixes
+    /// longer than one character match regardless of case (`"2.5gb"`).
+    /// Single-character suffixes are case-sensitive, so `"m"` and `"K"` stay
+    /// distinct. A bare number parses as [`NumberUnit::None`].
+    ///
+    /// Returns `None` if the number does not parse or the suffix is unknown.
+    /// Use [`to_base`](Self::to_base) on the result to normalize the value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::subtype::NumberUnit;
+    ///
+    /// assert_eq!(NumberUnit::parse("150 cm"), Some((150.0, NumberUnit::Centimeters)));
+    /// assert_eq!(NumberUnit::parse("90°"), Some((90.0, NumberUnit::Degrees)));
+    /// assert_eq!(NumberUnit::parse("2.5GB"), Some((2.5, NumberUnit::Gigabytes)));
+    /// assert_eq!(NumberUnit::parse("3 parsecs"), None);
+    /// ```
+    #[must_use]
+    pub fn parse(s: &str) -> Option<(f64, NumberUnit)> {
+        let s = s.trim();
+        let split = s
+            .find(|c: char| !(c.is_ascii_digit() || matches!(c, '.' | '+' | '-' | 'e' | 'E')))
+            .unwrap_or(s.len());
+        let (number, suffix) = s.split_at(split);
+        let value: f64 = number.trim_end().parse().ok()?;
+        let suffix = suffix.trim_start();
+        if suffix.is_empty() {
+            return Some((value, Self::None));
+        }
+
+        let unit = Self::ALL
+            .iter()
+            .find(|unit| unit.display_suffix() == suffix)
+            .or_else(|| {
+                Self::ALL.iter().find(|unit| {
+                    let candidate = unit.display_suffix();
+                    candidate.chars().count() > 1
+                        && candidate.to_lowercase() == suffix.to_lowercase()
+                })
+            })?;
+
+        Some((value, *unit))
+    }
+
     /// Converts a value from this unit to the base unit.
     ///
     /// # Example
@@ -267,6 +474,85 @@ impl NumberUnit {
         }
     }
 
+    /// Converts a value to the base unit, clamping percentages.
+    ///
+    /// For [`Percent`](Self::Percent) and [`Factor`](Self::Factor) the
+    /// result is clamped to the factor range `[0, 1]`. Other units convert
+    /// exactly like [`to_base`](Self::to_base).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::subtype::NumberUnit;
+    ///
+    /// assert_eq!(NumberUnit::Percent.to_base_clamped(150.0), 1.0);
+    /// assert_eq!(NumberUnit::Factor.to_base_clamped(-0.5), 0.0);
+    /// assert_eq!(NumberUnit::Meters.to_base_clamped(-2.0), -2.0);
+    /// ```
+    #[must_use]
+    pub fn to_base_clamped(&self, value: f64) -> f64 {
+        let base = self.to_base(value);
+        match self {
+            Self::Percent | Self::Factor => base.clamp(0.0, 1.0),
+            _ => base,
+        }
+    }
+
+    /// Converts a value from the base unit, clamping percentages.
+    ///
+    /// The result is clamped to `[0, 100]` for [`Percent`](Self::Percent)
+    /// and `[0, 1]` for [`Factor`](Self::Factor). Other units convert
+    /// exactly like [`from_base`](Self::from_base).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::subtype::NumberUnit;
+    ///
+    /// assert_eq!(NumberUnit::Percent.from_base_clamped(1.2), 100.0);
+    /// assert_eq!(NumberUnit::Percent.from_base_clamped(0.25), 25.0);
+    /// ```
+    #[must_use]
+    pub fn from_base_clamped(&self, value: f64) -> f64 {
+        let converted = self.from_base(value);
+        match self {
+            Self::Percent => converted.clamp(0.0, 100.0),
+            Self::Factor => converted.clamp(0.0, 1.0),
+            _ => converted,
+        }
+    }
+
+    /// Wraps an angle into one full turn.
+    ///
+    /// Degrees wrap into `[0, 360)`, radians into `[0, 2π)` and turns into
+    /// `[0, 1)`, so `-90°` becomes `270°`. Non-rotation units are returned
+    /// unchanged. The remainder is computed exactly, so large magnitudes do
+    /// not lose precision beyond that of the input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::subtype::NumberUnit;
+    ///
+    /// assert_eq!(NumberUnit::Degrees.normalize_angle(-90.0), 270.0);
+    /// assert_eq!(NumberUnit::Degrees.normalize_angle(720.0), 0.0);
+    /// assert_eq!(NumberUnit::Turns.normalize_angle(2.25), 0.25);
+    /// assert_eq!(NumberUnit::Meters.normalize_angle(-90.0), -90.0);
+    /// ```
+    #[must_use]
+    pub fn normalize_angle(&self, value: f64) -> f64 {
+        let period = match self {
+            Self::Degrees => 360.0,
+            Self::Radians => std::f64::consts::TAU,
+            Self::Turns => 1.0,
+            _ => return value,
+        };
+
+        let wrapped = value.rem_euclid(period);
+        // Tiny negative inputs round up to exactly `period`.
+        if wrapped >= period { 0.0 } else { wrapped }
+    }
+
     /// Converts a value from this unit to another unit.
     ///
     /// # Example
@@ -373,6 +659,27 @@ mod tests {
         assert!((turns - 360.0).abs() < 0.001);
     }
 
+    #[test]
+    fn test_normalize_angle() {
+        use std::f64::consts::{PI, TAU};
+
+        assert!((NumberUnit::Degrees.normalize_angle(-90.0) - 270.0).abs() < 1e-9);
+        assert!((NumberUnit::Degrees.normalize_angle(450.0) - 90.0).abs() < 1e-9);
+        assert!(NumberUnit::Degrees.normalize_angle(360.0).abs() < 1e-9);
+        assert!((NumberUnit::Radians.normalize_angle(-PI / 2.0) - 1.5 * PI).abs() < 1e-9);
+        assert!((NumberUnit::Radians.normalize_angle(5.0 * TAU + 1.0) - 1.0).abs() < 1e-9);
+        assert!((NumberUnit::Turns.normalize_angle(-0.25) - 0.75).abs() < 1e-9);
+        assert!((NumberUnit::Seconds.normalize_angle(-90.0) + 90.0).abs() < 1e-9);
+
+        // Large magnitudes stay exact.
+        let large = 360.0 * 1e12 + 45.0;
+        assert!((NumberUnit::Degrees.normalize_angle(large) - 45.0).abs() < 1e-9);
+
+        // Results never reach the upper bound.
+        let tiny = NumberUnit::Degrees.normalize_angle(-1e-20);
+        assert!((0.0..360.0).contains(&tiny));
+    }
+
     // === Data Tests ===
 
     #[test]
@@ -417,6 +724,22 @@ mod tests {
         assert!((NumberUnit::Percent.from_base(0.5) - 50.0).abs() < 0.001);
     }
 
+    #[test]
+    fn test_percentage_clamped() {
+        assert!((NumberUnit::Percent.to_base_clamped(50.0) - 0.5).abs() < 0.001);
+        assert!((NumberUnit::Percent.to_base_clamped(250.0) - 1.0).abs() < 0.001);
+        assert!(NumberUnit::Percent.to_base_clamped(-10.0).abs() < 0.001);
+        assert!((NumberUnit::Factor.to_base_clamped(1.5) - 1.0).abs() < 0.001);
+
+        assert!((NumberUnit::Percent.from_base_clamped(2.0) - 100.0).abs() < 0.001);
+        assert!(NumberUnit::Percent.from_base_clamped(-0.1).abs() < 0.001);
+        assert!(NumberUnit::Factor.from_base_clamped(-3.0).abs() < 0.001);
+
+        // Unclamped conversions are unchanged.
+        assert!((NumberUnit::Percent.to_base(250.0) - 2.5).abs() < 0.001);
+        assert!((NumberUnit::Celsius.to_base_clamped(-40.0) + 40.0).abs() < 0.001);
+    }
+
     // === Category Tests ===
 
     #[test]
@@ -430,6 +753,132 @@ mod tests {
         assert_eq!(NumberUnit::None.category(), "none");
     }
 
+    // === Formatting Tests ===
+
+    #[test]
+    fn test_format() {
+        assert_eq!(NumberUnit::Meters.format(1.5), "1.5 m");
+        assert_eq!(NumberUnit::Kilometers.format(-2.0), "-2 km");
+        assert_eq!(NumberUnit::Degrees.format(90.0), "90°");
+        assert_eq!(NumberUnit::Celsius.format(21.5), "21.5 °C");
+        assert_eq!(NumberUnit::Percent.format(12.5), "12.5%");
+        assert_eq!(NumberUnit::Factor.format(0.25), "0.25");
+    }
+
+    #[test]
+    fn test_format_with_precision() {
+        assert_eq!(NumberUnit::Meters.format_with_precision(1.0, 2), "1.00 m");
+        assert_eq!(
+            NumberUnit::Degrees.format_with_precision(45.678, 1),
+            "45.7°"
+        );
+        assert_eq!(NumberUnit::Hours.format_with_precision(1.5, 0), "2 h");
+    }
+
+    #[test]
+    fn test_format_auto() {
+        assert_eq!(NumberUnit::format_auto(0.0), "0 B");
+        assert_eq!(NumberUnit::format_auto(1023.0), "1023 B");
+        assert_eq!(NumberUnit::format_auto(1024.0), "1 KB");
+        assert_eq!(NumberUnit::format_auto(1_572_864.0), "1.5 MB");
+        assert_eq!(NumberUnit::format_auto(1_100_000_000.0), "1.02 GB");
+        assert_eq!(NumberUnit::format_auto(5.0 * 1024f64.powi(5)), "5120 TB");
+    }
+
+    // === Parsing Tests ===
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            NumberUnit::parse("150 cm"),
+            Some((150.0, NumberUnit::Centimeters))
+        );
+        assert_eq!(
+            NumberUnit::parse("  1.5m "),
+            Some((1.5, NumberUnit::Meters))
+        );
+        assert_eq!(
+            NumberUnit::parse("-40 °F"),
+            Some((-40.0, NumberUnit::Fahrenheit))
+        );
+        assert_eq!(NumberUnit::parse("90°"), Some((90.0, NumberUnit::Degrees)));
+        assert_eq!(NumberUnit::parse("25%"), Some((25.0, NumberUnit::Percent)));
+        assert_eq!(
+            NumberUnit::parse("1e3 ms"),
+            Some((1000.0, NumberUnit::Milliseconds))
+        );
+        assert_eq!(NumberUnit::parse("42"), Some((42.0, NumberUnit::None)));
+    }
+
+    #[test]
+    fn test_parse_case() {
+        assert_eq!(
+            NumberUnit::parse("2.5gb"),
+            Some((2.5, NumberUnit::Gigabytes))
+        );
+        assert_eq!(
+            NumberUnit::parse("10 MIN"),
+            Some((10.0, NumberUnit::Minutes))
+        );
+        assert_eq!(
+            NumberUnit::parse("5 Km"),
+            Some((5.0, NumberUnit::Kilometers))
+        );
+        // Single-letter suffixes are case-sensitive.
+        assert_eq!(
+            NumberUnit::parse("300 K"),
+            Some((300.0, NumberUnit::Kelvin))
+        );
+        assert_eq!(NumberUnit::parse("300 k"), None);
+        assert_eq!(NumberUnit::parse("8 b"), None);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(NumberUnit::parse(""), None);
+        assert_eq!(NumberUnit::parse("cm"), None);
+        assert_eq!(NumberUnit::parse("1.2.3 m"), None);
+        assert_eq!(NumberUnit::parse("3 parsecs"), None);
+    }
+
+    #[test]
+    fn test_parse_round_trips_format() {
+        for unit in NumberUnit::ALL {
+            let (value, parsed) = NumberUnit::parse(&unit.format(12.5)).unwrap();
+            assert!((value - 12.5).abs() < f64::EPSILON);
+            assert_eq!(parsed.display_suffix(), unit.display_suffix());
+        }
+    }
+
+    #[test]
+    fn test_units_in_category() {
+        assert_eq!(
+            NumberUnit::units_in_category("length"),
+            [
+                NumberUnit::Millimeters,
+                NumberUnit::Centimeters,
+                NumberUnit::Meters,
+                NumberUnit::Kilometers,
+                NumberUnit::Inches,
+                NumberUnit::Feet,
+                NumberUnit::Miles,
+            ]
+        );
+        assert!(NumberUnit::units_in_category("unknown").is_empty());
+
+        // Every unit belongs to exactly the slice of its own category.
+        let mut total = 0;
+        for unit in NumberUnit::all() {
+            let siblings = NumberUnit::units_in_category(unit.category());
+            assert!(siblings.contains(unit), "{unit:?}");
+            assert!(siblings.iter().all(|u| u.category() == unit.category()));
+            if siblings[0] == *unit {
+                total += siblings.len();
+            }
+        }
+        assert_eq!(total, NumberUnit::all().len());
+    }
+
     #[test]
     fn test_default() {
         assert_eq!(NumberUnit::default(), NumberUnit::Meters);
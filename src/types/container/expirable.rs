@@ -5,6 +5,7 @@
 
 use std::any::Any;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
 use crate::core::{Flags, Key, Metadata, SmartStr};
@@ -155,6 +156,10 @@ impl Node for Expirable {
         self.metadata.key()
     }
 
+    fn flags(&self) -> Flags {
+        self.flags
+    }
+
     fn kind(&self) -> NodeKind {
         NodeKind::Container
     }
@@ -166,6 +171,26 @@ impl Node for Expirable {
     fn as_any_mut(&mut self) -> &mut dyn Any {
         self
     }
+
+    fn child_nodes(&self) -> &[Arc<dyn Node>] {
+        Container::children(self)
+    }
+
+    #[cfg(feature = "serde")]
+    fn json_schema(&self) -> Option<serde_json::Value> {
+        self.child.as_ref().and_then(|child| child.json_schema())
+    }
+
+    fn hash_settings(&self, mut state: &mut dyn Hasher) {
+        let options = &self.options;
+        (
+            options.ttl,
+            options.auto_refresh,
+            options.auto_clear_expired,
+            options.warning_threshold,
+        )
+            .hash(&mut state);
+    }
 }
 
 impl Container for Expirable {
@@ -184,6 +209,8 @@ pub struct ExpirableBuilder {
     label: Option<SmartStr>,
     description: Option<SmartStr>,
     flags: Flags,
+    tags: Vec<Key>,
+    order: Option<i32>,
     child: Option<Arc<dyn Node>>,
     options: ExpirableOptions,
 }
@@ -195,6 +222,8 @@ impl fmt::Debug for ExpirableBuilder {
             .field("label", &self.label)
             .field("description", &self.description)
             .field("flags", &self.flags)
+            .field("tags", &self.tags)
+            .field("order", &self.order)
             .field("has_child", &self.child.is_some())
             .field("options", &self.options)
             .finish()
@@ -210,6 +239,8 @@ impl ExpirableBuilder {
             label: None,
             description: None,
             flags: Flags::empty(),
+            tags: Vec::new(),
+            order: None,
             child: None,
             options: ExpirableOptions::default(),
         }
@@ -229,6 +260,20 @@ impl ExpirableBuilder {
         self
     }
 
+    /// Adds a tag, such as `"advanced"`, for filtering and grouping.
+    #[must_use]
+    pub fn tag(mut self, tag: impl Into<Key>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Sets the layout order hint; lower values come first.
+    #[must_use]
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+
     /// Sets the flags.
     #[must_use]
     pub fn flags(mut self, flags: Flags) -> Self {
@@ -311,6 +356,12 @@ impl ExpirableBuilder {
         if let Some(description) = self.description {
             metadata = metadata.with_description(description);
         }
+        for tag in self.tags {
+            metadata = metadata.with_tag(tag);
+        }
+        if let Some(order) = self.order {
+            metadata = metadata.with_order(order);
+        }
 
         // Validate warning_threshold < ttl
         if let Some(threshold) = self.options.warning_threshold {
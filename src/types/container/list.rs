@@ -27,18 +27,22 @@
 
 use std::any::Any;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
-use crate::core::{Flags, Key, Metadata, SmartStr};
+use crate::core::{Error, Flags, Key, Metadata, Result, SmartStr, Value};
 use crate::types::kind::NodeKind;
 use crate::types::traits::{Container, Node};
 
+#[cfg(feature = "validation")]
+use crate::types::traits::Validatable;
+
 // =============================================================================
 // RankingConfig
 // =============================================================================
 
 /// Direction of ranking (which end is highest priority).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum RankDirection {
     /// First item = highest priority (rank 1).
     #[default]
@@ -234,6 +238,163 @@ impl List {
     pub fn ranking_config(&self) -> Option<&RankingConfig> {
         self.ranking.as_ref()
     }
+
+    /// Returns a copy of `array` with `item` inserted at `index`.
+    ///
+    /// Items at or after `index` shift right; `index` may equal the length
+    /// to append. `Null` is treated as an empty list.
+    ///
+    /// # Errors
+    ///
+    /// - Type mismatch if `array` is neither an array nor `Null`.
+    /// - Not found if `index` is greater than the length.
+    /// - `"too_many_items"` validation error if the list is already at
+    ///   [`max_items`](Self::max_items).
+    /// - `"duplicate_item"` validation error if the list is
+    ///   [unique](Self::is_unique) and already holds an equal item.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::core::Value;
+    /// use paramdef::types::container::List;
+    /// use paramdef::types::leaf::Text;
+    ///
+    /// let tags = List::builder("tags")
+    ///     .item_template(Text::builder("tag").build())
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let value = Value::array([Value::text("a"), Value::text("c")]);
+    /// let value = tags.insert_item(&value, 1, Value::text("b")).unwrap();
+    /// assert_eq!(
+    ///     value,
+    ///     Value::array([Value::text("a"), Value::text("b"), Value::text("c")])
+    /// );
+    /// ```
+    pub fn insert_item(&self, array: &Value, index: usize, item: Value) -> Result<Value> {
+        let items = Self::items(array)?;
+        if index > items.len() {
+            return Err(self.index_not_found(index));
+        }
+        if let Some(max) = self.max_items.filter(|max| items.len() >= *max) {
+            return Err(Error::validation_with_fields(
+                "too_many_items",
+                format!("list '{}' cannot have more than {max} items", self.key()),
+                vec![self.key().to_string()],
+            ));
+        }
+        if self.unique && items.iter().any(|existing| existing.numeric_eq(&item)) {
+            return Err(Error::validation_with_fields(
+                "duplicate_item",
+                format!("list '{}' already contains this item", self.key()),
+                vec![self.key().to_string()],
+            ));
+        }
+
+        let mut items = items.to_vec();
+        items.insert(index, item);
+        Ok(Value::array(items))
+    }
+
+    /// Returns a copy of `array` with the item at `index` removed.
+    ///
+    /// Items after `index` shift left. `Null` is treated as an empty list.
+    ///
+    /// # Errors
+    ///
+    /// - Type mismatch if `array` is neither an array nor `Null`.
+    /// - Not found if `index` is out of range.
+    /// - `"too_few_items"` validation error if the list is already at
+    ///   [`min_items`](Self::min_items).
+    pub fn remove_item(&self, array: &Value, index: usize) -> Result<Value> {
+        let items = Self::items(array)?;
+        if index >= items.len() {
+            return Err(self.index_not_found(index));
+        }
+        if let Some(min) = self.min_items.filter(|min| items.len() <= *min) {
+            return Err(Error::validation_with_fields(
+                "too_few_items",
+                format!("list '{}' must have at least {min} items", self.key()),
+                vec![self.key().to_string()],
+            ));
+        }
+
+        let mut items = items.to_vec();
+        items.remove(index);
+        Ok(Value::array(items))
+    }
+
+    /// Returns a copy of `array` with the item at `from` moved to `to`.
+    ///
+    /// Items between the two positions shift by one to close the gap, so
+    /// `to` is the item's index in the result. In a
+    /// [rankable](Self::is_rankable) list an item's priority is its
+    /// position, so the moved item takes the rank of `to`; no separate
+    /// priority data is stored or changed.
+    ///
+    /// # Errors
+    ///
+    /// - `"not_sortable"` validation error if the list is not
+    ///   [sortable](Self::is_sortable) or rankable.
+    /// - Type mismatch if `array` is neither an array nor `Null`.
+    /// - Not found if `from` or `to` is out of range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::core::Value;
+    /// use paramdef::types::container::List;
+    /// use paramdef::types::leaf::Text;
+    ///
+    /// let tasks = List::builder("tasks")
+    ///     .item_template(Text::builder("task").build())
+    ///     .rankable()
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let value = Value::array([Value::text("a"), Value::text("b"), Value::text("c")]);
+    /// let value = tasks.move_item(&value, 2, 0).unwrap();
+    /// assert_eq!(
+    ///     value,
+    ///     Value::array([Value::text("c"), Value::text("a"), Value::text("b")])
+    /// );
+    /// ```
+    pub fn move_item(&self, array: &Value, from: usize, to: usize) -> Result<Value> {
+        if !self.sortable && !self.is_rankable() {
+            return Err(Error::validation_with_fields(
+                "not_sortable",
+                format!("list '{}' cannot be reordered", self.key()),
+                vec![self.key().to_string()],
+            ));
+        }
+
+        let items = Self::items(array)?;
+        for index in [from, to] {
+            if index >= items.len() {
+                return Err(self.index_not_found(index));
+            }
+        }
+
+        let mut items = items.to_vec();
+        let item = items.remove(from);
+        items.insert(to, item);
+        Ok(Value::array(items))
+    }
+
+    /// Returns the items of a runtime list value, treating `Null` as empty.
+    fn items(array: &Value) -> Result<&[Value]> {
+        match array {
+            Value::Array(items) => Ok(items),
+            Value::Null => Ok(&[]),
+            other => Err(Error::type_mismatch("array", other.type_name())),
+        }
+    }
+
+    /// Returns the error for an item index outside the list.
+    fn index_not_found(&self, index: usize) -> Error {
+        Error::not_found(format!("{}[{index}]", self.key()))
+    }
 }
 
 impl Node for List {
@@ -245,6 +406,10 @@ impl Node for List {
         self.metadata.key()
     }
 
+    fn flags(&self) -> Flags {
+        self.flags
+    }
+
     fn kind(&self) -> NodeKind {
         NodeKind::Container
     }
@@ -256,6 +421,42 @@ impl Node for List {
     fn as_any_mut(&mut self) -> &mut dyn Any {
         self
     }
+
+    fn child_nodes(&self) -> &[Arc<dyn Node>] {
+        Container::children(self)
+    }
+
+    #[cfg(feature = "validation")]
+    fn validatable(&self) -> Option<&dyn Validatable> {
+        Some(self)
+    }
+
+    #[cfg(feature = "serde")]
+    fn json_schema(&self) -> Option<serde_json::Value> {
+        use crate::schema::json_schema::{annotated, child_schema};
+
+        let mut schema = serde_json::Map::new();
+        schema.insert("type".into(), "array".into());
+        schema.insert("items".into(), child_schema(self.item_template.as_ref()));
+        if let Some(min) = self.min_items {
+            schema.insert("minItems".into(), min.into());
+        }
+        if let Some(max) = self.max_items {
+            schema.insert("maxItems".into(), max.into());
+        }
+        if self.unique {
+            schema.insert("uniqueItems".into(), true.into());
+        }
+        Some(annotated(self, schema, None))
+    }
+
+    fn hash_settings(&self, mut state: &mut dyn Hasher) {
+        (self.min_items, self.max_items, self.unique, self.sortable).hash(&mut state);
+        self.ranking
+            .as_ref()
+            .map(|ranking| (ranking.show_numbers, ranking.direction))
+            .hash(&mut state);
+    }
 }
 
 impl Container for List {
@@ -264,6 +465,46 @@ impl Container for List {
     }
 }
 
+#[cfg(feature = "validation")]
+impl Validatable for List {
+    /// Validates the item count, uniqueness, and each item against the
+    /// item template.
+    ///
+    /// Item failures carry a field path starting with the item index, such
+    /// as `"3"` or `"3.name"`.
+    fn validate_sync(&self, value: &Value) -> Result<()> {
+        let items = match value {
+            Value::Null if self.flags.is_required() => {
+                return Err(Error::missing_required(self.key().as_str()));
+            }
+            Value::Null => return Ok(()),
+            other => Self::items(other)?,
+        };
+
+        let min = self.min_items.unwrap_or(0);
+        let max = self.max_items.unwrap_or(usize::MAX);
+        if items.len() < min || items.len() > max {
+            return Err(Error::length_out_of_bounds(items.len(), min, max));
+        }
+
+        for (index, item) in items.iter().enumerate() {
+            let segment = index.to_string();
+            if self.unique && items[..index].iter().any(|prev| prev.numeric_eq(item)) {
+                return Err(Error::validation_at(
+                    "duplicate_item",
+                    segment,
+                    format!("list '{}' already contains this item", self.key()),
+                ));
+            }
+            if let Some(template) = self.item_template.validatable() {
+                template.validate_sync(item).map_err(|e| e.at(&segment))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
 // =============================================================================
 // Builder
 // =============================================================================
@@ -274,6 +515,8 @@ pub struct ListBuilder {
     label: Option<SmartStr>,
     description: Option<SmartStr>,
     flags: Flags,
+    tags: Vec<Key>,
+    order: Option<i32>,
     item_template: Option<Arc<dyn Node>>,
     min_items: Option<usize>,
     max_items: Option<usize>,
@@ -289,6 +532,8 @@ impl fmt::Debug for ListBuilder {
             .field("label", &self.label)
             .field("description", &self.description)
             .field("flags", &self.flags)
+            .field("tags", &self.tags)
+            .field("order", &self.order)
             .field("has_template", &self.item_template.is_some())
             .field("min_items", &self.min_items)
             .field("max_items", &self.max_items)
@@ -308,6 +553,8 @@ impl ListBuilder {
             label: None,
             description: None,
             flags: Flags::empty(),
+            tags: Vec::new(),
+            order: None,
             item_template: None,
             min_items: None,
             max_items: None,
@@ -331,6 +578,20 @@ impl ListBuilder {
         self
     }
 
+    /// Adds a tag, such as `"advanced"`, for filtering and grouping.
+    #[must_use]
+    pub fn tag(mut self, tag: impl Into<Key>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Sets the layout order hint; lower values come first.
+    #[must_use]
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+
     /// Sets the flags for this list.
     #[must_use]
     pub fn flags(mut self, flags: Flags) -> Self {
@@ -443,6 +704,12 @@ impl ListBuilder {
         if let Some(description) = self.description {
             metadata = metadata.with_description(description);
         }
+        for tag in self.tags {
+            metadata = metadata.with_tag(tag);
+        }
+        if let Some(order) = self.order {
+            metadata = metadata.with_order(order);
+        }
 
         let item_template = self
             .item_template
@@ -642,6 +909,125 @@ mod tests {
         assert_eq!(list.min_items(), Some(3));
     }
 
+    #[test]
+    fn test_list_insert_item() {
+        let list = List::builder("tags")
+            .item_template(Text::builder("tag").build())
+            .max_items(3)
+            .unique(true)
+            .build()
+            .unwrap();
+
+        let value = list.insert_item(&Value::Null, 0, Value::text("a")).unwrap();
+        let value = list.insert_item(&value, 0, Value::text("b")).unwrap();
+        assert_eq!(value, Value::array([Value::text("b"), Value::text("a")]));
+
+        let err = list.insert_item(&value, 1, Value::text("a")).unwrap_err();
+        assert_eq!(err.code(), Some("duplicate_item"));
+        assert!(matches!(
+            list.insert_item(&value, 3, Value::text("c")),
+            Err(Error::NotFound { .. })
+        ));
+        assert!(matches!(
+            list.insert_item(&Value::Int(1), 0, Value::text("c")),
+            Err(Error::TypeMismatch { .. })
+        ));
+
+        let value = list.insert_item(&value, 2, Value::text("c")).unwrap();
+        let err = list.insert_item(&value, 0, Value::text("d")).unwrap_err();
+        assert_eq!(err.code(), Some("too_many_items"));
+        assert_eq!(err.fields(), ["tags"]);
+    }
+
+    #[test]
+    fn test_list_remove_item() {
+        let list = List::builder("tags")
+            .item_template(Text::builder("tag").build())
+            .min_items(1)
+            .build()
+            .unwrap();
+        let value = Value::array([Value::text("a"), Value::text("b")]);
+
+        let value = list.remove_item(&value, 0).unwrap();
+        assert_eq!(value, Value::array([Value::text("b")]));
+
+        let err = list.remove_item(&value, 0).unwrap_err();
+        assert_eq!(err.code(), Some("too_few_items"));
+        assert!(matches!(
+            list.remove_item(&value, 1),
+            Err(Error::NotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_list_move_item() {
+        let list = List::builder("tasks")
+            .item_template(Text::builder("task").build())
+            .sortable(true)
+            .build()
+            .unwrap();
+        let value = Value::array([Value::Int(0), Value::Int(1), Value::Int(2)]);
+
+        assert_eq!(
+            list.move_item(&value, 0, 2).unwrap(),
+            Value::array([Value::Int(1), Value::Int(2), Value::Int(0)])
+        );
+        assert_eq!(list.move_item(&value, 1, 1).unwrap(), value);
+        assert!(matches!(
+            list.move_item(&value, 0, 3),
+            Err(Error::NotFound { .. })
+        ));
+
+        let fixed = List::builder("fixed")
+            .item_template(Text::builder("item").build())
+            .build()
+            .unwrap();
+        let err = fixed.move_item(&value, 0, 1).unwrap_err();
+        assert_eq!(err.code(), Some("not_sortable"));
+    }
+
+    #[cfg(feature = "validation")]
+    #[test]
+    fn test_list_validate_items() {
+        let list = List::builder("tags")
+            .item_template(Text::builder("tag").max_length(3).build())
+            .max_items(3)
+            .unique(true)
+            .build()
+            .unwrap();
+
+        assert!(list.validate_sync(&Value::Null).is_ok());
+        assert!(
+            list.validate_sync(&Value::array([Value::text("a"), Value::text("b")]))
+                .is_ok()
+        );
+
+        let err = list
+            .validate_sync(&Value::array([Value::text("a"), Value::text("long")]))
+            .unwrap_err();
+        assert_eq!(err.code(), Some("length_out_of_bounds"));
+        assert_eq!(err.field_path(), Some("1"));
+
+        let err = list
+            .validate_sync(&Value::array([Value::text("a"), Value::text("a")]))
+            .unwrap_err();
+        assert_eq!(err.code(), Some("duplicate_item"));
+        assert_eq!(err.field_path(), Some("1"));
+
+        let four = Value::array(["a", "b", "c", "d"].map(Value::text));
+        assert!(matches!(
+            list.validate_sync(&four),
+            Err(Error::LengthOutOfBounds { .. })
+        ));
+
+        let bounded = List::builder("bounded")
+            .item_template(Text::builder("item").build())
+            .min_items(1)
+            .build()
+            .unwrap();
+        assert!(bounded.validate_sync(&Value::Null).is_ok());
+    }
+
     #[test]
     fn test_ranking_config_builder() {
         let config = RankingConfig::new()
@@ -6,6 +6,7 @@
 
 use std::any::Any;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
 use crate::core::{Flags, FxHashSet, Key, Metadata, SmartStr};
@@ -152,7 +153,7 @@ impl MatrixColumn {
 }
 
 /// Selection mode for matrix cells.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum MatrixCellType {
     /// Radio buttons - single selection per row (default).
     #[default]
@@ -367,6 +368,10 @@ impl Node for Matrix {
         self.metadata.key()
     }
 
+    fn flags(&self) -> Flags {
+        self.flags
+    }
+
     fn kind(&self) -> NodeKind {
         NodeKind::Container
     }
@@ -378,6 +383,34 @@ impl Node for Matrix {
     fn as_any_mut(&mut self) -> &mut dyn Any {
         self
     }
+
+    fn child_nodes(&self) -> &[Arc<dyn Node>] {
+        Container::children(self)
+    }
+
+    fn hash_settings(&self, mut state: &mut dyn Hasher) {
+        state.write_usize(self.rows.len());
+        for row in &self.rows {
+            (&row.key, &row.label, &row.description).hash(&mut state);
+        }
+        state.write_usize(self.columns.len());
+        for column in &self.columns {
+            (
+                &column.value,
+                &column.label,
+                column.weight,
+                column.exclusive,
+            )
+                .hash(&mut state);
+        }
+        (
+            self.cell_type,
+            self.all_rows_required,
+            self.show_row_numbers,
+            self.alternate_rows,
+        )
+            .hash(&mut state);
+    }
 }
 
 impl Container for Matrix {
@@ -399,6 +432,8 @@ pub struct MatrixBuilder {
     label: Option<SmartStr>,
     description: Option<SmartStr>,
     flags: Flags,
+    tags: Vec<Key>,
+    order: Option<i32>,
     rows: Vec<MatrixRow>,
     columns: Vec<MatrixColumn>,
     cell_type: MatrixCellType,
@@ -416,6 +451,8 @@ impl MatrixBuilder {
             label: None,
             description: None,
             flags: Flags::empty(),
+            tags: Vec::new(),
+            order: None,
             rows: Vec::new(),
             columns: Vec::new(),
             cell_type: MatrixCellType::default(),
@@ -439,6 +476,20 @@ impl MatrixBuilder {
         self
     }
 
+    /// Adds a tag, such as `"advanced"`, for filtering and grouping.
+    #[must_use]
+    pub fn tag(mut self, tag: impl Into<Key>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Sets the layout order hint; lower values come first.
+    #[must_use]
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+
     /// Sets the flags for this matrix.
     #[must_use]
     pub fn flags(mut self, flags: Flags) -> Self {
@@ -667,6 +718,12 @@ impl MatrixBuilder {
         if let Some(description) = self.description {
             metadata = metadata.with_description(description);
         }
+        for tag in self.tags {
+            metadata = metadata.with_tag(tag);
+        }
+        if let Some(order) = self.order {
+            metadata = metadata.with_order(order);
+        }
 
         Ok(Matrix {
             metadata,
@@ -5,12 +5,21 @@
 
 use std::any::Any;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
-use crate::core::{Flags, FxHashSet, Key, Metadata, SmartStr};
+use crate::core::{Flags, FxHashSet, Key, Metadata, SmartStr, Value};
 use crate::types::kind::NodeKind;
 use crate::types::traits::{Container, Node};
 
+#[cfg(feature = "validation")]
+use crate::core::Error;
+#[cfg(feature = "validation")]
+use crate::types::traits::Validatable;
+
+/// Field names of the `{ mode, value }` output object.
+const DISCRIMINATOR_KEYS: [&str; 2] = ["mode", "value"];
+
 /// A variant in a Mode container.
 ///
 /// Each variant has a key, label, and content node.
@@ -168,6 +177,10 @@ impl Node for Mode {
         self.metadata.key()
     }
 
+    fn flags(&self) -> Flags {
+        self.flags
+    }
+
     fn kind(&self) -> NodeKind {
         NodeKind::Container
     }
@@ -179,6 +192,61 @@ impl Node for Mode {
     fn as_any_mut(&mut self) -> &mut dyn Any {
         self
     }
+
+    fn child_nodes(&self) -> &[Arc<dyn Node>] {
+        Container::children(self)
+    }
+
+    fn initial_value(&self) -> Option<Value> {
+        let variant = self.default_variant.as_ref()?;
+        Some(Value::object([("mode", Value::text(variant.as_str()))]))
+    }
+
+    #[cfg(feature = "serde")]
+    fn json_schema(&self) -> Option<serde_json::Value> {
+        use crate::schema::json_schema::{annotated, child_schema};
+
+        let variants = self
+            .variants
+            .iter()
+            .map(|variant| {
+                let mut schema = serde_json::json!({
+                    "type": "object",
+                    "title": variant.label.as_str(),
+                    "properties": {
+                        "mode": { "const": variant.key.as_str() },
+                        "value": child_schema(variant.content.as_ref()),
+                    },
+                    "required": ["mode", "value"],
+                });
+                if let Some(description) = &variant.description {
+                    schema["description"] = description.as_str().into();
+                }
+                schema
+            })
+            .collect();
+
+        let mut schema = serde_json::Map::new();
+        schema.insert("oneOf".into(), serde_json::Value::Array(variants));
+        schema.insert(
+            "discriminator".into(),
+            serde_json::json!({ "propertyName": "mode" }),
+        );
+        Some(annotated(self, schema, None))
+    }
+
+    #[cfg(feature = "validation")]
+    fn validatable(&self) -> Option<&dyn Validatable> {
+        Some(self)
+    }
+
+    fn hash_settings(&self, mut state: &mut dyn Hasher) {
+        state.write_usize(self.variants.len());
+        for variant in &self.variants {
+            (&variant.key, &variant.label, &variant.description).hash(&mut state);
+        }
+        self.default_variant.hash(&mut state);
+    }
 }
 
 impl Container for Mode {
@@ -187,6 +255,39 @@ impl Container for Mode {
     }
 }
 
+#[cfg(feature = "validation")]
+impl Validatable for Mode {
+    /// Validates the selected variant and its value.
+    ///
+    /// An unknown variant fails at the `"mode"` field path; content failures
+    /// carry a path starting with `"value"`, such as `"value.token"`.
+    fn validate_sync(&self, value: &Value) -> crate::core::Result<()> {
+        let fields = match value {
+            Value::Null if self.flags.is_required() => {
+                return Err(Error::missing_required(self.key().as_str()));
+            }
+            Value::Null => return Ok(()),
+            Value::Object(fields) => fields,
+            other => return Err(Error::type_mismatch("object", other.type_name())),
+        };
+
+        let variant = match fields.get("mode") {
+            Some(Value::Text(mode)) => self
+                .get_variant(mode)
+                .ok_or_else(|| Error::not_in_allowed_values(mode.as_str()).at("mode"))?,
+            Some(other) => return Err(Error::type_mismatch("text", other.type_name()).at("mode")),
+            None => return Err(Error::missing_required("mode").at("mode")),
+        };
+
+        match variant.content.validatable() {
+            Some(content) => content
+                .validate_sync(fields.get("value").unwrap_or(&Value::Null))
+                .map_err(|e| e.at("value")),
+            None => Ok(()),
+        }
+    }
+}
+
 // =============================================================================
 // Builder
 // =============================================================================
@@ -198,6 +299,8 @@ pub struct ModeBuilder {
     label: Option<SmartStr>,
     description: Option<SmartStr>,
     flags: Flags,
+    tags: Vec<Key>,
+    order: Option<i32>,
     variants: Vec<ModeVariant>,
     default_variant: Option<Key>,
 }
@@ -211,6 +314,8 @@ impl ModeBuilder {
             label: None,
             description: None,
             flags: Flags::empty(),
+            tags: Vec::new(),
+            order: None,
             variants: Vec::new(),
             default_variant: None,
         }
@@ -230,6 +335,20 @@ impl ModeBuilder {
         self
     }
 
+    /// Adds a tag, such as `"advanced"`, for filtering and grouping.
+    #[must_use]
+    pub fn tag(mut self, tag: impl Into<Key>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Sets the layout order hint; lower values come first.
+    #[must_use]
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+
     /// Sets the flags for this mode.
     #[must_use]
     pub fn flags(mut self, flags: Flags) -> Self {
@@ -288,6 +407,10 @@ impl ModeBuilder {
     /// Returns an error if:
     /// - No variants were added
     /// - Duplicate variant keys exist
+    /// - Two variants share a content key (`"duplicate_key"`)
+    /// - A variant's content key, or a key of one of its direct children,
+    ///   is the mode's own key or a discriminator field name (`"mode"`,
+    ///   `"value"`); reported as `"reserved_key"`
     /// - `default_variant` references a non-existent variant key
     pub fn build(self) -> crate::core::Result<Mode> {
         if self.variants.is_empty() {
@@ -305,6 +428,28 @@ impl ModeBuilder {
             }
         }
 
+        // Check variant contents for shared or reserved keys
+        let mut seen_content_keys = FxHashSet::default();
+        for variant in &self.variants {
+            let content_key = variant.content.key();
+            if !seen_content_keys.insert(content_key) {
+                return Err(crate::core::Error::validation(
+                    "duplicate_key",
+                    format!("duplicate variant content key: {content_key}"),
+                ));
+            }
+            let inner_keys = std::iter::once(content_key)
+                .chain(variant.content.child_nodes().iter().map(|c| c.key()));
+            for key in inner_keys {
+                if *key == self.key || DISCRIMINATOR_KEYS.contains(&key.as_str()) {
+                    return Err(crate::core::Error::validation(
+                        "reserved_key",
+                        format!("variant '{}' uses reserved key: {key}", variant.key),
+                    ));
+                }
+            }
+        }
+
         // Validate default_variant references an existing key
         if let Some(ref default) = self.default_variant {
             if !self.variants.iter().any(|v| &v.key == default) {
@@ -321,6 +466,12 @@ impl ModeBuilder {
         if let Some(description) = self.description {
             metadata = metadata.with_description(description);
         }
+        for tag in self.tags {
+            metadata = metadata.with_tag(tag);
+        }
+        if let Some(order) = self.order {
+            metadata = metadata.with_order(order);
+        }
 
         // Build children cache from variant contents
         let children_cache: Arc<[Arc<dyn Node>]> = self
@@ -461,6 +612,44 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_mode_duplicate_content_keys() {
+        let err = Mode::builder("mode")
+            .variant("a", "A", Object::empty("shared"))
+            .variant("b", "B", Object::empty("shared"))
+            .build()
+            .unwrap_err();
+        assert_eq!(err.code(), Some("duplicate_key"));
+    }
+
+    #[test]
+    fn test_mode_reserved_content_keys() {
+        let err = Mode::builder("auth")
+            .variant("a", "A", Object::empty("auth"))
+            .build()
+            .unwrap_err();
+        assert_eq!(err.code(), Some("reserved_key"));
+
+        let err = Mode::builder("auth")
+            .variant("a", "A", Text::builder("value").build())
+            .build()
+            .unwrap_err();
+        assert_eq!(err.code(), Some("reserved_key"));
+
+        let err = Mode::builder("auth")
+            .variant(
+                "a",
+                "A",
+                Object::builder("a")
+                    .field("mode", Text::builder("mode").build())
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap_err();
+        assert_eq!(err.code(), Some("reserved_key"));
+    }
+
     #[test]
     fn test_mode_invalid_default_variant() {
         let result = Mode::builder("mode")
@@ -480,4 +669,51 @@ mod tests {
 
         assert_eq!(mode.children().len(), 2);
     }
+
+    #[cfg(feature = "validation")]
+    #[test]
+    fn test_mode_validate() {
+        let mode = Mode::builder("auth")
+            .variant("none", "No Auth", Object::empty("none"))
+            .variant(
+                "bearer",
+                "Bearer",
+                Object::builder("bearer")
+                    .field("token", Text::builder("token").required().build())
+                    .build()
+                    .unwrap(),
+            )
+            .required()
+            .build()
+            .unwrap();
+        assert!(mode.validatable().is_some());
+
+        let value = |mode: &str, value: Value| {
+            Value::object([("mode", Value::text(mode)), ("value", value)])
+        };
+        assert!(mode.validate_sync(&value("none", Value::Null)).is_ok());
+        assert!(
+            mode.validate_sync(&value(
+                "bearer",
+                Value::object([("token", Value::text("abc"))])
+            ))
+            .is_ok()
+        );
+
+        let err = mode
+            .validate_sync(&value("bearer", Value::object([("other", Value::Int(1))])))
+            .unwrap_err();
+        assert_eq!(err.field_path(), Some("value.token"));
+
+        let err = mode
+            .validate_sync(&value("oauth", Value::Null))
+            .unwrap_err();
+        assert_eq!(err.code(), Some("not_in_allowed_values"));
+        assert_eq!(err.field_path(), Some("mode"));
+
+        assert!(matches!(
+            mode.validate_sync(&Value::Null),
+            Err(Error::MissingRequired { .. })
+        ));
+    }
 }
@@ -26,12 +26,18 @@
 
 use std::any::Any;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
-use crate::core::{Flags, FxHashSet, Key, Metadata, SmartStr};
+use crate::core::{Flags, FxHashSet, IndexMap, Key, Metadata, SmartStr, Value};
 use crate::types::kind::NodeKind;
 use crate::types::traits::{Container, Node};
 
+#[cfg(feature = "validation")]
+use crate::core::Error;
+#[cfg(feature = "validation")]
+use crate::types::traits::Validatable;
+
 // =============================================================================
 // ExtensibleConfig
 // =============================================================================
@@ -267,6 +273,44 @@ impl Object {
     pub fn extensible_config(&self) -> Option<&ExtensibleConfig> {
         self.extensible.as_ref()
     }
+
+    /// Builds a fully-shaped value with every fixed field present.
+    ///
+    /// Each field maps to its [`initial_value`](Node::initial_value) (a
+    /// leaf's default, or a `Mode`'s default variant), or `Null` if it has
+    /// none. Nested objects recurse. Extensible extra properties are
+    /// omitted.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::core::Value;
+    /// use paramdef::types::container::Object;
+    /// use paramdef::types::leaf::{Number, Text};
+    ///
+    /// let address = Object::builder("address")
+    ///     .field("city", Text::builder("city").build())
+    ///     .field("zip", Number::builder("zip").default(75001.0).build())
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(
+    ///     address.empty_value(),
+    ///     Value::object([("city", Value::Null), ("zip", Value::Float(75001.0))])
+    /// );
+    /// ```
+    #[must_use]
+    pub fn empty_value(&self) -> Value {
+        let mut map = IndexMap::with_capacity(self.fields.len());
+        for (key, node) in &self.fields {
+            let value = match node.as_any().downcast_ref::<Object>() {
+                Some(object) => object.empty_value(),
+                None => node.initial_value().unwrap_or(Value::Null),
+            };
+            map.insert(key.clone(), value);
+        }
+        Value::Object(Arc::new(map))
+    }
 }
 
 impl Node for Object {
@@ -278,6 +322,10 @@ impl Node for Object {
         self.metadata.key()
     }
 
+    fn flags(&self) -> Flags {
+        self.flags
+    }
+
     fn kind(&self) -> NodeKind {
         NodeKind::Container
     }
@@ -289,6 +337,68 @@ impl Node for Object {
     fn as_any_mut(&mut self) -> &mut dyn Any {
         self
     }
+
+    fn child_nodes(&self) -> &[Arc<dyn Node>] {
+        Container::children(self)
+    }
+
+    #[cfg(feature = "validation")]
+    fn validatable(&self) -> Option<&dyn Validatable> {
+        Some(self)
+    }
+
+    #[cfg(feature = "serde")]
+    fn json_schema(&self) -> Option<serde_json::Value> {
+        use crate::schema::json_schema::{annotated, child_schema, object_schema};
+
+        let mut schema = object_schema(self.fields.iter().map(|(k, n)| (k, n)));
+        match &self.extensible {
+            Some(config) => {
+                schema.insert(
+                    "additionalProperties".into(),
+                    child_schema(config.value_template.as_ref()),
+                );
+                if let Some(pattern) = &config.key_pattern {
+                    schema.insert(
+                        "propertyNames".into(),
+                        serde_json::json!({ "pattern": pattern.as_str() }),
+                    );
+                }
+                if let Some(min) = config.min_properties {
+                    schema.insert("minProperties".into(), min.into());
+                }
+                if let Some(max) = config.max_properties {
+                    schema.insert("maxProperties".into(), max.into());
+                }
+            }
+            None => {
+                schema.insert("additionalProperties".into(), false.into());
+            }
+        }
+        Some(annotated(self, schema, None))
+    }
+
+    fn hash_settings(&self, mut state: &mut dyn Hasher) {
+        state.write_usize(self.fields.len());
+        for (key, _) in &self.fields {
+            key.hash(&mut state);
+        }
+        // The value template is not a child node, so hash it here.
+        if let Some(config) = &self.extensible {
+            state.write_u8(1);
+            let template = config.value_template.as_ref();
+            (template.key(), template.kind(), template.flags()).hash(&mut state);
+            template.hash_settings(state);
+            (
+                &config.key_pattern,
+                config.min_properties,
+                config.max_properties,
+            )
+                .hash(&mut state);
+        } else {
+            state.write_u8(0);
+        }
+    }
 }
 
 impl Container for Object {
@@ -297,6 +407,34 @@ impl Container for Object {
     }
 }
 
+#[cfg(feature = "validation")]
+impl Validatable for Object {
+    /// Validates each fixed field against its node.
+    ///
+    /// Missing fields are validated as `Null`. Field failures carry a field
+    /// path starting with the field key, such as `"city"` or
+    /// `"address.city"`. Extensible extra properties are not checked.
+    fn validate_sync(&self, value: &Value) -> crate::core::Result<()> {
+        let fields = match value {
+            Value::Null if self.flags.is_required() => {
+                return Err(Error::missing_required(self.key().as_str()));
+            }
+            Value::Null => return Ok(()),
+            Value::Object(fields) => fields,
+            other => return Err(Error::type_mismatch("object", other.type_name())),
+        };
+
+        for (key, node) in &self.fields {
+            if let Some(node) = node.validatable() {
+                let value = fields.get(key).unwrap_or(&Value::Null);
+                node.validate_sync(value).map_err(|e| e.at(key))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
 // =============================================================================
 // Builder
 // =============================================================================
@@ -308,6 +446,8 @@ pub struct ObjectBuilder {
     label: Option<SmartStr>,
     description: Option<SmartStr>,
     flags: Flags,
+    tags: Vec<Key>,
+    order: Option<i32>,
     fields: Vec<(Key, Arc<dyn Node>)>,
     extensible: Option<ExtensibleConfig>,
 }
@@ -319,6 +459,8 @@ impl fmt::Debug for ObjectBuilder {
             .field("label", &self.label)
             .field("description", &self.description)
             .field("flags", &self.flags)
+            .field("tags", &self.tags)
+            .field("order", &self.order)
             .field("field_count", &self.fields.len())
             .field("extensible", &self.extensible.is_some())
             .finish()
@@ -334,6 +476,8 @@ impl ObjectBuilder {
             label: None,
             description: None,
             flags: Flags::empty(),
+            tags: Vec::new(),
+            order: None,
             fields: Vec::new(),
             extensible: None,
         }
@@ -353,6 +497,20 @@ impl ObjectBuilder {
         self
     }
 
+    /// Adds a tag, such as `"advanced"`, for filtering and grouping.
+    #[must_use]
+    pub fn tag(mut self, tag: impl Into<Key>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Sets the layout order hint; lower values come first.
+    #[must_use]
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+
     /// Sets the flags for this object.
     #[must_use]
     pub fn flags(mut self, flags: Flags) -> Self {
@@ -446,6 +604,12 @@ impl ObjectBuilder {
         if let Some(description) = self.description {
             metadata = metadata.with_description(description);
         }
+        for tag in self.tags {
+            metadata = metadata.with_tag(tag);
+        }
+        if let Some(order) = self.order {
+            metadata = metadata.with_order(order);
+        }
 
         // Build children cache
         let children_cache: Arc<[Arc<dyn Node>]> = self
@@ -575,6 +739,82 @@ mod tests {
         assert_eq!(obj.children().len(), 2);
     }
 
+    #[test]
+    fn test_object_empty_value() {
+        use crate::types::leaf::Boolean;
+
+        let address = Object::builder("address")
+            .field("city", Text::builder("city").default("Paris").build())
+            .build()
+            .unwrap();
+        let obj = Object::builder("user")
+            .field("name", Text::builder("name").build())
+            .field("active", Boolean::builder("active").default(true).build())
+            .field("address", address)
+            .extensible(Text::builder("extra").build())
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            obj.empty_value(),
+            Value::object([
+                ("name", Value::Null),
+                ("active", Value::Bool(true)),
+                ("address", Value::object([("city", Value::text("Paris"))])),
+            ])
+        );
+        assert_eq!(
+            Object::empty("e").empty_value(),
+            Value::object(Vec::<(Key, Value)>::new())
+        );
+    }
+
+    #[cfg(feature = "validation")]
+    #[test]
+    fn test_object_validate_nested_path() {
+        use crate::types::container::List;
+
+        let address = Object::builder("address")
+            .field("city", Text::builder("city").required().build())
+            .build()
+            .unwrap();
+        let obj = Object::builder("user")
+            .field("address", address.clone())
+            .field(
+                "addresses",
+                List::builder("addresses")
+                    .item_template(address)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap();
+
+        let city = |c: &str| Value::object([("city", Value::text(c))]);
+        let ok = Value::object([
+            ("address", city("Paris")),
+            ("addresses", Value::array([city("Oslo")])),
+        ]);
+        assert!(obj.validate_sync(&ok).is_ok());
+
+        let err = obj
+            .validate_sync(&Value::object([(
+                "address",
+                Value::object([("x", Value::Null)]),
+            )]))
+            .unwrap_err();
+        assert_eq!(err.code(), Some("required"));
+        assert_eq!(err.field_path(), Some("address.city"));
+
+        let bad = Value::object([
+            ("address", city("Paris")),
+            ("addresses", Value::array([city("Oslo"), Value::Int(1)])),
+        ]);
+        let err = obj.validate_sync(&bad).unwrap_err();
+        assert_eq!(err.code(), Some("type_mismatch"));
+        assert_eq!(err.field_path(), Some("addresses.1"));
+    }
+
     #[test]
     fn test_object_duplicate_key_error() {
         let result = Object::builder("config")
@@ -4,6 +4,7 @@
 //! allowing structure reuse without duplication.
 
 use std::any::Any;
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
 use crate::core::{Flags, Key, Metadata, SmartStr};
@@ -76,6 +77,10 @@ impl Node for Reference {
         self.metadata.key()
     }
 
+    fn flags(&self) -> Flags {
+        self.flags
+    }
+
     fn kind(&self) -> NodeKind {
         NodeKind::Container
     }
@@ -87,6 +92,14 @@ impl Node for Reference {
     fn as_any_mut(&mut self) -> &mut dyn Any {
         self
     }
+
+    fn child_nodes(&self) -> &[Arc<dyn Node>] {
+        Container::children(self)
+    }
+
+    fn hash_settings(&self, mut state: &mut dyn Hasher) {
+        self.target.hash(&mut state);
+    }
 }
 
 impl Container for Reference {
@@ -109,6 +122,8 @@ pub struct ReferenceBuilder {
     label: Option<SmartStr>,
     description: Option<SmartStr>,
     flags: Flags,
+    tags: Vec<Key>,
+    order: Option<i32>,
     target: Option<Key>,
 }
 
@@ -121,6 +136,8 @@ impl ReferenceBuilder {
             label: None,
             description: None,
             flags: Flags::empty(),
+            tags: Vec::new(),
+            order: None,
             target: None,
         }
     }
@@ -139,6 +156,20 @@ impl ReferenceBuilder {
         self
     }
 
+    /// Adds a tag, such as `"advanced"`, for filtering and grouping.
+    #[must_use]
+    pub fn tag(mut self, tag: impl Into<Key>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Sets the layout order hint; lower values come first.
+    #[must_use]
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+
     /// Sets the flags.
     #[must_use]
     pub fn flags(mut self, flags: Flags) -> Self {
@@ -166,6 +197,12 @@ impl ReferenceBuilder {
         if let Some(description) = self.description {
             metadata = metadata.with_description(description);
         }
+        for tag in self.tags {
+            metadata = metadata.with_tag(tag);
+        }
+        if let Some(order) = self.order {
+            metadata = metadata.with_order(order);
+        }
 
         let target = self
             .target
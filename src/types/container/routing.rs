@@ -5,6 +5,7 @@
 
 use std::any::Any;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
 use crate::core::{Flags, Key, Metadata, SmartStr};
@@ -20,6 +21,8 @@ pub struct RoutingOptions {
     pub connection_required: bool,
     /// Maximum number of connections (None = unlimited).
     pub max_connections: Option<usize>,
+    /// Port types this connection point accepts (empty = any type).
+    pub accepted_types: Vec<SmartStr>,
 }
 
 impl RoutingOptions {
@@ -49,6 +52,14 @@ impl RoutingOptions {
         self.max_connections = Some(max);
         self
     }
+
+    /// Adds port types this connection point accepts.
+    #[must_use]
+    pub fn accepts(mut self, types: &[&str]) -> Self {
+        self.accepted_types
+            .extend(types.iter().copied().map(SmartStr::from));
+        self
+    }
 }
 
 /// A container for workflow connections.
@@ -118,6 +129,31 @@ impl Routing {
     pub fn options(&self) -> &RoutingOptions {
         &self.options
     }
+
+    /// Returns `true` if a port of type `other_port_type` may connect here.
+    ///
+    /// Port types are plain strings such as `"number"` or `"image"`. A
+    /// routing that declares no [accepted types](RoutingBuilder::accepts)
+    /// accepts every type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::types::container::Routing;
+    ///
+    /// let input = Routing::builder("amount")
+    ///     .accepts(&["number", "integer"])
+    ///     .build();
+    ///
+    /// assert!(input.can_connect("number"));
+    /// assert!(!input.can_connect("string"));
+    /// assert!(Routing::builder("any").build().can_connect("string"));
+    /// ```
+    #[must_use]
+    pub fn can_connect(&self, other_port_type: &str) -> bool {
+        let accepted = &self.options.accepted_types;
+        accepted.is_empty() || accepted.iter().any(|t| t.as_str() == other_port_type)
+    }
 }
 
 impl Node for Routing {
@@ -129,6 +165,10 @@ impl Node for Routing {
         self.metadata.key()
     }
 
+    fn flags(&self) -> Flags {
+        self.flags
+    }
+
     fn kind(&self) -> NodeKind {
         NodeKind::Container
     }
@@ -140,6 +180,26 @@ impl Node for Routing {
     fn as_any_mut(&mut self) -> &mut dyn Any {
         self
     }
+
+    fn child_nodes(&self) -> &[Arc<dyn Node>] {
+        Container::children(self)
+    }
+
+    #[cfg(feature = "serde")]
+    fn json_schema(&self) -> Option<serde_json::Value> {
+        self.child.as_ref().and_then(|child| child.json_schema())
+    }
+
+    fn hash_settings(&self, mut state: &mut dyn Hasher) {
+        let options = &self.options;
+        (
+            &options.connection_label,
+            options.connection_required,
+            options.max_connections,
+            &options.accepted_types,
+        )
+            .hash(&mut state);
+    }
 }
 
 impl Container for Routing {
@@ -158,6 +218,8 @@ pub struct RoutingBuilder {
     label: Option<SmartStr>,
     description: Option<SmartStr>,
     flags: Flags,
+    tags: Vec<Key>,
+    order: Option<i32>,
     child: Option<Arc<dyn Node>>,
     options: RoutingOptions,
 }
@@ -169,6 +231,8 @@ impl fmt::Debug for RoutingBuilder {
             .field("label", &self.label)
             .field("description", &self.description)
             .field("flags", &self.flags)
+            .field("tags", &self.tags)
+            .field("order", &self.order)
             .field("has_child", &self.child.is_some())
             .field("options", &self.options)
             .finish()
@@ -184,6 +248,8 @@ impl RoutingBuilder {
             label: None,
             description: None,
             flags: Flags::empty(),
+            tags: Vec::new(),
+            order: None,
             child: None,
             options: RoutingOptions::default(),
         }
@@ -203,6 +269,20 @@ impl RoutingBuilder {
         self
     }
 
+    /// Adds a tag, such as `"advanced"`, for filtering and grouping.
+    #[must_use]
+    pub fn tag(mut self, tag: impl Into<Key>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Sets the layout order hint; lower values come first.
+    #[must_use]
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+
     /// Sets the flags.
     #[must_use]
     pub fn flags(mut self, flags: Flags) -> Self {
@@ -238,6 +318,15 @@ impl RoutingBuilder {
         self
     }
 
+    /// Adds port types this connection point accepts.
+    ///
+    /// See [`Routing::can_connect`].
+    #[must_use]
+    pub fn accepts(mut self, types: &[&str]) -> Self {
+        self.options = self.options.accepts(types);
+        self
+    }
+
     /// Builds the Routing container.
     #[must_use]
     pub fn build(self) -> Routing {
@@ -248,6 +337,12 @@ impl RoutingBuilder {
         if let Some(description) = self.description {
             metadata = metadata.with_description(description);
         }
+        for tag in self.tags {
+            metadata = metadata.with_tag(tag);
+        }
+        if let Some(order) = self.order {
+            metadata = metadata.with_order(order);
+        }
 
         // Build children cache
         let children_cache: Arc<[Arc<dyn Node>]> = match &self.child {
@@ -299,6 +394,21 @@ mod tests {
         assert_eq!(routing.options().max_connections, Some(1));
     }
 
+    #[test]
+    fn test_routing_port_types() {
+        let routing = Routing::builder("input")
+            .accepts(&["number"])
+            .accepts(&["integer"])
+            .build();
+
+        assert_eq!(routing.options().accepted_types, ["number", "integer"]);
+        assert!(routing.can_connect("integer"));
+        assert!(!routing.can_connect("string"));
+
+        let options = RoutingOptions::new().accepts(&["image"]);
+        assert_eq!(options.accepted_types, ["image"]);
+    }
+
     #[test]
     fn test_routing_with_child() {
         let routing = Routing::builder("input")
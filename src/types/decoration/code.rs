@@ -3,8 +3,9 @@
 //! Code displays syntax-highlighted code examples.
 
 use std::any::Any;
+use std::hash::{Hash, Hasher};
 
-use crate::core::{Flags, Key, Metadata};
+use crate::core::{Error, Flags, Key, Metadata, Result};
 use crate::types::kind::NodeKind;
 use crate::types::traits::{Decoration, Node};
 
@@ -28,7 +29,14 @@ use crate::types::traits::{Decoration, Node};
 /// let curl = Code::builder("curl_example")
 ///     .language("bash")
 ///     .code("curl -X GET https://api.example.com/v1/data")
-///     .show_line_numbers(true)
+///     .line_numbers(true)
+///     .build();
+///
+/// // Error location with a highlighted line
+/// let trace = Code::builder("error_location")
+///     .language("rust")
+///     .code("let x = 1;\nlet y = x / 0;")
+///     .highlight_lines([2])
 ///     .build();
 ///
 /// // Collapsible code block
@@ -45,7 +53,8 @@ pub struct Code {
     content: String,
     language: String,
     show_line_numbers: bool,
-    highlight_lines: Vec<usize>,
+    /// 1-based line numbers, sorted and deduplicated.
+    highlight_lines: Vec<u32>,
     collapsible: bool,
 }
 
@@ -98,12 +107,18 @@ impl Code {
         self.show_line_numbers
     }
 
-    /// Returns the highlighted line numbers.
+    /// Returns the highlighted 1-based line numbers in ascending order.
     #[must_use]
-    pub fn highlight_lines(&self) -> &[usize] {
+    pub fn highlight_lines(&self) -> &[u32] {
         &self.highlight_lines
     }
 
+    /// Returns `true` if the given 1-based line is highlighted.
+    #[must_use]
+    pub fn is_highlighted(&self, line: u32) -> bool {
+        self.highlight_lines.binary_search(&line).is_ok()
+    }
+
     /// Returns whether the code block is collapsible.
     #[must_use]
     pub fn is_collapsible(&self) -> bool {
@@ -120,6 +135,10 @@ impl Node for Code {
         self.metadata.key()
     }
 
+    fn flags(&self) -> Flags {
+        self.flags
+    }
+
     fn kind(&self) -> NodeKind {
         NodeKind::Decoration
     }
@@ -131,6 +150,11 @@ impl Node for Code {
     fn as_any_mut(&mut self) -> &mut dyn Any {
         self
     }
+
+    fn hash_settings(&self, mut state: &mut dyn Hasher) {
+        (&self.content, &self.language, self.show_line_numbers).hash(&mut state);
+        (&self.highlight_lines, self.collapsible).hash(&mut state);
+    }
 }
 
 impl Decoration for Code {}
@@ -144,10 +168,12 @@ impl Decoration for Code {}
 pub struct CodeBuilder {
     key: Key,
     flags: Flags,
+    tags: Vec<Key>,
+    order: Option<i32>,
     content: String,
     language: String,
     show_line_numbers: bool,
-    highlight_lines: Vec<usize>,
+    highlight_lines: Vec<u32>,
     collapsible: bool,
 }
 
@@ -158,6 +184,8 @@ impl CodeBuilder {
         Self {
             key: key.into(),
             flags: Flags::empty(),
+            tags: Vec::new(),
+            order: None,
             content: String::new(),
             language: String::new(),
             show_line_numbers: false,
@@ -166,6 +194,20 @@ impl CodeBuilder {
         }
     }
 
+    /// Adds a tag, such as `"advanced"`, for filtering and grouping.
+    #[must_use]
+    pub fn tag(mut self, tag: impl Into<Key>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Sets the layout order hint; lower values come first.
+    #[must_use]
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+
     /// Sets the flags.
     #[must_use]
     pub fn flags(mut self, flags: Flags) -> Self {
@@ -189,15 +231,25 @@ impl CodeBuilder {
 
     /// Sets whether to show line numbers.
     #[must_use]
-    pub fn show_line_numbers(mut self, show: bool) -> Self {
+    pub fn line_numbers(mut self, show: bool) -> Self {
         self.show_line_numbers = show;
         self
     }
 
-    /// Sets the lines to highlight.
+    /// Sets whether to show line numbers.
+    ///
+    /// Same as [`line_numbers`](Self::line_numbers).
+    #[must_use]
+    pub fn show_line_numbers(self, show: bool) -> Self {
+        self.line_numbers(show)
+    }
+
+    /// Adds 1-based line numbers to highlight.
+    ///
+    /// Lines may be given in any order and more than once.
     #[must_use]
-    pub fn highlight_lines(mut self, lines: Vec<usize>) -> Self {
-        self.highlight_lines = lines;
+    pub fn highlight_lines(mut self, lines: impl IntoIterator<Item = u32>) -> Self {
+        self.highlight_lines.extend(lines);
         self
     }
 
@@ -209,17 +261,54 @@ impl CodeBuilder {
     }
 
     /// Builds the Code block.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a highlighted line is `0`. Use
+    /// [`try_build`](Self::try_build) to handle this as an error.
     #[must_use]
     pub fn build(self) -> Code {
-        Code {
-            metadata: Metadata::new(self.key),
+        match self.try_build() {
+            Ok(code) => code,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Builds the Code block, reporting invalid highlight lines as errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `"invalid_highlight_line"` validation error if a
+    /// highlighted line is `0`; line numbers are 1-based.
+    pub fn try_build(mut self) -> Result<Code> {
+        if self.highlight_lines.contains(&0) {
+            return Err(Error::validation_with_fields(
+                "invalid_highlight_line",
+                "highlighted line numbers are 1-based and must not be 0",
+                vec![self.key.to_string()],
+            ));
+        }
+
+        self.highlight_lines.sort_unstable();
+        self.highlight_lines.dedup();
+
+        let mut metadata = Metadata::new(self.key);
+        for tag in self.tags {
+            metadata = metadata.with_tag(tag);
+        }
+        if let Some(order) = self.order {
+            metadata = metadata.with_order(order);
+        }
+
+        Ok(Code {
+            metadata,
             flags: self.flags,
             content: self.content,
             language: self.language,
             show_line_numbers: self.show_line_numbers,
             highlight_lines: self.highlight_lines,
             collapsible: self.collapsible,
-        }
+        })
     }
 }
 
@@ -272,6 +361,38 @@ mod tests {
         assert!(code.is_collapsible());
     }
 
+    #[test]
+    fn test_code_highlight_lines_sorted_deduped() {
+        let code = Code::builder("diff")
+            .code("a\nb\nc\nd")
+            .line_numbers(true)
+            .highlight_lines([4, 2])
+            .highlight_lines(vec![2, 1])
+            .build();
+
+        assert!(code.show_line_numbers());
+        assert_eq!(code.highlight_lines(), &[1, 2, 4]);
+        assert!(code.is_highlighted(2));
+        assert!(!code.is_highlighted(3));
+    }
+
+    #[test]
+    fn test_code_highlight_line_zero() {
+        let err = Code::builder("bad")
+            .highlight_lines([0, 1])
+            .try_build()
+            .unwrap_err();
+
+        assert_eq!(err.code(), Some("invalid_highlight_line"));
+        assert_eq!(err.fields(), ["bad"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "1-based")]
+    fn test_code_highlight_line_zero_panics() {
+        let _ = Code::builder("bad").highlight_lines([0]).build();
+    }
+
     #[test]
     fn test_code_kind() {
         let code = Code::json("test", "{}");
@@ -4,7 +4,9 @@
 //! complex formatting, embedded widgets, or custom rendering.
 
 use std::any::Any;
+use std::hash::{Hash, Hasher};
 
+use super::sanitize;
 use crate::core::{Flags, Key, Metadata, SmartStr};
 use crate::types::kind::NodeKind;
 use crate::types::traits::{Decoration, Node};
@@ -12,23 +14,24 @@ use crate::types::traits::{Decoration, Node};
 /// HTML sanitization level for security.
 ///
 /// Controls what HTML tags and attributes are allowed.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum SanitizeLevel {
     /// No sanitization - trust content completely.
     /// Only use for trusted, internal content.
     None,
 
-    /// Basic sanitization - allow common formatting tags.
-    /// Removes scripts, iframes, and dangerous attributes.
+    /// Basic sanitization - allow inline formatting tags only.
+    /// Removes scripts, iframes, block elements, links, images, and all
+    /// attributes.
     #[default]
     Basic,
 
-    /// Strict sanitization - only allow safe inline formatting.
-    /// Removes all block elements, links, and images.
+    /// Strict sanitization - strip all tags, leaving only text.
     Strict,
 
     /// Custom sanitization - defer to UI implementation.
-    /// The UI layer decides what to allow based on context.
+    /// The UI layer decides what to allow based on context;
+    /// [`Html::sanitized`] falls back to [`SanitizeLevel::Basic`].
     Custom,
 }
 
@@ -117,6 +120,43 @@ impl Html {
         self.sanitize
     }
 
+    /// Returns the content sanitized according to the sanitization level.
+    ///
+    /// - [`SanitizeLevel::None`] returns the content as-is.
+    /// - [`SanitizeLevel::Basic`] and [`SanitizeLevel::Custom`] keep inline
+    ///   formatting tags (`<b>`, `<em>`, `<code>`, ...) with all attributes
+    ///   removed, and drop every other tag.
+    /// - [`SanitizeLevel::Strict`] strips all tags, leaving only text.
+    ///
+    /// Elements such as `<script>`, `<style>`, and `<iframe>` are removed
+    /// together with their contents, and comments are dropped. Entities are
+    /// left untouched, so the result is still HTML.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::types::decoration::{Html, SanitizeLevel};
+    ///
+    /// let html = Html::new("intro", "<p onclick='x()'>Hi <b>there</b><script>x()</script></p>");
+    /// assert_eq!(html.sanitized(), "Hi <b>there</b>");
+    ///
+    /// let strict = Html::builder("intro")
+    ///     .content(html.content())
+    ///     .sanitize(SanitizeLevel::Strict)
+    ///     .build();
+    /// assert_eq!(strict.sanitized(), "Hi there");
+    /// ```
+    #[must_use]
+    pub fn sanitized(&self) -> String {
+        match self.sanitize {
+            SanitizeLevel::None => self.content.to_string(),
+            SanitizeLevel::Basic | SanitizeLevel::Custom => {
+                sanitize::sanitize(&self.content, sanitize::INLINE_TAGS)
+            }
+            SanitizeLevel::Strict => sanitize::sanitize(&self.content, &[]),
+        }
+    }
+
     /// Returns the optional CSS class.
     #[must_use]
     pub fn css_class(&self) -> Option<&str> {
@@ -139,6 +179,10 @@ impl Node for Html {
         self.metadata.key()
     }
 
+    fn flags(&self) -> Flags {
+        self.flags
+    }
+
     fn kind(&self) -> NodeKind {
         NodeKind::Decoration
     }
@@ -150,6 +194,10 @@ impl Node for Html {
     fn as_any_mut(&mut self) -> &mut dyn Any {
         self
     }
+
+    fn hash_settings(&self, mut state: &mut dyn Hasher) {
+        (&self.content, self.sanitize, &self.css_class, self.inline).hash(&mut state);
+    }
 }
 
 impl Decoration for Html {}
@@ -165,6 +213,8 @@ pub struct HtmlBuilder {
     label: Option<SmartStr>,
     description: Option<SmartStr>,
     flags: Flags,
+    tags: Vec<Key>,
+    order: Option<i32>,
     content: SmartStr,
     sanitize: SanitizeLevel,
     css_class: Option<SmartStr>,
@@ -180,6 +230,8 @@ impl HtmlBuilder {
             label: None,
             description: None,
             flags: Flags::empty(),
+            tags: Vec::new(),
+            order: None,
             content: SmartStr::new(),
             sanitize: SanitizeLevel::default(),
             css_class: None,
@@ -201,6 +253,20 @@ impl HtmlBuilder {
         self
     }
 
+    /// Adds a tag, such as `"advanced"`, for filtering and grouping.
+    #[must_use]
+    pub fn tag(mut self, tag: impl Into<Key>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Sets the layout order hint; lower values come first.
+    #[must_use]
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+
     /// Sets the flags.
     #[must_use]
     pub fn flags(mut self, flags: Flags) -> Self {
@@ -246,6 +312,12 @@ impl HtmlBuilder {
         if let Some(description) = self.description {
             metadata = metadata.with_description(description);
         }
+        for tag in self.tags {
+            metadata = metadata.with_tag(tag);
+        }
+        if let Some(order) = self.order {
+            metadata = metadata.with_order(order);
+        }
 
         Html {
             metadata,
@@ -314,6 +386,24 @@ mod tests {
         assert!(html.content().contains("<script>"));
     }
 
+    #[test]
+    fn test_html_sanitized_levels() {
+        let content = "<div>Read <a href='/x' onmouseover=\"x()\">this</a> <em>now</em></div>\
+                       <script>steal()</script>";
+        let html = |level| {
+            Html::builder("h")
+                .content(content)
+                .sanitize(level)
+                .build()
+                .sanitized()
+        };
+
+        assert_eq!(html(SanitizeLevel::None), content);
+        assert_eq!(html(SanitizeLevel::Basic), "Read this <em>now</em>");
+        assert_eq!(html(SanitizeLevel::Custom), "Read this <em>now</em>");
+        assert_eq!(html(SanitizeLevel::Strict), "Read this now");
+    }
+
     #[test]
     fn test_html_kind() {
         let html = Html::new("test", "");
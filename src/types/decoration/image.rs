@@ -1,16 +1,18 @@
 //! Image decoration for static image display.
 //!
-//! Image displays static images from various sources.
+//! Image displays static images from various sources, with alt text for
+//! screen readers and an optional responsive `srcset`.
 
 use std::any::Any;
+use std::hash::{Hash, Hasher};
 use std::path::PathBuf;
 
-use crate::core::{Flags, Key, Metadata};
+use crate::core::{Flags, Key, Metadata, SmartStr};
 use crate::types::kind::NodeKind;
 use crate::types::traits::{Decoration, Node};
 
 /// The source of an image.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum ImageSource {
     /// URL to an image.
     Url(String),
@@ -69,6 +71,9 @@ impl ImageAlignment {
 /// Image displays an image from a URL, Base64 data, or file path.
 /// It has no value and cannot contain children.
 ///
+/// Every image should have alt text; [`Image::has_alt`] lets accessibility
+/// checks flag images built without it.
+///
 /// # Example
 ///
 /// ```ignore
@@ -77,7 +82,11 @@ impl ImageAlignment {
 /// // Image from URL
 /// let screenshot = Image::builder("step1")
 ///     .source(ImageSource::url("https://example.com/step1.png"))
-///     .alt_text("Click the connect button")
+///     .alt("Click the connect button")
+///     .srcset([
+///         ("https://example.com/step1-480.png".into(), 480),
+///         ("https://example.com/step1-960.png".into(), 960),
+///     ])
 ///     .width(400)
 ///     .alignment(ImageAlignment::Center)
 ///     .build();
@@ -93,6 +102,7 @@ pub struct Image {
     flags: Flags,
     source: ImageSource,
     alt_text: String,
+    srcset: Vec<(SmartStr, u32)>,
     width: Option<u32>,
     height: Option<u32>,
     alignment: ImageAlignment,
@@ -130,11 +140,52 @@ impl Image {
     }
 
     /// Returns the alt text.
+    ///
+    /// Empty if none was set; see [`Image::alt`].
     #[must_use]
     pub fn alt_text(&self) -> &str {
         &self.alt_text
     }
 
+    /// Returns the alt text, or `None` if it was not set.
+    #[must_use]
+    pub fn alt(&self) -> Option<&str> {
+        (!self.alt_text.is_empty()).then_some(self.alt_text.as_str())
+    }
+
+    /// Returns whether the image has alt text.
+    ///
+    /// Images without alt text are invisible to screen readers and should
+    /// be reported by accessibility checks.
+    #[must_use]
+    pub fn has_alt(&self) -> bool {
+        !self.alt_text.is_empty()
+    }
+
+    /// Returns the responsive `(url, width)` candidates, in the order added.
+    #[must_use]
+    pub fn srcset(&self) -> &[(SmartStr, u32)] {
+        &self.srcset
+    }
+
+    /// Formats the candidates as an HTML `srcset` attribute value, such as
+    /// `"a.png 480w, b.png 960w"`.
+    ///
+    /// Returns `None` if no candidates were added.
+    #[must_use]
+    pub fn srcset_attribute(&self) -> Option<String> {
+        if self.srcset.is_empty() {
+            return None;
+        }
+
+        let entries: Vec<String> = self
+            .srcset
+            .iter()
+            .map(|(url, width)| format!("{url} {width}w"))
+            .collect();
+        Some(entries.join(", "))
+    }
+
     /// Returns the width, if specified.
     #[must_use]
     pub fn width(&self) -> Option<u32> {
@@ -163,6 +214,10 @@ impl Node for Image {
         self.metadata.key()
     }
 
+    fn flags(&self) -> Flags {
+        self.flags
+    }
+
     fn kind(&self) -> NodeKind {
         NodeKind::Decoration
     }
@@ -174,6 +229,11 @@ impl Node for Image {
     fn as_any_mut(&mut self) -> &mut dyn Any {
         self
     }
+
+    fn hash_settings(&self, mut state: &mut dyn Hasher) {
+        (&self.source, &self.alt_text, &self.srcset).hash(&mut state);
+        (self.width, self.height, self.alignment).hash(&mut state);
+    }
 }
 
 impl Decoration for Image {}
@@ -187,8 +247,11 @@ impl Decoration for Image {}
 pub struct ImageBuilder {
     key: Key,
     flags: Flags,
+    tags: Vec<Key>,
+    order: Option<i32>,
     source: Option<ImageSource>,
     alt_text: String,
+    srcset: Vec<(SmartStr, u32)>,
     width: Option<u32>,
     height: Option<u32>,
     alignment: ImageAlignment,
@@ -204,14 +267,31 @@ impl ImageBuilder {
         Self {
             key: key.into(),
             flags: Flags::empty(),
+            tags: Vec::new(),
+            order: None,
             source: None,
             alt_text: String::new(),
+            srcset: Vec::new(),
             width: None,
             height: None,
             alignment: ImageAlignment::Center,
         }
     }
 
+    /// Adds a tag, such as `"advanced"`, for filtering and grouping.
+    #[must_use]
+    pub fn tag(mut self, tag: impl Into<Key>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Sets the layout order hint; lower values come first.
+    #[must_use]
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+
     /// Sets the flags.
     #[must_use]
     pub fn flags(mut self, flags: Flags) -> Self {
@@ -233,6 +313,21 @@ impl ImageBuilder {
         self
     }
 
+    /// Sets the alt text read by screen readers.
+    ///
+    /// Same as [`ImageBuilder::alt_text`].
+    #[must_use]
+    pub fn alt(self, text: impl Into<String>) -> Self {
+        self.alt_text(text)
+    }
+
+    /// Adds responsive `(url, width)` candidates, with widths in pixels.
+    #[must_use]
+    pub fn srcset(mut self, entries: impl IntoIterator<Item = (SmartStr, u32)>) -> Self {
+        self.srcset.extend(entries);
+        self
+    }
+
     /// Sets the width in pixels.
     #[must_use]
     pub fn width(mut self, width: u32) -> Self {
@@ -272,11 +367,20 @@ impl ImageBuilder {
             .source
             .ok_or_else(|| crate::core::Error::missing_required("source"))?;
 
+        let mut metadata = Metadata::new(self.key);
+        for tag in self.tags {
+            metadata = metadata.with_tag(tag);
+        }
+        if let Some(order) = self.order {
+            metadata = metadata.with_order(order);
+        }
+
         Ok(Image {
-            metadata: Metadata::new(self.key),
+            metadata,
             flags: self.flags,
             source,
             alt_text: self.alt_text,
+            srcset: self.srcset,
             width: self.width,
             height: self.height,
             alignment: self.alignment,
@@ -310,6 +414,36 @@ mod tests {
         assert!(image.height().is_none());
     }
 
+    #[test]
+    fn test_image_alt() {
+        let image = Image::from_url("img", "#").alt("A chart").build().unwrap();
+        assert!(image.has_alt());
+        assert_eq!(image.alt(), Some("A chart"));
+
+        let image = Image::from_url("img", "#").build().unwrap();
+        assert!(!image.has_alt());
+        assert_eq!(image.alt(), None);
+    }
+
+    #[test]
+    fn test_image_srcset() {
+        let image = Image::from_url("img", "small.png")
+            .srcset([("small.png".into(), 480), ("large.png".into(), 960)])
+            .build()
+            .unwrap();
+
+        assert_eq!(image.srcset().len(), 2);
+        assert_eq!(image.srcset()[1], ("large.png".into(), 960));
+        assert_eq!(
+            image.srcset_attribute().as_deref(),
+            Some("small.png 480w, large.png 960w")
+        );
+
+        let plain = Image::from_url("img", "#").build().unwrap();
+        assert!(plain.srcset().is_empty());
+        assert_eq!(plain.srcset_attribute(), None);
+    }
+
     #[test]
     fn test_image_from_path() {
         let image = Image::from_path("logo", "./assets/logo.png")
@@ -0,0 +1,222 @@
+//! Key-value list decoration for labeled summaries.
+//!
+//! `KeyValueList` displays labeled values as a definition list, such as a
+//! summary of computed results.
+
+use std::any::Any;
+use std::hash::{Hash, Hasher};
+
+use crate::core::{Flags, Key, Metadata, SmartStr, Value};
+use crate::types::kind::NodeKind;
+use crate::types::traits::{Decoration, Node};
+
+/// A labeled key-value display decoration.
+///
+/// Holds ordered `(label, value)` pairs that renderers show as a
+/// two-column definition list. Values stay structured, so they can be
+/// formatted per locale. It has no value and cannot contain children.
+///
+/// # Example
+///
+/// ```
+/// use paramdef::core::Value;
+/// use paramdef::types::decoration::KeyValueList;
+///
+/// let summary = KeyValueList::builder("summary")
+///     .pair("Items", Value::Int(3))
+///     .pair("Total", Value::Float(42.5))
+///     .build();
+///
+/// assert_eq!(summary.len(), 2);
+/// assert_eq!(summary.get("Total"), Some(&Value::Float(42.5)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct KeyValueList {
+    metadata: Metadata,
+    flags: Flags,
+    pairs: Vec<(SmartStr, Value)>,
+}
+
+impl KeyValueList {
+    /// Creates a new builder for a `KeyValueList`.
+    #[must_use]
+    pub fn builder(key: impl Into<Key>) -> KeyValueListBuilder {
+        KeyValueListBuilder::new(key)
+    }
+
+    /// Returns the flags for this list.
+    #[must_use]
+    pub fn flags(&self) -> Flags {
+        self.flags
+    }
+
+    /// Returns the `(label, value)` pairs in display order.
+    #[must_use]
+    pub fn pairs(&self) -> &[(SmartStr, Value)] {
+        &self.pairs
+    }
+
+    /// Returns the value of the first pair with the given label.
+    #[must_use]
+    pub fn get(&self, label: &str) -> Option<&Value> {
+        self.pairs
+            .iter()
+            .find(|(l, _)| l.as_str() == label)
+            .map(|(_, value)| value)
+    }
+
+    /// Returns the number of pairs.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.pairs.len()
+    }
+
+    /// Returns `true` if the list has no pairs.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.pairs.is_empty()
+    }
+}
+
+impl Node for KeyValueList {
+    fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    fn key(&self) -> &Key {
+        self.metadata.key()
+    }
+
+    fn flags(&self) -> Flags {
+        self.flags
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::Decoration
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn hash_settings(&self, mut state: &mut dyn Hasher) {
+        state.write_usize(self.pairs.len());
+        for (label, value) in &self.pairs {
+            (label, value.content_hash()).hash(&mut state);
+        }
+    }
+}
+
+impl Decoration for KeyValueList {}
+
+// =============================================================================
+// Builder
+// =============================================================================
+
+/// Builder for [`KeyValueList`].
+#[derive(Debug)]
+pub struct KeyValueListBuilder {
+    key: Key,
+    flags: Flags,
+    tags: Vec<Key>,
+    order: Option<i32>,
+    pairs: Vec<(SmartStr, Value)>,
+}
+
+impl KeyValueListBuilder {
+    /// Creates a new builder with the given key.
+    #[must_use]
+    pub fn new(key: impl Into<Key>) -> Self {
+        Self {
+            key: key.into(),
+            flags: Flags::empty(),
+            tags: Vec::new(),
+            order: None,
+            pairs: Vec::new(),
+        }
+    }
+
+    /// Adds a tag, such as `"advanced"`, for filtering and grouping.
+    #[must_use]
+    pub fn tag(mut self, tag: impl Into<Key>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Sets the layout order hint; lower values come first.
+    #[must_use]
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the flags.
+    #[must_use]
+    pub fn flags(mut self, flags: Flags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Appends a labeled value.
+    #[must_use]
+    pub fn pair(mut self, label: impl Into<SmartStr>, value: impl Into<Value>) -> Self {
+        self.pairs.push((label.into(), value.into()));
+        self
+    }
+
+    /// Builds the `KeyValueList`.
+    #[must_use]
+    pub fn build(self) -> KeyValueList {
+        let mut metadata = Metadata::new(self.key);
+        for tag in self.tags {
+            metadata = metadata.with_tag(tag);
+        }
+        if let Some(order) = self.order {
+            metadata = metadata.with_order(order);
+        }
+
+        KeyValueList {
+            metadata,
+            flags: self.flags,
+            pairs: self.pairs,
+        }
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_value_list_builder() {
+        let list = KeyValueList::builder("summary")
+            .pair("Name", "Alice")
+            .pair("Age", 30)
+            .pair("Name", "Bob")
+            .build();
+
+        assert_eq!(list.key().as_str(), "summary");
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.pairs()[1], ("Age".into(), Value::Int(30)));
+        assert_eq!(list.get("Name"), Some(&Value::text("Alice")));
+        assert_eq!(list.get("Missing"), None);
+    }
+
+    #[test]
+    fn test_key_value_list_invariants() {
+        let list = KeyValueList::builder("empty").build();
+
+        assert!(list.is_empty());
+        assert_eq!(list.kind(), NodeKind::Decoration);
+        assert!(!list.kind().has_own_value());
+        assert!(!list.kind().can_have_children());
+    }
+}
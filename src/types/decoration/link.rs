@@ -3,6 +3,7 @@
 //! Link provides clickable references to documentation, tutorials, or external resources.
 
 use std::any::Any;
+use std::hash::{Hash, Hasher};
 
 use crate::core::{Flags, Key, Metadata, SmartStr};
 use crate::types::kind::{LinkType, NodeKind};
@@ -118,6 +119,58 @@ impl Link {
     pub fn open_in_new_tab(&self) -> bool {
         self.open_in_new_tab
     }
+
+    /// Returns whether the URL points to a host other than `current_host`.
+    ///
+    /// Hosts are compared case-insensitively, ignoring ports and user info.
+    /// Relative URLs and URLs without a host (such as `mailto:`) are not
+    /// external.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::types::decoration::Link;
+    ///
+    /// let link = Link::builder("repo")
+    ///     .url("https://github.com/example/repo")
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// assert!(link.is_external("docs.example.com"));
+    /// assert!(!link.is_external("GitHub.com"));
+    /// ```
+    #[must_use]
+    pub fn is_external(&self, current_host: &str) -> bool {
+        url_host(&self.url).is_some_and(|host| !host.eq_ignore_ascii_case(strip_port(current_host)))
+    }
+
+    /// Returns the `rel` attribute a renderer should emit, if any.
+    ///
+    /// External links opened in a new tab get `"noopener noreferrer"` so
+    /// the target page cannot reach back through `window.opener`.
+    #[must_use]
+    pub fn rel(&self, current_host: &str) -> Option<&'static str> {
+        (self.open_in_new_tab && self.is_external(current_host)).then_some("noopener noreferrer")
+    }
+}
+
+/// Extracts the host from an absolute or protocol-relative URL.
+fn url_host(url: &str) -> Option<&str> {
+    let rest = match url.find("://") {
+        Some(pos) => &url[pos + 3..],
+        None => url.strip_prefix("//")?,
+    };
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host = strip_port(authority.rsplit('@').next().unwrap_or_default());
+    (!host.is_empty()).then_some(host)
+}
+
+/// Removes a trailing `:port`, leaving bracketed IPv6 addresses intact.
+fn strip_port(host: &str) -> &str {
+    match host.rfind(':') {
+        Some(pos) if !host[pos..].contains(']') => &host[..pos],
+        _ => host,
+    }
 }
 
 impl Node for Link {
@@ -129,6 +182,10 @@ impl Node for Link {
         self.metadata.key()
     }
 
+    fn flags(&self) -> Flags {
+        self.flags
+    }
+
     fn kind(&self) -> NodeKind {
         NodeKind::Decoration
     }
@@ -140,6 +197,10 @@ impl Node for Link {
     fn as_any_mut(&mut self) -> &mut dyn Any {
         self
     }
+
+    fn hash_settings(&self, mut state: &mut dyn Hasher) {
+        (&self.text, &self.url, self.kind, self.open_in_new_tab).hash(&mut state);
+    }
 }
 
 impl Decoration for Link {}
@@ -153,6 +214,8 @@ impl Decoration for Link {}
 pub struct LinkBuilder {
     key: Key,
     flags: Flags,
+    tags: Vec<Key>,
+    order: Option<i32>,
     text: SmartStr,
     url: Option<SmartStr>,
     kind: LinkType,
@@ -166,6 +229,8 @@ impl LinkBuilder {
         Self {
             key: key.into(),
             flags: Flags::empty(),
+            tags: Vec::new(),
+            order: None,
             text: SmartStr::new(),
             url: None,
             kind: LinkType::Documentation,
@@ -173,6 +238,20 @@ impl LinkBuilder {
         }
     }
 
+    /// Adds a tag, such as `"advanced"`, for filtering and grouping.
+    #[must_use]
+    pub fn tag(mut self, tag: impl Into<Key>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Sets the layout order hint; lower values come first.
+    #[must_use]
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+
     /// Sets the flags.
     #[must_use]
     pub fn flags(mut self, flags: Flags) -> Self {
@@ -202,6 +281,8 @@ impl LinkBuilder {
     }
 
     /// Sets whether the link opens in a new tab.
+    ///
+    /// See [`Link::rel`] for the matching `rel` attribute.
     #[must_use]
     pub fn open_in_new_tab(mut self, open_in_new_tab: bool) -> Self {
         self.open_in_new_tab = open_in_new_tab;
@@ -218,8 +299,16 @@ impl LinkBuilder {
             .url
             .ok_or_else(|| crate::core::Error::missing_required("url"))?;
 
+        let mut metadata = Metadata::new(self.key);
+        for tag in self.tags {
+            metadata = metadata.with_tag(tag);
+        }
+        if let Some(order) = self.order {
+            metadata = metadata.with_order(order);
+        }
+
         Ok(Link {
-            metadata: Metadata::new(self.key),
+            metadata,
             flags: self.flags,
             text: self.text,
             url,
@@ -307,6 +396,37 @@ mod tests {
         assert!(link.open_in_new_tab());
     }
 
+    #[test]
+    fn test_link_is_external() {
+        let link = |url: &str| Link::builder("l").url(url).build().unwrap();
+
+        assert!(link("https://github.com/x").is_external("example.com"));
+        assert!(link("//cdn.example.net/x.js").is_external("example.com"));
+        assert!(!link("https://Example.com:8443/docs").is_external("example.com"));
+        assert!(!link("https://user@example.com?q=1").is_external("example.com:80"));
+        assert!(!link("/docs/intro").is_external("example.com"));
+        assert!(!link("#section").is_external("example.com"));
+        assert!(!link("mailto:team@example.org").is_external("example.com"));
+        assert!(link("http://[::1]:8080/").is_external("example.com"));
+        assert!(!link("http://[::1]:8080/").is_external("[::1]"));
+    }
+
+    #[test]
+    fn test_link_rel() {
+        let external = Link::external("gh", "GitHub")
+            .url("https://github.com")
+            .build()
+            .unwrap();
+        assert_eq!(external.rel("example.com"), Some("noopener noreferrer"));
+        assert_eq!(external.rel("github.com"), None);
+
+        let same_tab = Link::documentation("docs", "Docs")
+            .url("https://github.com")
+            .build()
+            .unwrap();
+        assert_eq!(same_tab.rel("example.com"), None);
+    }
+
     #[test]
     fn test_link_kind() {
         let link = Link::documentation("test", "Test")
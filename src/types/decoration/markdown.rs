@@ -0,0 +1,313 @@
+//! Markdown decoration for authored help text.
+//!
+//! Markdown keeps its source text so renderers can format it on the
+//! client. With the `markdown` feature it can also be rendered to HTML or
+//! reduced to plain text on the server.
+
+use std::any::Any;
+use std::hash::{Hash, Hasher};
+
+use crate::core::{Flags, Key, Metadata, SmartStr};
+use crate::types::kind::NodeKind;
+use crate::types::traits::{Decoration, Node};
+
+/// A display-only Markdown decoration.
+///
+/// Markdown suits multi-paragraph guidance that a
+/// [`Notice`](super::Notice) cannot format. It has no value and cannot
+/// contain children.
+///
+/// # Example
+///
+/// ```
+/// use paramdef::types::decoration::Markdown;
+///
+/// let help = Markdown::new("help", "## Setup\n\nRun `init` **first**.");
+/// assert!(help.source().starts_with("## Setup"));
+///
+/// # #[cfg(feature = "markdown")]
+/// # {
+/// assert_eq!(help.plain_text(), "Setup\nRun init first.");
+/// assert!(help.rendered_html().contains("<strong>first</strong>"));
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Markdown {
+    metadata: Metadata,
+    flags: Flags,
+    source: SmartStr,
+}
+
+impl Markdown {
+    /// Creates a new builder for a Markdown decoration.
+    #[must_use]
+    pub fn builder(key: impl Into<Key>) -> MarkdownBuilder {
+        MarkdownBuilder::new(key)
+    }
+
+    /// Creates a Markdown decoration with the given source.
+    #[must_use]
+    pub fn new(key: impl Into<Key>, source: impl Into<SmartStr>) -> Self {
+        Self::builder(key).source(source).build()
+    }
+
+    /// Returns the flags for this Markdown decoration.
+    #[must_use]
+    pub fn flags(&self) -> Flags {
+        self.flags
+    }
+
+    /// Returns the Markdown source.
+    #[must_use]
+    pub fn source(&self) -> &str {
+        self.source.as_str()
+    }
+
+    /// Renders the source to HTML.
+    ///
+    /// Supports `CommonMark` plus tables and strikethrough. Raw HTML in the
+    /// source is escaped rather than passed through. Link URLs are kept
+    /// as written, so sanitize the output if the source is untrusted.
+    #[cfg(feature = "markdown")]
+    #[must_use]
+    pub fn rendered_html(&self) -> String {
+        use pulldown_cmark::{Event, html};
+
+        let events = parse(&self.source).map(|event| match event {
+            Event::Html(raw) | Event::InlineHtml(raw) => Event::Text(raw),
+            other => other,
+        });
+        let mut out = String::with_capacity(self.source.len() * 3 / 2);
+        html::push_html(&mut out, events);
+        out
+    }
+
+    /// Returns the text of the source without formatting.
+    ///
+    /// Markup, link targets, and raw HTML are dropped. Each block, such as
+    /// a paragraph, heading, or list item, ends with a newline; soft line
+    /// breaks become spaces. Useful for screen readers and search indexes.
+    #[cfg(feature = "markdown")]
+    #[must_use]
+    pub fn plain_text(&self) -> String {
+        use pulldown_cmark::{Event, TagEnd};
+
+        let mut out = String::with_capacity(self.source.len());
+        for event in parse(&self.source) {
+            match event {
+                Event::Text(text) | Event::Code(text) => out.push_str(&text),
+                Event::SoftBreak | Event::End(TagEnd::TableCell) => out.push(' '),
+                Event::HardBreak => out.push('\n'),
+                Event::End(
+                    TagEnd::Paragraph
+                    | TagEnd::Heading(_)
+                    | TagEnd::CodeBlock
+                    | TagEnd::Item
+                    | TagEnd::TableHead
+                    | TagEnd::TableRow,
+                ) => {
+                    let trimmed = out.trim_end_matches(' ').len();
+                    out.truncate(trimmed);
+                    if !out.is_empty() && !out.ends_with('\n') {
+                        out.push('\n');
+                    }
+                }
+                _ => {}
+            }
+        }
+        out.truncate(out.trim_end().len());
+        out
+    }
+}
+
+/// Parses `source` with the extensions Markdown decorations support.
+#[cfg(feature = "markdown")]
+fn parse(source: &str) -> pulldown_cmark::Parser<'_> {
+    use pulldown_cmark::{Options, Parser};
+
+    Parser::new_ext(
+        source,
+        Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH,
+    )
+}
+
+impl Node for Markdown {
+    fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    fn key(&self) -> &Key {
+        self.metadata.key()
+    }
+
+    fn flags(&self) -> Flags {
+        self.flags
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::Decoration
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn hash_settings(&self, mut state: &mut dyn Hasher) {
+        self.source.hash(&mut state);
+    }
+}
+
+impl Decoration for Markdown {}
+
+// =============================================================================
+// Builder
+// =============================================================================
+
+/// Builder for [`Markdown`].
+#[derive(Debug)]
+pub struct MarkdownBuilder {
+    key: Key,
+    label: Option<SmartStr>,
+    description: Option<SmartStr>,
+    flags: Flags,
+    tags: Vec<Key>,
+    order: Option<i32>,
+    source: SmartStr,
+}
+
+impl MarkdownBuilder {
+    /// Creates a new builder with the given key.
+    #[must_use]
+    pub fn new(key: impl Into<Key>) -> Self {
+        Self {
+            key: key.into(),
+            label: None,
+            description: None,
+            flags: Flags::empty(),
+            tags: Vec::new(),
+            order: None,
+            source: SmartStr::new(),
+        }
+    }
+
+    /// Sets the label.
+    #[must_use]
+    pub fn label(mut self, label: impl Into<SmartStr>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the description.
+    #[must_use]
+    pub fn description(mut self, description: impl Into<SmartStr>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Adds a tag, such as `"advanced"`, for filtering and grouping.
+    #[must_use]
+    pub fn tag(mut self, tag: impl Into<Key>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Sets the layout order hint; lower values come first.
+    #[must_use]
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the flags.
+    #[must_use]
+    pub fn flags(mut self, flags: Flags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Sets the Markdown source.
+    #[must_use]
+    pub fn source(mut self, source: impl Into<SmartStr>) -> Self {
+        self.source = source.into();
+        self
+    }
+
+    /// Builds the Markdown decoration.
+    #[must_use]
+    pub fn build(self) -> Markdown {
+        let mut metadata = Metadata::new(self.key);
+        if let Some(label) = self.label {
+            metadata = metadata.with_label(label);
+        }
+        if let Some(description) = self.description {
+            metadata = metadata.with_description(description);
+        }
+        for tag in self.tags {
+            metadata = metadata.with_tag(tag);
+        }
+        if let Some(order) = self.order {
+            metadata = metadata.with_order(order);
+        }
+
+        Markdown {
+            metadata,
+            flags: self.flags,
+            source: self.source,
+        }
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markdown_builder() {
+        let md = Markdown::builder("guide")
+            .label("Guide")
+            .source("*Hello*")
+            .build();
+
+        assert_eq!(md.key().as_str(), "guide");
+        assert_eq!(md.metadata().label(), Some("Guide"));
+        assert_eq!(md.source(), "*Hello*");
+        assert_eq!(md.kind(), NodeKind::Decoration);
+        assert!(md.child_nodes().is_empty());
+    }
+
+    #[cfg(feature = "markdown")]
+    #[test]
+    fn test_markdown_rendered_html() {
+        let md = Markdown::new("help", "# Title\n\n- a\n- ~~b~~\n\n<script>x()</script>");
+        let html = md.rendered_html();
+
+        assert!(html.contains("<h1>Title</h1>"));
+        assert!(html.contains("<li><del>b</del></li>"));
+        assert!(!html.contains("<script>"));
+        assert!(html.contains("&lt;script&gt;"));
+    }
+
+    #[cfg(feature = "markdown")]
+    #[test]
+    fn test_markdown_plain_text() {
+        let md = Markdown::new(
+            "help",
+            "# Title\n\nSee [the docs](https://example.com)\nfor <b>details</b>.\n\n\
+             ```\ncode\n```\n\n| a | b |\n|---|---|\n| 1 | 2 |\n",
+        );
+
+        assert_eq!(
+            md.plain_text(),
+            "Title\nSee the docs for details.\ncode\na b\n1 2"
+        );
+        assert_eq!(Markdown::new("empty", "").plain_text(), "");
+    }
+}
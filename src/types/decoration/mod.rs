@@ -13,6 +13,9 @@
 //! - [`Html`] - Rich HTML content with sanitization options
 //! - [`Video`] - Embedded video content (YouTube/Vimeo/direct URL)
 //! - [`Progress`] - Progress bars, spinners, and step indicators
+//! - [`Table`] - Read-only rows of values under column headers
+//! - [`KeyValueList`] - Labeled values shown as a definition list
+//! - [`Markdown`] - Markdown help text, renderable with the `markdown` feature
 //!
 //! # Example
 //!
@@ -33,17 +36,24 @@
 mod code;
 mod html;
 mod image;
+mod key_value;
 mod link;
+mod markdown;
 mod notice;
 mod progress;
+mod sanitize;
 mod separator;
+mod table;
 mod video;
 
 pub use code::{Code, CodeBuilder};
 pub use html::{Html, HtmlBuilder, SanitizeLevel};
 pub use image::{Image, ImageAlignment, ImageBuilder, ImageSource};
+pub use key_value::{KeyValueList, KeyValueListBuilder};
 pub use link::{Link, LinkBuilder};
-pub use notice::{Notice, NoticeBuilder};
+pub use markdown::{Markdown, MarkdownBuilder};
+pub use notice::{Notice, NoticeAction, NoticeBuilder};
 pub use progress::{Progress, ProgressBuilder, ProgressOptions, ProgressSource, ProgressStyle};
 pub use separator::{Separator, SeparatorBuilder};
+pub use table::{Table, TableBuilder};
 pub use video::{Video, VideoBuilder, VideoOptions, VideoSize, VideoSource};
@@ -1,8 +1,10 @@
 //! Notice decoration for displaying messages.
 //!
-//! Notice displays info, warning, error, success, or tip messages.
+//! Notice displays info, warning, error, success, or tip messages, optionally
+//! with action links for the UI to offer.
 
 use std::any::Any;
+use std::hash::{Hash, Hasher};
 
 use crate::core::{Flags, Key, Metadata, SmartStr};
 use crate::types::kind::NodeKind;
@@ -28,6 +30,7 @@ use crate::types::traits::{Decoration, Node};
 ///     .notice_type(NoticeType::Warning)
 ///     .message("This feature will be removed in v2.0.")
 ///     .dismissible(true)
+///     .action("Migration guide", "https://example.com/migrate")
 ///     .build();
 ///
 /// // Error message
@@ -46,6 +49,30 @@ pub struct Notice {
     kind: NoticeType,
     message: SmartStr,
     dismissible: bool,
+    actions: Vec<NoticeAction>,
+}
+
+/// An action link offered alongside a [`Notice`].
+///
+/// Actions only describe what the UI should offer; the notice itself
+/// still has no value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoticeAction {
+    /// Button or link text.
+    pub label: SmartStr,
+    /// Target URL of the action.
+    pub href: SmartStr,
+}
+
+impl NoticeAction {
+    /// Creates a new action.
+    #[must_use]
+    pub fn new(label: impl Into<SmartStr>, href: impl Into<SmartStr>) -> Self {
+        Self {
+            label: label.into(),
+            href: href.into(),
+        }
+    }
 }
 
 impl Notice {
@@ -123,6 +150,12 @@ impl Notice {
     pub fn is_dismissible(&self) -> bool {
         self.dismissible
     }
+
+    /// Returns the action links, in the order they were added.
+    #[must_use]
+    pub fn actions(&self) -> &[NoticeAction] {
+        &self.actions
+    }
 }
 
 impl Node for Notice {
@@ -134,6 +167,10 @@ impl Node for Notice {
         self.metadata.key()
     }
 
+    fn flags(&self) -> Flags {
+        self.flags
+    }
+
     fn kind(&self) -> NodeKind {
         NodeKind::Decoration
     }
@@ -145,6 +182,14 @@ impl Node for Notice {
     fn as_any_mut(&mut self) -> &mut dyn Any {
         self
     }
+
+    fn hash_settings(&self, mut state: &mut dyn Hasher) {
+        (self.kind, &self.message, self.dismissible).hash(&mut state);
+        state.write_usize(self.actions.len());
+        for action in &self.actions {
+            (&action.label, &action.href).hash(&mut state);
+        }
+    }
 }
 
 impl Decoration for Notice {}
@@ -160,9 +205,12 @@ pub struct NoticeBuilder {
     label: Option<SmartStr>,
     description: Option<SmartStr>,
     flags: Flags,
+    tags: Vec<Key>,
+    order: Option<i32>,
     notice_type: NoticeType,
     message: SmartStr,
     dismissible: bool,
+    actions: Vec<NoticeAction>,
 }
 
 impl NoticeBuilder {
@@ -174,9 +222,12 @@ impl NoticeBuilder {
             label: None,
             description: None,
             flags: Flags::empty(),
+            tags: Vec::new(),
+            order: None,
             notice_type: NoticeType::Info,
             message: SmartStr::new(),
             dismissible: false,
+            actions: Vec::new(),
         }
     }
 
@@ -194,6 +245,20 @@ impl NoticeBuilder {
         self
     }
 
+    /// Adds a tag, such as `"advanced"`, for filtering and grouping.
+    #[must_use]
+    pub fn tag(mut self, tag: impl Into<Key>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Sets the layout order hint; lower values come first.
+    #[must_use]
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+
     /// Sets the flags.
     #[must_use]
     pub fn flags(mut self, flags: Flags) -> Self {
@@ -222,6 +287,13 @@ impl NoticeBuilder {
         self
     }
 
+    /// Adds an action link.
+    #[must_use]
+    pub fn action(mut self, label: impl Into<SmartStr>, href: impl Into<SmartStr>) -> Self {
+        self.actions.push(NoticeAction::new(label, href));
+        self
+    }
+
     /// Builds the Notice.
     #[must_use]
     pub fn build(self) -> Notice {
@@ -232,6 +304,12 @@ impl NoticeBuilder {
         if let Some(description) = self.description {
             metadata = metadata.with_description(description);
         }
+        for tag in self.tags {
+            metadata = metadata.with_tag(tag);
+        }
+        if let Some(order) = self.order {
+            metadata = metadata.with_order(order);
+        }
 
         Notice {
             metadata,
@@ -239,6 +317,7 @@ impl NoticeBuilder {
             kind: self.notice_type,
             message: self.message,
             dismissible: self.dismissible,
+            actions: self.actions,
         }
     }
 }
@@ -308,6 +387,26 @@ mod tests {
         assert!(notice.is_dismissible());
     }
 
+    #[test]
+    fn test_notice_actions() {
+        let notice = Notice::builder("update")
+            .message("A new version is available.")
+            .dismissible(true)
+            .action("Release notes", "https://example.com/notes")
+            .action("Update", "app://update")
+            .build();
+
+        assert!(notice.is_dismissible());
+        assert_eq!(
+            notice.actions(),
+            [
+                NoticeAction::new("Release notes", "https://example.com/notes"),
+                NoticeAction::new("Update", "app://update"),
+            ]
+        );
+        assert!(Notice::info("plain", "Hi").actions().is_empty());
+    }
+
     #[test]
     fn test_notice_kind() {
         let notice = Notice::info("test", "Test");
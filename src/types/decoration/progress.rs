@@ -4,13 +4,15 @@
 //! as a display-only element in the UI.
 
 use std::any::Any;
+use std::hash::{Hash, Hasher};
 
-use crate::core::{Flags, Key, Metadata, SmartStr};
+use crate::context::Context;
+use crate::core::{Flags, Key, Metadata, SmartStr, Value};
 use crate::types::kind::NodeKind;
 use crate::types::traits::{Decoration, Node};
 
 /// Visual style for progress display.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum ProgressStyle {
     /// Horizontal progress bar (default).
     #[default]
@@ -56,7 +58,8 @@ pub enum ProgressSource {
     /// Bind to a parameter key (reads value from context).
     Parameter(Key),
     /// Expression to compute progress (e.g., `filled / total`).
-    /// Note: Expression evaluation requires the visibility feature.
+    /// Note: Expression evaluation requires the visibility feature and
+    /// supports a single `a / b` division of parameters or numbers.
     Expression(SmartStr),
 }
 
@@ -252,6 +255,96 @@ impl Progress {
     pub fn is_indeterminate(&self) -> bool {
         matches!(self.style, ProgressStyle::Spinner)
     }
+
+    /// Resolves the current progress fraction from a context.
+    ///
+    /// - [`ProgressSource::Static`] returns the stored value.
+    /// - [`ProgressSource::Parameter`] reads the bound value. Numbers are
+    ///   scaled to the bound node's declared range, so `25` in a `0..=100`
+    ///   number is `0.25`; without a range they are fractions. Text such as
+    ///   `"0.4"` is read the same way, `"40%"` is always a percentage, and
+    ///   booleans map to `0.0` or `1.0`.
+    /// - [`ProgressSource::Expression`] evaluates `a / b`, where each side
+    ///   is a parameter key or a number. This requires the `visibility`
+    ///   feature; without it, expressions resolve to `None`.
+    ///
+    /// The result is clamped to `[0, 1]`. Returns `None` if the value is
+    /// missing, not numeric, or a division by zero.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::context::Context;
+    /// use paramdef::core::Value;
+    /// use paramdef::schema::Schema;
+    /// use paramdef::types::decoration::Progress;
+    /// use paramdef::types::leaf::Number;
+    /// use std::sync::Arc;
+    ///
+    /// let schema = Arc::new(Schema::builder()
+    ///     .parameter(Number::builder("done").range(0.0, 100.0).build())
+    ///     .build());
+    /// let mut ctx = Context::new(schema);
+    /// ctx.set("done", Value::Int(25));
+    ///
+    /// let progress = Progress::builder("bar").bind_to("done").build();
+    /// assert_eq!(progress.resolve(&ctx), Some(0.25));
+    /// ```
+    #[must_use]
+    pub fn resolve(&self, ctx: &Context) -> Option<f64> {
+        let fraction = match &self.source {
+            ProgressSource::Static(value) => Some(*value),
+            ProgressSource::Parameter(key) => {
+                let range = ctx.node(key).and_then(|n| n.node().numeric_range());
+                ctx.get(key).and_then(|value| value_fraction(value, range))
+            }
+            #[cfg(feature = "visibility")]
+            ProgressSource::Expression(expr) => evaluate_ratio(expr, ctx),
+            #[cfg(not(feature = "visibility"))]
+            ProgressSource::Expression(_) => None,
+        };
+
+        fraction.filter(|f| !f.is_nan()).map(|f| f.clamp(0.0, 1.0))
+    }
+}
+
+/// Coerces a bound parameter value to a progress fraction, scaling numbers
+/// to `range` if the bound node declares one.
+fn value_fraction(value: &Value, range: Option<(f64, f64)>) -> Option<f64> {
+    let number = match value {
+        Value::Bool(b) => return Some(if *b { 1.0 } else { 0.0 }),
+        Value::Text(text) => {
+            let text = text.trim();
+            if let Some(percent) = text.strip_suffix('%') {
+                return percent.trim_end().parse::<f64>().ok().map(|p| p / 100.0);
+            }
+            text.parse::<f64>().ok()?
+        }
+        _ => value.as_f64()?,
+    };
+
+    Some(match range {
+        Some((min, max)) if max > min => (number - min) / (max - min),
+        _ => number,
+    })
+}
+
+/// Evaluates an `a / b` expression against a context.
+#[cfg(feature = "visibility")]
+fn evaluate_ratio(expr: &str, ctx: &Context) -> Option<f64> {
+    let operand = |s: &str| {
+        let s = s.trim();
+        s.parse::<f64>()
+            .ok()
+            .or_else(|| ctx.get(s).and_then(Value::as_f64))
+    };
+
+    let (numerator, denominator) = expr.split_once('/')?;
+    let denominator = operand(denominator)?;
+    if denominator == 0.0 {
+        return None;
+    }
+    Some(operand(numerator)? / denominator)
 }
 
 impl Node for Progress {
@@ -263,6 +356,10 @@ impl Node for Progress {
         self.metadata.key()
     }
 
+    fn flags(&self) -> Flags {
+        self.flags
+    }
+
     fn kind(&self) -> NodeKind {
         NodeKind::Decoration
     }
@@ -274,6 +371,23 @@ impl Node for Progress {
     fn as_any_mut(&mut self) -> &mut dyn Any {
         self
     }
+
+    fn hash_settings(&self, mut state: &mut dyn Hasher) {
+        match &self.source {
+            ProgressSource::Static(value) => (0u8, value.to_bits()).hash(&mut state),
+            ProgressSource::Parameter(key) => (1u8, key).hash(&mut state),
+            ProgressSource::Expression(expr) => (2u8, expr).hash(&mut state),
+        }
+        let options = &self.options;
+        (
+            options.show_percentage,
+            options.show_value,
+            options.animated,
+            options.striped,
+        )
+            .hash(&mut state);
+        (self.style, self.total_steps, &self.color, &self.size).hash(&mut state);
+    }
 }
 
 impl Decoration for Progress {}
@@ -289,6 +403,8 @@ pub struct ProgressBuilder {
     label: Option<SmartStr>,
     description: Option<SmartStr>,
     flags: Flags,
+    tags: Vec<Key>,
+    order: Option<i32>,
     source: ProgressSource,
     style: ProgressStyle,
     total_steps: Option<u32>,
@@ -306,6 +422,8 @@ impl ProgressBuilder {
             label: None,
             description: None,
             flags: Flags::empty(),
+            tags: Vec::new(),
+            order: None,
             source: ProgressSource::Static(0.0),
             style: ProgressStyle::default(),
             total_steps: None,
@@ -329,6 +447,20 @@ impl ProgressBuilder {
         self
     }
 
+    /// Adds a tag, such as `"advanced"`, for filtering and grouping.
+    #[must_use]
+    pub fn tag(mut self, tag: impl Into<Key>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Sets the layout order hint; lower values come first.
+    #[must_use]
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+
     /// Sets the flags.
     #[must_use]
     pub fn flags(mut self, flags: Flags) -> Self {
@@ -430,6 +562,12 @@ impl ProgressBuilder {
         if let Some(description) = self.description {
             metadata = metadata.with_description(description);
         }
+        for tag in self.tags {
+            metadata = metadata.with_tag(tag);
+        }
+        if let Some(order) = self.order {
+            metadata = metadata.with_order(order);
+        }
 
         Progress {
             metadata,
@@ -545,6 +683,78 @@ mod tests {
         assert!(matches!(source, ProgressSource::Static(v) if v.abs() < f64::EPSILON));
     }
 
+    fn context() -> Context {
+        use crate::schema::Schema;
+        use crate::types::leaf::{Number, Text};
+        use std::sync::Arc;
+
+        let schema = Schema::builder()
+            .parameter(Number::builder("filled").build())
+            .parameter(Number::builder("total").build())
+            .parameter(Number::builder("percent").range(0.0, 100.0).build())
+            .parameter(Number::builder("level").range(-10.0, 10.0).build())
+            .parameter(Text::builder("status").build())
+            .build();
+        Context::new(Arc::new(schema))
+    }
+
+    #[test]
+    fn test_progress_resolve_parameter() {
+        let mut ctx = context();
+        let progress = Progress::builder("p").bind_to("filled").build();
+        assert_eq!(progress.resolve(&ctx), None);
+
+        // Without a declared range, numbers are fractions
+        ctx.set("filled", Value::Float(0.4));
+        assert_eq!(progress.resolve(&ctx), Some(0.4));
+        ctx.set("filled", Value::Int(1));
+        assert_eq!(progress.resolve(&ctx), Some(1.0));
+        ctx.set("filled", Value::Int(2));
+        assert_eq!(progress.resolve(&ctx), Some(1.0));
+
+        // With one, they are scaled to it
+        let progress = Progress::builder("p").bind_to("percent").build();
+        for (value, expected) in [(0, 0.0), (1, 0.01), (2, 0.02), (80, 0.8), (250, 1.0)] {
+            ctx.set("percent", Value::Int(value));
+            assert_eq!(progress.resolve(&ctx), Some(expected));
+        }
+        let progress = Progress::builder("p").bind_to("level").build();
+        ctx.set("level", Value::Int(5));
+        assert_eq!(progress.resolve(&ctx), Some(0.75));
+
+        let progress = Progress::builder("p").bind_to("status").build();
+        ctx.set("status", Value::text("40%"));
+        assert_eq!(progress.resolve(&ctx), Some(0.4));
+        ctx.set("status", Value::text("busy"));
+        assert_eq!(progress.resolve(&ctx), None);
+
+        assert_eq!(Progress::bar("s", 0.3).build().resolve(&ctx), Some(0.3));
+    }
+
+    #[test]
+    fn test_progress_resolve_expression() {
+        let mut ctx = context();
+        ctx.set("filled", Value::Int(3));
+        ctx.set("total", Value::Int(4));
+
+        let progress = Progress::builder("p").expression("filled / total").build();
+
+        #[cfg(feature = "visibility")]
+        {
+            assert_eq!(progress.resolve(&ctx), Some(0.75));
+
+            ctx.set("total", Value::Int(0));
+            assert_eq!(progress.resolve(&ctx), None);
+
+            let over = Progress::builder("p").expression("filled / 2").build();
+            ctx.set("filled", Value::Int(5));
+            assert_eq!(over.resolve(&ctx), Some(1.0));
+        }
+
+        #[cfg(not(feature = "visibility"))]
+        assert_eq!(progress.resolve(&ctx), None);
+    }
+
     #[test]
     fn test_progress_kind() {
         let progress = Progress::bar("test", 0.5).build();
@@ -0,0 +1,152 @@
+//! Minimal allowlist-based HTML sanitizer used by [`Html::sanitized`].
+//!
+//! This is deliberately small: it tokenizes tags, drops everything not on
+//! the allowlist, strips all attributes from allowed tags, and removes the
+//! contents of elements that carry code (`<script>`, `<style>`, ...).
+//!
+//! [`Html::sanitized`]: super::Html::sanitized
+
+/// Inline formatting tags kept by the basic level.
+pub(crate) const INLINE_TAGS: &[&str] = &[
+    "abbr", "b", "br", "code", "del", "em", "i", "ins", "kbd", "mark", "s", "small", "span",
+    "strong", "sub", "sup", "u",
+];
+
+/// Elements whose contents are dropped along with the tags.
+const DROP_CONTENT_TAGS: &[&str] = &[
+    "iframe", "noscript", "object", "script", "style", "template", "textarea", "title",
+];
+
+/// Sanitizes `input`, keeping only tags in `allowed`.
+///
+/// Allowed tags are re-emitted without attributes. Comments, disallowed
+/// tags, and the contents of [`DROP_CONTENT_TAGS`] are removed. A `<` that
+/// does not start a tag is escaped as `&lt;`.
+pub(crate) fn sanitize(input: &str, allowed: &[&str]) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut rest = input;
+
+    while let Some(pos) = rest.find('<') {
+        out.push_str(&rest[..pos]);
+        rest = &rest[pos..];
+
+        if let Some(after) = rest.strip_prefix("<!--") {
+            rest = after.find("-->").map_or("", |end| &after[end + 3..]);
+            continue;
+        }
+
+        let Some(tag) = parse_tag(rest) else {
+            out.push_str("&lt;");
+            rest = &rest[1..];
+            continue;
+        };
+        rest = &rest[tag.len..];
+
+        if !tag.closing && DROP_CONTENT_TAGS.contains(&tag.name.as_str()) {
+            rest = skip_past_close(rest, &tag.name);
+        } else if allowed.contains(&tag.name.as_str()) {
+            out.push('<');
+            if tag.closing {
+                out.push('/');
+            }
+            out.push_str(&tag.name);
+            out.push('>');
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+/// A tag found at the start of the input.
+struct Tag {
+    /// Lowercased tag name.
+    name: String,
+    /// Whether this is a closing tag (`</name>`).
+    closing: bool,
+    /// Length of the whole tag in bytes, including `<` and `>`.
+    len: usize,
+}
+
+/// Parses a tag at the start of `s`, which begins with `<`.
+///
+/// Returns `None` if `s` does not start with a well-formed tag, in which
+/// case the `<` is treated as text.
+fn parse_tag(s: &str) -> Option<Tag> {
+    let bytes = s.as_bytes();
+    let mut i = 1;
+    let closing = bytes.get(i) == Some(&b'/');
+    if closing {
+        i += 1;
+    }
+
+    let name_start = i;
+    if !bytes.get(i).is_some_and(u8::is_ascii_alphabetic) {
+        return None;
+    }
+    while bytes
+        .get(i)
+        .is_some_and(|b| b.is_ascii_alphanumeric() || *b == b'-')
+    {
+        i += 1;
+    }
+    let name = s[name_start..i].to_ascii_lowercase();
+
+    // Find the closing `>`, skipping over quoted attribute values.
+    let mut quote = None;
+    while let Some(&b) = bytes.get(i) {
+        match (quote, b) {
+            (Some(q), _) if b == q => quote = None,
+            (None, b'"' | b'\'') => quote = Some(b),
+            (None, b'>') => {
+                return Some(Tag {
+                    name,
+                    closing,
+                    len: i + 1,
+                });
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    None
+}
+
+/// Skips past the closing tag for `name`, or to the end of the input if
+/// there is none.
+fn skip_past_close<'a>(s: &'a str, name: &str) -> &'a str {
+    let mut rest = s;
+    while let Some(pos) = rest.find("</") {
+        rest = &rest[pos..];
+        if let Some(tag) = parse_tag(rest).filter(|t| t.name == name) {
+            return &rest[tag.len..];
+        }
+        rest = &rest[2..];
+    }
+    ""
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_keeps_allowed_tags_without_attributes() {
+        let html = r#"<b onclick="x()">bold</b> <a href="javascript:x()">link</a>"#;
+        assert_eq!(sanitize(html, INLINE_TAGS), "<b>bold</b> link");
+    }
+
+    #[test]
+    fn test_sanitize_drops_script_contents() {
+        let html = "a<SCRIPT type='text/javascript'>alert('</b>')</script >b<style>p{}</style>";
+        assert_eq!(sanitize(html, INLINE_TAGS), "ab");
+        assert_eq!(sanitize("x<script>never closed", &[]), "x");
+    }
+
+    #[test]
+    fn test_sanitize_quoted_gt_and_stray_lt() {
+        let html = r#"<img alt="a > b" onerror="x()">1 < 2<!-- <script> -->"#;
+        assert_eq!(sanitize(html, &[]), "1 &lt; 2");
+    }
+}
@@ -3,6 +3,7 @@
 //! Separator creates visual boundaries between sections.
 
 use std::any::Any;
+use std::hash::{Hash, Hasher};
 
 use crate::core::{Flags, Key, Metadata, SmartStr};
 use crate::types::kind::NodeKind;
@@ -12,7 +13,8 @@ use crate::types::traits::{Decoration, Node};
 /// A visual separator decoration.
 ///
 /// Separator creates visual boundaries between form sections. It can have
-/// an optional label and configurable spacing.
+/// an optional label, rendered centered on the line ("— Advanced —"), and
+/// configurable spacing and line thickness.
 ///
 /// # Example
 ///
@@ -26,6 +28,7 @@ use crate::types::traits::{Decoration, Node};
 /// // Thick separator with label
 /// let section = Separator::thick("advanced")
 ///     .label("Advanced Settings")
+///     .thickness(2)
 ///     .build();
 ///
 /// // Dashed separator
@@ -43,6 +46,7 @@ pub struct Separator {
     style: SeparatorStyle,
     label: Option<SmartStr>,
     spacing: Option<f32>,
+    thickness: Option<u8>,
 }
 
 impl Separator {
@@ -85,7 +89,7 @@ impl Separator {
         self.style
     }
 
-    /// Returns the optional label.
+    /// Returns the optional label, rendered centered on the line.
     #[must_use]
     pub fn label(&self) -> Option<&str> {
         self.label.as_deref()
@@ -96,6 +100,14 @@ impl Separator {
     pub fn spacing(&self) -> Option<f32> {
         self.spacing
     }
+
+    /// Returns the line thickness in pixels, if set.
+    ///
+    /// When unset, the thickness follows the style.
+    #[must_use]
+    pub fn thickness(&self) -> Option<u8> {
+        self.thickness
+    }
 }
 
 impl Node for Separator {
@@ -107,6 +119,10 @@ impl Node for Separator {
         self.metadata.key()
     }
 
+    fn flags(&self) -> Flags {
+        self.flags
+    }
+
     fn kind(&self) -> NodeKind {
         NodeKind::Decoration
     }
@@ -118,6 +134,11 @@ impl Node for Separator {
     fn as_any_mut(&mut self) -> &mut dyn Any {
         self
     }
+
+    fn hash_settings(&self, mut state: &mut dyn Hasher) {
+        (self.style, &self.label, self.thickness).hash(&mut state);
+        self.spacing.map(f32::to_bits).hash(&mut state);
+    }
 }
 
 impl Decoration for Separator {}
@@ -131,9 +152,12 @@ impl Decoration for Separator {}
 pub struct SeparatorBuilder {
     key: Key,
     flags: Flags,
+    tags: Vec<Key>,
+    order: Option<i32>,
     style: SeparatorStyle,
     label: Option<SmartStr>,
     spacing: Option<f32>,
+    thickness: Option<u8>,
 }
 
 impl SeparatorBuilder {
@@ -143,12 +167,29 @@ impl SeparatorBuilder {
         Self {
             key: key.into(),
             flags: Flags::empty(),
+            tags: Vec::new(),
+            order: None,
             style: SeparatorStyle::Thin,
             label: None,
             spacing: None,
+            thickness: None,
         }
     }
 
+    /// Adds a tag, such as `"advanced"`, for filtering and grouping.
+    #[must_use]
+    pub fn tag(mut self, tag: impl Into<Key>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Sets the layout order hint; lower values come first.
+    #[must_use]
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+
     /// Sets the flags.
     #[must_use]
     pub fn flags(mut self, flags: Flags) -> Self {
@@ -163,7 +204,7 @@ impl SeparatorBuilder {
         self
     }
 
-    /// Sets the section label.
+    /// Sets the section label, rendered centered on the line.
     #[must_use]
     pub fn label(mut self, label: impl Into<SmartStr>) -> Self {
         self.label = Some(label.into());
@@ -177,15 +218,31 @@ impl SeparatorBuilder {
         self
     }
 
+    /// Sets the line thickness in pixels, overriding the style's default.
+    #[must_use]
+    pub fn thickness(mut self, px: u8) -> Self {
+        self.thickness = Some(px);
+        self
+    }
+
     /// Builds the Separator.
     #[must_use]
     pub fn build(self) -> Separator {
+        let mut metadata = Metadata::new(self.key);
+        for tag in self.tags {
+            metadata = metadata.with_tag(tag);
+        }
+        if let Some(order) = self.order {
+            metadata = metadata.with_order(order);
+        }
+
         Separator {
-            metadata: Metadata::new(self.key),
+            metadata,
             flags: self.flags,
             style: self.style,
             label: self.label,
             spacing: self.spacing,
+            thickness: self.thickness,
         }
     }
 }
@@ -206,6 +263,7 @@ mod tests {
         assert_eq!(sep.style(), SeparatorStyle::Thin);
         assert!(sep.label().is_none());
         assert!(sep.spacing().is_none());
+        assert!(sep.thickness().is_none());
     }
 
     #[test]
@@ -220,6 +278,19 @@ mod tests {
         assert_eq!(sep.spacing(), Some(20.0));
     }
 
+    #[test]
+    fn test_separator_thickness() {
+        let sep = Separator::builder("section")
+            .style(SeparatorStyle::Dashed)
+            .label("Advanced")
+            .thickness(3)
+            .build();
+
+        assert_eq!(sep.style(), SeparatorStyle::Dashed);
+        assert_eq!(sep.label(), Some("Advanced"));
+        assert_eq!(sep.thickness(), Some(3));
+    }
+
     #[test]
     fn test_separator_space() {
         let sep = Separator::space("gap", 32.0);
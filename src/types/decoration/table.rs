@@ -0,0 +1,294 @@
+//! Table decoration for static read-only data.
+//!
+//! Table displays rows of values under column headers. It is the read-only
+//! counterpart to the editable [`Matrix`](crate::types::container::Matrix)
+//! container.
+
+use std::any::Any;
+use std::hash::{Hash, Hasher};
+
+use crate::core::{Error, Flags, Key, Metadata, Result, SmartStr, Value};
+use crate::types::kind::NodeKind;
+use crate::types::traits::{Decoration, Node};
+
+/// A read-only table decoration.
+///
+/// Table displays computed or reference data in rows and columns. It has
+/// no value and cannot contain children.
+///
+/// # Example
+///
+/// ```
+/// use paramdef::core::Value;
+/// use paramdef::types::decoration::Table;
+///
+/// let table = Table::builder("results")
+///     .columns(["Name", "Score"])
+///     .row([Value::text("Alice"), Value::Int(92)])
+///     .row([Value::text("Bob"), Value::Int(87)])
+///     .build();
+///
+/// assert_eq!(table.columns().len(), 2);
+/// assert_eq!(table.rows().len(), 2);
+/// assert_eq!(table.cell(1, 1), Some(&Value::Int(87)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Table {
+    metadata: Metadata,
+    flags: Flags,
+    columns: Vec<SmartStr>,
+    /// Every row has one cell per column.
+    rows: Vec<Vec<Value>>,
+}
+
+impl Table {
+    /// Creates a new builder for a Table.
+    #[must_use]
+    pub fn builder(key: impl Into<Key>) -> TableBuilder {
+        TableBuilder::new(key)
+    }
+
+    /// Returns the flags for this table.
+    #[must_use]
+    pub fn flags(&self) -> Flags {
+        self.flags
+    }
+
+    /// Returns the column headers.
+    #[must_use]
+    pub fn columns(&self) -> &[SmartStr] {
+        &self.columns
+    }
+
+    /// Returns the rows of cells.
+    #[must_use]
+    pub fn rows(&self) -> &[Vec<Value>] {
+        &self.rows
+    }
+
+    /// Returns the cell at `row` and `column`, both 0-based.
+    ///
+    /// Returns `None` if either index is out of bounds.
+    #[must_use]
+    pub fn cell(&self, row: usize, column: usize) -> Option<&Value> {
+        self.rows.get(row)?.get(column)
+    }
+}
+
+impl Node for Table {
+    fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    fn key(&self) -> &Key {
+        self.metadata.key()
+    }
+
+    fn flags(&self) -> Flags {
+        self.flags
+    }
+
+    fn kind(&self) -> NodeKind {
+        NodeKind::Decoration
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn hash_settings(&self, mut state: &mut dyn Hasher) {
+        self.columns.hash(&mut state);
+        state.write_usize(self.rows.len());
+        for row in &self.rows {
+            state.write_usize(row.len());
+            for cell in row {
+                state.write_u64(cell.content_hash());
+            }
+        }
+    }
+}
+
+impl Decoration for Table {}
+
+// =============================================================================
+// Builder
+// =============================================================================
+
+/// Builder for [`Table`].
+#[derive(Debug)]
+pub struct TableBuilder {
+    key: Key,
+    flags: Flags,
+    tags: Vec<Key>,
+    order: Option<i32>,
+    columns: Vec<SmartStr>,
+    rows: Vec<Vec<Value>>,
+}
+
+impl TableBuilder {
+    /// Creates a new builder with the given key.
+    #[must_use]
+    pub fn new(key: impl Into<Key>) -> Self {
+        Self {
+            key: key.into(),
+            flags: Flags::empty(),
+            tags: Vec::new(),
+            order: None,
+            columns: Vec::new(),
+            rows: Vec::new(),
+        }
+    }
+
+    /// Adds a tag, such as `"advanced"`, for filtering and grouping.
+    #[must_use]
+    pub fn tag(mut self, tag: impl Into<Key>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Sets the layout order hint; lower values come first.
+    #[must_use]
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Sets the flags.
+    #[must_use]
+    pub fn flags(mut self, flags: Flags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Adds a column header.
+    #[must_use]
+    pub fn column(mut self, header: impl Into<SmartStr>) -> Self {
+        self.columns.push(header.into());
+        self
+    }
+
+    /// Adds several column headers.
+    #[must_use]
+    pub fn columns(mut self, headers: impl IntoIterator<Item = impl Into<SmartStr>>) -> Self {
+        self.columns.extend(headers.into_iter().map(Into::into));
+        self
+    }
+
+    /// Adds a row of cells, one per column.
+    #[must_use]
+    pub fn row(mut self, cells: impl IntoIterator<Item = Value>) -> Self {
+        self.rows.push(cells.into_iter().collect());
+        self
+    }
+
+    /// Builds the Table.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a row's length differs from the number of columns. Use
+    /// [`try_build`](Self::try_build) to handle this as an error.
+    #[must_use]
+    pub fn build(self) -> Table {
+        match self.try_build() {
+            Ok(table) => table,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Builds the Table, reporting ragged rows as errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `"invalid_table_row"` validation error if a row's length
+    /// differs from the number of columns.
+    pub fn try_build(self) -> Result<Table> {
+        if let Some(index) = self
+            .rows
+            .iter()
+            .position(|row| row.len() != self.columns.len())
+        {
+            return Err(Error::validation_with_fields(
+                "invalid_table_row",
+                format!(
+                    "row {index} has {} cells but the table has {} columns",
+                    self.rows[index].len(),
+                    self.columns.len()
+                ),
+                vec![self.key.to_string()],
+            ));
+        }
+
+        let mut metadata = Metadata::new(self.key);
+        for tag in self.tags {
+            metadata = metadata.with_tag(tag);
+        }
+        if let Some(order) = self.order {
+            metadata = metadata.with_order(order);
+        }
+
+        Ok(Table {
+            metadata,
+            flags: self.flags,
+            columns: self.columns,
+            rows: self.rows,
+        })
+    }
+}
+
+// =============================================================================
+// Tests
+// =============================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_builder() {
+        let table = Table::builder("stats")
+            .column("Metric")
+            .column("Value")
+            .row([Value::text("mean"), Value::Float(1.5)])
+            .row([Value::text("max"), Value::Int(4)])
+            .build();
+
+        assert_eq!(table.key().as_str(), "stats");
+        assert_eq!(table.columns(), ["Metric", "Value"]);
+        assert_eq!(table.rows().len(), 2);
+        assert_eq!(table.cell(0, 1), Some(&Value::Float(1.5)));
+        assert_eq!(table.cell(2, 0), None);
+        assert_eq!(table.cell(0, 2), None);
+    }
+
+    #[test]
+    fn test_table_ragged_row() {
+        let err = Table::builder("bad")
+            .columns(["a", "b"])
+            .row([Value::Int(1)])
+            .try_build()
+            .unwrap_err();
+
+        assert_eq!(err.code(), Some("invalid_table_row"));
+        assert_eq!(err.fields(), ["bad"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "columns")]
+    fn test_table_ragged_row_panics() {
+        let _ = Table::builder("bad").row([Value::Null]).build();
+    }
+
+    #[test]
+    fn test_table_invariants() {
+        let table = Table::builder("empty").build();
+
+        assert_eq!(table.kind(), NodeKind::Decoration);
+        assert!(!table.kind().has_own_value());
+        assert!(!table.kind().can_have_children());
+        assert!(table.child_nodes().is_empty());
+    }
+}
@@ -4,13 +4,14 @@
 //! (YouTube/Vimeo/direct URLs) as a display-only element in the UI.
 
 use std::any::Any;
+use std::hash::{Hash, Hasher};
 
 use crate::core::{Flags, Key, Metadata, SmartStr};
 use crate::types::kind::NodeKind;
 use crate::types::traits::{Decoration, Node};
 
 /// Source type for video content.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum VideoSource {
     /// Direct URL to video file (mp4, webm, etc.).
     Url(SmartStr),
@@ -78,6 +79,104 @@ impl VideoSource {
             }
         }
     }
+
+    /// Returns a URL suitable for an `<iframe>` or `<video>` element.
+    ///
+    /// YouTube and Vimeo sources accept a bare video ID or any common URL
+    /// form (`watch?v=`, `youtu.be/`, `/embed/`, `/shorts/`,
+    /// `vimeo.com/<id>`, ...) and produce the canonical embed URL. `Url`
+    /// and `File` sources are returned as-is.
+    ///
+    /// Returns `None` for `Embed` sources, which are already HTML, and for
+    /// YouTube or Vimeo values with no recognizable video ID.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::types::decoration::VideoSource;
+    ///
+    /// let source = VideoSource::youtube("https://youtu.be/dQw4w9WgXcQ?t=42");
+    /// assert_eq!(
+    ///     source.embed_url().as_deref(),
+    ///     Some("https://www.youtube.com/embed/dQw4w9WgXcQ")
+    /// );
+    /// ```
+    #[must_use]
+    #[allow(clippy::doc_markdown)]
+    pub fn embed_url(&self) -> Option<String> {
+        match self {
+            Self::Url(v) | Self::File(v) => Some(v.to_string()),
+            Self::YouTube(v) => {
+                youtube_id(v).map(|id| format!("https://www.youtube.com/embed/{id}"))
+            }
+            Self::Vimeo(v) => vimeo_id(v).map(|id| format!("https://player.vimeo.com/video/{id}")),
+            Self::Embed(_) => None,
+        }
+    }
+}
+
+/// Splits a URL into its lowercased host (without `www.` or `m.`), path,
+/// and query. Returns `None` if `s` does not look like a URL.
+fn split_url(s: &str) -> Option<(String, &str, &str)> {
+    let rest = s.split_once("://").map_or(s, |(_, rest)| rest);
+    let rest = rest.split('#').next().unwrap_or_default();
+    let (rest, query) = rest.split_once('?').unwrap_or((rest, ""));
+    let (host, path) = rest.split_once('/').unwrap_or((rest, ""));
+    if !host.contains('.') {
+        return None;
+    }
+
+    let host = host.to_ascii_lowercase();
+    let host = host
+        .strip_prefix("www.")
+        .or_else(|| host.strip_prefix("m."))
+        .unwrap_or(&host)
+        .to_string();
+    Some((host, path, query))
+}
+
+/// Extracts a YouTube video ID from a bare ID or a YouTube URL.
+#[allow(clippy::doc_markdown)]
+fn youtube_id(s: &str) -> Option<&str> {
+    let is_id = |id: &str| {
+        !id.is_empty()
+            && id
+                .bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+    };
+
+    let s = s.trim();
+    let Some((host, path, query)) = split_url(s) else {
+        return is_id(s).then_some(s);
+    };
+
+    let mut segments = path.split('/').filter(|seg| !seg.is_empty());
+    let id = match host.as_str() {
+        "youtu.be" => segments.next(),
+        "youtube.com" | "youtube-nocookie.com" => match segments.next() {
+            Some("watch") => query.split('&').find_map(|pair| pair.strip_prefix("v=")),
+            Some("embed" | "shorts" | "live" | "v") => segments.next(),
+            _ => None,
+        },
+        _ => None,
+    }?;
+
+    is_id(id).then_some(id)
+}
+
+/// Extracts a numeric Vimeo video ID from a bare ID or a Vimeo URL.
+fn vimeo_id(s: &str) -> Option<&str> {
+    let is_id = |id: &str| !id.is_empty() && id.bytes().all(|b| b.is_ascii_digit());
+
+    let s = s.trim();
+    let Some((host, path, _)) = split_url(s) else {
+        return is_id(s).then_some(s);
+    };
+
+    if host != "vimeo.com" && host != "player.vimeo.com" {
+        return None;
+    }
+    path.split('/').find(|seg| is_id(seg))
 }
 
 /// Video size specification.
@@ -272,6 +371,10 @@ impl Node for Video {
         self.metadata.key()
     }
 
+    fn flags(&self) -> Flags {
+        self.flags
+    }
+
     fn kind(&self) -> NodeKind {
         NodeKind::Decoration
     }
@@ -283,6 +386,22 @@ impl Node for Video {
     fn as_any_mut(&mut self) -> &mut dyn Any {
         self
     }
+
+    fn hash_settings(&self, mut state: &mut dyn Hasher) {
+        (&self.source, &self.poster).hash(&mut state);
+        self.size
+            .as_ref()
+            .map(|size| (size.width, size.height))
+            .hash(&mut state);
+        let options = &self.options;
+        (
+            options.autoplay,
+            options.muted,
+            options.looping,
+            options.controls,
+        )
+            .hash(&mut state);
+    }
 }
 
 impl Decoration for Video {}
@@ -298,6 +417,8 @@ pub struct VideoBuilder {
     label: Option<SmartStr>,
     description: Option<SmartStr>,
     flags: Flags,
+    tags: Vec<Key>,
+    order: Option<i32>,
     source: Option<VideoSource>,
     poster: Option<SmartStr>,
     size: Option<VideoSize>,
@@ -313,6 +434,8 @@ impl VideoBuilder {
             label: None,
             description: None,
             flags: Flags::empty(),
+            tags: Vec::new(),
+            order: None,
             source: None,
             poster: None,
             size: None,
@@ -334,6 +457,20 @@ impl VideoBuilder {
         self
     }
 
+    /// Adds a tag, such as `"advanced"`, for filtering and grouping.
+    #[must_use]
+    pub fn tag(mut self, tag: impl Into<Key>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Sets the layout order hint; lower values come first.
+    #[must_use]
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+
     /// Sets the flags.
     #[must_use]
     pub fn flags(mut self, flags: Flags) -> Self {
@@ -415,6 +552,12 @@ impl VideoBuilder {
         if let Some(description) = self.description {
             metadata = metadata.with_description(description);
         }
+        for tag in self.tags {
+            metadata = metadata.with_tag(tag);
+        }
+        if let Some(order) = self.order {
+            metadata = metadata.with_order(order);
+        }
 
         Video {
             metadata,
@@ -516,6 +659,70 @@ mod tests {
         assert!(!video.kind().can_have_children());
     }
 
+    #[test]
+    fn test_video_source_embed_url_youtube() {
+        let embed = "https://www.youtube.com/embed/dQw4w9WgXcQ";
+        for input in [
+            "dQw4w9WgXcQ",
+            "https://www.youtube.com/watch?v=dQw4w9WgXcQ",
+            "https://youtube.com/watch?feature=share&v=dQw4w9WgXcQ#t=10",
+            "https://m.youtube.com/watch?v=dQw4w9WgXcQ",
+            "youtu.be/dQw4w9WgXcQ",
+            "https://youtu.be/dQw4w9WgXcQ?si=abc",
+            "https://www.youtube.com/embed/dQw4w9WgXcQ",
+            "https://www.youtube.com/shorts/dQw4w9WgXcQ",
+        ] {
+            assert_eq!(
+                VideoSource::youtube(input).embed_url().as_deref(),
+                Some(embed),
+                "{input}"
+            );
+        }
+
+        assert_eq!(
+            VideoSource::youtube("https://example.com/watch?v=x").embed_url(),
+            None
+        );
+        assert_eq!(
+            VideoSource::youtube("https://youtube.com/watch").embed_url(),
+            None
+        );
+        assert_eq!(VideoSource::youtube("<b>").embed_url(), None);
+    }
+
+    #[test]
+    fn test_video_source_embed_url_other() {
+        let embed = "https://player.vimeo.com/video/123456789";
+        for input in [
+            "123456789",
+            "https://vimeo.com/123456789",
+            "https://vimeo.com/channels/staffpicks/123456789",
+            "https://player.vimeo.com/video/123456789?h=abc",
+        ] {
+            assert_eq!(
+                VideoSource::vimeo(input).embed_url().as_deref(),
+                Some(embed),
+                "{input}"
+            );
+        }
+        assert_eq!(
+            VideoSource::vimeo("https://vimeo.com/about").embed_url(),
+            None
+        );
+
+        assert_eq!(
+            VideoSource::url("https://example.com/a.mp4")
+                .embed_url()
+                .as_deref(),
+            Some("https://example.com/a.mp4")
+        );
+        assert_eq!(
+            VideoSource::file("./a.webm").embed_url().as_deref(),
+            Some("./a.webm")
+        );
+        assert_eq!(VideoSource::embed("<iframe></iframe>").embed_url(), None);
+    }
+
     #[test]
     fn test_video_source_constructors() {
         let url = VideoSource::url("https://example.com");
@@ -6,8 +6,13 @@
 
 use std::any::Any;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
+#[cfg(feature = "visibility")]
+use crate::context::Context;
+#[cfg(feature = "visibility")]
+use crate::core::Value;
 use crate::core::{Flags, Key, Metadata, SmartStr};
 use crate::types::kind::NodeKind;
 use crate::types::traits::{Layout, Node};
@@ -79,6 +84,8 @@ pub struct Panel {
     children: Vec<Arc<dyn Node>>,
     display_type: PanelDisplayType,
     collapsed: bool,
+    #[cfg(feature = "visibility")]
+    collapse_when: Option<Value>,
 }
 
 impl fmt::Debug for Panel {
@@ -89,7 +96,7 @@ impl fmt::Debug for Panel {
             .field("child_count", &self.children.len())
             .field("display_type", &self.display_type)
             .field("collapsed", &self.collapsed)
-            .finish()
+            .finish_non_exhaustive()
     }
 }
 
@@ -111,6 +118,54 @@ impl Panel {
     pub fn display_type(&self) -> PanelDisplayType {
         self.display_type
     }
+
+    /// Returns the collapse expression, if set.
+    #[cfg(feature = "visibility")]
+    #[must_use]
+    pub fn collapse_expr(&self) -> Option<&Value> {
+        self.collapse_when.as_ref()
+    }
+
+    /// Returns whether the panel should be collapsed given the current
+    /// values.
+    ///
+    /// Evaluates the collapse expression against `ctx` (see
+    /// [`crate::visibility`] for the supported shape); like visibility,
+    /// a malformed expression evaluates to `true`. Without an expression,
+    /// returns the static collapsed state.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::context::Context;
+    /// use paramdef::core::Value;
+    /// use paramdef::schema::Schema;
+    /// use paramdef::types::group::Panel;
+    /// use paramdef::types::leaf::Text;
+    /// use paramdef::visibility::ne;
+    /// use std::sync::Arc;
+    ///
+    /// let advanced = Panel::builder("advanced")
+    ///     .collapse_when(ne("mode", "expert"))
+    ///     .build();
+    ///
+    /// let schema = Arc::new(Schema::builder()
+    ///     .parameter(Text::builder("mode").build())
+    ///     .build());
+    /// let mut ctx = Context::new(schema);
+    /// assert!(advanced.should_collapse(&ctx));
+    ///
+    /// ctx.set("mode", Value::text("expert"));
+    /// assert!(!advanced.should_collapse(&ctx));
+    /// ```
+    #[cfg(feature = "visibility")]
+    #[must_use]
+    pub fn should_collapse(&self, ctx: &Context) -> bool {
+        match &self.collapse_when {
+            Some(expr) => crate::visibility::evaluate(expr, &|k| ctx.get(k)),
+            None => self.collapsed,
+        }
+    }
 }
 
 impl Node for Panel {
@@ -122,6 +177,10 @@ impl Node for Panel {
         self.metadata.key()
     }
 
+    fn flags(&self) -> Flags {
+        self.flags
+    }
+
     fn kind(&self) -> NodeKind {
         NodeKind::Layout
     }
@@ -133,6 +192,19 @@ impl Node for Panel {
     fn as_any_mut(&mut self) -> &mut dyn Any {
         self
     }
+
+    fn child_nodes(&self) -> &[Arc<dyn Node>] {
+        Layout::children(self)
+    }
+
+    fn hash_settings(&self, mut state: &mut dyn Hasher) {
+        (self.display_type, self.collapsed).hash(&mut state);
+        #[cfg(feature = "visibility")]
+        self.collapse_when
+            .as_ref()
+            .map(Value::content_hash)
+            .hash(&mut state);
+    }
 }
 
 impl Layout for Panel {
@@ -159,9 +231,13 @@ pub struct PanelBuilder {
     label: Option<SmartStr>,
     description: Option<SmartStr>,
     flags: Flags,
+    tags: Vec<Key>,
+    order: Option<i32>,
     children: Vec<Arc<dyn Node>>,
     display_type: PanelDisplayType,
     collapsed: bool,
+    #[cfg(feature = "visibility")]
+    collapse_when: Option<Value>,
 }
 
 impl fmt::Debug for PanelBuilder {
@@ -171,10 +247,12 @@ impl fmt::Debug for PanelBuilder {
             .field("label", &self.label)
             .field("description", &self.description)
             .field("flags", &self.flags)
+            .field("tags", &self.tags)
+            .field("order", &self.order)
             .field("child_count", &self.children.len())
             .field("display_type", &self.display_type)
             .field("collapsed", &self.collapsed)
-            .finish()
+            .finish_non_exhaustive()
     }
 }
 
@@ -187,9 +265,13 @@ impl PanelBuilder {
             label: None,
             description: None,
             flags: Flags::empty(),
+            tags: Vec::new(),
+            order: None,
             children: Vec::new(),
             display_type: PanelDisplayType::default(),
             collapsed: false,
+            #[cfg(feature = "visibility")]
+            collapse_when: None,
         }
     }
 
@@ -207,6 +289,20 @@ impl PanelBuilder {
         self
     }
 
+    /// Adds a tag, such as `"advanced"`, for filtering and grouping.
+    #[must_use]
+    pub fn tag(mut self, tag: impl Into<Key>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Sets the layout order hint; lower values come first.
+    #[must_use]
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+
     /// Sets the flags.
     #[must_use]
     pub fn flags(mut self, flags: Flags) -> Self {
@@ -275,6 +371,18 @@ impl PanelBuilder {
         self
     }
 
+    /// Collapses the panel while `expr` holds.
+    ///
+    /// The expression uses the visibility shape (see [`crate::visibility`])
+    /// and takes precedence over [`collapsed`](Self::collapsed) in
+    /// [`Panel::should_collapse`].
+    #[cfg(feature = "visibility")]
+    #[must_use]
+    pub fn collapse_when(mut self, expr: Value) -> Self {
+        self.collapse_when = Some(expr);
+        self
+    }
+
     /// Builds the Panel.
     #[must_use]
     pub fn build(self) -> Panel {
@@ -285,6 +393,12 @@ impl PanelBuilder {
         if let Some(description) = self.description {
             metadata = metadata.with_description(description);
         }
+        for tag in self.tags {
+            metadata = metadata.with_tag(tag);
+        }
+        if let Some(order) = self.order {
+            metadata = metadata.with_order(order);
+        }
 
         Panel {
             metadata,
@@ -292,6 +406,8 @@ impl PanelBuilder {
             children: self.children,
             display_type: self.display_type,
             collapsed: self.collapsed,
+            #[cfg(feature = "visibility")]
+            collapse_when: self.collapse_when,
         }
     }
 }
@@ -350,6 +466,30 @@ mod tests {
         assert!(!panel.is_collapsed());
     }
 
+    #[cfg(feature = "visibility")]
+    #[test]
+    fn test_panel_should_collapse() {
+        use crate::schema::Schema;
+        use crate::visibility::eq;
+
+        let schema = Schema::builder()
+            .parameter(Text::builder("mode").build())
+            .build();
+        let mut ctx = Context::new(Arc::new(schema));
+
+        let basic = Panel::builder("basic").collapsed(true).build();
+        assert!(basic.should_collapse(&ctx));
+        assert!(basic.collapse_expr().is_none());
+
+        let advanced = Panel::builder("advanced")
+            .collapse_when(eq("mode", "simple"))
+            .build();
+        assert!(!advanced.should_collapse(&ctx));
+
+        ctx.set("mode", Value::text("simple"));
+        assert!(advanced.should_collapse(&ctx));
+    }
+
     #[test]
     fn test_panel_display_type_names() {
         assert_eq!(PanelDisplayType::Section.name(), "section");
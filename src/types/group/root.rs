@@ -5,24 +5,44 @@
 
 use std::any::Any;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 
+use super::Panel;
 use crate::core::{Flags, Key, Metadata, SmartStr};
 use crate::types::kind::NodeKind;
 use crate::types::traits::{GroupNode, Node};
 
 /// Layout style for a Group.
+///
+/// The layout is metadata for renderers; it does not change which children
+/// a group holds or how values are collected.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum GroupLayout {
     /// Vertical layout (default).
+    ///
+    /// Children are stacked top to bottom.
     #[default]
     Vertical,
     /// Horizontal layout.
+    ///
+    /// Children are placed side by side in a single row.
     Horizontal,
     /// Grid layout.
+    ///
+    /// Children flow into a grid; the column count is up to the renderer.
     Grid,
     /// Tabbed layout (children are shown as tabs).
+    ///
+    /// Each direct [`Panel`] child is a tab page, titled by its label and
+    /// shown one at a time. Other children are rendered above the tab strip.
     Tabs,
+    /// Accordion layout.
+    ///
+    /// Each direct [`Panel`] child is a collapsible section whose header is
+    /// its label; [`Panel`]'s collapsed state gives the initial state. Other
+    /// children are rendered above the sections.
+    Accordion,
 }
 
 impl GroupLayout {
@@ -34,8 +54,15 @@ impl GroupLayout {
             Self::Horizontal => "horizontal",
             Self::Grid => "grid",
             Self::Tabs => "tabs",
+            Self::Accordion => "accordion",
         }
     }
+
+    /// Returns true if direct [`Panel`] children become pages or sections.
+    #[must_use]
+    pub const fn is_paged(&self) -> bool {
+        matches!(self, Self::Tabs | Self::Accordion)
+    }
 }
 
 /// Root aggregator that can contain all node types.
@@ -106,6 +133,16 @@ impl Group {
     pub fn is_collapsed(&self) -> bool {
         self.collapsed
     }
+
+    /// Returns the direct children that are [`Panel`]s, in order.
+    ///
+    /// With [`GroupLayout::Tabs`] or [`GroupLayout::Accordion`], these are
+    /// the tab pages or accordion sections.
+    pub fn panels(&self) -> impl Iterator<Item = &Panel> {
+        self.children
+            .iter()
+            .filter_map(|child| child.as_any().downcast_ref::<Panel>())
+    }
 }
 
 impl Node for Group {
@@ -117,6 +154,10 @@ impl Node for Group {
         self.metadata.key()
     }
 
+    fn flags(&self) -> Flags {
+        self.flags
+    }
+
     fn kind(&self) -> NodeKind {
         NodeKind::Group
     }
@@ -128,6 +169,14 @@ impl Node for Group {
     fn as_any_mut(&mut self) -> &mut dyn Any {
         self
     }
+
+    fn child_nodes(&self) -> &[Arc<dyn Node>] {
+        GroupNode::children(self)
+    }
+
+    fn hash_settings(&self, mut state: &mut dyn Hasher) {
+        (self.layout, self.collapsed).hash(&mut state);
+    }
 }
 
 impl GroupNode for Group {
@@ -146,6 +195,8 @@ pub struct GroupBuilder {
     label: Option<SmartStr>,
     description: Option<SmartStr>,
     flags: Flags,
+    tags: Vec<Key>,
+    order: Option<i32>,
     children: Vec<Arc<dyn Node>>,
     layout: GroupLayout,
     collapsed: bool,
@@ -158,6 +209,8 @@ impl fmt::Debug for GroupBuilder {
             .field("label", &self.label)
             .field("description", &self.description)
             .field("flags", &self.flags)
+            .field("tags", &self.tags)
+            .field("order", &self.order)
             .field("child_count", &self.children.len())
             .field("layout", &self.layout)
             .field("collapsed", &self.collapsed)
@@ -174,6 +227,8 @@ impl GroupBuilder {
             label: None,
             description: None,
             flags: Flags::empty(),
+            tags: Vec::new(),
+            order: None,
             children: Vec::new(),
             layout: GroupLayout::default(),
             collapsed: false,
@@ -194,6 +249,20 @@ impl GroupBuilder {
         self
     }
 
+    /// Adds a tag, such as `"advanced"`, for filtering and grouping.
+    #[must_use]
+    pub fn tag(mut self, tag: impl Into<Key>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Sets the layout order hint; lower values come first.
+    #[must_use]
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+
     /// Sets the flags.
     #[must_use]
     pub fn flags(mut self, flags: Flags) -> Self {
@@ -222,6 +291,18 @@ impl GroupBuilder {
         self
     }
 
+    /// Uses a tabbed layout, with each [`Panel`] child as a tab page.
+    #[must_use]
+    pub fn tabs(self) -> Self {
+        self.layout(GroupLayout::Tabs)
+    }
+
+    /// Uses an accordion layout, with each [`Panel`] child as a section.
+    #[must_use]
+    pub fn accordion(self) -> Self {
+        self.layout(GroupLayout::Accordion)
+    }
+
     /// Sets whether the group is initially collapsed.
     #[must_use]
     pub fn collapsed(mut self, collapsed: bool) -> Self {
@@ -239,6 +320,12 @@ impl GroupBuilder {
         if let Some(description) = self.description {
             metadata = metadata.with_description(description);
         }
+        for tag in self.tags {
+            metadata = metadata.with_tag(tag);
+        }
+        if let Some(order) = self.order {
+            metadata = metadata.with_order(order);
+        }
 
         Group {
             metadata,
@@ -257,6 +344,8 @@ impl GroupBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::container::Object;
+    use crate::types::decoration::Notice;
     use crate::types::leaf::Text;
 
     #[test]
@@ -288,6 +377,30 @@ mod tests {
 
         let tabs = Group::builder("t").layout(GroupLayout::Tabs).build();
         assert_eq!(tabs.layout(), GroupLayout::Tabs);
+
+        assert_eq!(
+            Group::builder("t").tabs().build().layout(),
+            GroupLayout::Tabs
+        );
+        assert_eq!(
+            Group::builder("a").accordion().build().layout(),
+            GroupLayout::Accordion
+        );
+        assert!(GroupLayout::Accordion.is_paged());
+        assert!(!GroupLayout::Grid.is_paged());
+    }
+
+    #[test]
+    fn test_group_panels() {
+        let group = Group::builder("settings")
+            .tabs()
+            .child(Text::builder("title").build())
+            .child(Panel::builder("general").label("General").build())
+            .child(Panel::builder("advanced").label("Advanced").build())
+            .build();
+
+        let keys: Vec<_> = group.panels().map(|p| p.key().as_str()).collect();
+        assert_eq!(keys, ["general", "advanced"]);
     }
 
     #[test]
@@ -309,12 +422,40 @@ mod tests {
         assert_eq!(group.len(), 1);
     }
 
+    #[test]
+    fn test_group_descendant_count() {
+        use crate::types::container::Object;
+        use crate::types::decoration::Notice;
+
+        let group = Group::builder("g")
+            .child(Text::builder("a").build())
+            .child(Notice::builder("note").build())
+            .child(
+                Panel::builder("p")
+                    .child(Text::builder("b").build())
+                    .child(
+                        Object::builder("o")
+                            .field("c", Text::builder("c").build())
+                            .field("d", Text::builder("d").build())
+                            .build()
+                            .unwrap(),
+                    )
+                    .build(),
+            )
+            .build();
+
+        // a, p, b, o, c, d; the notice is skipped.
+        assert_eq!(group.descendant_count(), 6);
+        assert_eq!(Text::builder("x").build().descendant_count(), 0);
+    }
+
     #[test]
     fn test_group_layout_names() {
         assert_eq!(GroupLayout::Vertical.name(), "vertical");
         assert_eq!(GroupLayout::Horizontal.name(), "horizontal");
         assert_eq!(GroupLayout::Grid.name(), "grid");
         assert_eq!(GroupLayout::Tabs.name(), "tabs");
+        assert_eq!(GroupLayout::Accordion.name(), "accordion");
     }
 
     #[test]
@@ -330,4 +471,38 @@ mod tests {
         // Group CAN have children
         assert!(group.kind().can_have_children());
     }
+
+    #[test]
+    fn test_group_children_sorted_by_order() {
+        let group = Group::builder("form")
+            .child(Text::builder("first").build())
+            .child(Text::builder("second").order(1).build())
+            .child(Text::builder("third").order(1).build())
+            .child(Text::builder("fourth").order(0).build())
+            .build();
+
+        let keys: Vec<_> = group
+            .children_sorted_by_order()
+            .iter()
+            .map(|n| n.key().as_str())
+            .collect();
+        assert_eq!(keys, vec!["fourth", "second", "third", "first"]);
+    }
+
+    #[test]
+    fn test_group_children_sorted_by_order_non_leaf() {
+        let group = Group::builder("form")
+            .child(Notice::builder("tip").order(2).build())
+            .child(Panel::builder("advanced").order(1).build())
+            .child(Text::builder("name").build())
+            .child(Object::builder("address").order(0).build().unwrap())
+            .build();
+
+        let keys: Vec<_> = group
+            .children_sorted_by_order()
+            .iter()
+            .map(|n| n.key().as_str())
+            .collect();
+        assert_eq!(keys, vec!["address", "advanced", "tip", "name"]);
+    }
 }
@@ -7,6 +7,11 @@ use std::fmt;
 /// Every node in the system falls into one of these five categories,
 /// which determines its capabilities and constraints.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "lowercase")
+)]
 #[non_exhaustive]
 pub enum NodeKind {
     /// Root aggregator (Group).
@@ -51,6 +56,27 @@ impl NodeKind {
         matches!(self, Self::Container | Self::Leaf)
     }
 
+    /// Returns whether nodes of this kind carry a value in a context.
+    ///
+    /// True for [`Leaf`](Self::Leaf) and [`Container`](Self::Container);
+    /// the complement of [`is_structural`](Self::is_structural).
+    #[inline]
+    #[must_use]
+    pub const fn is_value_bearing(&self) -> bool {
+        matches!(self, Self::Container | Self::Leaf)
+    }
+
+    /// Returns whether nodes of this kind only shape the UI.
+    ///
+    /// True for [`Group`](Self::Group), [`Layout`](Self::Layout), and
+    /// [`Decoration`](Self::Decoration), which never hold a value of their
+    /// own.
+    #[inline]
+    #[must_use]
+    pub const fn is_structural(&self) -> bool {
+        matches!(self, Self::Group | Self::Layout | Self::Decoration)
+    }
+
     /// Returns whether this kind can access child values.
     #[inline]
     #[must_use]
@@ -235,6 +261,23 @@ mod tests {
         assert!(NodeKind::Leaf.has_own_value());
     }
 
+    #[test]
+    fn test_node_kind_value_bearing_and_structural() {
+        for kind in [
+            NodeKind::Group,
+            NodeKind::Layout,
+            NodeKind::Decoration,
+            NodeKind::Container,
+            NodeKind::Leaf,
+        ] {
+            assert_ne!(kind.is_value_bearing(), kind.is_structural());
+        }
+        assert!(NodeKind::Leaf.is_value_bearing());
+        assert!(NodeKind::Container.is_value_bearing());
+        assert!(NodeKind::Decoration.is_structural());
+        assert!(NodeKind::Group.is_structural());
+    }
+
     #[test]
     fn test_node_kind_has_value_access() {
         assert!(NodeKind::Group.has_value_access());
@@ -1,8 +1,17 @@
 //! Boolean parameter type for true/false values.
 
-use crate::core::{Flags, Key, Metadata, Value};
+use std::hash::{Hash, Hasher};
+
+use crate::core::{Flags, Key, Metadata, Result, SmartStr, Value};
 use crate::types::kind::NodeKind;
-use crate::types::traits::{Leaf, Node};
+use crate::types::traits::{Leaf, Node, invalid_input};
+
+#[cfg(feature = "validation")]
+use crate::core::Error;
+#[cfg(feature = "validation")]
+use crate::types::traits::Validatable;
+#[cfg(feature = "visibility")]
+use crate::types::traits::Visibility;
 
 /// A boolean parameter schema for true/false values.
 ///
@@ -24,6 +33,10 @@ pub struct Boolean {
     metadata: Metadata,
     flags: Flags,
     default: Option<bool>,
+    /// Captions for the true and false states.
+    captions: Option<(SmartStr, SmartStr)>,
+    #[cfg(feature = "visibility")]
+    visibility: Option<Value>,
 }
 
 impl Boolean {
@@ -38,6 +51,18 @@ impl Boolean {
         self.default
     }
 
+    /// Returns the caption for the true state, if set.
+    #[must_use]
+    pub fn true_label(&self) -> Option<&str> {
+        self.captions.as_ref().map(|(on, _)| on.as_str())
+    }
+
+    /// Returns the caption for the false state, if set.
+    #[must_use]
+    pub fn false_label(&self) -> Option<&str> {
+        self.captions.as_ref().map(|(_, off)| off.as_str())
+    }
+
     /// Returns the flags.
     #[must_use]
     pub fn flags(&self) -> Flags {
@@ -54,6 +79,10 @@ impl Node for Boolean {
         self.metadata.key()
     }
 
+    fn flags(&self) -> Flags {
+        self.flags
+    }
+
     fn kind(&self) -> NodeKind {
         NodeKind::Leaf
     }
@@ -65,6 +94,45 @@ impl Node for Boolean {
     fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
         self
     }
+
+    fn initial_value(&self) -> Option<Value> {
+        self.default_value()
+    }
+
+    /// Accepts `true`/`on`/`yes`/`1` and `false`/`off`/`no`/`0`, ignoring
+    /// case. Empty input is `false`, as browsers omit unchecked boxes.
+    fn coerce_input(&self, input: &str) -> Result<Value> {
+        match input.trim().to_ascii_lowercase().as_str() {
+            "true" | "on" | "yes" | "1" => Ok(Value::Bool(true)),
+            "false" | "off" | "no" | "0" | "" => Ok(Value::Bool(false)),
+            _ => Err(invalid_input(self.key(), "expected a boolean")),
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    fn json_schema(&self) -> Option<serde_json::Value> {
+        let mut schema = serde_json::Map::new();
+        schema.insert("type".into(), "boolean".into());
+        Some(crate::schema::json_schema::annotated(
+            self,
+            schema,
+            self.default_value(),
+        ))
+    }
+
+    #[cfg(feature = "validation")]
+    fn validatable(&self) -> Option<&dyn Validatable> {
+        Some(self)
+    }
+
+    #[cfg(feature = "visibility")]
+    fn visibility(&self) -> Option<&dyn Visibility> {
+        Some(self)
+    }
+
+    fn hash_settings(&self, mut state: &mut dyn Hasher) {
+        (self.default, &self.captions).hash(&mut state);
+    }
 }
 
 impl Leaf for Boolean {
@@ -73,6 +141,30 @@ impl Leaf for Boolean {
     }
 }
 
+#[cfg(feature = "visibility")]
+impl Visibility for Boolean {
+    fn visibility_expr(&self) -> Option<&Value> {
+        self.visibility.as_ref()
+    }
+
+    fn set_visibility_expr(&mut self, expr: Option<Value>) {
+        self.visibility = expr;
+    }
+}
+
+#[cfg(feature = "validation")]
+impl Validatable for Boolean {
+    fn validate_sync(&self, value: &Value) -> Result<()> {
+        match value {
+            Value::Null if self.flags.is_required() => {
+                Err(Error::missing_required(self.key().as_str()))
+            }
+            Value::Null | Value::Bool(_) => Ok(()),
+            other => Err(Error::type_mismatch("boolean", other.type_name())),
+        }
+    }
+}
+
 /// Builder for [`Boolean`] parameters.
 #[derive(Debug, Clone)]
 pub struct BooleanBuilder {
@@ -80,8 +172,14 @@ pub struct BooleanBuilder {
     label: Option<Key>,
     description: Option<Key>,
     group: Option<Key>,
+    tags: Vec<Key>,
+    order: Option<i32>,
+    depends_on: Vec<Key>,
     flags: Flags,
     default: Option<bool>,
+    captions: Option<(SmartStr, SmartStr)>,
+    #[cfg(feature = "visibility")]
+    visibility: Option<Value>,
 }
 
 impl BooleanBuilder {
@@ -92,8 +190,14 @@ impl BooleanBuilder {
             label: None,
             description: None,
             group: None,
+            tags: Vec::new(),
+            order: None,
+            depends_on: Vec::new(),
             flags: Flags::empty(),
             default: None,
+            captions: None,
+            #[cfg(feature = "visibility")]
+            visibility: None,
         }
     }
 
@@ -118,6 +222,29 @@ impl BooleanBuilder {
         self
     }
 
+    /// Adds a tag, such as `"advanced"`, for filtering and grouping.
+    #[must_use]
+    pub fn tag(mut self, tag: impl Into<Key>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Sets the layout order hint; lower values come first.
+    #[must_use]
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Declares that this parameter is derived from another one.
+    ///
+    /// See [`Schema::evaluation_order`](crate::schema::Schema::evaluation_order).
+    #[must_use]
+    pub fn depends_on(mut self, key: impl Into<Key>) -> Self {
+        self.depends_on.push(key.into());
+        self
+    }
+
     /// Sets the default value.
     #[must_use]
     pub fn default(mut self, value: bool) -> Self {
@@ -125,6 +252,31 @@ impl BooleanBuilder {
         self
     }
 
+    /// Sets captions for the true and false states, such as
+    /// `"Yes"`/`"No"` or `"Enabled"`/`"Disabled"`.
+    ///
+    /// Renderers use these for toggle or segmented controls. The stored
+    /// value is still a [`Value::Bool`].
+    #[must_use]
+    pub fn captions(
+        mut self,
+        true_label: impl Into<SmartStr>,
+        false_label: impl Into<SmartStr>,
+    ) -> Self {
+        self.captions = Some((true_label.into(), false_label.into()));
+        self
+    }
+
+    /// Sets the visibility expression.
+    ///
+    /// See [`crate::visibility`] for the supported expression shape.
+    #[cfg(feature = "visibility")]
+    #[must_use]
+    pub fn visible_when(mut self, expr: Value) -> Self {
+        self.visibility = Some(expr);
+        self
+    }
+
     /// Marks the parameter as required.
     #[must_use]
     pub fn required(mut self) -> Self {
@@ -146,6 +298,23 @@ impl BooleanBuilder {
         self
     }
 
+    /// Marks the parameter as deprecated.
+    #[must_use]
+    pub fn deprecated(mut self) -> Self {
+        self.flags |= Flags::DEPRECATED;
+        self
+    }
+
+    /// Marks the parameter as computed by the evaluation engine.
+    ///
+    /// Applies [`Flags::computed`], so the value is read-only and skipped
+    /// when saving.
+    #[must_use]
+    pub fn computed(mut self) -> Self {
+        self.flags |= Flags::computed();
+        self
+    }
+
     /// Builds the boolean parameter.
     #[must_use]
     pub fn build(self) -> Boolean {
@@ -160,11 +329,23 @@ impl BooleanBuilder {
         if let Some(group) = self.group {
             metadata_builder = metadata_builder.group(group);
         }
+        for tag in self.tags {
+            metadata_builder = metadata_builder.tag(tag);
+        }
+        if let Some(order) = self.order {
+            metadata_builder = metadata_builder.order(order);
+        }
+        for dep in self.depends_on {
+            metadata_builder = metadata_builder.depends_on(dep);
+        }
 
         Boolean {
             metadata: metadata_builder.build(),
             flags: self.flags,
             default: self.default,
+            captions: self.captions,
+            #[cfg(feature = "visibility")]
+            visibility: self.visibility,
         }
     }
 }
@@ -182,6 +363,19 @@ mod tests {
         assert!(bool_param.default_value().is_none());
     }
 
+    #[test]
+    fn test_boolean_coerce_input() {
+        let bool_param = Boolean::builder("agree").build();
+
+        for input in ["on", "TRUE", "1", "yes"] {
+            assert_eq!(bool_param.coerce_input(input).unwrap(), Value::Bool(true));
+        }
+        for input in ["off", "false", "0", ""] {
+            assert_eq!(bool_param.coerce_input(input).unwrap(), Value::Bool(false));
+        }
+        assert!(bool_param.coerce_input("maybe").is_err());
+    }
+
     #[test]
     fn test_boolean_builder() {
         let bool_param = Boolean::builder("dark_mode")
@@ -199,6 +393,21 @@ mod tests {
         assert_eq!(bool_param.default_bool(), Some(true));
     }
 
+    #[test]
+    fn test_boolean_captions() {
+        let plain = Boolean::builder("enabled").build();
+        assert_eq!(plain.true_label(), None);
+        assert_eq!(plain.false_label(), None);
+
+        let toggle = Boolean::builder("enabled")
+            .captions("Enabled", "Disabled")
+            .default(true)
+            .build();
+        assert_eq!(toggle.true_label(), Some("Enabled"));
+        assert_eq!(toggle.false_label(), Some("Disabled"));
+        assert_eq!(toggle.default_value(), Some(Value::Bool(true)));
+    }
+
     #[test]
     fn test_boolean_default_false() {
         let bool_param = Boolean::builder("disabled").default(false).build();
@@ -215,4 +424,23 @@ mod tests {
         assert!(value.is_some());
         assert_eq!(value.unwrap(), Value::Bool(true));
     }
+
+    #[cfg(feature = "validation")]
+    #[test]
+    fn test_boolean_validate() {
+        let flag = Boolean::builder("flag").build();
+        assert!(flag.validatable().is_some());
+        assert!(flag.validate_sync(&Value::Bool(true)).is_ok());
+        assert!(flag.validate_sync(&Value::Null).is_ok());
+        assert!(matches!(
+            flag.validate_sync(&Value::text("yes")),
+            Err(Error::TypeMismatch { .. })
+        ));
+
+        let agree = Boolean::builder("agree").required().build();
+        assert!(matches!(
+            agree.validate_sync(&Value::Null),
+            Err(Error::MissingRequired { .. })
+        ));
+    }
 }
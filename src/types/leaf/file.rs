@@ -1,10 +1,20 @@
 //! File parameter type for file uploads and references.
 
+use std::hash::{Hash, Hasher};
+
 use crate::core::{Flags, Key, Metadata, SmartStr, Value};
 use crate::subtype::FileSubtype;
 use crate::types::kind::NodeKind;
 use crate::types::traits::{Leaf, Node};
 
+#[cfg(feature = "validation")]
+use crate::core::{Error, Result};
+#[cfg(feature = "validation")]
+use crate::types::traits::Validatable;
+
+#[cfg(feature = "visibility")]
+use crate::types::traits::Visibility;
+
 /// A file parameter schema for file uploads and references.
 ///
 /// File parameters support various file types through [`FileSubtype`],
@@ -58,6 +68,8 @@ pub struct File<S: FileSubtype = crate::subtype::GenericFile> {
     max_size: Option<u64>,
     /// Allow multiple files.
     multiple: bool,
+    #[cfg(feature = "visibility")]
+    visibility: Option<Value>,
 }
 
 impl<S: FileSubtype> File<S> {
@@ -180,6 +192,10 @@ impl<S: FileSubtype + 'static> Node for File<S> {
         self.metadata.key()
     }
 
+    fn flags(&self) -> Flags {
+        self.flags
+    }
+
     fn kind(&self) -> NodeKind {
         NodeKind::Leaf
     }
@@ -191,6 +207,36 @@ impl<S: FileSubtype + 'static> Node for File<S> {
     fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
         self
     }
+
+    fn initial_value(&self) -> Option<Value> {
+        self.default_value()
+    }
+
+    #[cfg(feature = "serde")]
+    fn json_schema(&self) -> Option<serde_json::Value> {
+        let mut schema = serde_json::Map::new();
+        if self.multiple {
+            schema.insert("type".into(), "array".into());
+            schema.insert("items".into(), serde_json::json!({ "type": "string" }));
+        } else {
+            schema.insert("type".into(), "string".into());
+        }
+        Some(crate::schema::json_schema::annotated(self, schema, None))
+    }
+
+    #[cfg(feature = "validation")]
+    fn validatable(&self) -> Option<&dyn Validatable> {
+        Some(self)
+    }
+
+    #[cfg(feature = "visibility")]
+    fn visibility(&self) -> Option<&dyn Visibility> {
+        Some(self)
+    }
+
+    fn hash_settings(&self, mut state: &mut dyn Hasher) {
+        (S::name(), self.accept(), self.max_size(), self.multiple).hash(&mut state);
+    }
 }
 
 impl<S: FileSubtype> Leaf for File<S> {
@@ -200,6 +246,94 @@ impl<S: FileSubtype> Leaf for File<S> {
     }
 }
 
+#[cfg(feature = "visibility")]
+impl<S: FileSubtype + 'static> Visibility for File<S> {
+    fn visibility_expr(&self) -> Option<&Value> {
+        self.visibility.as_ref()
+    }
+
+    fn set_visibility_expr(&mut self, expr: Option<Value>) {
+        self.visibility = expr;
+    }
+}
+
+#[cfg(feature = "validation")]
+impl<S: FileSubtype> File<S> {
+    /// Returns whether a MIME type matches the accept list.
+    ///
+    /// An empty accept list allows any type; `type/*` matches any subtype.
+    fn accepts(&self, mime: &str) -> bool {
+        let accept = self.accept();
+        accept.is_empty()
+            || accept
+                .iter()
+                .any(|pattern| match pattern.strip_suffix("/*") {
+                    Some(prefix) => mime
+                        .strip_prefix(prefix)
+                        .is_some_and(|rest| rest.starts_with('/')),
+                    None => pattern.eq_ignore_ascii_case(mime),
+                })
+    }
+
+    /// Checks one file value: a text reference or a file object.
+    fn check_file(&self, value: &Value) -> Result<()> {
+        let file = match value {
+            Value::Text(_) => return Ok(()),
+            Value::Object(file) => file,
+            other => return Err(Error::type_mismatch("object", other.type_name())),
+        };
+
+        if let (Some(max), Some(size)) = (self.max_size(), file.get("size").and_then(Value::as_i64))
+        {
+            if u64::try_from(size).is_ok_and(|size| size > max) {
+                return Err(Error::validation_with_fields(
+                    "file_too_large",
+                    format!("file is {size} bytes, the limit is {max}"),
+                    vec![self.key().to_string()],
+                ));
+            }
+        }
+
+        if let Some(mime) = file.get("mime").and_then(Value::as_text) {
+            if !self.accepts(mime) {
+                return Err(Error::validation_with_fields(
+                    "invalid_mime_type",
+                    format!("file type '{mime}' is not accepted"),
+                    vec![self.key().to_string()],
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "validation")]
+impl<S: FileSubtype + 'static> Validatable for File<S> {
+    /// Validates the file size and MIME type of each file.
+    ///
+    /// Text values are treated as references to stored files and accepted
+    /// as-is. Multiple-file failures carry the file index as their field
+    /// path.
+    fn validate_sync(&self, value: &Value) -> Result<()> {
+        match value {
+            Value::Null if self.flags.is_required() => {
+                Err(Error::missing_required(self.key().as_str()))
+            }
+            Value::Null => Ok(()),
+            Value::Array(files) if self.multiple => {
+                for (index, file) in files.iter().enumerate() {
+                    self.check_file(file)
+                        .map_err(|e| e.at(&index.to_string()))?;
+                }
+                Ok(())
+            }
+            other if self.multiple => Err(Error::type_mismatch("array", other.type_name())),
+            other => self.check_file(other),
+        }
+    }
+}
+
 /// Builder for [`File`] parameters.
 #[derive(Debug, Clone)]
 pub struct FileBuilder<S: FileSubtype = crate::subtype::GenericFile> {
@@ -207,11 +341,16 @@ pub struct FileBuilder<S: FileSubtype = crate::subtype::GenericFile> {
     label: Option<SmartStr>,
     description: Option<SmartStr>,
     group: Option<Key>,
+    tags: Vec<Key>,
+    order: Option<i32>,
+    depends_on: Vec<Key>,
     flags: Flags,
     subtype: S,
     accept: Vec<SmartStr>,
     max_size: Option<u64>,
     multiple: bool,
+    #[cfg(feature = "visibility")]
+    visibility: Option<Value>,
 }
 
 impl FileBuilder<crate::subtype::GenericFile> {
@@ -222,11 +361,16 @@ impl FileBuilder<crate::subtype::GenericFile> {
             label: None,
             description: None,
             group: None,
+            tags: Vec::new(),
+            order: None,
+            depends_on: Vec::new(),
             flags: Flags::empty(),
             subtype: crate::subtype::GenericFile,
             accept: Vec::new(),
             max_size: None,
             multiple: false,
+            #[cfg(feature = "visibility")]
+            visibility: None,
         }
     }
 }
@@ -239,11 +383,16 @@ impl<S: FileSubtype> FileBuilder<S> {
             label: self.label,
             description: self.description,
             group: self.group,
+            tags: self.tags,
+            order: self.order,
+            depends_on: self.depends_on,
             flags: self.flags,
             subtype,
             accept: self.accept,
             max_size: self.max_size,
             multiple: self.multiple,
+            #[cfg(feature = "visibility")]
+            visibility: self.visibility,
         }
     }
 
@@ -268,6 +417,29 @@ impl<S: FileSubtype> FileBuilder<S> {
         self
     }
 
+    /// Adds a tag, such as `"advanced"`, for filtering and grouping.
+    #[must_use]
+    pub fn tag(mut self, tag: impl Into<Key>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Sets the layout order hint; lower values come first.
+    #[must_use]
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Declares that this parameter is derived from another one.
+    ///
+    /// See [`Schema::evaluation_order`](crate::schema::Schema::evaluation_order).
+    #[must_use]
+    pub fn depends_on(mut self, key: impl Into<Key>) -> Self {
+        self.depends_on.push(key.into());
+        self
+    }
+
     /// Adds additional accepted MIME types.
     #[must_use]
     pub fn accept(mut self, mime_types: impl IntoIterator<Item = impl Into<SmartStr>>) -> Self {
@@ -303,6 +475,16 @@ impl<S: FileSubtype> FileBuilder<S> {
         self
     }
 
+    /// Sets the visibility expression.
+    ///
+    /// See [`crate::visibility`] for the supported expression shape.
+    #[cfg(feature = "visibility")]
+    #[must_use]
+    pub fn visible_when(mut self, expr: Value) -> Self {
+        self.visibility = Some(expr);
+        self
+    }
+
     /// Marks the parameter as required.
     #[must_use]
     pub fn required(mut self) -> Self {
@@ -324,6 +506,23 @@ impl<S: FileSubtype> FileBuilder<S> {
         self
     }
 
+    /// Marks the parameter as deprecated.
+    #[must_use]
+    pub fn deprecated(mut self) -> Self {
+        self.flags |= Flags::DEPRECATED;
+        self
+    }
+
+    /// Marks the parameter as computed by the evaluation engine.
+    ///
+    /// Applies [`Flags::computed`], so the value is read-only and skipped
+    /// when saving.
+    #[must_use]
+    pub fn computed(mut self) -> Self {
+        self.flags |= Flags::computed();
+        self
+    }
+
     /// Builds the file parameter.
     #[must_use]
     pub fn build(self) -> File<S> {
@@ -338,6 +537,15 @@ impl<S: FileSubtype> FileBuilder<S> {
         if let Some(group) = self.group {
             metadata_builder = metadata_builder.group(group);
         }
+        for tag in self.tags {
+            metadata_builder = metadata_builder.tag(tag);
+        }
+        if let Some(order) = self.order {
+            metadata_builder = metadata_builder.order(order);
+        }
+        for dep in self.depends_on {
+            metadata_builder = metadata_builder.depends_on(dep);
+        }
 
         File {
             metadata: metadata_builder.build(),
@@ -346,6 +554,8 @@ impl<S: FileSubtype> FileBuilder<S> {
             accept: self.accept,
             max_size: self.max_size,
             multiple: self.multiple,
+            #[cfg(feature = "visibility")]
+            visibility: self.visibility,
         }
     }
 }
@@ -442,4 +652,45 @@ mod tests {
         assert_eq!(pdf_file.key(), "file");
         assert_eq!(pdf_file.accept(), vec!["application/pdf"]);
     }
+
+    #[cfg(feature = "validation")]
+    #[test]
+    fn test_file_validate() {
+        let upload = |mime: &str, size: i64| {
+            Value::object([
+                ("name", Value::text("upload")),
+                ("mime", Value::text(mime)),
+                ("size", Value::Int(size)),
+            ])
+        };
+
+        let photo = File::image("photo").max_size_kb(1).required().build();
+        assert!(photo.validatable().is_some());
+        assert!(photo.validate_sync(&upload("image/png", 512)).is_ok());
+        assert!(photo.validate_sync(&Value::text("file-abc123")).is_ok());
+        let err = photo.validate_sync(&upload("image/png", 4096)).unwrap_err();
+        assert_eq!(err.code(), Some("file_too_large"));
+        let err = photo
+            .validate_sync(&upload("application/pdf", 512))
+            .unwrap_err();
+        assert_eq!(err.code(), Some("invalid_mime_type"));
+        assert!(photo.validate_sync(&upload("imagefoo/png", 512)).is_err());
+        assert!(matches!(
+            photo.validate_sync(&Value::Null),
+            Err(Error::MissingRequired { .. })
+        ));
+
+        let attachments = File::builder("attachments").multiple().build();
+        assert!(attachments.validate_sync(&Value::Null).is_ok());
+        assert!(
+            attachments
+                .validate_sync(&Value::array([upload("text/plain", 1)]))
+                .is_ok()
+        );
+        let err = attachments
+            .validate_sync(&Value::array([upload("text/plain", 1), Value::Int(3)]))
+            .unwrap_err();
+        assert_eq!(err.field_path(), Some("1"));
+        assert!(attachments.validate_sync(&upload("text/plain", 1)).is_err());
+    }
 }
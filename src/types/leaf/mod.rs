@@ -29,7 +29,7 @@
 //! // Number with range and unit
 //! let temperature = Number::builder("temp")
 //!     .label("Temperature")
-//!     .range(-273.15..=1000.0)
+//!     .range(-273.15, 1000.0)
 //!     .build();
 //!
 //! // Boolean with default
@@ -60,11 +60,13 @@ mod file;
 mod number;
 mod select;
 mod text;
+#[cfg(feature = "validation")]
+mod validator;
 mod vector;
 
 pub use boolean::{Boolean, BooleanBuilder};
 pub use file::{File, FileBuilder};
 pub use number::{Number, NumberBuilder};
-pub use select::{OptionSource, Select, SelectBuilder, SelectOption, SelectionMode};
+pub use select::{OptionLoader, OptionSource, Select, SelectBuilder, SelectOption, SelectionMode};
 pub use text::{Text, TextBuilder};
 pub use vector::{Vector, VectorBuilder};
@@ -1,9 +1,21 @@
 //! Number parameter type for numeric values.
 
-use crate::core::{Flags, Key, Metadata, Value};
-use crate::subtype::{NumberSubtype, NumberUnit};
+use std::hash::{Hash, Hasher};
+
+use crate::core::{Error, Flags, Key, Metadata, Result, SmartStr, Value};
+use crate::subtype::{NumberSubtype, NumberSubtypeInfo, NumberUnit, Numeric};
 use crate::types::kind::NodeKind;
-use crate::types::traits::{Leaf, Node};
+use crate::types::traits::{Leaf, Node, invalid_input};
+
+#[cfg(feature = "visibility")]
+use crate::types::traits::Visibility;
+
+#[cfg(feature = "validation")]
+use super::validator::{FnValidator, NumberValidatorFn, collect_failures};
+#[cfg(feature = "validation")]
+use crate::types::traits::Validatable;
+#[cfg(feature = "validation")]
+use std::sync::Arc;
 
 /// A number parameter schema for numeric values.
 ///
@@ -31,8 +43,18 @@ pub struct Number<S: NumberSubtype> {
     metadata: Metadata,
     flags: Flags,
     subtype: S,
+    /// Set when built from a named subtype rather than a generic number.
+    subtype_info: Option<NumberSubtypeInfo>,
     unit: Option<NumberUnit>,
+    range: Option<(f64, f64)>,
+    soft_range: Option<(f64, f64)>,
     default: Option<f64>,
+    logarithmic: bool,
+    marks: Vec<(f64, SmartStr)>,
+    #[cfg(feature = "validation")]
+    validators: Vec<FnValidator<NumberValidatorFn>>,
+    #[cfg(feature = "visibility")]
+    visibility: Option<Value>,
 }
 
 impl<S: NumberSubtype> Number<S> {
@@ -42,12 +64,76 @@ impl<S: NumberSubtype> Number<S> {
         &self.subtype
     }
 
+    /// Returns a type-erased description of the subtype.
+    ///
+    /// The descriptor is recorded when building from a named subtype, such
+    /// as [`Number::port`] or [`IntoBuilder::into_builder`]. Its range and
+    /// unit are the effective ones: explicit builder settings take
+    /// precedence over the subtype defaults. Returns `None` for generic
+    /// numbers from [`Number::builder`].
+    ///
+    /// [`IntoBuilder::into_builder`]: crate::subtype::IntoBuilder::into_builder
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::subtype::NumericKind;
+    /// use paramdef::types::leaf::Number;
+    ///
+    /// let port = Number::port("port").build();
+    /// let info = port.subtype_descriptor().unwrap();
+    /// assert_eq!(info.name, "port");
+    /// assert_eq!(info.kind, NumericKind::I32);
+    /// assert_eq!(info.range, Some((1.0, 65535.0)));
+    ///
+    /// assert!(Number::integer("count").build().subtype_descriptor().is_none());
+    /// ```
+    #[must_use]
+    pub fn subtype_descriptor(&self) -> Option<NumberSubtypeInfo> {
+        self.subtype_info
+    }
+
     /// Returns the unit, if set.
     #[must_use]
     pub fn unit(&self) -> Option<NumberUnit> {
         self.unit
     }
 
+    /// Returns the allowed range as `(min, max)`, if any.
+    ///
+    /// An explicit range set on the builder takes precedence over the
+    /// subtype's [`default_range`](NumberSubtype::default_range).
+    #[must_use]
+    pub fn range(&self) -> Option<(f64, f64)> {
+        self.range
+            .or_else(|| S::default_range().map(|(min, max)| (min.to_f64(), max.to_f64())))
+    }
+
+    /// Returns the hard range as `(min, max)`, if any.
+    ///
+    /// Same as [`range`](Self::range): values outside it are invalid.
+    #[must_use]
+    pub fn hard_range(&self) -> Option<(f64, f64)> {
+        self.range()
+    }
+
+    /// Returns the recommended range as `(min, max)`, if set.
+    ///
+    /// Values outside it but inside the [hard range](Self::hard_range)
+    /// are valid and raise a warning. Sliders highlight this zone.
+    #[must_use]
+    pub fn soft_range(&self) -> Option<(f64, f64)> {
+        self.soft_range
+    }
+
+    /// Returns the labeled slider tick marks, sorted by value.
+    ///
+    /// Marks are display metadata and do not constrain the value.
+    #[must_use]
+    pub fn marks(&self) -> &[(f64, SmartStr)] {
+        &self.marks
+    }
+
     /// Returns the default value as f64, if set.
     #[must_use]
     pub fn default_f64(&self) -> Option<f64> {
@@ -66,6 +152,79 @@ impl<S: NumberSubtype> Number<S> {
     pub fn flags(&self) -> Flags {
         self.flags
     }
+
+    /// Returns whether sliders should use a logarithmic scale.
+    #[must_use]
+    pub fn is_logarithmic(&self) -> bool {
+        self.logarithmic
+    }
+
+    /// Maps a value to a slider position in `0.0..=1.0`.
+    ///
+    /// Uses linear interpolation over the [`range`](Self::range), or
+    /// logarithmic interpolation if [`is_logarithmic`](Self::is_logarithmic).
+    /// Values outside the range are clamped. Returns `None` if there is no
+    /// range or `value` is NaN.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::types::leaf::Number;
+    ///
+    /// let freq = Number::float("frequency")
+    ///     .range(20.0, 20_000.0)
+    ///     .logarithmic(true)
+    ///     .build();
+    ///
+    /// let mid = freq.to_slider_fraction(632.455_532).unwrap();
+    /// assert!((mid - 0.5).abs() < 1e-6);
+    /// ```
+    #[must_use]
+    pub fn to_slider_fraction(&self, value: f64) -> Option<f64> {
+        let (min, max) = self.range()?;
+        if value.is_nan() {
+            return None;
+        }
+        if max <= min {
+            return Some(0.0);
+        }
+
+        let value = value.clamp(min, max);
+        let fraction = if self.logarithmic {
+            (value / min).ln() / (max / min).ln()
+        } else {
+            (value - min) / (max - min)
+        };
+        Some(fraction.clamp(0.0, 1.0))
+    }
+
+    /// Maps a slider position in `0.0..=1.0` back to a value.
+    ///
+    /// The inverse of [`to_slider_fraction`](Self::to_slider_fraction).
+    /// Positions outside `0.0..=1.0` are clamped. Returns `None` if there is
+    /// no range or `fraction` is NaN.
+    #[must_use]
+    pub fn from_slider_fraction(&self, fraction: f64) -> Option<f64> {
+        let (min, max) = self.range()?;
+        if fraction.is_nan() {
+            return None;
+        }
+
+        let fraction = fraction.clamp(0.0, 1.0);
+        let value = if self.logarithmic {
+            min * (max / min).powf(fraction)
+        } else {
+            min + (max - min) * fraction
+        };
+        Some(value.clamp(min, max.max(min)))
+    }
+
+    /// Returns the number of custom validators attached to this parameter.
+    #[cfg(feature = "validation")]
+    #[must_use]
+    pub fn validator_count(&self) -> usize {
+        self.validators.len()
+    }
 }
 
 // Convenience constructors
@@ -90,21 +249,71 @@ impl Number<crate::subtype::GenericNumber> {
 impl Number<crate::subtype::Percentage> {
     /// Creates a percentage number parameter builder.
     pub fn percentage(key: impl Into<Key>) -> NumberBuilder<crate::subtype::Percentage> {
-        NumberBuilder::new(key, crate::subtype::Percentage)
+        NumberBuilder::from_subtype(key, crate::subtype::Percentage)
     }
 }
 
 impl Number<crate::subtype::Port> {
     /// Creates a port number parameter builder.
     pub fn port(key: impl Into<Key>) -> NumberBuilder<crate::subtype::Port> {
-        NumberBuilder::new(key, crate::subtype::Port)
+        NumberBuilder::from_subtype(key, crate::subtype::Port)
     }
 }
 
 impl Number<crate::subtype::Factor> {
     /// Creates a factor/multiplier parameter builder.
     pub fn factor(key: impl Into<Key>) -> NumberBuilder<crate::subtype::Factor> {
-        NumberBuilder::new(key, crate::subtype::Factor)
+        NumberBuilder::from_subtype(key, crate::subtype::Factor)
+    }
+}
+
+impl Number<crate::subtype::Money> {
+    /// Creates a money parameter builder storing minor units (cents).
+    ///
+    /// Values are integers such as `1234` for `$12.34`. Negative amounts
+    /// fail validation unless [`allow_negative`](NumberBuilder::allow_negative)
+    /// is set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `currency` is not a three-letter ISO 4217 code.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::types::leaf::Number;
+    ///
+    /// let price = Number::money("price", "USD").build();
+    /// assert_eq!(price.format_amount(1234), "$12.34");
+    /// ```
+    pub fn money(key: impl Into<Key>, currency: &str) -> NumberBuilder<crate::subtype::Money> {
+        let Some(subtype) = crate::subtype::Money::new(currency) else {
+            panic!("invalid ISO 4217 currency code '{currency}'");
+        };
+        NumberBuilder::from_subtype(key, subtype)
+    }
+
+    /// Returns the currency code of this parameter.
+    #[must_use]
+    pub fn currency(&self) -> &str {
+        self.subtype.currency()
+    }
+
+    /// Formats an amount in minor units in this parameter's currency.
+    ///
+    /// See [`Money::format`](crate::subtype::Money::format).
+    #[must_use]
+    pub fn format_amount(&self, minor_units: i64) -> String {
+        crate::subtype::Money::format(minor_units, self.currency())
+    }
+}
+
+impl NumberBuilder<crate::subtype::Money> {
+    /// Sets whether negative amounts (refunds, balances) are accepted.
+    #[must_use]
+    pub fn allow_negative(mut self, allow: bool) -> Self {
+        self.subtype = self.subtype.with_negative(allow);
+        self
     }
 }
 
@@ -117,6 +326,10 @@ impl<S: NumberSubtype + 'static> Node for Number<S> {
         self.metadata.key()
     }
 
+    fn flags(&self) -> Flags {
+        self.flags
+    }
+
     fn kind(&self) -> NodeKind {
         NodeKind::Leaf
     }
@@ -128,6 +341,88 @@ impl<S: NumberSubtype + 'static> Node for Number<S> {
     fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
         self
     }
+
+    fn numeric_range(&self) -> Option<(f64, f64)> {
+        self.range()
+    }
+
+    fn initial_value(&self) -> Option<Value> {
+        self.default_value()
+    }
+
+    fn coerce_input(&self, input: &str) -> Result<Value> {
+        let input = input.trim();
+        if input.is_empty() {
+            return Ok(Value::Null);
+        }
+        let parsed = if S::Value::kind().is_integer() {
+            input.parse().map(Value::Int).ok()
+        } else {
+            input.parse().map(Value::Float).ok()
+        };
+        parsed
+            .or_else(|| {
+                let unit = self.unit.or_else(S::recommended_unit)?;
+                match Value::text(input).coerce_with_units(unit)? {
+                    Value::Float(f) if !S::Value::kind().is_integer() => Some(Value::Float(f)),
+                    Value::Float(_) => None,
+                    Value::Int(i) if !S::Value::kind().is_integer() =>
+                    {
+                        #[allow(clippy::cast_precision_loss)]
+                        Some(Value::Float(i as f64))
+                    }
+                    value => Some(value),
+                }
+            })
+            .ok_or_else(|| invalid_input(self.key(), "expected a number"))
+    }
+
+    #[cfg(feature = "serde")]
+    fn json_schema(&self) -> Option<serde_json::Value> {
+        let integer = S::Value::kind().is_integer();
+
+        let mut schema = serde_json::Map::new();
+        schema.insert(
+            "type".into(),
+            if integer { "integer" } else { "number" }.into(),
+        );
+        if let Some((min, max)) = self.range() {
+            if min.is_finite() {
+                schema.insert("minimum".into(), min.into());
+            }
+            if max.is_finite() {
+                schema.insert("maximum".into(), max.into());
+            }
+        }
+
+        let default = if integer {
+            self.default_i64().map(Value::Int)
+        } else {
+            self.default_value()
+        };
+        Some(crate::schema::json_schema::annotated(self, schema, default))
+    }
+
+    #[cfg(feature = "validation")]
+    fn validatable(&self) -> Option<&dyn Validatable> {
+        Some(self)
+    }
+
+    #[cfg(feature = "visibility")]
+    fn visibility(&self) -> Option<&dyn Visibility> {
+        Some(self)
+    }
+
+    fn hash_settings(&self, mut state: &mut dyn Hasher) {
+        let bits = |(min, max): (f64, f64)| (min.to_bits(), max.to_bits());
+        (S::name(), self.unit, self.logarithmic).hash(&mut state);
+        (self.range.map(bits), self.soft_range.map(bits)).hash(&mut state);
+        self.default.map(f64::to_bits).hash(&mut state);
+        state.write_usize(self.marks.len());
+        for (value, label) in &self.marks {
+            (value.to_bits(), label).hash(&mut state);
+        }
+    }
 }
 
 impl<S: NumberSubtype> Leaf for Number<S> {
@@ -136,6 +431,67 @@ impl<S: NumberSubtype> Leaf for Number<S> {
     }
 }
 
+#[cfg(feature = "visibility")]
+impl<S: NumberSubtype + 'static> Visibility for Number<S> {
+    fn visibility_expr(&self) -> Option<&Value> {
+        self.visibility.as_ref()
+    }
+
+    fn set_visibility_expr(&mut self, expr: Option<Value>) {
+        self.visibility = expr;
+    }
+}
+
+#[cfg(feature = "validation")]
+impl<S: NumberSubtype + 'static> Validatable for Number<S> {
+    fn validate_sync(&self, value: &Value) -> crate::core::Result<()> {
+        let number = match value {
+            Value::Null if self.flags.is_required() => {
+                return Err(Error::missing_required(self.key().as_str()));
+            }
+            Value::Null => return Ok(()),
+            other => other
+                .as_f64()
+                .ok_or_else(|| Error::type_mismatch("number", other.type_name()))?,
+        };
+
+        // Every comparison with NaN is false, so range checks alone would pass it.
+        if !number.is_finite() {
+            return Err(Error::validation_with_fields(
+                "not_finite",
+                format!("value {number} is not a finite number"),
+                vec![self.key().to_string()],
+            ));
+        }
+
+        if let Some((min, max)) = self.range() {
+            if number < min || number > max {
+                return Err(Error::out_of_range(number, min, max));
+            }
+        }
+
+        if let Err(message) = self.subtype.check_value(number) {
+            return Err(Error::validation_with_fields(
+                format!("invalid_{}", S::name()),
+                message,
+                vec![self.key().to_string()],
+            ));
+        }
+
+        collect_failures(
+            self.key(),
+            self.validators.iter().map(|v| (v.get())(number)),
+        )
+    }
+
+    fn has_warning(&self, value: &Value) -> bool {
+        match (self.soft_range, value.as_f64()) {
+            (Some((min, max)), Some(number)) => number < min || number > max,
+            _ => false,
+        }
+    }
+}
+
 /// Builder for [`Number`] parameters.
 #[derive(Debug, Clone)]
 pub struct NumberBuilder<S: NumberSubtype> {
@@ -143,10 +499,22 @@ pub struct NumberBuilder<S: NumberSubtype> {
     label: Option<Key>,
     description: Option<Key>,
     group: Option<Key>,
+    tags: Vec<Key>,
+    order: Option<i32>,
+    depends_on: Vec<Key>,
     flags: Flags,
     subtype: S,
+    subtype_info: Option<NumberSubtypeInfo>,
     unit: Option<NumberUnit>,
+    range: Option<(f64, f64)>,
+    soft_range: Option<(f64, f64)>,
     default: Option<f64>,
+    logarithmic: bool,
+    marks: Vec<(f64, SmartStr)>,
+    #[cfg(feature = "validation")]
+    validators: Vec<FnValidator<NumberValidatorFn>>,
+    #[cfg(feature = "visibility")]
+    visibility: Option<Value>,
 }
 
 impl<S: NumberSubtype> NumberBuilder<S> {
@@ -157,10 +525,34 @@ impl<S: NumberSubtype> NumberBuilder<S> {
             label: None,
             description: None,
             group: None,
+            tags: Vec::new(),
+            order: None,
+            depends_on: Vec::new(),
             flags: Flags::empty(),
             subtype,
+            subtype_info: None,
             unit: None,
+            range: None,
+            soft_range: None,
             default: None,
+            logarithmic: false,
+            marks: Vec::new(),
+            #[cfg(feature = "validation")]
+            validators: Vec::new(),
+            #[cfg(feature = "visibility")]
+            visibility: None,
+        }
+    }
+
+    /// Creates a builder for a named subtype.
+    ///
+    /// Unlike [`new`](Self::new), the built number records a
+    /// [`NumberSubtypeInfo`] descriptor, returned by
+    /// [`Number::subtype_descriptor`].
+    pub fn from_subtype(key: impl Into<Key>, subtype: S) -> Self {
+        Self {
+            subtype_info: Some(NumberSubtypeInfo::of::<S>()),
+            ..Self::new(key, subtype)
         }
     }
 
@@ -185,6 +577,29 @@ impl<S: NumberSubtype> NumberBuilder<S> {
         self
     }
 
+    /// Adds a tag, such as `"advanced"`, for filtering and grouping.
+    #[must_use]
+    pub fn tag(mut self, tag: impl Into<Key>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Sets the layout order hint; lower values come first.
+    #[must_use]
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Declares that this parameter is derived from another one.
+    ///
+    /// See [`Schema::evaluation_order`](crate::schema::Schema::evaluation_order).
+    #[must_use]
+    pub fn depends_on(mut self, key: impl Into<Key>) -> Self {
+        self.depends_on.push(key.into());
+        self
+    }
+
     /// Sets the unit.
     #[must_use]
     pub fn unit(mut self, unit: NumberUnit) -> Self {
@@ -199,6 +614,53 @@ impl<S: NumberSubtype> NumberBuilder<S> {
         self
     }
 
+    /// Sets the allowed range (inclusive).
+    #[must_use]
+    pub fn range(mut self, min: f64, max: f64) -> Self {
+        self.range = Some((min, max));
+        self
+    }
+
+    /// Sets the recommended range (inclusive).
+    ///
+    /// Values outside it are still valid but raise a warning. Must lie
+    /// within the [allowed range](Self::range).
+    #[must_use]
+    pub fn soft_range(mut self, min: f64, max: f64) -> Self {
+        self.soft_range = Some((min, max));
+        self
+    }
+
+    /// Sets whether sliders should use a logarithmic scale.
+    ///
+    /// Suits frequencies, gains, and other values spanning several orders
+    /// of magnitude. Requires a range with a positive minimum.
+    #[must_use]
+    pub fn logarithmic(mut self, logarithmic: bool) -> Self {
+        self.logarithmic = logarithmic;
+        self
+    }
+
+    /// Adds labeled tick marks for sliders, such as "Low" and "High".
+    ///
+    /// Marks must lie within the [allowed range](Self::range). They are
+    /// sorted by value when built.
+    #[must_use]
+    pub fn marks(mut self, marks: impl IntoIterator<Item = (f64, SmartStr)>) -> Self {
+        self.marks.extend(marks);
+        self
+    }
+
+    /// Sets the visibility expression.
+    ///
+    /// See [`crate::visibility`] for the supported expression shape.
+    #[cfg(feature = "visibility")]
+    #[must_use]
+    pub fn visible_when(mut self, expr: Value) -> Self {
+        self.visibility = Some(expr);
+        self
+    }
+
     /// Marks the parameter as required.
     #[must_use]
     pub fn required(mut self) -> Self {
@@ -220,9 +682,119 @@ impl<S: NumberSubtype> NumberBuilder<S> {
         self
     }
 
+    /// Marks the parameter as deprecated.
+    #[must_use]
+    pub fn deprecated(mut self) -> Self {
+        self.flags |= Flags::DEPRECATED;
+        self
+    }
+
+    /// Marks the parameter as computed by the evaluation engine.
+    ///
+    /// Applies [`Flags::computed`], so the value is read-only and skipped
+    /// when saving.
+    #[must_use]
+    pub fn computed(mut self) -> Self {
+        self.flags |= Flags::computed();
+        self
+    }
+
+    /// Adds a custom validator run against numeric values.
+    ///
+    /// Integer and float values are both passed as `f64`. Validators are run
+    /// in insertion order and every failure is reported.
+    #[cfg(feature = "validation")]
+    #[must_use]
+    pub fn validate_with(
+        mut self,
+        f: impl Fn(f64) -> std::result::Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        self.validators.push(FnValidator::new(Arc::new(f)));
+        self
+    }
+
     /// Builds the number parameter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [range](Self::range) is inverted, if the parameter is
+    /// [logarithmic](Self::logarithmic) without a range whose minimum is
+    /// positive, or if the
+    /// [soft range](Self::soft_range) or a [mark](Self::marks) is not within
+    /// the allowed range. Use [`try_build`](Self::try_build) to handle
+    /// these as errors.
     #[must_use]
     pub fn build(self) -> Number<S> {
+        match self.try_build() {
+            Ok(number) => number,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Builds the number parameter, reporting invalid settings as errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `invalid_range` validation error if the range set via
+    /// [`range`](Self::range) has a minimum above its maximum or a NaN bound.
+    ///
+    /// Returns an `invalid_log_scale` validation error if the parameter is
+    /// [logarithmic](Self::logarithmic) and its range (explicit or from the
+    /// subtype) is missing or has a minimum that is not positive.
+    ///
+    /// Returns an `invalid_soft_range` validation error if the soft range
+    /// is inverted or extends past the allowed range.
+    ///
+    /// Returns an `invalid_mark` validation error if a mark is NaN or
+    /// outside the allowed range.
+    pub fn try_build(mut self) -> crate::core::Result<Number<S>> {
+        if let Some((min, max)) = self.range {
+            if min.is_nan() || max.is_nan() || min > max {
+                return Err(Error::validation_with_fields(
+                    "invalid_range",
+                    format!("range [{min}, {max}] has its minimum above its maximum"),
+                    vec![self.key.to_string()],
+                ));
+            }
+        }
+
+        let range = self
+            .range
+            .or_else(|| S::default_range().map(|(min, max)| (min.to_f64(), max.to_f64())));
+
+        if let Some((soft_min, soft_max)) = self.soft_range {
+            let (min, max) = range.unwrap_or((f64::NEG_INFINITY, f64::INFINITY));
+            if !(min <= soft_min && soft_min <= soft_max && soft_max <= max) {
+                return Err(Error::validation_with_fields(
+                    "invalid_soft_range",
+                    format!("soft range [{soft_min}, {soft_max}] is not within [{min}, {max}]"),
+                    vec![self.key.to_string()],
+                ));
+            }
+        }
+
+        let (min, max) = range.unwrap_or((f64::NEG_INFINITY, f64::INFINITY));
+        if let Some((value, label)) = self
+            .marks
+            .iter()
+            .find(|(value, _)| !(min <= *value && *value <= max))
+        {
+            return Err(Error::validation_with_fields(
+                "invalid_mark",
+                format!("mark '{label}' at {value} is not within [{min}, {max}]"),
+                vec![self.key.to_string()],
+            ));
+        }
+        self.marks.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        if self.logarithmic && !range.is_some_and(|(min, _)| min > 0.0) {
+            return Err(Error::validation_with_fields(
+                "invalid_log_scale",
+                "logarithmic scale requires a range with a positive minimum",
+                vec![self.key.to_string()],
+            ));
+        }
+
         let mut metadata_builder = Metadata::builder(self.key);
 
         if let Some(label) = self.label {
@@ -234,14 +806,38 @@ impl<S: NumberSubtype> NumberBuilder<S> {
         if let Some(group) = self.group {
             metadata_builder = metadata_builder.group(group);
         }
+        for tag in self.tags {
+            metadata_builder = metadata_builder.tag(tag);
+        }
+        if let Some(order) = self.order {
+            metadata_builder = metadata_builder.order(order);
+        }
+        for dep in self.depends_on {
+            metadata_builder = metadata_builder.depends_on(dep);
+        }
+
+        let subtype_info = self.subtype_info.map(|info| NumberSubtypeInfo {
+            range,
+            unit: self.unit.or(info.unit),
+            ..info
+        });
 
-        Number {
+        Ok(Number {
             metadata: metadata_builder.build(),
             flags: self.flags,
             subtype: self.subtype,
+            subtype_info,
             unit: self.unit,
+            range: self.range,
+            soft_range: self.soft_range,
             default: self.default,
-        }
+            logarithmic: self.logarithmic,
+            marks: self.marks,
+            #[cfg(feature = "validation")]
+            validators: self.validators,
+            #[cfg(feature = "visibility")]
+            visibility: self.visibility,
+        })
     }
 }
 
@@ -258,6 +854,36 @@ mod tests {
         assert!(num.default_value().is_none());
     }
 
+    #[test]
+    fn test_number_coerce_input() {
+        let port = Number::port("port").build();
+        assert_eq!(port.coerce_input(" 8080 ").unwrap(), Value::Int(8080));
+        assert!(port.coerce_input("80.5").is_err());
+
+        let ratio = Number::builder("ratio").build();
+        assert_eq!(ratio.coerce_input("0.25").unwrap(), Value::Float(0.25));
+        assert_eq!(ratio.coerce_input("").unwrap(), Value::Null);
+        assert!(ratio.coerce_input("abc").is_err());
+    }
+
+    #[test]
+    fn test_number_coerce_input_with_unit() {
+        let size = Number::builder("size").unit(NumberUnit::Bytes).build();
+        assert_eq!(size.coerce_input("2 KB").unwrap(), Value::Float(2048.0));
+        assert_eq!(size.coerce_input("512").unwrap(), Value::Float(512.0));
+        assert!(size.coerce_input("5 s").is_err());
+
+        // Integer subtypes reject fractional results.
+        let port = Number::port("port").unit(NumberUnit::Kilobytes).build();
+        assert_eq!(port.coerce_input("1 MB").unwrap(), Value::Int(1024));
+        assert!(port.coerce_input("1 B").is_err());
+
+        let timeout = Number::float("timeout").unit(NumberUnit::Seconds).build();
+        assert_eq!(timeout.coerce_input("2 min").unwrap(), Value::Float(120.0));
+
+        assert!(Number::integer("n").build().coerce_input("2 KB").is_err());
+    }
+
     #[test]
     fn test_number_builder() {
         let num = Number::float("temperature")
@@ -301,4 +927,286 @@ mod tests {
         assert!(value.is_some());
         assert_eq!(value.unwrap(), Value::Float(3.14));
     }
+
+    #[test]
+    fn test_number_slider_fraction_linear() {
+        let num = Number::float("x").range(10.0, 20.0).build();
+        assert!(!num.is_logarithmic());
+
+        assert_eq!(num.to_slider_fraction(15.0), Some(0.5));
+        assert_eq!(num.to_slider_fraction(99.0), Some(1.0));
+        assert_eq!(num.from_slider_fraction(0.25), Some(12.5));
+        assert_eq!(num.from_slider_fraction(-1.0), Some(10.0));
+        assert_eq!(num.to_slider_fraction(f64::NAN), None);
+
+        assert_eq!(Number::float("y").build().to_slider_fraction(1.0), None);
+    }
+
+    #[test]
+    fn test_number_slider_fraction_logarithmic() {
+        let num = Number::float("gain")
+            .range(1.0, 1000.0)
+            .logarithmic(true)
+            .build();
+        assert!(num.is_logarithmic());
+
+        let third = num.to_slider_fraction(10.0).unwrap();
+        assert!((third - 1.0 / 3.0).abs() < 1e-12);
+
+        let value = num.from_slider_fraction(2.0 / 3.0).unwrap();
+        assert!((value - 100.0).abs() < 1e-9);
+        assert_eq!(num.from_slider_fraction(0.0), Some(1.0));
+        assert_eq!(num.to_slider_fraction(0.0), Some(0.0));
+    }
+
+    #[test]
+    fn test_number_logarithmic_requires_positive_min() {
+        let err = Number::float("f")
+            .range(0.0, 10.0)
+            .logarithmic(true)
+            .try_build()
+            .unwrap_err();
+        assert_eq!(err.code(), Some("invalid_log_scale"));
+        assert_eq!(err.fields(), ["f"]);
+
+        assert!(Number::float("f").logarithmic(true).try_build().is_err());
+        assert!(
+            Number::float("f")
+                .range(0.5, 10.0)
+                .logarithmic(true)
+                .try_build()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "logarithmic scale")]
+    fn test_number_logarithmic_build_panics() {
+        let _ = Number::float("f").logarithmic(true).build();
+    }
+
+    #[cfg(feature = "validation")]
+    #[test]
+    fn test_number_validate_with() {
+        let num = Number::integer("count")
+            .validate_with(|n| {
+                if n.fract().abs() < f64::EPSILON {
+                    Ok(())
+                } else {
+                    Err("must be a whole number".into())
+                }
+            })
+            .validate_with(|n| {
+                if n >= 0.0 {
+                    Ok(())
+                } else {
+                    Err("must be non-negative".into())
+                }
+            })
+            .build();
+
+        assert_eq!(num.validator_count(), 2);
+        assert!(num.validate_sync(&Value::Int(4)).is_ok());
+        assert!(num.validate_sync(&Value::Float(2.0)).is_ok());
+
+        let err = num.validate_sync(&Value::Float(-2.5)).unwrap_err();
+        assert_eq!(err.code(), Some("custom"));
+        assert!(err.to_string().contains("must be a whole number"));
+        assert!(err.to_string().contains("must be non-negative"));
+    }
+
+    #[cfg(feature = "validation")]
+    #[test]
+    fn test_number_validate_type_mismatch() {
+        let num = Number::builder("x").build();
+
+        assert!(num.validate_sync(&Value::text("1")).is_err());
+        assert!(num.validate_sync(&Value::Null).is_ok());
+    }
+
+    #[test]
+    fn test_number_range() {
+        let num = Number::builder("volume").range(0.0, 11.0).build();
+        assert_eq!(num.range(), Some((0.0, 11.0)));
+
+        // Falls back to the subtype range
+        let port = Number::port("port").build();
+        assert_eq!(port.range(), Some((1.0, 65535.0)));
+        assert_eq!(Number::builder("x").build().range(), None);
+
+        let err = Number::builder("volume")
+            .range(11.0, 0.0)
+            .try_build()
+            .unwrap_err();
+        assert_eq!(err.code(), Some("invalid_range"));
+        assert_eq!(err.fields(), ["volume"]);
+        assert!(
+            Number::builder("volume")
+                .range(f64::NAN, 1.0)
+                .try_build()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_number_soft_range() {
+        let gain = Number::builder("gain")
+            .range(-60.0, 12.0)
+            .soft_range(-24.0, 6.0)
+            .build();
+        assert_eq!(gain.hard_range(), Some((-60.0, 12.0)));
+        assert_eq!(gain.soft_range(), Some((-24.0, 6.0)));
+
+        let err = Number::builder("gain")
+            .range(-60.0, 12.0)
+            .soft_range(-24.0, 24.0)
+            .try_build()
+            .unwrap_err();
+        assert_eq!(err.code(), Some("invalid_soft_range"));
+        assert!(
+            Number::builder("gain")
+                .soft_range(6.0, -24.0)
+                .try_build()
+                .is_err()
+        );
+        assert!(
+            Number::builder("gain")
+                .soft_range(-24.0, 6.0)
+                .try_build()
+                .is_ok()
+        );
+        assert!(
+            Number::port("port")
+                .soft_range(0.0, 80.0)
+                .try_build()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn test_number_marks() {
+        let level = Number::builder("level")
+            .range(0.0, 100.0)
+            .marks([
+                (100.0, "High".into()),
+                (0.0, "Low".into()),
+                (50.0, "Medium".into()),
+            ])
+            .build();
+        let labels: Vec<&str> = level.marks().iter().map(|(_, l)| l.as_str()).collect();
+        assert_eq!(labels, ["Low", "Medium", "High"]);
+        assert_eq!(level.marks()[1], (50.0, SmartStr::from("Medium")));
+        assert!(Number::builder("plain").build().marks().is_empty());
+
+        let err = Number::builder("level")
+            .range(0.0, 100.0)
+            .marks([(150.0, "Max".into())])
+            .try_build()
+            .unwrap_err();
+        assert_eq!(err.code(), Some("invalid_mark"));
+        assert_eq!(err.fields(), ["level"]);
+        assert!(
+            Number::builder("free")
+                .marks([(f64::NAN, "?".into())])
+                .try_build()
+                .is_err()
+        );
+        assert!(
+            Number::port("port")
+                .marks([(0.0, "none".into())])
+                .try_build()
+                .is_err()
+        );
+    }
+
+    #[cfg(feature = "validation")]
+    #[test]
+    fn test_number_soft_range_warning() {
+        let gain = Number::builder("gain")
+            .range(-60.0, 12.0)
+            .soft_range(-24.0, 6.0)
+            .build();
+
+        assert!(!gain.has_warning(&Value::Float(0.0)));
+        assert!(gain.validate_sync(&Value::Float(10.0)).is_ok());
+        assert!(gain.has_warning(&Value::Float(10.0)));
+        assert!(gain.validate_sync(&Value::Float(20.0)).is_err());
+    }
+
+    #[cfg(feature = "validation")]
+    #[test]
+    fn test_number_validate_range() {
+        let num = Number::builder("volume").range(0.0, 11.0).build();
+
+        assert!(num.validate_sync(&Value::Int(11)).is_ok());
+        assert!(num.validate_sync(&Value::Float(11.5)).is_err());
+        assert!(num.validate_sync(&Value::Int(-1)).is_err());
+
+        for value in [f64::NAN, f64::INFINITY] {
+            let err = num.validate_sync(&Value::Float(value)).unwrap_err();
+            assert_eq!(err.code(), Some("not_finite"));
+        }
+        let unbounded = Number::builder("free").build();
+        assert!(unbounded.validate_sync(&Value::Float(f64::NAN)).is_err());
+    }
+
+    #[test]
+    fn test_number_money() {
+        let price = Number::money("price", "eur").build();
+
+        assert_eq!(price.currency(), "EUR");
+        assert_eq!(price.format_amount(1999), "€19.99");
+        assert!(!price.subtype().allows_negative());
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid ISO 4217 currency code")]
+    fn test_number_money_invalid_currency() {
+        let _ = Number::money("price", "euro");
+    }
+
+    #[cfg(feature = "validation")]
+    #[test]
+    fn test_number_money_validate() {
+        let price = Number::money("price", "USD").build();
+
+        assert!(price.validate_sync(&Value::Int(1234)).is_ok());
+        let err = price.validate_sync(&Value::Int(-1)).unwrap_err();
+        assert_eq!(err.code(), Some("invalid_money"));
+        assert!(price.validate_sync(&Value::Float(12.5)).is_err());
+
+        let balance = Number::money("balance", "USD").allow_negative(true).build();
+        assert!(balance.validate_sync(&Value::Int(-1)).is_ok());
+    }
+
+    #[test]
+    fn test_number_subtype_descriptor() {
+        use crate::subtype::{IntoBuilder, NumericKind};
+
+        let percentage = Number::percentage("opacity")
+            .unit(NumberUnit::Percent)
+            .range(0.0, 50.0)
+            .build();
+        let info = percentage.subtype_descriptor().unwrap();
+
+        assert_eq!(info.name, "percentage");
+        assert_eq!(info.kind, NumericKind::F64);
+        assert_eq!(info.range, Some((0.0, 50.0)));
+        assert_eq!(info.unit, Some(NumberUnit::Percent));
+
+        assert_eq!(
+            Number::money("price", "USD")
+                .build()
+                .subtype_descriptor()
+                .map(|i| i.kind),
+            Some(NumericKind::I64)
+        );
+        assert!(Number::builder("x").build().subtype_descriptor().is_none());
+
+        let port = crate::subtype::Port::into_builder("port").build();
+        assert_eq!(
+            port.subtype_descriptor().map(|i| (i.name, i.range)),
+            Some(("port", Some((1.0, 65535.0))))
+        );
+    }
 }
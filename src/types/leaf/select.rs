@@ -1,11 +1,23 @@
 //! Select parameter type for single/multiple selection.
 
-use crate::core::{Flags, Key, Metadata, SmartStr, Value};
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+use crate::context::Context;
+use crate::core::{Flags, Key, Metadata, Result, SmartStr, Value};
 use crate::types::kind::NodeKind;
 use crate::types::traits::{Leaf, Node};
 
+#[cfg(feature = "validation")]
+use crate::core::Error;
+#[cfg(feature = "validation")]
+use crate::types::traits::Validatable;
+#[cfg(feature = "visibility")]
+use crate::types::traits::Visibility;
+
 /// Selection mode for the select parameter.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub enum SelectionMode {
     /// Single selection (dropdown, radio buttons).
     #[default]
@@ -24,6 +36,43 @@ pub enum OptionSource {
     Dynamic,
 }
 
+/// Loads select options at runtime.
+///
+/// Attach a loader with [`SelectBuilder::loader`] to back a
+/// [`OptionSource::Dynamic`] select. The loader receives the current
+/// [`Context`], so options can depend on other parameters, such as cities
+/// filtered by the selected country.
+///
+/// Closures of the form `Fn(&Context) -> Result<Vec<SelectOption>>`
+/// implement this trait.
+pub trait OptionLoader: Send + Sync {
+    /// Loads the options for the current context.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the options cannot be loaded.
+    fn load(&self, ctx: &Context) -> Result<Vec<SelectOption>>;
+}
+
+impl<F> OptionLoader for F
+where
+    F: Fn(&Context) -> Result<Vec<SelectOption>> + Send + Sync,
+{
+    fn load(&self, ctx: &Context) -> Result<Vec<SelectOption>> {
+        self(ctx)
+    }
+}
+
+/// A shared, cloneable handle to an option loader.
+#[derive(Clone)]
+struct LoaderHandle(Arc<dyn OptionLoader>);
+
+impl fmt::Debug for LoaderHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("OptionLoader(..)")
+    }
+}
+
 /// A single option in a select parameter.
 #[derive(Debug, Clone)]
 pub struct SelectOption {
@@ -135,6 +184,10 @@ pub struct Select {
     searchable: bool,
     /// Whether new options can be created by the user.
     creatable: bool,
+    /// Runtime option loader (when `option_source` is `Dynamic`).
+    loader: Option<LoaderHandle>,
+    #[cfg(feature = "visibility")]
+    visibility: Option<Value>,
 }
 
 impl Select {
@@ -166,6 +219,57 @@ impl Select {
         &self.options
     }
 
+    /// Returns the runtime option loader, if set.
+    #[must_use]
+    pub fn loader(&self) -> Option<&Arc<dyn OptionLoader>> {
+        self.loader.as_ref().map(|handle| &handle.0)
+    }
+
+    /// Returns the options to offer for the current context.
+    ///
+    /// Dynamic selects with a [loader](SelectBuilder::loader) call it;
+    /// otherwise the static options are returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error reported by the loader.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use paramdef::context::Context;
+    /// use paramdef::core::{Result, Value};
+    /// use paramdef::schema::Schema;
+    /// use paramdef::types::leaf::{Select, SelectOption};
+    ///
+    /// let city = Select::single("city")
+    ///     .loader(Arc::new(|ctx: &Context| -> Result<Vec<SelectOption>> {
+    ///         let cities: &[&str] = match ctx.get("country").and_then(Value::as_text) {
+    ///             Some("fr") => &["paris", "lyon"],
+    ///             _ => &[],
+    ///         };
+    ///         Ok(cities.iter().copied().map(SelectOption::simple).collect())
+    ///     }))
+    ///     .build();
+    ///
+    /// let schema = Schema::builder()
+    ///     .parameter(Select::single("country").build())
+    ///     .parameter(city.clone())
+    ///     .build();
+    /// let mut ctx = Context::new(Arc::new(schema));
+    /// assert!(city.resolve_options(&ctx).unwrap().is_empty());
+    ///
+    /// ctx.set("country", Value::text("fr"));
+    /// assert_eq!(city.resolve_options(&ctx).unwrap().len(), 2);
+    /// ```
+    pub fn resolve_options(&self, ctx: &Context) -> Result<Vec<SelectOption>> {
+        match (&self.option_source, &self.loader) {
+            (OptionSource::Dynamic, Some(loader)) => loader.0.load(ctx),
+            _ => Ok(self.options.clone()),
+        }
+    }
+
     /// Returns the default value for single selection.
     #[must_use]
     pub fn default_single(&self) -> Option<&Key> {
@@ -206,6 +310,10 @@ impl Node for Select {
         self.metadata.key()
     }
 
+    fn flags(&self) -> Flags {
+        self.flags
+    }
+
     fn kind(&self) -> NodeKind {
         NodeKind::Leaf
     }
@@ -217,6 +325,87 @@ impl Node for Select {
     fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
         self
     }
+
+    fn initial_value(&self) -> Option<Value> {
+        self.default_value()
+    }
+
+    /// Multiple selects split the input on commas.
+    fn coerce_input(&self, input: &str) -> Result<Value> {
+        Ok(match self.selection_mode {
+            SelectionMode::Single => Value::text(input),
+            SelectionMode::Multiple => Value::array(
+                input
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|part| !part.is_empty())
+                    .map(Value::text),
+            ),
+        })
+    }
+
+    #[cfg(feature = "serde")]
+    fn json_schema(&self) -> Option<serde_json::Value> {
+        let mut item = serde_json::Map::new();
+        item.insert("type".into(), "string".into());
+        if matches!(self.option_source, OptionSource::Static) && !self.creatable {
+            let values = self
+                .options
+                .iter()
+                .map(|o| serde_json::Value::from(o.value.as_str()))
+                .collect();
+            item.insert("enum".into(), serde_json::Value::Array(values));
+        }
+
+        let schema = match self.selection_mode {
+            SelectionMode::Single => item,
+            SelectionMode::Multiple => {
+                let mut array = serde_json::Map::new();
+                array.insert("type".into(), "array".into());
+                array.insert("items".into(), serde_json::Value::Object(item));
+                array.insert("uniqueItems".into(), true.into());
+                array
+            }
+        };
+        Some(crate::schema::json_schema::annotated(
+            self,
+            schema,
+            self.default_value(),
+        ))
+    }
+
+    #[cfg(feature = "validation")]
+    fn validatable(&self) -> Option<&dyn Validatable> {
+        Some(self)
+    }
+
+    #[cfg(feature = "visibility")]
+    fn visibility(&self) -> Option<&dyn Visibility> {
+        Some(self)
+    }
+
+    fn hash_settings(&self, mut state: &mut dyn Hasher) {
+        let dynamic = matches!(self.option_source, OptionSource::Dynamic);
+        (
+            self.selection_mode,
+            dynamic,
+            self.searchable,
+            self.creatable,
+        )
+            .hash(&mut state);
+        state.write_usize(self.options.len());
+        for option in &self.options {
+            (
+                &option.value,
+                &option.label,
+                &option.description,
+                &option.icon,
+                &option.group,
+            )
+                .hash(&mut state);
+        }
+        (&self.default_single, &self.default_multiple).hash(&mut state);
+    }
 }
 
 impl Leaf for Select {
@@ -234,6 +423,69 @@ impl Leaf for Select {
     }
 }
 
+#[cfg(feature = "visibility")]
+impl Visibility for Select {
+    fn visibility_expr(&self) -> Option<&Value> {
+        self.visibility.as_ref()
+    }
+
+    fn set_visibility_expr(&mut self, expr: Option<Value>) {
+        self.visibility = expr;
+    }
+}
+
+#[cfg(feature = "validation")]
+impl Select {
+    /// Checks a single selected value against the static options.
+    ///
+    /// Dynamic and creatable selects accept any text, since their options
+    /// are not known at schema time.
+    fn check_choice(&self, value: &Value) -> Result<()> {
+        let Some(choice) = value.as_text() else {
+            return Err(Error::type_mismatch("text", value.type_name()));
+        };
+        let closed = matches!(self.option_source, OptionSource::Static) && !self.creatable;
+        if closed && !self.options.iter().any(|o| o.value.as_str() == choice) {
+            return Err(Error::not_in_allowed_values(choice));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "validation")]
+impl Validatable for Select {
+    /// Validates that the selection is one of the static options.
+    ///
+    /// Multiple selects must be arrays without duplicates; item failures
+    /// carry the item index as their field path.
+    fn validate_sync(&self, value: &Value) -> Result<()> {
+        match (value, self.selection_mode) {
+            (Value::Null, _) if self.flags.is_required() => {
+                Err(Error::missing_required(self.key().as_str()))
+            }
+            (Value::Null, _) => Ok(()),
+            (value, SelectionMode::Single) => self.check_choice(value),
+            (Value::Array(items), SelectionMode::Multiple) => {
+                for (index, item) in items.iter().enumerate() {
+                    let segment = index.to_string();
+                    self.check_choice(item).map_err(|e| e.at(&segment))?;
+                    if items[..index].contains(item) {
+                        return Err(Error::validation_at(
+                            "duplicate_item",
+                            segment,
+                            format!("select '{}' already contains this option", self.key()),
+                        ));
+                    }
+                }
+                Ok(())
+            }
+            (other, SelectionMode::Multiple) => {
+                Err(Error::type_mismatch("array", other.type_name()))
+            }
+        }
+    }
+}
+
 /// Builder for [`Select`] parameters.
 #[derive(Debug, Clone)]
 pub struct SelectBuilder {
@@ -241,6 +493,9 @@ pub struct SelectBuilder {
     label: Option<Key>,
     description: Option<Key>,
     group: Option<Key>,
+    tags: Vec<Key>,
+    order: Option<i32>,
+    depends_on: Vec<Key>,
     flags: Flags,
     selection_mode: SelectionMode,
     option_source: OptionSource,
@@ -249,6 +504,9 @@ pub struct SelectBuilder {
     default_multiple: Option<Vec<Key>>,
     searchable: bool,
     creatable: bool,
+    loader: Option<LoaderHandle>,
+    #[cfg(feature = "visibility")]
+    visibility: Option<Value>,
 }
 
 impl SelectBuilder {
@@ -259,6 +517,9 @@ impl SelectBuilder {
             label: None,
             description: None,
             group: None,
+            tags: Vec::new(),
+            order: None,
+            depends_on: Vec::new(),
             flags: Flags::empty(),
             selection_mode,
             option_source: OptionSource::Static,
@@ -267,6 +528,9 @@ impl SelectBuilder {
             default_multiple: None,
             searchable: false,
             creatable: false,
+            loader: None,
+            #[cfg(feature = "visibility")]
+            visibility: None,
         }
     }
 
@@ -291,6 +555,29 @@ impl SelectBuilder {
         self
     }
 
+    /// Adds a tag, such as `"advanced"`, for filtering and grouping.
+    #[must_use]
+    pub fn tag(mut self, tag: impl Into<Key>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Sets the layout order hint; lower values come first.
+    #[must_use]
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Declares that this parameter is derived from another one.
+    ///
+    /// See [`Schema::evaluation_order`](crate::schema::Schema::evaluation_order).
+    #[must_use]
+    pub fn depends_on(mut self, key: impl Into<Key>) -> Self {
+        self.depends_on.push(key.into());
+        self
+    }
+
     /// Sets the static options.
     #[must_use]
     pub fn options(mut self, options: Vec<SelectOption>) -> Self {
@@ -306,6 +593,17 @@ impl SelectBuilder {
         self
     }
 
+    /// Loads options at runtime with `loader`.
+    ///
+    /// Also marks the select as [dynamic](Self::dynamic). See
+    /// [`Select::resolve_options`].
+    #[must_use]
+    pub fn loader(mut self, loader: Arc<dyn OptionLoader>) -> Self {
+        self.loader = Some(LoaderHandle(loader));
+        self.option_source = OptionSource::Dynamic;
+        self
+    }
+
     /// Sets the default value for single selection.
     #[must_use]
     pub fn default_single(mut self, value: impl Into<Key>) -> Self {
@@ -334,6 +632,16 @@ impl SelectBuilder {
         self
     }
 
+    /// Sets the visibility expression.
+    ///
+    /// See [`crate::visibility`] for the supported expression shape.
+    #[cfg(feature = "visibility")]
+    #[must_use]
+    pub fn visible_when(mut self, expr: Value) -> Self {
+        self.visibility = Some(expr);
+        self
+    }
+
     /// Marks the parameter as required.
     #[must_use]
     pub fn required(mut self) -> Self {
@@ -355,6 +663,23 @@ impl SelectBuilder {
         self
     }
 
+    /// Marks the parameter as deprecated.
+    #[must_use]
+    pub fn deprecated(mut self) -> Self {
+        self.flags |= Flags::DEPRECATED;
+        self
+    }
+
+    /// Marks the parameter as computed by the evaluation engine.
+    ///
+    /// Applies [`Flags::computed`], so the value is read-only and skipped
+    /// when saving.
+    #[must_use]
+    pub fn computed(mut self) -> Self {
+        self.flags |= Flags::computed();
+        self
+    }
+
     /// Builds the select parameter.
     #[must_use]
     pub fn build(self) -> Select {
@@ -369,6 +694,15 @@ impl SelectBuilder {
         if let Some(group) = self.group {
             metadata_builder = metadata_builder.group(group);
         }
+        for tag in self.tags {
+            metadata_builder = metadata_builder.tag(tag);
+        }
+        if let Some(order) = self.order {
+            metadata_builder = metadata_builder.order(order);
+        }
+        for dep in self.depends_on {
+            metadata_builder = metadata_builder.depends_on(dep);
+        }
 
         Select {
             metadata: metadata_builder.build(),
@@ -380,6 +714,9 @@ impl SelectBuilder {
             default_multiple: self.default_multiple,
             searchable: self.searchable,
             creatable: self.creatable,
+            loader: self.loader,
+            #[cfg(feature = "visibility")]
+            visibility: self.visibility,
         }
     }
 }
@@ -477,4 +814,103 @@ mod tests {
         assert!(select.is_creatable());
         assert!(select.is_searchable());
     }
+
+    #[test]
+    fn test_select_resolve_options() {
+        use crate::schema::Schema;
+
+        let country = Select::single("country")
+            .options(vec![SelectOption::simple("fr"), SelectOption::simple("de")])
+            .build();
+        let city = Select::single("city")
+            .loader(Arc::new(|ctx: &Context| -> Result<Vec<SelectOption>> {
+                match ctx.get("country").and_then(Value::as_text) {
+                    Some("fr") => Ok(vec![SelectOption::simple("paris")]),
+                    Some(other) => Err(crate::core::Error::not_found(other)),
+                    None => Ok(Vec::new()),
+                }
+            }))
+            .build();
+
+        assert!(matches!(city.option_source(), OptionSource::Dynamic));
+        assert!(city.loader().is_some());
+        assert!(country.loader().is_none());
+
+        let schema = Schema::builder()
+            .parameter(country.clone())
+            .parameter(city.clone())
+            .build();
+        let mut ctx = Context::new(Arc::new(schema));
+
+        assert_eq!(country.resolve_options(&ctx).unwrap().len(), 2);
+        assert!(city.resolve_options(&ctx).unwrap().is_empty());
+
+        ctx.set("country", Value::text("fr"));
+        let cities = city.resolve_options(&ctx).unwrap();
+        assert_eq!(cities[0].value.as_str(), "paris");
+
+        ctx.set("country", Value::text("de"));
+        assert!(city.resolve_options(&ctx).is_err());
+    }
+
+    #[test]
+    fn test_select_dynamic_without_loader() {
+        use crate::schema::Schema;
+
+        let select = Select::single("db").dynamic().build();
+        let ctx = Context::new(Arc::new(Schema::builder().build()));
+
+        assert!(select.resolve_options(&ctx).unwrap().is_empty());
+    }
+
+    #[cfg(feature = "validation")]
+    #[test]
+    fn test_select_validate() {
+        let method = Select::single("method")
+            .options(vec![
+                SelectOption::simple("GET"),
+                SelectOption::simple("POST"),
+            ])
+            .required()
+            .build();
+        assert!(method.validatable().is_some());
+        assert!(method.validate_sync(&Value::text("GET")).is_ok());
+        assert!(matches!(
+            method.validate_sync(&Value::text("PATCH")),
+            Err(Error::NotInAllowedValues { .. })
+        ));
+        assert!(matches!(
+            method.validate_sync(&Value::Null),
+            Err(Error::MissingRequired { .. })
+        ));
+
+        let tags = Select::multiple("tags")
+            .options(vec![
+                SelectOption::simple("bug"),
+                SelectOption::simple("ui"),
+            ])
+            .build();
+        assert!(tags.validate_sync(&Value::Null).is_ok());
+        assert!(
+            tags.validate_sync(&Value::array([Value::text("bug"), Value::text("ui")]))
+                .is_ok()
+        );
+        let err = tags
+            .validate_sync(&Value::array([Value::text("bug"), Value::text("docs")]))
+            .unwrap_err();
+        assert_eq!(err.code(), Some("not_in_allowed_values"));
+        assert_eq!(err.field_path(), Some("1"));
+        let err = tags
+            .validate_sync(&Value::array([Value::text("bug"), Value::text("bug")]))
+            .unwrap_err();
+        assert_eq!(err.code(), Some("duplicate_item"));
+        assert!(tags.validate_sync(&Value::text("bug")).is_err());
+
+        let custom = Select::multiple("custom").creatable().build();
+        assert!(
+            custom
+                .validate_sync(&Value::array([Value::text("any")]))
+                .is_ok()
+        );
+    }
 }
@@ -1,10 +1,25 @@
 //! Text parameter type for string values.
 
-use crate::core::{Flags, Key, Metadata, SmartStr};
+use std::borrow::Cow;
+use std::hash::{Hash, Hasher};
+
+use crate::core::{Error, Flags, Key, Metadata, SmartStr};
 use crate::subtype::TextSubtype;
 use crate::types::kind::NodeKind;
 use crate::types::traits::{Leaf, Node};
 
+#[cfg(feature = "visibility")]
+use crate::types::traits::Visibility;
+
+#[cfg(feature = "validation")]
+use super::validator::{FnValidator, TextValidatorFn, collect_failures};
+#[cfg(any(feature = "validation", feature = "visibility", feature = "chrono"))]
+use crate::core::Value;
+#[cfg(feature = "validation")]
+use crate::types::traits::Validatable;
+#[cfg(feature = "validation")]
+use std::sync::Arc;
+
 /// A text parameter schema for string values.
 ///
 /// Text parameters support various string types through [`TextSubtype`].
@@ -30,6 +45,16 @@ pub struct Text<S: TextSubtype = crate::subtype::Plain> {
     flags: Flags,
     subtype: S,
     default: Option<SmartStr>,
+    pattern: Option<SmartStr>,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+    input_mask: Option<SmartStr>,
+    #[cfg(feature = "validation")]
+    compiled_patterns: Vec<regex::Regex>,
+    #[cfg(feature = "validation")]
+    validators: Vec<FnValidator<TextValidatorFn>>,
+    #[cfg(feature = "visibility")]
+    visibility: Option<Value>,
 }
 
 impl<S: TextSubtype> Text<S> {
@@ -50,8 +75,130 @@ impl<S: TextSubtype> Text<S> {
     pub fn flags(&self) -> Flags {
         self.flags
     }
+
+    /// Normalizes a value as the subtype requires.
+    ///
+    /// Subtypes such as [`Uuid`](crate::subtype::Uuid) and
+    /// [`Slug`](crate::subtype::Slug) lowercase their values; others return
+    /// the value unchanged. Validation checks the value as given, so
+    /// normalize input before storing it.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::types::leaf::Text;
+    ///
+    /// let id = Text::uuid("id");
+    /// assert_eq!(
+    ///     id.normalize("3F2504E0-4F89-11D3-9A0C-0305E82C3301"),
+    ///     "3f2504e0-4f89-11d3-9a0c-0305e82c3301"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn normalize<'a>(&self, value: &'a str) -> Cow<'a, str> {
+        if S::is_lowercase() && value.chars().any(char::is_uppercase) {
+            Cow::Owned(value.to_lowercase())
+        } else {
+            Cow::Borrowed(value)
+        }
+    }
+
+    /// Returns the explicit regex pattern set on the builder, if any.
+    ///
+    /// This does not include the subtype pattern; see [`TextSubtype::pattern`].
+    #[must_use]
+    pub fn pattern(&self) -> Option<&str> {
+        self.pattern.as_deref()
+    }
+
+    /// Returns the minimum length in characters, if set.
+    #[must_use]
+    pub fn min_length(&self) -> Option<usize> {
+        self.min_length
+    }
+
+    /// Returns the maximum length in characters, if set.
+    #[must_use]
+    pub fn max_length(&self) -> Option<usize> {
+        self.max_length
+    }
+
+    /// Returns the input mask, if set.
+    ///
+    /// See [`TextBuilder::input_mask`].
+    #[must_use]
+    pub fn input_mask(&self) -> Option<&str> {
+        self.input_mask.as_deref()
+    }
+
+    /// Formats raw input for display using the [input mask](Self::input_mask).
+    ///
+    /// Each `#` in the mask takes the next input character; other mask
+    /// characters are copied as literals. Output stops as soon as the input
+    /// runs out, so partial input is formatted as the user types. Input
+    /// beyond the last slot is dropped. Without a mask the input is
+    /// returned unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::types::leaf::Text;
+    ///
+    /// let phone = Text::builder("phone").input_mask("(###) ###-####").build();
+    /// assert_eq!(phone.apply_mask("5551234567"), "(555) 123-4567");
+    /// assert_eq!(phone.apply_mask("5551"), "(555) 1");
+    /// assert_eq!(phone.strip_mask("(555) 123-4567"), "5551234567");
+    /// ```
+    #[must_use]
+    pub fn apply_mask(&self, digits: &str) -> String {
+        let Some(mask) = self.input_mask.as_deref() else {
+            return digits.to_owned();
+        };
+
+        let mut input = digits.chars().peekable();
+        let mut masked = String::with_capacity(mask.len());
+        for slot in mask.chars() {
+            if input.peek().is_none() {
+                break;
+            }
+            if slot == MASK_SLOT {
+                masked.extend(input.next());
+            } else {
+                masked.push(slot);
+            }
+        }
+        masked
+    }
+
+    /// Recovers raw input from a masked string.
+    ///
+    /// Removes every character that appears as a literal in the
+    /// [input mask](Self::input_mask), so input pasted with different
+    /// separators is handled too. Without a mask the input is returned
+    /// unchanged.
+    #[must_use]
+    pub fn strip_mask(&self, masked: &str) -> String {
+        let Some(mask) = self.input_mask.as_deref() else {
+            return masked.to_owned();
+        };
+
+        masked
+            .chars()
+            .filter(|c| *c == MASK_SLOT || !mask.contains(*c))
+            .collect()
+    }
+
+    /// Returns the number of custom validators attached to this parameter.
+    #[cfg(feature = "validation")]
+    #[must_use]
+    pub fn validator_count(&self) -> usize {
+        self.validators.len()
+    }
 }
 
+/// The input mask placeholder for one raw character.
+const MASK_SLOT: char = '#';
+
 impl Text<crate::subtype::Plain> {
     /// Creates a new builder for a text parameter.
     pub fn builder(key: impl Into<Key>) -> TextBuilder<crate::subtype::Plain> {
@@ -76,13 +223,103 @@ impl Text<crate::subtype::Url> {
     }
 }
 
+impl Text<crate::subtype::Uuid> {
+    /// Creates a UUID text parameter.
+    ///
+    /// Values must be hyphenated hex (`8-4-4-4-12`), checked with the
+    /// `validation` feature, and [normalize](Text::normalize) to lowercase.
+    #[must_use]
+    pub fn uuid(key: impl Into<Key>) -> Self {
+        TextBuilder::new(key).subtype(crate::subtype::Uuid).build()
+    }
+}
+
+impl Text<crate::subtype::IpAddress> {
+    /// Creates an IP address text parameter.
+    ///
+    /// Values must be IPv4 or IPv6 addresses, checked with the
+    /// `validation` feature.
+    #[must_use]
+    pub fn ip(key: impl Into<Key>) -> Self {
+        TextBuilder::new(key)
+            .subtype(crate::subtype::IpAddress)
+            .build()
+    }
+}
+
+impl Text<crate::subtype::Cidr> {
+    /// Creates a CIDR network text parameter, such as `10.0.0.0/8`.
+    ///
+    /// Values are checked with the `validation` feature.
+    #[must_use]
+    pub fn cidr(key: impl Into<Key>) -> Self {
+        TextBuilder::new(key).subtype(crate::subtype::Cidr).build()
+    }
+}
+
+impl Text<crate::subtype::Phone> {
+    /// Creates a phone number text parameter builder.
+    ///
+    /// Values must be international numbers such as `+1 555 123 4567`,
+    /// checked with the `validation` feature. Set a
+    /// [`region`](TextBuilder::region) to also accept national numbers
+    /// with the `phonenumber` feature.
+    pub fn phone(key: impl Into<Key>) -> TextBuilder<crate::subtype::Phone> {
+        TextBuilder::new(key).subtype(crate::subtype::Phone::default())
+    }
+}
+
+impl Text<crate::subtype::Slug> {
+    /// Creates a URL slug text parameter.
+    ///
+    /// Values must be lowercase words joined by single hyphens, checked
+    /// with the `validation` feature, and [normalize](Text::normalize) to
+    /// lowercase.
+    #[must_use]
+    pub fn slug(key: impl Into<Key>) -> Self {
+        TextBuilder::new(key).subtype(crate::subtype::Slug).build()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl Text<crate::subtype::DateTime> {
+    /// Creates an RFC 3339 date-time text parameter.
+    ///
+    /// Values stay text; with the `validation` feature, unparseable values
+    /// are rejected with an `invalid_datetime` error.
+    #[must_use]
+    pub fn datetime(key: impl Into<Key>) -> Self {
+        TextBuilder::new(key)
+            .subtype(crate::subtype::DateTime)
+            .build()
+    }
+
+    /// Parses an RFC 3339 text value as a UTC timestamp.
+    ///
+    /// Returns `None` if the value is not text or does not parse.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::core::Value;
+    /// use paramdef::types::leaf::Text;
+    ///
+    /// let parsed = Text::parse_datetime(&Value::text("2024-05-01T12:00:00+02:00")).unwrap();
+    /// assert_eq!(parsed.to_rfc3339(), "2024-05-01T10:00:00+00:00");
+    /// ```
+    #[must_use]
+    pub fn parse_datetime(value: &Value) -> Option<chrono::DateTime<chrono::Utc>> {
+        let parsed = chrono::DateTime::parse_from_rfc3339(value.as_text()?).ok()?;
+        Some(parsed.with_timezone(&chrono::Utc))
+    }
+}
+
 impl Text<crate::subtype::Password> {
     /// Creates a password text parameter.
     #[must_use]
     pub fn password(key: impl Into<Key>) -> Self {
         TextBuilder::new(key)
             .subtype(crate::subtype::Password)
-            .sensitive()
             .build()
     }
 }
@@ -114,6 +351,10 @@ impl<S: TextSubtype + 'static> Node for Text<S> {
         self.metadata.key()
     }
 
+    fn flags(&self) -> Flags {
+        self.flags
+    }
+
     fn kind(&self) -> NodeKind {
         NodeKind::Leaf
     }
@@ -125,6 +366,75 @@ impl<S: TextSubtype + 'static> Node for Text<S> {
     fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
         self
     }
+
+    fn initial_value(&self) -> Option<crate::core::Value> {
+        self.default_value()
+    }
+
+    /// Keeps the input as text after applying [`normalize`](Text::normalize),
+    /// so lowercase subtypes store lowercased input.
+    fn coerce_input(&self, input: &str) -> crate::core::Result<crate::core::Value> {
+        Ok(crate::core::Value::text(&*self.normalize(input)))
+    }
+
+    #[cfg(feature = "serde")]
+    fn json_schema(&self) -> Option<serde_json::Value> {
+        use crate::schema::json_schema::{annotated, string_format};
+
+        let mut schema = serde_json::Map::new();
+        schema.insert("type".into(), "string".into());
+        if let Some(min) = self.min_length {
+            schema.insert("minLength".into(), min.into());
+        }
+        if let Some(max) = self.max_length {
+            schema.insert("maxLength".into(), max.into());
+        }
+
+        let patterns: Vec<&str> = S::pattern()
+            .into_iter()
+            .chain(self.pattern.as_deref())
+            .collect();
+        match patterns.as_slice() {
+            [] => {}
+            [pattern] => {
+                schema.insert("pattern".into(), (*pattern).into());
+            }
+            _ => {
+                let all_of = patterns
+                    .iter()
+                    .map(|p| serde_json::json!({ "pattern": p }))
+                    .collect();
+                schema.insert("allOf".into(), serde_json::Value::Array(all_of));
+            }
+        }
+
+        if let Some(format) = string_format(S::name()) {
+            schema.insert("format".into(), format.into());
+        }
+        if S::is_sensitive() {
+            schema.insert("writeOnly".into(), true.into());
+        }
+        if !S::allowed_values().is_empty() {
+            schema.insert("enum".into(), S::allowed_values().into());
+        }
+
+        Some(annotated(self, schema, self.default_value()))
+    }
+
+    #[cfg(feature = "validation")]
+    fn validatable(&self) -> Option<&dyn Validatable> {
+        Some(self)
+    }
+
+    #[cfg(feature = "visibility")]
+    fn visibility(&self) -> Option<&dyn Visibility> {
+        Some(self)
+    }
+
+    fn hash_settings(&self, mut state: &mut dyn Hasher) {
+        (S::name(), &self.default, &self.pattern, &self.input_mask).hash(&mut state);
+        (self.min_length, self.max_length).hash(&mut state);
+    }
 }
 
 impl<S: TextSubtype> Leaf for Text<S> {
@@ -133,6 +443,60 @@ impl<S: TextSubtype> Leaf for Text<S> {
     }
 }
 
+#[cfg(feature = "visibility")]
+impl<S: TextSubtype + 'static> Visibility for Text<S> {
+    fn visibility_expr(&self) -> Option<&Value> {
+        self.visibility.as_ref()
+    }
+
+    fn set_visibility_expr(&mut self, expr: Option<Value>) {
+        self.visibility = expr;
+    }
+}
+
+#[cfg(feature = "validation")]
+impl<S: TextSubtype + 'static> Validatable for Text<S> {
+    fn validate_sync(&self, value: &Value) -> crate::core::Result<()> {
+        let text = match value {
+            Value::Null if self.flags.is_required() => {
+                return Err(Error::missing_required(self.key().as_str()));
+            }
+            Value::Null => return Ok(()),
+            Value::Text(s) => s.as_str(),
+            other => return Err(Error::type_mismatch("text", other.type_name())),
+        };
+
+        if self.min_length.is_some() || self.max_length.is_some() {
+            let length = text.chars().count();
+            let min = self.min_length.unwrap_or(0);
+            let max = self.max_length.unwrap_or(usize::MAX);
+            if length < min || length > max {
+                return Err(Error::length_out_of_bounds(length, min, max));
+            }
+        }
+
+        // Empty optional values are handled by the required check, not patterns.
+        if !text.is_empty() {
+            if let Some(re) = self.compiled_patterns.iter().find(|re| !re.is_match(text)) {
+                return Err(Error::validation_with_fields(
+                    "pattern_mismatch",
+                    format!("value does not match pattern '{}'", re.as_str()),
+                    vec![self.key().to_string()],
+                ));
+            }
+            if let Err(message) = self.subtype.check_value(text) {
+                return Err(Error::validation_with_fields(
+                    format!("invalid_{}", S::name()),
+                    message,
+                    vec![self.key().to_string()],
+                ));
+            }
+        }
+
+        collect_failures(self.key(), self.validators.iter().map(|v| (v.get())(text)))
+    }
+}
+
 /// Builder for [`Text`] parameters.
 #[derive(Debug, Clone)]
 pub struct TextBuilder<S: TextSubtype = crate::subtype::Plain> {
@@ -140,9 +504,36 @@ pub struct TextBuilder<S: TextSubtype = crate::subtype::Plain> {
     label: Option<SmartStr>,
     description: Option<SmartStr>,
     group: Option<Key>,
+    tags: Vec<Key>,
+    order: Option<i32>,
+    depends_on: Vec<Key>,
     flags: Flags,
     subtype: S,
     default: Option<SmartStr>,
+    pattern: Option<SmartStr>,
+    min_length: Option<usize>,
+    max_length: Option<usize>,
+    input_mask: Option<SmartStr>,
+    #[cfg(feature = "validation")]
+    validators: Vec<FnValidator<TextValidatorFn>>,
+    #[cfg(feature = "visibility")]
+    visibility: Option<Value>,
+}
+
+impl TextBuilder<crate::subtype::Phone> {
+    /// Sets the ISO 3166 region used to read national phone numbers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `region` is not two ASCII letters.
+    #[must_use]
+    pub fn region(mut self, region: &str) -> Self {
+        let Some(phone) = crate::subtype::Phone::with_region(region) else {
+            panic!("invalid ISO 3166 region code '{region}'");
+        };
+        self.subtype = phone;
+        self
+    }
 }
 
 impl TextBuilder<crate::subtype::Plain> {
@@ -153,9 +544,20 @@ impl TextBuilder<crate::subtype::Plain> {
             label: None,
             description: None,
             group: None,
+            tags: Vec::new(),
+            order: None,
+            depends_on: Vec::new(),
             flags: Flags::empty(),
             subtype: crate::subtype::Plain,
             default: None,
+            pattern: None,
+            min_length: None,
+            max_length: None,
+            input_mask: None,
+            #[cfg(feature = "validation")]
+            validators: Vec::new(),
+            #[cfg(feature = "visibility")]
+            visibility: None,
         }
     }
 }
@@ -168,9 +570,20 @@ impl<S: TextSubtype> TextBuilder<S> {
             label: self.label,
             description: self.description,
             group: self.group,
+            tags: self.tags,
+            order: self.order,
+            depends_on: self.depends_on,
             flags: self.flags,
             subtype,
             default: self.default,
+            pattern: self.pattern,
+            min_length: self.min_length,
+            max_length: self.max_length,
+            input_mask: self.input_mask,
+            #[cfg(feature = "validation")]
+            validators: self.validators,
+            #[cfg(feature = "visibility")]
+            visibility: self.visibility,
         }
     }
 
@@ -195,6 +608,29 @@ impl<S: TextSubtype> TextBuilder<S> {
         self
     }
 
+    /// Adds a tag, such as `"advanced"`, for filtering and grouping.
+    #[must_use]
+    pub fn tag(mut self, tag: impl Into<Key>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Sets the layout order hint; lower values come first.
+    #[must_use]
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Declares that this parameter is derived from another one.
+    ///
+    /// See [`Schema::evaluation_order`](crate::schema::Schema::evaluation_order).
+    #[must_use]
+    pub fn depends_on(mut self, key: impl Into<Key>) -> Self {
+        self.depends_on.push(key.into());
+        self
+    }
+
     /// Sets the default value.
     #[must_use]
     pub fn default(mut self, value: impl Into<SmartStr>) -> Self {
@@ -202,6 +638,52 @@ impl<S: TextSubtype> TextBuilder<S> {
         self
     }
 
+    /// Sets a regex pattern that text values must match.
+    ///
+    /// The pattern is checked in addition to the subtype pattern, if any.
+    /// With the `validation` feature, it is compiled when the parameter is built.
+    #[must_use]
+    pub fn pattern(mut self, regex: &str) -> Self {
+        self.pattern = Some(regex.into());
+        self
+    }
+
+    /// Sets the minimum length in characters.
+    #[must_use]
+    pub fn min_length(mut self, min: usize) -> Self {
+        self.min_length = Some(min);
+        self
+    }
+
+    /// Sets the maximum length in characters.
+    #[must_use]
+    pub fn max_length(mut self, max: usize) -> Self {
+        self.max_length = Some(max);
+        self
+    }
+
+    /// Sets a display mask such as `(###) ###-####`.
+    ///
+    /// Each `#` is a slot for one input character; everything else is a
+    /// literal. The mask only affects display and entry, through
+    /// [`Text::apply_mask`] and [`Text::strip_mask`]; stored values and
+    /// validation are unchanged.
+    #[must_use]
+    pub fn input_mask(mut self, mask: impl Into<SmartStr>) -> Self {
+        self.input_mask = Some(mask.into());
+        self
+    }
+
+    /// Sets the visibility expression.
+    ///
+    /// See [`crate::visibility`] for the supported expression shape.
+    #[cfg(feature = "visibility")]
+    #[must_use]
+    pub fn visible_when(mut self, expr: Value) -> Self {
+        self.visibility = Some(expr);
+        self
+    }
+
     /// Marks the parameter as required.
     #[must_use]
     pub fn required(mut self) -> Self {
@@ -223,6 +705,23 @@ impl<S: TextSubtype> TextBuilder<S> {
         self
     }
 
+    /// Marks the parameter as deprecated.
+    #[must_use]
+    pub fn deprecated(mut self) -> Self {
+        self.flags |= Flags::DEPRECATED;
+        self
+    }
+
+    /// Marks the parameter as computed by the evaluation engine.
+    ///
+    /// Applies [`Flags::computed`], so the value is read-only and skipped
+    /// when saving.
+    #[must_use]
+    pub fn computed(mut self) -> Self {
+        self.flags |= Flags::computed();
+        self
+    }
+
     /// Marks the parameter as sensitive.
     #[must_use]
     pub fn sensitive(mut self) -> Self {
@@ -230,9 +729,73 @@ impl<S: TextSubtype> TextBuilder<S> {
         self
     }
 
+    /// Adds a custom validator run against text values.
+    ///
+    /// Validators are run in insertion order during
+    /// [`Validatable::validate_sync`]; every failure is reported, not just
+    /// the first one.
+    #[cfg(feature = "validation")]
+    #[must_use]
+    pub fn validate_with(
+        mut self,
+        f: impl Fn(&str) -> std::result::Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        self.validators.push(FnValidator::new(Arc::new(f)));
+        self
+    }
+
     /// Builds the text parameter.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`min_length`](Self::min_length) exceeds
+    /// [`max_length`](Self::max_length), or, with the `validation` feature,
+    /// if the subtype pattern or the pattern set via
+    /// [`pattern`](Self::pattern) is not a valid regex. Use
+    /// [`try_build`](Self::try_build) to handle these as errors.
     #[must_use]
     pub fn build(self) -> Text<S> {
+        match self.try_build() {
+            Ok(text) => text,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Builds the text parameter, reporting invalid patterns as errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `invalid_length_range` validation error if the minimum
+    /// length exceeds the maximum length.
+    ///
+    /// With the `validation` feature, returns an `invalid_pattern` validation
+    /// error if the subtype pattern or explicit pattern fails to compile.
+    pub fn try_build(self) -> crate::core::Result<Text<S>> {
+        if let (Some(min), Some(max)) = (self.min_length, self.max_length) {
+            if min > max {
+                return Err(Error::validation_with_fields(
+                    "invalid_length_range",
+                    format!("minimum length {min} exceeds maximum length {max}"),
+                    vec![self.key.to_string()],
+                ));
+            }
+        }
+
+        #[cfg(feature = "validation")]
+        let compiled_patterns = S::pattern()
+            .into_iter()
+            .chain(self.pattern.as_deref())
+            .map(|p| {
+                regex::Regex::new(p).map_err(|e| {
+                    Error::validation_with_fields(
+                        "invalid_pattern",
+                        format!("invalid pattern '{p}': {e}"),
+                        vec![self.key.to_string()],
+                    )
+                })
+            })
+            .collect::<crate::core::Result<Vec<_>>>()?;
+
         let mut metadata_builder = Metadata::builder(self.key);
 
         if let Some(label) = self.label {
@@ -244,20 +807,44 @@ impl<S: TextSubtype> TextBuilder<S> {
         if let Some(group) = self.group {
             metadata_builder = metadata_builder.group(group);
         }
+        for tag in self.tags {
+            metadata_builder = metadata_builder.tag(tag);
+        }
+        if let Some(order) = self.order {
+            metadata_builder = metadata_builder.order(order);
+        }
+        for dep in self.depends_on {
+            metadata_builder = metadata_builder.depends_on(dep);
+        }
+
+        let mut flags = self.flags;
+        if S::is_sensitive() {
+            flags |= Flags::SENSITIVE;
+        }
 
-        Text {
+        Ok(Text {
             metadata: metadata_builder.build(),
-            flags: self.flags,
+            flags,
             subtype: self.subtype,
             default: self.default,
-        }
+            pattern: self.pattern,
+            min_length: self.min_length,
+            max_length: self.max_length,
+            input_mask: self.input_mask,
+            #[cfg(feature = "validation")]
+            compiled_patterns,
+            #[cfg(feature = "validation")]
+            validators: self.validators,
+            #[cfg(feature = "visibility")]
+            visibility: self.visibility,
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::subtype::{Email, Password};
+    use crate::subtype::{Email, IntoBuilder, Password};
 
     #[test]
     fn test_text_minimal() {
@@ -293,6 +880,151 @@ mod tests {
         assert!(!Email::is_sensitive());
     }
 
+    #[test]
+    fn test_text_uuid_and_slug_convenience() {
+        let id = Text::uuid("id");
+        assert_eq!(id.subtype().to_owned(), crate::subtype::Uuid);
+        assert_eq!(id.normalize("AB-Cd"), "ab-cd");
+
+        let slug = Text::slug("slug");
+        assert_eq!(slug.normalize("my-post"), Cow::Borrowed("my-post"));
+        assert_eq!(slug.normalize("My-Post"), "my-post");
+
+        let plain = Text::builder("name").build();
+        assert_eq!(plain.normalize("Alice"), "Alice");
+
+        let slug = crate::subtype::Slug::into_builder("slug")
+            .label("Slug")
+            .build();
+        assert_eq!(slug.metadata().label(), Some("Slug"));
+    }
+
+    #[test]
+    fn test_text_coerce_input_normalizes() {
+        use crate::core::Value;
+
+        let slug = Text::slug("slug");
+        assert_eq!(
+            slug.coerce_input("My-Post").unwrap(),
+            Value::text("my-post")
+        );
+
+        let plain = Text::builder("name").build();
+        assert_eq!(plain.coerce_input("Alice").unwrap(), Value::text("Alice"));
+    }
+
+    #[cfg(feature = "validation")]
+    #[test]
+    fn test_text_uuid_and_slug_validation() {
+        let id = Text::uuid("id");
+        assert!(
+            id.validate_sync(&Value::text("3f2504e0-4f89-11d3-9a0c-0305e82c3301"))
+                .is_ok()
+        );
+        let err = id.validate_sync(&Value::text("not-a-uuid")).unwrap_err();
+        assert_eq!(err.code(), Some("pattern_mismatch"));
+
+        let slug = Text::slug("slug");
+        assert!(slug.validate_sync(&Value::text("hello-world-2")).is_ok());
+        for bad in ["Hello", "double--dash", "-leading", "with space"] {
+            assert!(slug.validate_sync(&Value::text(bad)).is_err(), "{bad}");
+        }
+    }
+
+    #[cfg(feature = "validation")]
+    #[test]
+    fn test_text_ip_and_cidr_validation() {
+        let ip = Text::ip("ip");
+        assert!(ip.validate_sync(&Value::text("10.0.0.1")).is_ok());
+        assert!(ip.validate_sync(&Value::text("::1")).is_ok());
+        let err = ip.validate_sync(&Value::text("999.1.1.1")).unwrap_err();
+        assert_eq!(err.code(), Some("invalid_ip_address"));
+
+        let cidr = Text::cidr("subnet");
+        assert!(cidr.validate_sync(&Value::text("10.0.0.0/8")).is_ok());
+        let err = cidr.validate_sync(&Value::text("10.0.0.0/40")).unwrap_err();
+        assert_eq!(err.code(), Some("invalid_cidr"));
+        assert_eq!(err.fields(), ["subnet"]);
+    }
+
+    #[cfg(feature = "validation")]
+    #[test]
+    fn test_text_phone_validation() {
+        let phone = Text::phone("phone").build();
+        assert_eq!(phone.subtype().region(), None);
+        assert!(phone.validate_sync(&Value::text("+1 415 555 2671")).is_ok());
+        let err = phone.validate_sync(&Value::text("12")).unwrap_err();
+        assert_eq!(err.code(), Some("invalid_phone"));
+
+        let local = Text::phone("phone").region("de").build();
+        assert_eq!(local.subtype().region(), Some("DE"));
+        #[cfg(feature = "phonenumber")]
+        assert!(local.validate_sync(&Value::text("030 12345678")).is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "region")]
+    fn test_text_phone_invalid_region() {
+        let _ = Text::phone("phone").region("Germany");
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_text_datetime() {
+        let at = Text::datetime("created_at");
+        assert_eq!(at.key(), "created_at");
+
+        let parsed = Text::parse_datetime(&Value::text("2024-01-01T00:00:00Z")).unwrap();
+        assert_eq!(parsed.timestamp(), 1_704_067_200);
+        assert!(Text::parse_datetime(&Value::text("2024-01-01")).is_none());
+        assert!(Text::parse_datetime(&Value::Int(0)).is_none());
+    }
+
+    #[cfg(all(feature = "chrono", feature = "validation"))]
+    #[test]
+    fn test_text_datetime_validation() {
+        let at = Text::datetime("created_at");
+        assert!(
+            at.validate_sync(&Value::text("2024-01-01T08:30:00+02:00"))
+                .is_ok()
+        );
+        assert!(at.validate_sync(&Value::text("")).is_ok());
+
+        let err = at.validate_sync(&Value::text("yesterday")).unwrap_err();
+        assert_eq!(err.code(), Some("invalid_datetime"));
+        assert_eq!(err.fields(), ["created_at"]);
+    }
+
+    #[test]
+    fn test_text_input_mask() {
+        let card = Text::builder("card")
+            .input_mask("#### #### #### ####")
+            .build();
+
+        assert_eq!(card.input_mask(), Some("#### #### #### ####"));
+        assert_eq!(card.apply_mask(""), "");
+        assert_eq!(card.apply_mask("4111"), "4111");
+        assert_eq!(card.apply_mask("41111"), "4111 1");
+        assert_eq!(
+            card.apply_mask("41111111111111119999"),
+            "4111 1111 1111 1111"
+        );
+        assert_eq!(card.strip_mask("4111 1111 1111 1111"), "4111111111111111");
+
+        let phone = Text::builder("phone").input_mask("(###) ###-####").build();
+        assert_eq!(phone.apply_mask("5"), "(5");
+        assert_eq!(phone.strip_mask("555-123-4567"), "5551234567");
+    }
+
+    #[test]
+    fn test_text_without_input_mask() {
+        let text = Text::builder("name").build();
+
+        assert_eq!(text.input_mask(), None);
+        assert_eq!(text.apply_mask("a-b"), "a-b");
+        assert_eq!(text.strip_mask("a-b"), "a-b");
+    }
+
     #[test]
     fn test_text_password_convenience() {
         let password: Text<Password> = Text::password("secret");
@@ -302,6 +1034,15 @@ mod tests {
         assert!(password.flags().contains(Flags::SENSITIVE));
     }
 
+    #[test]
+    fn test_text_sensitive_subtype_sets_flag() {
+        let secret = Text::builder("secret").subtype(Password).build();
+        assert!(secret.flags().is_sensitive());
+
+        let plain = Text::builder("name").build();
+        assert!(!plain.flags().is_sensitive());
+    }
+
     #[test]
     fn test_text_subtype_change() {
         use crate::subtype::Json;
@@ -322,4 +1063,143 @@ mod tests {
         assert!(value.is_some());
         assert_eq!(value, Some(crate::core::Value::text("hello")));
     }
+
+    #[cfg(feature = "validation")]
+    #[test]
+    fn test_text_validate_with() {
+        let text = Text::builder("username")
+            .validate_with(|s| {
+                if s.len() >= 3 {
+                    Ok(())
+                } else {
+                    Err("too short".into())
+                }
+            })
+            .build();
+
+        assert_eq!(text.validator_count(), 1);
+        assert!(text.validate_sync(&Value::text("alice")).is_ok());
+
+        let err = text.validate_sync(&Value::text("al")).unwrap_err();
+        assert_eq!(err.code(), Some("custom"));
+        assert_eq!(err.fields(), &["username".to_string()]);
+    }
+
+    #[cfg(feature = "validation")]
+    #[test]
+    fn test_text_validators_all_run() {
+        let taken = ["admin", "root"];
+        let text = Text::builder("username")
+            .validate_with(|s| {
+                if s.chars().all(char::is_alphanumeric) {
+                    Ok(())
+                } else {
+                    Err("must be alphanumeric".into())
+                }
+            })
+            .validate_with(move |s| {
+                if taken.contains(&s) {
+                    Err(format!("'{s}' is already taken"))
+                } else {
+                    Ok(())
+                }
+            })
+            .build();
+
+        let err = text.validate_sync(&Value::text("root")).unwrap_err();
+        assert!(err.to_string().contains("already taken"));
+        assert!(!err.to_string().contains("alphanumeric"));
+
+        let err = text.validate_sync(&Value::text("ro ot")).unwrap_err();
+        assert!(err.to_string().contains("alphanumeric"));
+    }
+
+    #[cfg(feature = "validation")]
+    #[test]
+    fn test_text_validate_type_mismatch() {
+        let text = Text::builder("name").build();
+
+        assert!(text.validate_sync(&Value::Int(1)).is_err());
+        assert!(text.validate_sync(&Value::Null).is_ok());
+
+        let required = Text::builder("name").required().build();
+        assert!(required.validate_sync(&Value::Null).is_err());
+    }
+
+    #[test]
+    fn test_text_pattern_setter() {
+        let text = Text::builder("code").pattern(r"^[A-Z]{3}$").build();
+
+        assert_eq!(text.pattern(), Some(r"^[A-Z]{3}$"));
+        assert!(Text::builder("plain").build().pattern().is_none());
+    }
+
+    #[cfg(feature = "validation")]
+    #[test]
+    fn test_text_pattern_validation() {
+        let text = Text::builder("code").pattern(r"^[A-Z]{3}$").build();
+
+        assert!(text.validate_sync(&Value::text("ABC")).is_ok());
+        assert!(text.validate_sync(&Value::text("")).is_ok());
+
+        let err = text.validate_sync(&Value::text("abc")).unwrap_err();
+        assert_eq!(err.code(), Some("pattern_mismatch"));
+    }
+
+    #[cfg(feature = "validation")]
+    #[test]
+    fn test_text_subtype_pattern_enforced() {
+        let email: Text<Email> = Text::email("contact");
+
+        assert!(
+            email
+                .validate_sync(&Value::text("user@example.com"))
+                .is_ok()
+        );
+        let err = email
+            .validate_sync(&Value::text("not-an-email"))
+            .unwrap_err();
+        assert_eq!(err.code(), Some("pattern_mismatch"));
+    }
+
+    #[cfg(feature = "validation")]
+    #[test]
+    fn test_text_invalid_pattern_fails_at_build() {
+        let result = Text::builder("bad").pattern("([a-z").try_build();
+
+        let err = result.unwrap_err();
+        assert_eq!(err.code(), Some("invalid_pattern"));
+    }
+
+    #[cfg(feature = "validation")]
+    #[test]
+    #[should_panic(expected = "invalid pattern")]
+    fn test_text_invalid_pattern_build_panics() {
+        let _ = Text::builder("bad").pattern("([a-z").build();
+    }
+
+    #[cfg(feature = "validation")]
+    #[test]
+    fn test_text_length_bounds() {
+        let text = Text::builder("code").min_length(2).max_length(4).build();
+
+        assert_eq!(text.min_length(), Some(2));
+        assert_eq!(text.max_length(), Some(4));
+        assert!(text.validate_sync(&Value::text("abc")).is_ok());
+        assert!(text.validate_sync(&Value::text("a")).is_err());
+        assert!(text.validate_sync(&Value::text("abcde")).is_err());
+        // Length counts characters, not bytes
+        assert!(text.validate_sync(&Value::text("äöü")).is_ok());
+    }
+
+    #[test]
+    fn test_text_inverted_length_bounds() {
+        let err = Text::builder("code")
+            .min_length(4)
+            .max_length(2)
+            .try_build()
+            .unwrap_err();
+        assert_eq!(err.code(), Some("invalid_length_range"));
+        assert_eq!(err.fields(), ["code"]);
+    }
 }
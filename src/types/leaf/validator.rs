@@ -0,0 +1,66 @@
+//! Closure-based validators attached to leaf parameters.
+//!
+//! Validators are stored on the schema node and run by
+//! [`Validatable::validate_sync`](crate::types::traits::Validatable::validate_sync).
+
+use std::fmt;
+use std::sync::Arc;
+
+use crate::core::{Error, Key, Result};
+
+/// Signature of a validator for text values.
+pub(crate) type TextValidatorFn = dyn Fn(&str) -> std::result::Result<(), String> + Send + Sync;
+
+/// Signature of a validator for numeric values.
+pub(crate) type NumberValidatorFn = dyn Fn(f64) -> std::result::Result<(), String> + Send + Sync;
+
+/// A shared, cloneable handle to a validator closure.
+pub(crate) struct FnValidator<F: ?Sized>(Arc<F>);
+
+impl<F: ?Sized> FnValidator<F> {
+    /// Wraps an already shared closure.
+    pub(crate) fn new(f: Arc<F>) -> Self {
+        Self(f)
+    }
+
+    /// Returns the wrapped closure.
+    pub(crate) fn get(&self) -> &F {
+        &self.0
+    }
+}
+
+impl<F: ?Sized> Clone for FnValidator<F> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<F: ?Sized> fmt::Debug for FnValidator<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("FnValidator(..)")
+    }
+}
+
+/// Folds validator outcomes into a single result.
+///
+/// Every failure message is kept, so all validators run even when an
+/// earlier one rejects the value.
+pub(crate) fn collect_failures(
+    key: &Key,
+    outcomes: impl IntoIterator<Item = std::result::Result<(), String>>,
+) -> Result<()> {
+    let messages: Vec<String> = outcomes
+        .into_iter()
+        .filter_map(std::result::Result::err)
+        .collect();
+
+    if messages.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::validation_with_fields(
+            "custom",
+            messages.join("; "),
+            vec![key.to_string()],
+        ))
+    }
+}
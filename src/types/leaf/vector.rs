@@ -1,9 +1,18 @@
 //! Vector parameter type for fixed-size numeric arrays.
 
-use crate::core::{Flags, Key, Metadata, Value};
-use crate::subtype::{Numeric, NumericKind};
+use std::hash::{Hash, Hasher};
+
+use crate::core::{Flags, Key, Metadata, Result, Value};
+use crate::subtype::{ColorRgba, Numeric, NumericKind, VectorSubtype};
 use crate::types::kind::NodeKind;
-use crate::types::traits::{Leaf, Node};
+use crate::types::traits::{Leaf, Node, invalid_input};
+
+#[cfg(feature = "validation")]
+use crate::core::Error;
+#[cfg(feature = "validation")]
+use crate::types::traits::Validatable;
+#[cfg(feature = "visibility")]
+use crate::types::traits::Visibility;
 
 /// A vector parameter schema for fixed-size numeric arrays.
 ///
@@ -32,7 +41,12 @@ pub struct Vector {
     flags: Flags,
     element_type: NumericKind,
     size: usize,
+    subtype: Option<&'static str>,
+    component_labels: Vec<&'static str>,
+    range: Option<(f64, f64)>,
     default: Option<Vec<f64>>,
+    #[cfg(feature = "visibility")]
+    visibility: Option<Value>,
 }
 
 impl Vector {
@@ -44,6 +58,24 @@ impl Vector {
         VectorBuilder::new(key)
     }
 
+    /// Creates an RGBA color builder with channels in `0.0..=1.0`.
+    ///
+    /// Uses the [`ColorRgba`] subtype; see
+    /// [`Color`](crate::subtype::Color) for hex conversions.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use paramdef::types::leaf::Vector;
+    ///
+    /// let tint = Vector::rgba("tint").default([1.0, 1.0, 1.0, 1.0]).build();
+    /// assert_eq!(tint.component_labels(), ["R", "G", "B", "A"]);
+    /// assert_eq!(tint.range(), Some((0.0, 1.0)));
+    /// ```
+    pub fn rgba(key: impl Into<Key>) -> VectorBuilder<f64, 4> {
+        VectorBuilder::new(key).subtype(ColorRgba)
+    }
+
     /// Returns the element type.
     #[must_use]
     pub fn element_type(&self) -> NumericKind {
@@ -56,6 +88,24 @@ impl Vector {
         self.size
     }
 
+    /// Returns the subtype name, if a subtype was set.
+    #[must_use]
+    pub fn subtype_name(&self) -> Option<&'static str> {
+        self.subtype
+    }
+
+    /// Returns the per-component labels, or an empty slice if none are set.
+    #[must_use]
+    pub fn component_labels(&self) -> &[&'static str] {
+        &self.component_labels
+    }
+
+    /// Returns the allowed component range (inclusive), if any.
+    #[must_use]
+    pub fn range(&self) -> Option<(f64, f64)> {
+        self.range
+    }
+
     /// Returns the default value, if set.
     #[must_use]
     pub fn default_vec(&self) -> Option<&[f64]> {
@@ -78,6 +128,10 @@ impl Node for Vector {
         self.metadata.key()
     }
 
+    fn flags(&self) -> Flags {
+        self.flags
+    }
+
     fn kind(&self) -> NodeKind {
         NodeKind::Leaf
     }
@@ -89,6 +143,83 @@ impl Node for Vector {
     fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
         self
     }
+
+    fn initial_value(&self) -> Option<Value> {
+        self.default_value()
+    }
+
+    /// Parses comma-separated components.
+    fn coerce_input(&self, input: &str) -> Result<Value> {
+        if input.trim().is_empty() {
+            return Ok(Value::Null);
+        }
+        let components = input
+            .split(',')
+            .map(|part| {
+                let part = part.trim();
+                if self.element_type.is_integer() {
+                    part.parse().map(Value::Int).ok()
+                } else {
+                    part.parse().map(Value::Float).ok()
+                }
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(|| invalid_input(self.key(), "expected numeric components"))?;
+        Ok(Value::array(components))
+    }
+
+    #[cfg(feature = "serde")]
+    fn json_schema(&self) -> Option<serde_json::Value> {
+        let item_type = if self.element_type.is_integer() {
+            "integer"
+        } else {
+            "number"
+        };
+
+        let mut schema = serde_json::Map::new();
+        schema.insert("type".into(), "array".into());
+        let mut items = serde_json::Map::new();
+        items.insert("type".into(), item_type.into());
+        if let Some((min, max)) = self.range {
+            items.insert("minimum".into(), min.into());
+            items.insert("maximum".into(), max.into());
+        }
+        schema.insert("items".into(), items.into());
+        schema.insert("minItems".into(), self.size.into());
+        schema.insert("maxItems".into(), self.size.into());
+        Some(crate::schema::json_schema::annotated(
+            self,
+            schema,
+            self.default_value(),
+        ))
+    }
+
+    #[cfg(feature = "validation")]
+    fn validatable(&self) -> Option<&dyn Validatable> {
+        Some(self)
+    }
+
+    #[cfg(feature = "visibility")]
+    fn visibility(&self) -> Option<&dyn Visibility> {
+        Some(self)
+    }
+
+    fn hash_settings(&self, mut state: &mut dyn Hasher) {
+        (
+            self.element_type,
+            self.size,
+            self.subtype,
+            &self.component_labels,
+        )
+            .hash(&mut state);
+        self.range
+            .map(|(min, max)| (min.to_bits(), max.to_bits()))
+            .hash(&mut state);
+        self.default
+            .as_ref()
+            .map(|default| default.iter().map(|v| v.to_bits()).collect::<Vec<_>>())
+            .hash(&mut state);
+    }
 }
 
 impl Leaf for Vector {
@@ -99,6 +230,62 @@ impl Leaf for Vector {
     }
 }
 
+#[cfg(feature = "visibility")]
+impl Visibility for Vector {
+    fn visibility_expr(&self) -> Option<&Value> {
+        self.visibility.as_ref()
+    }
+
+    fn set_visibility_expr(&mut self, expr: Option<Value>) {
+        self.visibility = expr;
+    }
+}
+
+#[cfg(feature = "validation")]
+impl Validatable for Vector {
+    /// Validates the component count, the component type, and the range.
+    ///
+    /// Component failures carry the component index as their field path.
+    fn validate_sync(&self, value: &Value) -> Result<()> {
+        let components = match value {
+            Value::Null if self.flags.is_required() => {
+                return Err(Error::missing_required(self.key().as_str()));
+            }
+            Value::Null => return Ok(()),
+            Value::Array(components) => components,
+            other => return Err(Error::type_mismatch("array", other.type_name())),
+        };
+
+        if components.len() != self.size {
+            return Err(Error::length_out_of_bounds(
+                components.len(),
+                self.size,
+                self.size,
+            ));
+        }
+
+        for (index, component) in components.iter().enumerate() {
+            let segment = index.to_string();
+            let number = component
+                .as_f64()
+                .filter(|n| n.is_finite())
+                .ok_or_else(|| {
+                    Error::type_mismatch("number", component.type_name()).at(&segment)
+                })?;
+            if self.element_type.is_integer() && number.fract() != 0.0 {
+                return Err(Error::type_mismatch("integer", "float").at(&segment));
+            }
+            if let Some((min, max)) = self.range {
+                if number < min || number > max {
+                    return Err(Error::out_of_range(number, min, max).at(&segment));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
 /// Builder for [`Vector`] parameters with compile-time type safety.
 ///
 /// The generic parameters `T` (element type) and `N` (size) provide
@@ -110,8 +297,16 @@ pub struct VectorBuilder<T: Numeric, const N: usize> {
     label: Option<Key>,
     description: Option<Key>,
     group: Option<Key>,
+    tags: Vec<Key>,
+    order: Option<i32>,
+    depends_on: Vec<Key>,
     flags: Flags,
+    subtype: Option<&'static str>,
+    component_labels: Vec<&'static str>,
+    range: Option<(f64, f64)>,
     default: Option<[T; N]>,
+    #[cfg(feature = "visibility")]
+    visibility: Option<Value>,
 }
 
 impl<T: Numeric, const N: usize> VectorBuilder<T, N> {
@@ -122,8 +317,16 @@ impl<T: Numeric, const N: usize> VectorBuilder<T, N> {
             label: None,
             description: None,
             group: None,
+            tags: Vec::new(),
+            order: None,
+            depends_on: Vec::new(),
             flags: Flags::empty(),
+            subtype: None,
+            component_labels: Vec::new(),
+            range: None,
             default: None,
+            #[cfg(feature = "visibility")]
+            visibility: None,
         }
     }
 
@@ -148,6 +351,47 @@ impl<T: Numeric, const N: usize> VectorBuilder<T, N> {
         self
     }
 
+    /// Adds a tag, such as `"advanced"`, for filtering and grouping.
+    #[must_use]
+    pub fn tag(mut self, tag: impl Into<Key>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    /// Sets the layout order hint; lower values come first.
+    #[must_use]
+    pub fn order(mut self, order: i32) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Declares that this parameter is derived from another one.
+    ///
+    /// See [`Schema::evaluation_order`](crate::schema::Schema::evaluation_order).
+    #[must_use]
+    pub fn depends_on(mut self, key: impl Into<Key>) -> Self {
+        self.depends_on.push(key.into());
+        self
+    }
+
+    /// Applies a vector subtype's name, component labels, and range.
+    ///
+    /// The subtype's size must match the vector size.
+    #[must_use]
+    pub fn subtype<V: VectorSubtype<N>>(mut self, _subtype: V) -> Self {
+        self.subtype = Some(V::name());
+        self.component_labels = V::component_labels().to_vec();
+        self.range = V::default_range();
+        self
+    }
+
+    /// Sets the allowed component range (inclusive).
+    #[must_use]
+    pub fn range(mut self, min: f64, max: f64) -> Self {
+        self.range = Some((min, max));
+        self
+    }
+
     /// Sets the default value with compile-time size checking.
     #[must_use]
     pub fn default(mut self, value: [T; N]) -> Self {
@@ -155,6 +399,16 @@ impl<T: Numeric, const N: usize> VectorBuilder<T, N> {
         self
     }
 
+    /// Sets the visibility expression.
+    ///
+    /// See [`crate::visibility`] for the supported expression shape.
+    #[cfg(feature = "visibility")]
+    #[must_use]
+    pub fn visible_when(mut self, expr: Value) -> Self {
+        self.visibility = Some(expr);
+        self
+    }
+
     /// Marks the parameter as required.
     #[must_use]
     pub fn required(mut self) -> Self {
@@ -176,6 +430,23 @@ impl<T: Numeric, const N: usize> VectorBuilder<T, N> {
         self
     }
 
+    /// Marks the parameter as deprecated.
+    #[must_use]
+    pub fn deprecated(mut self) -> Self {
+        self.flags |= Flags::DEPRECATED;
+        self
+    }
+
+    /// Marks the parameter as computed by the evaluation engine.
+    ///
+    /// Applies [`Flags::computed`], so the value is read-only and skipped
+    /// when saving.
+    #[must_use]
+    pub fn computed(mut self) -> Self {
+        self.flags |= Flags::computed();
+        self
+    }
+
     /// Builds the vector parameter.
     #[must_use]
     pub fn build(self) -> Vector {
@@ -190,15 +461,29 @@ impl<T: Numeric, const N: usize> VectorBuilder<T, N> {
         if let Some(group) = self.group {
             metadata_builder = metadata_builder.group(group);
         }
+        for tag in self.tags {
+            metadata_builder = metadata_builder.tag(tag);
+        }
+        if let Some(order) = self.order {
+            metadata_builder = metadata_builder.order(order);
+        }
+        for dep in self.depends_on {
+            metadata_builder = metadata_builder.depends_on(dep);
+        }
 
         Vector {
             metadata: metadata_builder.build(),
             flags: self.flags,
             element_type: T::kind(),
             size: N,
+            subtype: self.subtype,
+            component_labels: self.component_labels,
+            range: self.range,
             default: self
                 .default
                 .map(|arr| arr.iter().map(|v| v.to_f64()).collect()),
+            #[cfg(feature = "visibility")]
+            visibility: self.visibility,
         }
     }
 }
@@ -218,6 +503,18 @@ mod tests {
         assert!(vec.default_value().is_none());
     }
 
+    #[test]
+    fn test_vector_coerce_input() {
+        let vec = Vector::builder::<i32, 2>("size").build();
+
+        assert_eq!(
+            vec.coerce_input("640, 480").unwrap(),
+            Value::array([Value::Int(640), Value::Int(480)])
+        );
+        assert_eq!(vec.coerce_input(" ").unwrap(), Value::Null);
+        assert!(vec.coerce_input("640,wide").is_err());
+    }
+
     #[test]
     fn test_vector_with_default() {
         let vec = Vector::builder::<f64, 3>("position")
@@ -289,4 +586,64 @@ mod tests {
         assert!(NumericKind::F32.is_float());
         assert!(NumericKind::F64.is_float());
     }
+
+    #[test]
+    fn test_vector_rgba() {
+        let color = Vector::rgba("tint").build();
+
+        assert_eq!(color.size(), 4);
+        assert_eq!(color.element_type(), NumericKind::F64);
+        assert_eq!(color.subtype_name(), Some("color_rgba"));
+        assert_eq!(color.component_labels(), ["R", "G", "B", "A"]);
+        assert_eq!(color.range(), Some((0.0, 1.0)));
+    }
+
+    #[test]
+    fn test_vector_without_subtype() {
+        let vec = Vector::builder::<f64, 3>("pos").range(-1.0, 1.0).build();
+
+        assert_eq!(vec.subtype_name(), None);
+        assert!(vec.component_labels().is_empty());
+        assert_eq!(vec.range(), Some((-1.0, 1.0)));
+    }
+
+    #[cfg(feature = "validation")]
+    #[test]
+    fn test_vector_validate() {
+        let color = Vector::rgba("tint").build();
+        assert!(color.validatable().is_some());
+        assert!(color.validate_sync(&Value::Null).is_ok());
+        assert!(
+            color
+                .validate_sync(&Value::array([0.1, 0.2, 0.3, 1.0].map(Value::Float)))
+                .is_ok()
+        );
+        assert!(matches!(
+            color.validate_sync(&Value::array([0.1, 0.2, 0.3].map(Value::Float))),
+            Err(Error::LengthOutOfBounds { .. })
+        ));
+        let err = color
+            .validate_sync(&Value::array([0.1, 2.0, 0.3, 1.0].map(Value::Float)))
+            .unwrap_err();
+        assert_eq!(err.code(), Some("out_of_range"));
+        assert_eq!(err.field_path(), Some("1"));
+        let err = color
+            .validate_sync(&Value::array([0.1, f64::NAN, 0.3, 1.0].map(Value::Float)))
+            .unwrap_err();
+        assert_eq!(err.field_path(), Some("1"));
+
+        let grid = Vector::builder::<i32, 2>("cell").required().build();
+        assert!(
+            grid.validate_sync(&Value::array([1, 2].map(Value::Int)))
+                .is_ok()
+        );
+        assert!(
+            grid.validate_sync(&Value::array([1.5, 2.0].map(Value::Float)))
+                .is_err()
+        );
+        assert!(matches!(
+            grid.validate_sync(&Value::Null),
+            Err(Error::MissingRequired { .. })
+        ));
+    }
 }
@@ -1,11 +1,19 @@
 //! Core Node trait that all parameter types implement.
 
 use std::any::Any;
+use std::collections::VecDeque;
 use std::fmt::Debug;
+use std::hash::Hasher;
+use std::sync::Arc;
 
-use crate::core::{Key, Metadata};
+use crate::core::{Error, Flags, Key, Metadata, Result, Value};
 use crate::types::kind::NodeKind;
 
+#[cfg(feature = "validation")]
+use super::Validatable;
+#[cfg(feature = "visibility")]
+use super::Visibility;
+
 /// Base trait for all node types.
 ///
 /// Every node in the system implements this trait, which provides access to
@@ -39,6 +47,13 @@ pub trait Node: Send + Sync + Debug {
     /// Returns the node's unique key.
     fn key(&self) -> &Key;
 
+    /// Returns the node's flags.
+    ///
+    /// Defaults to no flags.
+    fn flags(&self) -> Flags {
+        Flags::empty()
+    }
+
     /// Returns the node's kind (category).
     fn kind(&self) -> NodeKind;
 
@@ -47,4 +62,160 @@ pub trait Node: Send + Sync + Debug {
 
     /// Returns a mutable reference to the underlying type for downcasting.
     fn as_any_mut(&mut self) -> &mut dyn Any;
+
+    /// Returns the value [`Context::with_defaults`] seeds for this node.
+    ///
+    /// Leaves return their [`Leaf::default_value`]; `Mode` returns its
+    /// default variant as `{ "mode": <variant> }`. Other nodes return
+    /// `None`.
+    ///
+    /// [`Context::with_defaults`]: crate::context::Context::with_defaults
+    /// [`Leaf::default_value`]: super::Leaf::default_value
+    fn initial_value(&self) -> Option<Value> {
+        None
+    }
+
+    /// Returns the range of numeric values this node accepts, as
+    /// `(min, max)`.
+    ///
+    /// Numbers return their effective range; other nodes return `None`.
+    /// Lets code holding an `Arc<dyn Node>` scale a number without knowing
+    /// its subtype.
+    fn numeric_range(&self) -> Option<(f64, f64)> {
+        None
+    }
+
+    /// Returns the node's direct children.
+    ///
+    /// Groups, layouts, and containers return their children; other nodes
+    /// return an empty slice. Lets code holding an `Arc<dyn Node>` walk the
+    /// tree without knowing the concrete type.
+    fn child_nodes(&self) -> &[Arc<dyn Node>] {
+        &[]
+    }
+
+    /// Returns the number of nodes below this one, at any depth.
+    ///
+    /// Recurses through [`child_nodes`](Self::child_nodes). Decorations are
+    /// not counted; groups, layouts, and containers are counted along with
+    /// their own descendants.
+    fn descendant_count(&self) -> usize {
+        self.child_nodes()
+            .iter()
+            .filter(|child| child.kind() != NodeKind::Decoration)
+            .map(|child| 1 + child.descendant_count())
+            .sum()
+    }
+
+    /// Converts text input, such as a submitted form field, into a value
+    /// for this node.
+    ///
+    /// Leaves keep the input as text unless they override this; numbers,
+    /// booleans, vectors, and multiple selects parse it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an `"invalid_input"` validation error if the input cannot
+    /// be parsed, or if the node does not take text input at all.
+    fn coerce_input(&self, input: &str) -> Result<Value> {
+        if self.kind() == NodeKind::Leaf {
+            Ok(Value::text(input))
+        } else {
+            Err(invalid_input(self.key(), "text input is not supported"))
+        }
+    }
+
+    /// Returns this node as a [`Visibility`] implementor, if it supports
+    /// conditional visibility.
+    ///
+    /// Lets code holding an `Arc<dyn Node>` reach the visibility expression.
+    #[cfg(feature = "visibility")]
+    fn visibility(&self) -> Option<&dyn Visibility> {
+        None
+    }
+
+    /// Returns this node as a [`Validatable`] implementor, if it can
+    /// validate values.
+    ///
+    /// Lets containers holding `Arc<dyn Node>` children validate nested
+    /// values.
+    #[cfg(feature = "validation")]
+    fn validatable(&self) -> Option<&dyn Validatable> {
+        None
+    }
+
+    /// Writes this node's own settings into `state`.
+    ///
+    /// [`Schema::version_hash`](crate::schema::Schema::version_hash) hashes
+    /// the key, kind, flags, metadata and visibility expression itself,
+    /// then calls this, then walks [`child_nodes`](Self::child_nodes).
+    /// Implementations write their constraints, defaults and options, with
+    /// floats as their bits and values as their
+    /// [`content_hash`](Value::content_hash). Closures such as custom
+    /// validators are not hashed. The default writes nothing.
+    fn hash_settings(&self, state: &mut dyn Hasher) {
+        let _ = state;
+    }
+
+    /// Returns a JSON Schema fragment describing this node's value.
+    ///
+    /// Returns `None` for nodes without a JSON Schema representation, such
+    /// as decorations. See
+    /// [`Schema::to_json_schema`](crate::schema::Schema::to_json_schema).
+    #[cfg(feature = "serde")]
+    fn json_schema(&self) -> Option<serde_json::Value> {
+        None
+    }
+}
+
+/// Builds the error [`Node::coerce_input`] returns for rejected input.
+pub(crate) fn invalid_input(key: &Key, reason: &str) -> Error {
+    Error::validation_with_fields(
+        "invalid_input",
+        format!("invalid input for '{key}': {reason}"),
+        vec![key.to_string()],
+    )
+}
+
+/// Finds a node by key anywhere in the tree rooted at `root`.
+///
+/// Searches breadth-first through [`Node::child_nodes`], starting with
+/// `root` itself, so shallower nodes are found before deeper ones. When the
+/// same key appears in different branches, the first breadth-first hit
+/// wins: the shallowest match, and among equally deep matches the one in
+/// the earliest branch.
+///
+/// # Example
+///
+/// ```
+/// use paramdef::types::group::{Group, Panel};
+/// use paramdef::types::leaf::Text;
+/// use paramdef::types::traits::{Node, find_node};
+///
+/// let group = Group::builder("root")
+///     .child(Panel::builder("general").child(Text::builder("name").build()).build())
+///     .build();
+///
+/// let found = find_node(&group, "name").unwrap();
+/// assert_eq!(found.key().as_str(), "name");
+/// assert!(find_node(&group, "missing").is_none());
+/// ```
+#[must_use]
+pub fn find_node<'a>(root: &'a dyn Node, key: &str) -> Option<&'a dyn Node> {
+    find_breadth_first([root], key)
+}
+
+/// Breadth-first key search starting from several roots, in order.
+pub(crate) fn find_breadth_first<'a>(
+    roots: impl IntoIterator<Item = &'a dyn Node>,
+    key: &str,
+) -> Option<&'a dyn Node> {
+    let mut queue: VecDeque<&'a dyn Node> = roots.into_iter().collect();
+    while let Some(node) = queue.pop_front() {
+        if node.key().as_str() == key {
+            return Some(node);
+        }
+        queue.extend(node.child_nodes().iter().map(|child| &**child));
+    }
+    None
 }
@@ -37,6 +37,28 @@ pub trait GroupNode: Node {
     fn is_empty(&self) -> bool {
         self.children().is_empty()
     }
+
+    /// Returns the children sorted by their [`Metadata::order`] hint.
+    ///
+    /// Children with an order come first, lowest value first. Children
+    /// without one follow in insertion order. Ties keep insertion order.
+    ///
+    /// [`Metadata::order`]: crate::core::Metadata::order
+    fn children_sorted_by_order(&self) -> Vec<&Arc<dyn Node>> {
+        sorted_by_order(self.children())
+    }
+}
+
+/// Stable-sorts nodes by their metadata order hint, unordered nodes last.
+pub(crate) fn sorted_by_order<'a>(
+    nodes: impl IntoIterator<Item = &'a Arc<dyn Node>>,
+) -> Vec<&'a Arc<dyn Node>> {
+    let mut nodes: Vec<_> = nodes.into_iter().collect();
+    nodes.sort_by_key(|node| {
+        let order = node.metadata().order();
+        (order.is_none(), order)
+    });
+    nodes
 }
 
 // =============================================================================
@@ -71,7 +71,9 @@ mod visibility;
 
 // Re-export all traits
 pub use access::ValueAccess;
-pub use base::Node;
+pub use base::{Node, find_node};
+pub(crate) use base::{find_breadth_first, invalid_input};
+pub(crate) use category::sorted_by_order;
 pub use category::{Container, Decoration, GroupNode, Layout, Leaf};
 
 #[cfg(feature = "validation")]
@@ -6,14 +6,15 @@ use crate::types::traits::Node;
 
 /// Trait for nodes that can be validated.
 ///
-/// Implemented by Container and Leaf nodes (11 out of 14 types) when the
-/// `validation` feature is enabled. Group, Layout, and Decoration do not
-/// have values to validate.
+/// Implemented by Container and Leaf nodes when the `validation` feature is
+/// enabled. Each implementor also overrides [`Node::validatable`], so
+/// [`Context`](crate::context::Context) validation reaches it through a
+/// `dyn Node`. Group, Layout, and Decoration do not have values to validate.
 ///
 /// # Implementors
 ///
-/// - **Container (6)**: Object, List, Mode, Routing, Expirable, Ref
-/// - **Leaf (5)**: Text, Number, Boolean, Vector, Select
+/// - **Container (3)**: Object, List, Mode
+/// - **Leaf (6)**: Text, Number, Boolean, Vector, Select, File
 ///
 /// # Future Extensions
 ///
@@ -30,7 +31,7 @@ use crate::types::traits::Node;
 /// use paramdef::core::Value;
 ///
 /// let number = Number::builder("age")
-///     .range(0.0..=150.0)
+///     .range(0.0, 150.0)
 ///     .build();
 ///
 /// // Validate a value
@@ -47,6 +48,16 @@ pub trait Validatable: Node {
     /// Returns a validation error if the value fails any validator.
     fn validate_sync(&self, value: &Value) -> crate::core::Result<()>;
 
+    /// Returns whether a value deserves a warning without being invalid.
+    ///
+    /// [`Context::validate_all`](crate::context::Context::validate_all)
+    /// sets [`StateFlags::WARNING`](crate::core::StateFlags::WARNING) for
+    /// valid values where this returns `true`.
+    fn has_warning(&self, value: &Value) -> bool {
+        let _ = value;
+        false
+    }
+
     /// Returns the expected `NodeKind` for values.
     fn expected_kind(&self) -> NodeKind {
         self.kind()
@@ -5,24 +5,24 @@ use crate::types::traits::Node;
 
 /// Trait for visibility control.
 ///
-/// All 14 node types implement this trait when the `visibility` feature is
-/// enabled. Provides methods to evaluate conditional visibility based on
-/// other parameter values.
+/// Leaf node types implement this trait when the `visibility` feature is
+/// enabled. The expression format is described in [`crate::visibility`];
+/// evaluate it against runtime values with
+/// [`Context::is_visible`](crate::context::Context::is_visible).
 ///
 /// # Example
 ///
-/// ```ignore
+/// ```
 /// use paramdef::types::traits::Visibility;
 /// use paramdef::types::leaf::Text;
-/// use paramdef::core::Value;
+/// use paramdef::visibility::eq;
 ///
 /// let mut text = Text::builder("advanced_option").build();
 ///
 /// // Set visibility condition
-/// text.set_visibility_expr(Some(Value::text("{{show_advanced}} == true")));
+/// text.set_visibility_expr(Some(eq("show_advanced", true)));
 ///
-/// // Check if visible (would evaluate expression in real implementation)
-/// assert!(text.is_visible());
+/// assert_eq!(text.dependencies(), vec!["show_advanced"]);
 /// ```
 pub trait Visibility: Node {
     /// Returns the visibility expression, if any.
@@ -33,13 +33,17 @@ pub trait Visibility: Node {
 
     /// Returns whether the node is currently visible.
     ///
-    /// If no visibility expression is set, returns `true`.
+    /// Without access to runtime values this only checks for constant
+    /// expressions; use [`Context::is_visible`](crate::context::Context::is_visible)
+    /// to evaluate field conditions. If no expression is set, returns `true`.
     fn is_visible(&self) -> bool {
-        true
+        !matches!(self.visibility_expr(), Some(Value::Bool(false)))
     }
 
     /// Returns the keys that this node's visibility depends on.
     fn dependencies(&self) -> Vec<String> {
-        Vec::new()
+        self.visibility_expr()
+            .map(crate::visibility::dependencies)
+            .unwrap_or_default()
     }
 }
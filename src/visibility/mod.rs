@@ -0,0 +1,266 @@
+//! Visibility expressions and their evaluation.
+//!
+//! A visibility expression decides whether a parameter is shown, based on
+//! the current values of other parameters. Expressions are plain [`Value`]s,
+//! so they can be stored in schemas and serialized without extra types.
+//!
+//! # Expression Shape
+//!
+//! | Form                                        | Meaning                                   |
+//! |---------------------------------------------|-------------------------------------------|
+//! | `true` / `false`                            | Constant visibility                       |
+//! | `{ "field": "mode", "eq": "advanced" }`     | Field value equals the operand            |
+//! | `{ "field": "mode", "ne": "basic" }`        | Field value differs from the operand      |
+//! | `{ "field": "mode", "in": ["a", "b"] }`     | Field value is one of the array items     |
+//! | `{ "field": "count", "gt": 10 }`            | Numeric field value is greater            |
+//! | `{ "field": "count", "lt": 10 }`            | Numeric field value is less               |
+//! | `{ "and": [expr, ...] }`                    | All sub-expressions hold                  |
+//! | `{ "or": [expr, ...] }`                     | At least one sub-expression holds         |
+//! | `{ "not": expr }`                           | The sub-expression does not hold          |
+//!
+//! A field without a value compares as [`Value::Null`]. Integers and floats
+//! compare numerically, so `Int(1)` equals `Float(1.0)`. Malformed
+//! expressions evaluate to `true`, so a typo never hides a field for good.
+//!
+//! # Example
+//!
+//! ```
+//! use paramdef::core::Value;
+//! use paramdef::visibility::{self, eq, gt, and};
+//!
+//! let expr = and([eq("mode", "advanced"), gt("level", 2)]);
+//!
+//! let mode = Value::text("advanced");
+//! let level = Value::Int(3);
+//! let visible = visibility::evaluate(&expr, &|key| match key {
+//!     "mode" => Some(&mode),
+//!     "level" => Some(&level),
+//!     _ => None,
+//! });
+//! assert!(visible);
+//!
+//! assert_eq!(visibility::dependencies(&expr), vec!["mode", "level"]);
+//! ```
+
+use crate::core::Value;
+
+/// Evaluates a visibility expression.
+///
+/// `lookup` resolves a field key to its current value.
+#[must_use]
+pub fn evaluate<'a, F>(expr: &Value, lookup: &F) -> bool
+where
+    F: Fn(&str) -> Option<&'a Value>,
+{
+    match expr {
+        Value::Bool(b) => *b,
+        Value::Object(obj) => {
+            if let Some(items) = obj.get("and") {
+                return items
+                    .as_array()
+                    .is_none_or(|items| items.iter().all(|e| evaluate(e, lookup)));
+            }
+            if let Some(items) = obj.get("or") {
+                return items
+                    .as_array()
+                    .is_none_or(|items| items.iter().any(|e| evaluate(e, lookup)));
+            }
+            if let Some(inner) = obj.get("not") {
+                return !evaluate(inner, lookup);
+            }
+
+            let Some(field) = obj.get("field").and_then(Value::as_text) else {
+                return true;
+            };
+            let actual = lookup(field).unwrap_or(&Value::Null);
+
+            if let Some(expected) = obj.get("eq") {
+                actual.numeric_eq(expected)
+            } else if let Some(expected) = obj.get("ne") {
+                !actual.numeric_eq(expected)
+            } else if let Some(options) = obj.get("in") {
+                options
+                    .as_array()
+                    .is_some_and(|opts| opts.iter().any(|o| actual.numeric_eq(o)))
+            } else if let Some(bound) = obj.get("gt") {
+                matches!((actual.as_f64(), bound.as_f64()), (Some(a), Some(b)) if a > b)
+            } else if let Some(bound) = obj.get("lt") {
+                matches!((actual.as_f64(), bound.as_f64()), (Some(a), Some(b)) if a < b)
+            } else {
+                true
+            }
+        }
+        _ => true,
+    }
+}
+
+/// Returns the field keys referenced by an expression, in first-seen order.
+#[must_use]
+pub fn dependencies(expr: &Value) -> Vec<String> {
+    let mut deps = Vec::new();
+    collect_dependencies(expr, &mut deps);
+    deps
+}
+
+fn collect_dependencies(expr: &Value, deps: &mut Vec<String>) {
+    let Value::Object(obj) = expr else {
+        return;
+    };
+
+    for combinator in ["and", "or"] {
+        if let Some(items) = obj.get(combinator).and_then(Value::as_array) {
+            for item in items {
+                collect_dependencies(item, deps);
+            }
+        }
+    }
+    if let Some(inner) = obj.get("not") {
+        collect_dependencies(inner, deps);
+    }
+    if let Some(field) = obj.get("field").and_then(Value::as_text) {
+        if !deps.iter().any(|d| d == field) {
+            deps.push(field.to_owned());
+        }
+    }
+}
+
+// =============================================================================
+// Expression constructors
+// =============================================================================
+
+fn comparison(field: &str, op: &str, operand: Value) -> Value {
+    Value::object([("field", Value::text(field)), (op, operand)])
+}
+
+/// Builds `{ "field": field, "eq": value }`.
+#[must_use]
+pub fn eq(field: &str, value: impl Into<Value>) -> Value {
+    comparison(field, "eq", value.into())
+}
+
+/// Builds `{ "field": field, "ne": value }`.
+#[must_use]
+pub fn ne(field: &str, value: impl Into<Value>) -> Value {
+    comparison(field, "ne", value.into())
+}
+
+/// Builds `{ "field": field, "in": [values...] }`.
+#[must_use]
+pub fn one_of(field: &str, values: impl IntoIterator<Item = impl Into<Value>>) -> Value {
+    comparison(
+        field,
+        "in",
+        Value::array(values.into_iter().map(Into::into)),
+    )
+}
+
+/// Builds `{ "field": field, "gt": value }`.
+#[must_use]
+pub fn gt(field: &str, value: impl Into<Value>) -> Value {
+    comparison(field, "gt", value.into())
+}
+
+/// Builds `{ "field": field, "lt": value }`.
+#[must_use]
+pub fn lt(field: &str, value: impl Into<Value>) -> Value {
+    comparison(field, "lt", value.into())
+}
+
+/// Builds `{ "and": [exprs...] }`.
+#[must_use]
+pub fn and(exprs: impl IntoIterator<Item = Value>) -> Value {
+    Value::object([("and", Value::array(exprs))])
+}
+
+/// Builds `{ "or": [exprs...] }`.
+#[must_use]
+pub fn or(exprs: impl IntoIterator<Item = Value>) -> Value {
+    Value::object([("or", Value::array(exprs))])
+}
+
+/// Builds `{ "not": expr }`.
+#[must_use]
+pub fn not(expr: Value) -> Value {
+    Value::object([("not", expr)])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lookup_from<'a>(pairs: &'a [(&'a str, Value)]) -> impl Fn(&str) -> Option<&'a Value> {
+        move |key| pairs.iter().find(|(k, _)| *k == key).map(|(_, v)| v)
+    }
+
+    #[test]
+    fn test_evaluate_eq_ne() {
+        let values = [("mode", Value::text("advanced"))];
+        let lookup = lookup_from(&values);
+
+        assert!(evaluate(&eq("mode", "advanced"), &lookup));
+        assert!(!evaluate(&eq("mode", "basic"), &lookup));
+        assert!(evaluate(&ne("mode", "basic"), &lookup));
+    }
+
+    #[test]
+    fn test_evaluate_in() {
+        let values = [("format", Value::text("png"))];
+        let lookup = lookup_from(&values);
+
+        assert!(evaluate(&one_of("format", ["png", "jpg"]), &lookup));
+        assert!(!evaluate(&one_of("format", ["gif"]), &lookup));
+    }
+
+    #[test]
+    fn test_evaluate_numeric() {
+        let values = [("count", Value::Int(5))];
+        let lookup = lookup_from(&values);
+
+        assert!(evaluate(&gt("count", 3), &lookup));
+        assert!(!evaluate(&gt("count", 5), &lookup));
+        assert!(evaluate(&lt("count", 5.5), &lookup));
+        assert!(evaluate(&eq("count", 5.0), &lookup));
+    }
+
+    #[test]
+    fn test_evaluate_composition() {
+        let values = [("a", Value::Bool(true)), ("b", Value::Int(1))];
+        let lookup = lookup_from(&values);
+
+        assert!(evaluate(&and([eq("a", true), eq("b", 1)]), &lookup));
+        assert!(!evaluate(&and([eq("a", true), eq("b", 2)]), &lookup));
+        assert!(evaluate(&or([eq("a", false), eq("b", 1)]), &lookup));
+        assert!(evaluate(&not(eq("b", 2)), &lookup));
+    }
+
+    #[test]
+    fn test_evaluate_missing_field_is_null() {
+        let values: [(&str, Value); 0] = [];
+        let lookup = lookup_from(&values);
+
+        assert!(evaluate(&eq("missing", Value::Null), &lookup));
+        assert!(!evaluate(&gt("missing", 0), &lookup));
+    }
+
+    #[test]
+    fn test_evaluate_constants_and_malformed() {
+        let values: [(&str, Value); 0] = [];
+        let lookup = lookup_from(&values);
+
+        assert!(evaluate(&Value::Bool(true), &lookup));
+        assert!(!evaluate(&Value::Bool(false), &lookup));
+        assert!(evaluate(&Value::text("garbage"), &lookup));
+        assert!(evaluate(&Value::object([("eq", Value::Int(1))]), &lookup));
+    }
+
+    #[test]
+    fn test_dependencies() {
+        let expr = or([
+            eq("mode", "advanced"),
+            and([gt("level", 2), not(eq("mode", "basic"))]),
+        ]);
+
+        assert_eq!(dependencies(&expr), vec!["mode", "level"]);
+        assert!(dependencies(&Value::Bool(true)).is_empty());
+    }
+}